@@ -2,6 +2,7 @@ mod change;
 mod edit;
 mod editor;
 mod node;
+pub mod refactor;
 mod rule;
 
 use change::Change;
@@ -0,0 +1,493 @@
+//! Structural rewrites between the table forms of TOML.
+//!
+//! Each rewrite returns the text edits to apply to the source,
+//! and keeps the comments of the moved key-values and headers.
+
+mod array_of_tables;
+mod key_value;
+mod table;
+
+use itertools::Itertools;
+use tombi_ast::{AstNode, AstToken, DanglingCommentGroupOr};
+use tombi_syntax::SyntaxKind::{COMMENT, LINE_BREAK, WHITESPACE};
+use tombi_toml_version::TomlVersion;
+
+pub use array_of_tables::{array_of_tables_to_inline_array, inline_array_to_array_of_tables};
+pub use key_value::{dotted_keys_to_table, extract_to_table_section};
+pub use table::{table_to_dotted_keys, table_to_inline_table};
+
+const INDENT: &str = "  ";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: tombi_text::Range,
+    pub new_text: String,
+}
+
+/// A line of a table body: a key-value or a value with its comments, or only comments.
+#[derive(Debug, Default)]
+struct Entry {
+    leading_comments: Vec<String>,
+    text: Option<String>,
+    trailing_comment: Option<String>,
+}
+
+impl Entry {
+    fn comments(comments: Vec<String>) -> Self {
+        Self {
+            leading_comments: comments,
+            ..Default::default()
+        }
+    }
+
+    fn has_comments(&self) -> bool {
+        !self.leading_comments.is_empty() || self.trailing_comment.is_some()
+    }
+}
+
+/// The entries of a table body, separated into the groups of the source.
+type EntryGroups = Vec<Vec<Entry>>;
+
+fn has_comments(groups: &EntryGroups) -> bool {
+    groups.iter().flatten().any(Entry::has_comments)
+}
+
+/// The lines of the entries, with a blank line between the groups.
+fn render_lines(groups: &EntryGroups, indent: &str, separator: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (index, group) in groups.iter().filter(|group| !group.is_empty()).enumerate() {
+        if index != 0 {
+            lines.push(String::new());
+        }
+        for entry in group {
+            lines.extend(
+                entry
+                    .leading_comments
+                    .iter()
+                    .map(|comment| format!("{indent}{comment}")),
+            );
+            if let Some(text) = &entry.text {
+                let mut line = format!("{indent}{text}{separator}");
+                if let Some(comment) = &entry.trailing_comment {
+                    line.push(' ');
+                    line.push_str(comment);
+                }
+                lines.push(line);
+            }
+        }
+    }
+    lines
+}
+
+/// The inline table of the entries.
+///
+/// The inline table is written in a single line if possible,
+/// otherwise in multiple lines, which needs TOML v1.1.0 or later.
+fn render_inline_table(
+    groups: &EntryGroups,
+    brace_trailing_comment: Option<&str>,
+    indent: &str,
+    toml_version: TomlVersion,
+) -> Option<String> {
+    if !has_comments(groups) && brace_trailing_comment.is_none() {
+        let texts = groups
+            .iter()
+            .flatten()
+            .filter_map(|entry| entry.text.as_deref())
+            .collect_vec();
+        if texts.is_empty() {
+            return Some("{}".to_string());
+        }
+        return Some(format!("{{ {} }}", texts.join(", ")));
+    }
+
+    if toml_version == TomlVersion::V1_0_0 {
+        return None;
+    }
+
+    let mut text = "{".to_string();
+    if let Some(comment) = brace_trailing_comment {
+        text.push(' ');
+        text.push_str(comment);
+    }
+    for line in render_lines(groups, &format!("{indent}{INDENT}"), ",") {
+        text.push('\n');
+        text.push_str(&line);
+    }
+    text.push('\n');
+    text.push_str(indent);
+    text.push('}');
+
+    Some(text)
+}
+
+fn comment_texts(comments: impl Iterator<Item = impl AsRef<tombi_ast::Comment>>) -> Vec<String> {
+    comments
+        .map(|comment| comment.as_ref().syntax().text().to_string())
+        .collect_vec()
+}
+
+fn key_value_entry(key_value: &tombi_ast::KeyValue, prefix: &str) -> Option<Entry> {
+    Some(Entry {
+        leading_comments: comment_texts(key_value.leading_comments()),
+        text: Some(format!("{prefix}{}", core_text(key_value.syntax())?)),
+        trailing_comment: key_value
+            .trailing_comment()
+            .map(|comment| comment.syntax().text().to_string()),
+    })
+}
+
+/// The entries of the key-values of a table, an array of tables or the root table.
+fn key_value_group_entries(
+    groups: impl Iterator<Item = DanglingCommentGroupOr<tombi_ast::KeyValueGroup>>,
+    prefix: &str,
+) -> Option<EntryGroups> {
+    groups
+        .map(|group| match group {
+            DanglingCommentGroupOr::DanglingCommentGroup(comment_group) => {
+                Some(vec![Entry::comments(comment_texts(
+                    comment_group.into_comments(),
+                ))])
+            }
+            DanglingCommentGroupOr::ItemGroup(key_value_group) => key_value_group
+                .into_key_values()
+                .map(|key_value| key_value_entry(&key_value, prefix))
+                .collect(),
+        })
+        .collect()
+}
+
+/// The entries of the key-values of an inline table.
+///
+/// The key-value given as `excluded` is skipped.
+/// Returns `None` if a key-value has a trailing comment both before and after its comma.
+fn inline_table_entries(
+    inline_table: &tombi_ast::InlineTable,
+    prefix: &str,
+    excluded: Option<&tombi_ast::KeyValue>,
+) -> Option<EntryGroups> {
+    inline_table
+        .key_value_with_comma_groups()
+        .map(|group| match group {
+            DanglingCommentGroupOr::DanglingCommentGroup(comment_group) => {
+                Some(vec![Entry::comments(comment_texts(
+                    comment_group.into_comments(),
+                ))])
+            }
+            DanglingCommentGroupOr::ItemGroup(key_value_group) => key_value_group
+                .into_key_values_with_comma()
+                .filter(|(key_value, _)| Some(key_value) != excluded)
+                .map(|(key_value, comma)| {
+                    let mut entry = key_value_entry(&key_value, prefix)?;
+                    if let Some(comma) = comma {
+                        entry
+                            .leading_comments
+                            .extend(comment_texts(comma.leading_comments()));
+                        if let Some(comment) = comma.trailing_comment() {
+                            // Both comments cannot be written after the key-value.
+                            if entry.trailing_comment.is_some() {
+                                return None;
+                            }
+                            entry.trailing_comment = Some(comment.syntax().text().to_string());
+                        }
+                    }
+                    Some(entry)
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn is_trivia(token: &tombi_syntax::SyntaxToken) -> bool {
+    matches!(token.kind(), WHITESPACE | LINE_BREAK)
+}
+
+/// The text of the node without its leading comments and trailing comment.
+fn core_text(node: &tombi_syntax::SyntaxNode) -> Option<String> {
+    let tokens = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .collect_vec();
+
+    let first = tokens
+        .iter()
+        .position(|token| !is_trivia(token) && token.kind() != COMMENT)?;
+    let mut last = tokens.iter().rposition(|token| !is_trivia(token))?;
+    if tokens[last].kind() == COMMENT && node.last_token().as_ref() == Some(&tokens[last]) {
+        last = tokens[..last].iter().rposition(|token| !is_trivia(token))?;
+    }
+    if last < first {
+        return None;
+    }
+
+    Some(
+        tokens[first..=last]
+            .iter()
+            .map(|token| token.text())
+            .collect(),
+    )
+}
+
+/// The range of the node without its leading and trailing whitespaces and line breaks.
+fn trimmed_range(node: &tombi_syntax::SyntaxNode) -> Option<tombi_text::Range> {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !is_trivia(token));
+
+    let first = tokens.next()?;
+    let last = tokens.last().unwrap_or_else(|| first.clone());
+
+    Some(tombi_text::Range::new(
+        first.range().start,
+        last.range().end,
+    ))
+}
+
+/// The edit that removes the node with the line break before it.
+fn remove_node(node: &tombi_syntax::SyntaxNode) -> Option<TextEdit> {
+    let range = trimmed_range(node)?;
+    let mut prev_token = node.first_token()?.prev_token();
+    while let Some(token) = prev_token.as_ref().filter(|token| is_trivia(token)) {
+        prev_token = token.prev_token();
+    }
+
+    Some(TextEdit {
+        range: tombi_text::Range::new(
+            prev_token.map_or(tombi_text::Position::default(), |token| token.range().end),
+            range.end,
+        ),
+        new_text: String::new(),
+    })
+}
+
+/// The position after the key-values of the root table.
+fn root_key_values_end(root: &tombi_ast::Root) -> Option<tombi_text::Position> {
+    root.syntax()
+        .children()
+        .take_while(|node| tombi_ast::TableOrArrayOfTable::cast(node.clone()).is_none())
+        .filter_map(|node| trimmed_range(&node))
+        .last()
+        .map(|range| range.end)
+}
+
+/// The edit that appends the lines after the key-values of the root table.
+fn append_to_root(root: &tombi_ast::Root, lines: &[String]) -> TextEdit {
+    match root_key_values_end(root) {
+        Some(end) => TextEdit {
+            range: tombi_text::Range::at(end),
+            new_text: format!("\n{}", lines.join("\n")),
+        },
+        None => TextEdit {
+            range: tombi_text::Range::at(tombi_text::Position::default()),
+            new_text: format!("{}\n\n", lines.join("\n")),
+        },
+    }
+}
+
+/// The edit that appends the lines after the key-values of the table.
+fn append_to_table(table: &tombi_syntax::SyntaxNode, lines: &[String]) -> Option<TextEdit> {
+    Some(TextEdit {
+        range: tombi_text::Range::at(trimmed_range(table)?.end),
+        new_text: format!("\n{}", lines.join("\n")),
+    })
+}
+
+fn keys_text(keys: impl Iterator<Item = tombi_ast::Key>) -> Option<String> {
+    Some(
+        keys.map(|key| key.token().map(|token| token.text().to_string()))
+            .collect::<Option<Vec<_>>>()?
+            .join("."),
+    )
+}
+
+fn join_keys(prefix: &str, keys: &str) -> String {
+    if prefix.is_empty() {
+        keys.to_string()
+    } else {
+        format!("{prefix}.{keys}")
+    }
+}
+
+/// The table that holds the key-value and the keys of its header.
+enum Container {
+    Root(tombi_ast::Root),
+    Table(tombi_ast::Table),
+    ArrayOfTable(tombi_ast::ArrayOfTable),
+}
+
+impl Container {
+    /// The table that holds the key-value directly, not via an inline table.
+    fn of_key_value(key_value: &tombi_ast::KeyValue) -> Option<Self> {
+        let parent = key_value.syntax().parent()?;
+        let node = if tombi_ast::KeyValueGroup::cast(parent.clone()).is_some() {
+            parent.parent()?
+        } else {
+            parent
+        };
+
+        if let Some(root) = tombi_ast::Root::cast(node.clone()) {
+            Some(Container::Root(root))
+        } else if let Some(table) = tombi_ast::Table::cast(node.clone()) {
+            Some(Container::Table(table))
+        } else {
+            tombi_ast::ArrayOfTable::cast(node).map(Container::ArrayOfTable)
+        }
+    }
+
+    fn header_text(&self) -> Option<String> {
+        match self {
+            Container::Root(_) => Some(String::new()),
+            Container::Table(table) => keys_text(table.header()?.keys()),
+            Container::ArrayOfTable(array_of_table) => keys_text(array_of_table.header()?.keys()),
+        }
+    }
+
+    fn key_values(&self) -> Vec<tombi_ast::KeyValue> {
+        match self {
+            Container::Root(root) => root.key_values().collect_vec(),
+            Container::Table(table) => table.key_values().collect_vec(),
+            Container::ArrayOfTable(array_of_table) => array_of_table.key_values().collect_vec(),
+        }
+    }
+
+    /// The edit that appends the lines after the key-values of the table.
+    fn append(&self, lines: &[String]) -> Option<TextEdit> {
+        match self {
+            Container::Root(root) => Some(append_to_root(root, lines)),
+            Container::Table(table) => append_to_table(table.syntax(), lines),
+            Container::ArrayOfTable(array_of_table) => {
+                append_to_table(array_of_table.syntax(), lines)
+            }
+        }
+    }
+
+    /// The edit that appends the table sections after the key-values of the table.
+    fn append_sections(&self, sections: &[String]) -> Option<TextEdit> {
+        let mut lines = vec![String::new()];
+        lines.push(sections.join("\n\n"));
+        self.append(&lines)
+    }
+}
+
+/// The table to move the key-values of the header into, and the keys relative to it.
+enum Parent {
+    Root,
+    Table(tombi_ast::Table),
+}
+
+/// The nearest table that the header is defined under.
+///
+/// Returns `None` if the header is defined under an array of tables,
+/// because the key-values would be moved into its last element.
+fn find_parent(
+    root: &tombi_ast::Root,
+    header: &tombi_ast::Keys,
+    toml_version: TomlVersion,
+) -> Option<(Parent, String)> {
+    let accessors = header.accessors(toml_version);
+    let keys = header.keys().collect_vec();
+
+    let parent = root
+        .table_or_array_of_tables()
+        .filter_map(|table_or_array_of_table| {
+            let parent_accessors = table_or_array_of_table.header()?.accessors(toml_version);
+            (parent_accessors.len() < accessors.len() && accessors.starts_with(&parent_accessors))
+                .then_some((parent_accessors.len(), table_or_array_of_table))
+        })
+        .max_by_key(|(len, _)| *len);
+
+    match parent {
+        None => Some((Parent::Root, keys_text(keys.into_iter())?)),
+        Some((len, tombi_ast::TableOrArrayOfTable::Table(table))) => {
+            Some((Parent::Table(table), keys_text(keys.into_iter().skip(len))?))
+        }
+        Some((_, tombi_ast::TableOrArrayOfTable::ArrayOfTable(_))) => None,
+    }
+}
+
+impl Parent {
+    fn append(&self, root: &tombi_ast::Root, lines: &[String]) -> Option<TextEdit> {
+        match self {
+            Parent::Root => Some(append_to_root(root, lines)),
+            Parent::Table(table) => append_to_table(table.syntax(), lines),
+        }
+    }
+}
+
+/// Whether any other header of the document is under the header.
+fn has_sub_headers(
+    root: &tombi_ast::Root,
+    header: &tombi_ast::Keys,
+    toml_version: TomlVersion,
+) -> bool {
+    let accessors = header.accessors(toml_version);
+    root.table_or_array_of_tables()
+        .any(|table_or_array_of_table| {
+            table_or_array_of_table.header().is_some_and(|other| {
+                let other_accessors = other.accessors(toml_version);
+                other_accessors.len() > accessors.len() && other_accessors.starts_with(&accessors)
+            })
+        })
+}
+
+/// Whether any header of the document is the same as the accessors.
+fn has_header(
+    root: &tombi_ast::Root,
+    accessors: &[tombi_schema_store::Accessor],
+    toml_version: TomlVersion,
+) -> bool {
+    root.table_or_array_of_tables()
+        .any(|table_or_array_of_table| {
+            table_or_array_of_table
+                .header()
+                .is_some_and(|header| header.accessors(toml_version) == accessors)
+        })
+}
+
+fn next_non_trivia_token(
+    root: &tombi_ast::Root,
+    position: tombi_text::Position,
+) -> Option<tombi_syntax::SyntaxToken> {
+    root.syntax()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| !is_trivia(token) && token.range().start >= position)
+}
+
+/// Sort the edits and merge the adjacent ones, so that no edits share a position.
+///
+/// The edit at the start of the document does not leave blank lines at the top.
+fn merge_edits(root: &tombi_ast::Root, mut edits: Vec<TextEdit>) -> Vec<TextEdit> {
+    edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+
+    let mut merged: Vec<TextEdit> = Vec::with_capacity(edits.len());
+    for edit in edits {
+        match merged.last_mut() {
+            Some(last) if last.range.end == edit.range.start => {
+                last.range = tombi_text::Range::new(last.range.start, edit.range.end);
+                last.new_text.push_str(&edit.new_text);
+            }
+            _ => merged.push(edit),
+        }
+    }
+
+    if let Some(first) = merged
+        .first_mut()
+        .filter(|edit| edit.range.start == tombi_text::Position::default())
+    {
+        let next_token = next_non_trivia_token(root, first.range.end);
+        if first.new_text.is_empty() {
+            if let Some(token) = next_token {
+                first.range = tombi_text::Range::new(first.range.start, token.range().start);
+            }
+        } else {
+            first.new_text = first.new_text.trim_start_matches('\n').to_string();
+            if next_token.is_none() {
+                first.new_text = first.new_text.trim_end_matches('\n').to_string();
+            }
+        }
+    }
+
+    merged
+}
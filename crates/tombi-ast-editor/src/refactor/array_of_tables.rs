@@ -0,0 +1,167 @@
+use tombi_ast::{AstNode, DanglingCommentGroupOr};
+use tombi_toml_version::TomlVersion;
+
+use super::{
+    Container, Entry, INDENT, TextEdit, comment_texts, find_parent, has_sub_headers,
+    inline_table_entries, join_keys, key_value_group_entries, keys_text, merge_edits, remove_node,
+    render_inline_table, render_lines,
+};
+
+/// Convert all the `[[array.of.tables]]` with the same header into an inline array of tables.
+///
+/// ```toml
+/// [[bin]]
+/// name = "foo"
+///
+/// [[bin]]
+/// name = "bar"
+/// ```
+///
+/// ```toml
+/// bin = [
+///   { name = "foo" },
+///   { name = "bar" },
+/// ]
+/// ```
+pub fn array_of_tables_to_inline_array(
+    root: &tombi_ast::Root,
+    array_of_table: &tombi_ast::ArrayOfTable,
+    toml_version: TomlVersion,
+) -> Option<Vec<TextEdit>> {
+    let header = array_of_table.header()?;
+    if has_sub_headers(root, &header, toml_version) {
+        return None;
+    }
+    let (parent, keys) = find_parent(root, &header, toml_version)?;
+
+    let accessors = header.accessors(toml_version);
+    let mut elements = Vec::new();
+    let mut edits = Vec::new();
+    for table_or_array_of_table in root.table_or_array_of_tables() {
+        let Some(other_header) = table_or_array_of_table.header() else {
+            continue;
+        };
+        if other_header.accessors(toml_version) != accessors {
+            continue;
+        }
+        let tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) = table_or_array_of_table
+        else {
+            return None;
+        };
+
+        let groups = key_value_group_entries(array_of_table.key_value_groups(), "")?;
+        elements.push(Entry {
+            leading_comments: comment_texts(array_of_table.header_leading_comments()),
+            text: Some(render_inline_table(&groups, None, INDENT, toml_version)?),
+            trailing_comment: array_of_table
+                .header_trailing_comment()
+                .map(|comment| comment.syntax().text().to_string()),
+        });
+        edits.push(remove_node(array_of_table.syntax())?);
+    }
+
+    let mut lines = vec![format!("{keys} = [")];
+    lines.extend(render_lines(&vec![elements], INDENT, ","));
+    lines.push("]".to_string());
+    edits.push(parent.append(root, &lines)?);
+
+    Some(merge_edits(root, edits))
+}
+
+/// Convert an inline array of tables into `[[array.of.tables]]`.
+///
+/// ```toml
+/// bin = [
+///   { name = "foo" },
+///   { name = "bar" },
+/// ]
+/// ```
+///
+/// ```toml
+/// [[bin]]
+/// name = "foo"
+///
+/// [[bin]]
+/// name = "bar"
+/// ```
+pub fn inline_array_to_array_of_tables(
+    root: &tombi_ast::Root,
+    key_value: &tombi_ast::KeyValue,
+    _toml_version: TomlVersion,
+) -> Option<Vec<TextEdit>> {
+    let container = Container::of_key_value(key_value)?;
+    let tombi_ast::Value::Array(array) = key_value.value()? else {
+        return None;
+    };
+    let header = join_keys(
+        &container.header_text()?,
+        &keys_text(key_value.keys()?.keys())?,
+    );
+
+    let mut leading_comments = comment_texts(key_value.leading_comments());
+    leading_comments.extend(comment_texts(
+        array.bracket_start_trailing_comment().into_iter(),
+    ));
+
+    let mut sections = Vec::new();
+    for group in array.value_with_comma_groups() {
+        let value_group = match group {
+            DanglingCommentGroupOr::DanglingCommentGroup(comment_group) => {
+                leading_comments.extend(comment_texts(comment_group.into_comments()));
+                continue;
+            }
+            DanglingCommentGroupOr::ItemGroup(value_group) => value_group,
+        };
+
+        for (value, comma) in value_group.into_values_with_comma() {
+            let tombi_ast::Value::InlineTable(inline_table) = &value else {
+                return None;
+            };
+
+            let mut lines = std::mem::take(&mut leading_comments);
+            lines.extend(comment_texts(value.leading_comments()));
+            let mut trailing_comment = value.trailing_comment();
+            if let Some(comma) = comma {
+                lines.extend(comment_texts(comma.leading_comments()));
+                if let Some(comment) = comma.trailing_comment() {
+                    // Both comments cannot be written after the header.
+                    if trailing_comment.is_some() {
+                        return None;
+                    }
+                    trailing_comment = Some(comment);
+                }
+            }
+            lines.push(match trailing_comment {
+                Some(comment) => format!("[[{header}]] {}", comment.syntax().text()),
+                None => format!("[[{header}]]"),
+            });
+
+            let mut groups = inline_table_entries(inline_table, "", None)?;
+            if let Some(comment) = inline_table.brace_start_trailing_comment() {
+                groups.insert(
+                    0,
+                    vec![Entry::comments(vec![comment.syntax().text().to_string()])],
+                );
+            }
+            lines.extend(render_lines(&groups, "", ""));
+            sections.push(lines);
+        }
+    }
+
+    let last_section = sections.last_mut()?;
+    last_section.extend(leading_comments);
+    last_section.extend(comment_texts(key_value.trailing_comment().into_iter()));
+
+    let sections = sections
+        .into_iter()
+        .map(|lines| lines.join("\n"))
+        .collect::<Vec<_>>();
+
+    Some(merge_edits(
+        root,
+        vec![
+            remove_node(key_value.syntax())?,
+            container.append_sections(&sections)?,
+        ],
+    ))
+}
@@ -0,0 +1,161 @@
+use itertools::Itertools;
+use tombi_ast::AstNode;
+use tombi_toml_version::TomlVersion;
+
+use super::{
+    Container, Entry, TextEdit, comment_texts, core_text, has_header, inline_table_entries,
+    join_keys, keys_text, merge_edits, remove_node, render_lines,
+};
+
+/// Extract an inline table into its own `[table]` section.
+///
+/// The inline tables that hold the key-value are also converted into table sections,
+/// because the keys of an inline table cannot be added by a table header.
+///
+/// ```toml
+/// package = { name = "foo", metadata = { bar = 1 } }
+/// ```
+///
+/// ```toml
+/// [package]
+/// name = "foo"
+///
+/// [package.metadata]
+/// bar = 1
+/// ```
+pub fn extract_to_table_section(
+    root: &tombi_ast::Root,
+    key_value: &tombi_ast::KeyValue,
+    _toml_version: TomlVersion,
+) -> Option<Vec<TextEdit>> {
+    let mut key_values = vec![key_value.clone()];
+    let container = loop {
+        let inner_key_value = key_values.last()?;
+        if let Some(container) = Container::of_key_value(inner_key_value) {
+            break container;
+        }
+        let inline_table = inner_key_value
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(tombi_ast::InlineTable::cast)?;
+        key_values.push(tombi_ast::KeyValue::cast(inline_table.syntax().parent()?)?);
+    };
+    key_values.reverse();
+
+    let mut header = container.header_text()?;
+    let mut sections = Vec::new();
+    for (index, key_value) in key_values.iter().enumerate() {
+        let tombi_ast::Value::InlineTable(inline_table) = key_value.value()? else {
+            return None;
+        };
+        header = join_keys(&header, &keys_text(key_value.keys()?.keys())?);
+        let inner_key_value = key_values.get(index + 1);
+
+        let mut groups = inline_table_entries(&inline_table, "", inner_key_value)?;
+        if let Some(comment) = inline_table.brace_start_trailing_comment() {
+            groups.insert(
+                0,
+                vec![Entry::comments(vec![comment.syntax().text().to_string()])],
+            );
+        }
+        let leading_comments = comment_texts(key_value.leading_comments());
+        let trailing_comment = key_value.trailing_comment();
+
+        // The header of a table holding only the extracted table is implicit.
+        if inner_key_value.is_some()
+            && leading_comments.is_empty()
+            && trailing_comment.is_none()
+            && groups.iter().flatten().next().is_none()
+        {
+            continue;
+        }
+
+        let mut lines = leading_comments;
+        lines.push(match trailing_comment {
+            Some(comment) => format!("[{header}] {}", comment.syntax().text()),
+            None => format!("[{header}]"),
+        });
+        lines.extend(render_lines(&groups, "", ""));
+        sections.push(lines.join("\n"));
+    }
+
+    Some(merge_edits(
+        root,
+        vec![
+            remove_node(key_values.first()?.syntax())?,
+            container.append_sections(&sections)?,
+        ],
+    ))
+}
+
+/// Convert the dotted keys with the same first key into a `[table]` section.
+///
+/// ```toml
+/// package.name = "foo"
+/// package.version = "0.1.0"
+/// ```
+///
+/// ```toml
+/// [package]
+/// name = "foo"
+/// version = "0.1.0"
+/// ```
+pub fn dotted_keys_to_table(
+    root: &tombi_ast::Root,
+    key_value: &tombi_ast::KeyValue,
+    toml_version: TomlVersion,
+) -> Option<Vec<TextEdit>> {
+    let container = Container::of_key_value(key_value)?;
+    let keys = key_value.keys()?.keys().collect_vec();
+    if keys.len() < 2 {
+        return None;
+    }
+    let first_key = keys.first()?;
+    let first_accessor = first_key.accessor(toml_version);
+
+    let mut header_accessors = match &container {
+        Container::Root(_) => Vec::new(),
+        Container::Table(table) => table.header()?.accessors(toml_version),
+        Container::ArrayOfTable(array_of_table) => array_of_table.header()?.accessors(toml_version),
+    };
+    header_accessors.push(first_accessor.clone());
+    if has_header(root, &header_accessors, toml_version) {
+        return None;
+    }
+    let header = join_keys(
+        &container.header_text()?,
+        &keys_text(std::iter::once(first_key.clone()))?,
+    );
+
+    let mut entries = Vec::new();
+    let mut edits = Vec::new();
+    for key_value in container.key_values() {
+        let keys = key_value.keys()?.keys().collect_vec();
+        if keys.first().map(|key| key.accessor(toml_version)) != Some(first_accessor.clone()) {
+            continue;
+        }
+        if keys.len() < 2 {
+            return None;
+        }
+
+        entries.push(Entry {
+            leading_comments: comment_texts(key_value.leading_comments()),
+            text: Some(format!(
+                "{} = {}",
+                keys_text(keys.into_iter().skip(1))?,
+                core_text(key_value.value()?.syntax())?
+            )),
+            trailing_comment: key_value
+                .trailing_comment()
+                .map(|comment| comment.syntax().text().to_string()),
+        });
+        edits.push(remove_node(key_value.syntax())?);
+    }
+
+    let mut lines = vec![format!("[{header}]")];
+    lines.extend(render_lines(&vec![entries], "", ""));
+    edits.push(container.append_sections(&[lines.join("\n")])?);
+
+    Some(merge_edits(root, edits))
+}
@@ -0,0 +1,83 @@
+use tombi_ast::AstNode;
+use tombi_toml_version::TomlVersion;
+
+use super::{
+    TextEdit, comment_texts, find_parent, has_sub_headers, key_value_group_entries, merge_edits,
+    remove_node, render_inline_table, render_lines,
+};
+
+/// Convert a `[table]` section into an inline table under its parent table.
+///
+/// ```toml
+/// [package.metadata]
+/// foo = 1
+/// ```
+///
+/// ```toml
+/// [package]
+/// metadata = { foo = 1 }
+/// ```
+pub fn table_to_inline_table(
+    root: &tombi_ast::Root,
+    table: &tombi_ast::Table,
+    toml_version: TomlVersion,
+) -> Option<Vec<TextEdit>> {
+    let header = table.header()?;
+    if has_sub_headers(root, &header, toml_version) {
+        return None;
+    }
+    let (parent, keys) = find_parent(root, &header, toml_version)?;
+
+    let groups = key_value_group_entries(table.key_value_groups(), "")?;
+    let header_trailing_comment = table
+        .header_trailing_comment()
+        .map(|comment| comment.syntax().text().to_string());
+    let inline_table = render_inline_table(
+        &groups,
+        header_trailing_comment.as_deref(),
+        "",
+        toml_version,
+    )?;
+
+    let mut lines = comment_texts(table.header_leading_comments());
+    lines.push(format!("{keys} = {inline_table}"));
+
+    Some(merge_edits(
+        root,
+        vec![remove_node(table.syntax())?, parent.append(root, &lines)?],
+    ))
+}
+
+/// Convert a `[table]` section into dotted keys under its parent table.
+///
+/// ```toml
+/// [package.metadata]
+/// foo = 1
+/// ```
+///
+/// ```toml
+/// [package]
+/// metadata.foo = 1
+/// ```
+pub fn table_to_dotted_keys(
+    root: &tombi_ast::Root,
+    table: &tombi_ast::Table,
+    toml_version: TomlVersion,
+) -> Option<Vec<TextEdit>> {
+    let header = table.header()?;
+    if table.key_values().next().is_none() || has_sub_headers(root, &header, toml_version) {
+        return None;
+    }
+    let (parent, keys) = find_parent(root, &header, toml_version)?;
+
+    let groups = key_value_group_entries(table.key_value_groups(), &format!("{keys}."))?;
+
+    let mut lines = comment_texts(table.header_leading_comments());
+    lines.extend(comment_texts(table.header_trailing_comment().into_iter()));
+    lines.extend(render_lines(&groups, "", ""));
+
+    Some(merge_edits(
+        root,
+        vec![remove_node(table.syntax())?, parent.append(root, &lines)?],
+    ))
+}
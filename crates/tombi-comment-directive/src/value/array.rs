@@ -2,8 +2,8 @@ use std::str::FromStr;
 
 use crate::TombiCommentDirectiveImpl;
 use crate::value::{
-    ErrorRuleOptions, SortOptions, TombiValueDirectiveContent, WithCommonFormatRules,
-    WithCommonLintRules, WithKeyFormatRules, WithKeyTableLintRules,
+    ErrorRuleOptions, SortOptions, TombiValueDirectiveContent, WarnRuleOptions,
+    WithCommonFormatRules, WithCommonLintRules, WithKeyFormatRules, WithKeyTableLintRules,
 };
 use tombi_uri::SchemaUri;
 
@@ -75,4 +75,21 @@ pub struct ArrayLintRules {
     /// ```
     ///
     pub array_unique_values: Option<ErrorRuleOptions>,
+
+    /// # No duplicate array values
+    ///
+    /// Check if the array has duplicate values, regardless of the schema.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// features = ["std", "derive", "std"]
+    /// ```
+    pub no_duplicate_array_values: Option<WarnRuleOptions>,
+
+    /// # No inline table in array of tables context
+    ///
+    /// Check if an array of inline tables is defined in a document
+    /// that uses `[[array of tables]]` headers.
+    ///
+    pub no_inline_table_in_array_of_tables_context: Option<WarnRuleOptions>,
 }
@@ -178,6 +178,28 @@ pub struct TableLintRules {
     /// Check if the key is required in this Table.
     ///
     pub table_key_required: Option<ErrorRuleOptions>,
+
+    /// # Prefer table over long inline table
+    ///
+    /// Check if an inline table is longer than the maximum width.
+    ///
+    pub prefer_table_over_long_inline_table: Option<WarnRuleOptions>,
+
+    /// # Max nesting depth
+    ///
+    /// Check if tables are nested deeper than the maximum depth.
+    ///
+    pub max_nesting_depth: Option<WarnRuleOptions>,
+
+    /// # No empty tables
+    ///
+    /// Check if a table has no key-values.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// [table]
+    /// ```
+    pub no_empty_tables: Option<WarnRuleOptions>,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, serde::Deserialize)]
@@ -231,6 +253,19 @@ pub struct RootTableLintRules {
     /// ```
     pub tables_out_of_order: Option<WarnRuleOptions>,
 
+    /// # No mixed key styles
+    ///
+    /// Check if the same table is defined by both dotted keys and table headers.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// fruit.apple.color = "red"
+    ///
+    /// [fruit.apple.texture]
+    /// smooth = true
+    /// ```
+    pub no_mixed_key_styles: Option<WarnRuleOptions>,
+
     #[serde(flatten)]
     pub table: TableLintRules,
 }
@@ -101,17 +101,19 @@ macro_rules! toggle_features {
 }
 
 mod cargo;
+mod external;
 mod pyproject;
 mod tombi;
 
 pub use cargo::*;
+pub use external::*;
 pub use pyproject::*;
 pub use tombi::*;
 
 /// # Extension options
 ///
-/// 🚧 Currently, third-party extensions are not supported,
-/// and only built-in extensions are provided. 🚧
+/// Configure the built-in extensions, and register external extensions
+/// that run as separate processes.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
@@ -140,6 +142,11 @@ pub struct Extensions {
     ///
     /// Configure built-in support for `tombi.toml`.
     pub tombi: Option<TombiExtensionFeatures>,
+
+    /// # External extensions
+    ///
+    /// Executables providing language features for other TOML files.
+    pub external: Option<Vec<ExternalExtension>>,
 }
 
 impl Extensions {
@@ -175,6 +182,10 @@ impl Extensions {
     pub fn tombi_features(&self) -> Option<&TombiExtensionFeatures> {
         self.tombi.as_ref()
     }
+
+    pub fn external(&self) -> &[ExternalExtension] {
+        self.external.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
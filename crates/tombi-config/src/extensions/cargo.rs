@@ -114,11 +114,16 @@ mod tests {
     #[test]
     fn cargo_hover_feature_tree_serializes_feature_dependencies_key() {
         let value = serde_json::to_value(super::CargoHoverFeatureTree {
+            cargo_config: None,
             dependency_detail: None,
+            dependents: None,
             default_features: None,
             feature_dependencies: Some(ToggleFeatureDefaultTrue {
                 enabled: Some(false.into()),
             }),
+            lint: None,
+            patch: None,
+            target_cfg: None,
         })
         .expect("feature-dependencies should serialize");
 
@@ -133,11 +138,16 @@ mod tests {
     #[test]
     fn cargo_hover_feature_tree_serializes_default_features_key() {
         let value = serde_json::to_value(super::CargoHoverFeatureTree {
+            cargo_config: None,
             dependency_detail: None,
+            dependents: None,
             default_features: Some(ToggleFeatureDefaultTrue {
                 enabled: Some(false.into()),
             }),
             feature_dependencies: None,
+            lint: None,
+            patch: None,
+            target_cfg: None,
         })
         .expect("default-features should serialize");
 
@@ -1,7 +1,9 @@
 use crate::extensions::EnabledOnly;
 
 mod code_action;
+mod code_lens;
 mod completion;
+mod diagnostic;
 mod document_link;
 mod goto_declaration;
 mod goto_definition;
@@ -10,7 +12,9 @@ mod inlay_hint;
 mod references;
 
 pub use code_action::*;
+pub use code_lens::*;
 pub use completion::*;
+pub use diagnostic::*;
 pub use document_link::*;
 pub use goto_declaration::*;
 pub use goto_definition::*;
@@ -45,9 +49,15 @@ extension_features! {
         /// # Code action feature options
         pub code_action: Option<CargoCodeActionFeatures>,
 
+        /// # Code lens feature options
+        pub code_lens: Option<CargoCodeLensFeatures>,
+
         /// # Completion feature options
         pub completion: Option<CargoCompletionFeatures>,
 
+        /// # Diagnostic feature options
+        pub diagnostic: Option<CargoDiagnosticFeatures>,
+
         /// # Document link feature options
         pub document_link: Option<CargoDocumentLinkFeatures>,
 
@@ -48,5 +48,10 @@ toggle_features! {
         ///
         /// Whether code actions can rewrite dependency versions to the latest published version.
         pub update_dependency_to_latest_version: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Patch-dependency code action feature
+        ///
+        /// Whether code actions can add a `[patch]` entry with a local path or git branch for a dependency.
+        pub patch_dependency: Option<ToggleFeatureDefaultTrue>,
     }
 }
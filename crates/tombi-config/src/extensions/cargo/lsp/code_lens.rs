@@ -0,0 +1,43 @@
+use crate::extensions::{EnabledOnly, ToggleFeatureDefaultTrue};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "jsonschema",
+    schemars(extend(
+        "x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Ascending
+    ))
+)]
+pub enum CargoCodeLensFeatures {
+    Enabled(EnabledOnly),
+    Features(CargoCodeLensFeatureTree),
+}
+
+toggle_features! {
+    CargoCodeLensFeatures,
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+    #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(extend(
+            "x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Ascending
+        ))
+    )]
+    pub struct CargoCodeLensFeatureTree {
+        /// # Workspace dependency usage code lens feature
+        ///
+        /// Whether code lenses show how many workspace members use a `[workspace.dependencies]` entry.
+        pub workspace_dependency_usage: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Dependency features code lens feature
+        ///
+        /// Whether code lenses show how many features are enabled for a dependency.
+        pub dependency_features: Option<ToggleFeatureDefaultTrue>,
+    }
+}
@@ -24,6 +24,11 @@ toggle_features! {
         ))
     )]
     pub struct CargoCompletionFeatureTree {
+        /// # Cargo config completion feature
+        ///
+        /// Whether completion suggests keys and target triples in `.cargo/config.toml`.
+        pub cargo_config: Option<ToggleFeatureDefaultTrue>,
+
         /// # Dependency version completion feature
         ///
         /// Whether completion suggests dependency versions.
@@ -34,9 +39,24 @@ toggle_features! {
         /// Whether completion suggests dependency features.
         pub dependency_feature: Option<ToggleFeatureDefaultTrue>,
 
+        /// # Lint completion feature
+        ///
+        /// Whether completion suggests rustc, Clippy and rustdoc lint names in `[lints]`.
+        pub lint: Option<ToggleFeatureDefaultTrue>,
+
         /// # Path completion feature
         ///
         /// Whether completion suggests filesystem paths.
         pub path: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Rust toolchain completion feature
+        ///
+        /// Whether completion suggests channels, components and targets in `rust-toolchain.toml`.
+        pub rust_toolchain: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Target cfg completion feature
+        ///
+        /// Whether completion suggests cfg names and values in `[target.'cfg(...)']` keys.
+        pub target_cfg: Option<ToggleFeatureDefaultTrue>,
     }
 }
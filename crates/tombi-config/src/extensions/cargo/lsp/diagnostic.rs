@@ -0,0 +1,45 @@
+use crate::{
+    ToggleFeatureDefaultFalse,
+    extensions::{EnabledOnly, ToggleFeatureDefaultTrue},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CargoDiagnosticFeatures {
+    Enabled(EnabledOnly),
+    Features(CargoDiagnosticFeatureTree),
+}
+
+toggle_features! {
+    CargoDiagnosticFeatures,
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+    #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(extend(
+            "x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Ascending
+        ))
+    )]
+    pub struct CargoDiagnosticFeatureTree {
+        /// # Duplicate version diagnostic feature
+        ///
+        /// Whether diagnostics warn about dependencies that resolve to multiple versions in `Cargo.lock`.
+        pub duplicate_version: Option<ToggleFeatureDefaultFalse>,
+
+        /// # Unused feature diagnostic feature
+        ///
+        /// Whether diagnostics warn about features that no other feature, workspace member or `default` enables.
+        pub unused_feature: Option<ToggleFeatureDefaultFalse>,
+
+        /// # Unused patch diagnostic feature
+        ///
+        /// Whether diagnostics warn about `[patch]` and `[replace]` entries that no dependency in `Cargo.lock` uses.
+        pub unused_patch: Option<ToggleFeatureDefaultTrue>,
+    }
+}
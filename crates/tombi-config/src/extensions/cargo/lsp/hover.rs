@@ -30,11 +30,21 @@ toggle_features! {
         ))
     )]
     pub struct CargoHoverFeatureTree {
+        /// # Cargo config hover feature
+        ///
+        /// Whether hover shows the documentation of keys in `.cargo/config.toml`.
+        pub cargo_config: Option<ToggleFeatureDefaultTrue>,
+
         /// # Dependency detail hover feature
         ///
         /// Whether hover shows detailed dependency metadata.
         pub dependency_detail: Option<ToggleFeatureDefaultTrue>,
 
+        /// # Dependents hover feature
+        ///
+        /// Whether hover on a dependency lists the `Cargo.lock` packages that pull it in.
+        pub dependents: Option<ToggleFeatureDefaultTrue>,
+
         /// # Default features hover feature
         ///
         /// Whether hover shows default Cargo dependency features.
@@ -44,5 +54,20 @@ toggle_features! {
         ///
         /// Whether hover shows dependencies of the selected Cargo feature.
         pub feature_dependencies: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Lint hover feature
+        ///
+        /// Whether hover shows the description and default level of lints in `[lints]`.
+        pub lint: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Patch hover feature
+        ///
+        /// Whether hover on a `[patch]` or `[replace]` entry compares the patched version with `Cargo.lock`.
+        pub patch: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Target cfg hover feature
+        ///
+        /// Whether hover shows the built-in targets matched by `[target.'cfg(...)']` keys.
+        pub target_cfg: Option<ToggleFeatureDefaultTrue>,
     }
 }
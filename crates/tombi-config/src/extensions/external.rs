@@ -0,0 +1,69 @@
+use crate::{BoolDefaultTrue, GlobPattern};
+
+/// # External extension
+///
+/// An executable that provides language features for the matched TOML files.
+/// Tombi starts it on demand and talks to it with JSON-RPC 2.0 over stdio,
+/// one message per line.
+///
+/// Only the user or system config registers external extensions.
+/// They are ignored in a project config, so opening a checkout never runs its commands.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "jsonschema", schemars(extend("x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Schema)))]
+pub struct ExternalExtension {
+    /// # Extension name
+    ///
+    /// The name used in logs, such as `"acme/deploy"`.
+    #[cfg_attr(feature = "jsonschema", schemars(length(min = 1)))]
+    pub name: String,
+
+    /// # Enable the extension
+    pub enabled: Option<BoolDefaultTrue>,
+
+    /// # The command to start the extension
+    ///
+    /// The executable is looked up in `PATH` unless it contains a path separator.
+    /// A relative path is resolved against the directory of the config file.
+    #[cfg_attr(feature = "jsonschema", schemars(length(min = 1)))]
+    pub command: String,
+
+    /// # The arguments of the command
+    pub args: Option<Vec<String>>,
+
+    /// # The file match pattern of the extension
+    ///
+    /// The file match pattern of the TOML files the extension handles.
+    /// Supports glob pattern.
+    #[cfg_attr(feature = "jsonschema", schemars(length(min = 1)))]
+    pub include: Vec<GlobPattern>,
+}
+
+impl ExternalExtension {
+    pub fn enabled(&self) -> BoolDefaultTrue {
+        self.enabled.unwrap_or_default()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn external_extension_deserializes() {
+        let extension: ExternalExtension = serde_json::from_value(serde_json::json!({
+            "name": "acme/deploy",
+            "command": "acme-tombi-extension",
+            "args": ["--stdio"],
+            "include": ["deploy/*.toml"]
+        }))
+        .expect("external extension should deserialize");
+
+        assert_eq!(extension.name, "acme/deploy");
+        assert_eq!(extension.args, Some(vec!["--stdio".to_string()]));
+        assert!(extension.enabled().value());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::extensions::EnabledOnly;
 
 mod code_action;
+mod code_lens;
 mod completion;
 mod document_link;
 mod goto_declaration;
@@ -10,6 +11,7 @@ mod inlay_hint;
 mod references;
 
 pub use code_action::*;
+pub use code_lens::*;
 pub use completion::*;
 pub use document_link::*;
 pub use goto_declaration::*;
@@ -47,6 +49,11 @@ extension_features! {
         /// Configure pyproject code action features.
         pub code_action: Option<PyprojectCodeActionFeatures>,
 
+        /// # Code lens feature options
+        ///
+        /// Configure pyproject code lens features.
+        pub code_lens: Option<PyprojectCodeLensFeatures>,
+
         /// # Completion feature options
         ///
         /// Configure pyproject completion features.
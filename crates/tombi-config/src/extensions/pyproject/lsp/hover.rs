@@ -34,5 +34,10 @@ toggle_features! {
         ///
         /// Whether hover shows detailed dependency metadata.
         pub dependency_detail: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Dependents hover feature
+        ///
+        /// Whether hover on a `uv.lock` or `poetry.lock` package lists the packages that pull it in.
+        pub dependents: Option<ToggleFeatureDefaultTrue>,
     }
 }
@@ -112,6 +112,15 @@ impl Config {
             .and_then(Extensions::cargo_features)
     }
 
+    pub fn cargo_code_lens_enabled(&self) -> bool {
+        self.cargo_extension_features()
+            .and_then(|features| features.lsp())
+            .and_then(|lsp| lsp.code_lens())
+            .map(|code_lens| code_lens.enabled())
+            .unwrap_or_default()
+            .value()
+    }
+
     pub fn cargo_inlay_hint_enabled(&self) -> bool {
         self.cargo_extension_features()
             .and_then(|features| features.lsp())
@@ -135,6 +144,15 @@ impl Config {
             .and_then(Extensions::pyproject_features)
     }
 
+    pub fn pyproject_code_lens_enabled(&self) -> bool {
+        self.pyproject_extension_features()
+            .and_then(|features| features.lsp())
+            .and_then(|lsp| lsp.code_lens())
+            .map(|code_lens| code_lens.enabled())
+            .unwrap_or_default()
+            .value()
+    }
+
     pub fn pyproject_inlay_hint_enabled(&self) -> bool {
         self.pyproject_extension_features()
             .and_then(|features| features.lsp())
@@ -158,6 +176,14 @@ impl Config {
             .and_then(Extensions::tombi_features)
     }
 
+    /// The enabled external extensions.
+    pub fn external_extensions(&self) -> impl Iterator<Item = &ExternalExtension> {
+        self.extensions
+            .iter()
+            .flat_map(Extensions::external)
+            .filter(|extension| extension.enabled().value())
+    }
+
     pub fn merge_format(&self, override_options: Option<&OverrideFormatOptions>) -> FormatOptions {
         let options = self.format.clone().unwrap_or_default();
         let base_rules = options.rules.unwrap_or_default();
@@ -1,4 +1,6 @@
-use tombi_severity_level::SeverityLevelDefaultWarn;
+use tombi_severity_level::{SeverityLevelDefaultOff, SeverityLevelDefaultWarn};
+
+use crate::{LineWidth, MaxNestingDepth};
 
 /// # Linter options
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -70,6 +72,97 @@ pub struct LintRules {
     /// [animal]
     /// ```
     pub tables_out_of_order: Option<SeverityLevelDefaultWarn>,
+
+    /// # No inline table in array of tables context
+    ///
+    /// Check if an array of inline tables is defined in a document
+    /// that uses `[[array of tables]]` headers.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// [[fruits]]
+    /// name = "apple"
+    ///
+    /// [vegetables]
+    /// items = [{ name = "carrot" }]
+    ///
+    /// # RECOMMENDED
+    /// [[fruits]]
+    /// name = "apple"
+    ///
+    /// [[vegetables.items]]
+    /// name = "carrot"
+    /// ```
+    pub no_inline_table_in_array_of_tables_context: Option<SeverityLevelDefaultOff>,
+
+    /// # Prefer table over long inline table
+    ///
+    /// Check if an inline table is longer than the maximum width.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// dependency = { version = "1.0.0", features = ["derive", "std"], default-features = false }
+    ///
+    /// # RECOMMENDED
+    /// [dependency]
+    /// version = "1.0.0"
+    /// features = ["derive", "std"]
+    /// default-features = false
+    /// ```
+    pub prefer_table_over_long_inline_table: Option<PreferTableOverLongInlineTableRuleOptions>,
+
+    /// # Max nesting depth
+    ///
+    /// Check if tables are nested deeper than the maximum depth.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED (max-depth = 2)
+    /// [a.b.c]
+    /// d = 1
+    /// ```
+    pub max_nesting_depth: Option<MaxNestingDepthRuleOptions>,
+
+    /// # No mixed key styles
+    ///
+    /// Check if the same table is defined by both dotted keys and table headers.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// fruit.apple.color = "red"
+    ///
+    /// [fruit.apple.texture]
+    /// smooth = true
+    ///
+    /// # RECOMMENDED
+    /// [fruit.apple]
+    /// color = "red"
+    ///
+    /// [fruit.apple.texture]
+    /// smooth = true
+    /// ```
+    pub no_mixed_key_styles: Option<SeverityLevelDefaultOff>,
+
+    /// # No empty tables
+    ///
+    /// Check if a table has no key-values.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// [table]
+    ///
+    /// inline-table = {}
+    /// ```
+    pub no_empty_tables: Option<SeverityLevelDefaultOff>,
+
+    /// # No duplicate array values
+    ///
+    /// Check if an array has duplicate values.
+    ///
+    /// ```toml
+    /// # VALID BUT DISCOURAGED
+    /// features = ["std", "derive", "std"]
+    /// ```
+    pub no_duplicate_array_values: Option<SeverityLevelDefaultOff>,
 }
 
 impl LintRules {
@@ -82,6 +175,56 @@ impl LintRules {
             tables_out_of_order: self
                 .tables_out_of_order
                 .or(override_rules.tables_out_of_order),
+            no_inline_table_in_array_of_tables_context: self
+                .no_inline_table_in_array_of_tables_context
+                .or(override_rules.no_inline_table_in_array_of_tables_context),
+            prefer_table_over_long_inline_table: self
+                .prefer_table_over_long_inline_table
+                .or(override_rules.prefer_table_over_long_inline_table),
+            max_nesting_depth: self.max_nesting_depth.or(override_rules.max_nesting_depth),
+            no_mixed_key_styles: self
+                .no_mixed_key_styles
+                .or(override_rules.no_mixed_key_styles),
+            no_empty_tables: self.no_empty_tables.or(override_rules.no_empty_tables),
+            no_duplicate_array_values: self
+                .no_duplicate_array_values
+                .or(override_rules.no_duplicate_array_values),
         }
     }
 }
+
+/// # Prefer table over long inline table options
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "jsonschema", schemars(extend("x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Schema)))]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PreferTableOverLongInlineTableRuleOptions {
+    /// # Severity level
+    pub level: Option<SeverityLevelDefaultWarn>,
+
+    /// # The maximum width of an inline table
+    ///
+    /// Inline tables longer than this width are reported.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "LineWidth::default"))]
+    pub max_width: Option<LineWidth>,
+}
+
+/// # Max nesting depth options
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "jsonschema", schemars(extend("x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Schema)))]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MaxNestingDepthRuleOptions {
+    /// # Severity level
+    pub level: Option<SeverityLevelDefaultWarn>,
+
+    /// # The maximum nesting depth
+    ///
+    /// The number of keys from the root to the deepest table.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "MaxNestingDepth::default"))]
+    pub max_depth: Option<MaxNestingDepth>,
+}
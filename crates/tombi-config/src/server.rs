@@ -11,6 +11,9 @@ pub struct LspOptions {
     /// # Code Action Feature options
     pub code_action: Option<LspCodeAction>,
 
+    /// # Code Lens Feature options
+    pub code_lens: Option<LspCodeLens>,
+
     /// # Completion Feature options
     pub completion: Option<LspCompletion>,
 
@@ -46,6 +49,7 @@ impl LspOptions {
     pub const fn default() -> Self {
         Self {
             code_action: None,
+            code_lens: None,
             completion: None,
             diagnostic: None,
             document_link: None,
@@ -84,6 +88,18 @@ pub struct LspCodeAction {
     pub enabled: Option<BoolDefaultTrue>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LspCodeLens {
+    /// # Enable code lens feature
+    ///
+    /// Whether to enable code lens.
+    pub enabled: Option<BoolDefaultTrue>,
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
@@ -118,6 +134,13 @@ pub struct LspDiagnostic {
     ///
     /// Whether to enable diagnostic.
     pub enabled: Option<BoolDefaultTrue>,
+
+    /// # Baseline file
+    ///
+    /// The path to a baseline file written by `tombi lint --write-baseline`,
+    /// relative to the config file.
+    /// The diagnostics recorded in the baseline are greyed out as unnecessary.
+    pub baseline: Option<String>,
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
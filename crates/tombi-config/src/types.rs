@@ -12,6 +12,7 @@ mod inline_table_comma_space_width;
 mod key_value_equals_sign_space_width;
 mod line_ending;
 mod line_width;
+mod max_nesting_depth;
 mod one_or_many;
 mod schema_catalog_path;
 mod string_quote_style;
@@ -31,6 +32,7 @@ pub use inline_table_comma_space_width::InlineTableCommaSpaceWidth;
 pub use key_value_equals_sign_space_width::KeyValueEqualsSignSpaceWidth;
 pub use line_ending::LineEnding;
 pub use line_width::LineWidth;
+pub use max_nesting_depth::MaxNestingDepth;
 pub use one_or_many::OneOrMany;
 pub use schema_catalog_path::{
     JSON_SCHEMASTORE_CATALOG_URL, SchemaCatalogPath, TOMBI_SCHEMASTORE_CATALOG_URL,
@@ -0,0 +1,31 @@
+use tombi_text::{FromLsp, IntoLsp};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeLens {
+    pub range: tombi_text::Range,
+    pub title: String,
+
+    /// The command to run when the lens is clicked.
+    ///
+    /// `None` shows the title only.
+    pub command: Option<String>,
+    pub arguments: Option<Vec<serde_json::Value>>,
+}
+
+impl FromLsp<CodeLens> for tower_lsp::lsp_types::CodeLens {
+    fn from_lsp(
+        source: CodeLens,
+        line_index: &tombi_text::LineIndex,
+    ) -> tower_lsp::lsp_types::CodeLens {
+        tower_lsp::lsp_types::CodeLens {
+            range: source.range.into_lsp(line_index),
+            command: Some(tower_lsp::lsp_types::Command {
+                title: source.title,
+                // An empty command makes the lens a label.
+                command: source.command.unwrap_or_default(),
+                arguments: source.arguments,
+            }),
+            data: None,
+        }
+    }
+}
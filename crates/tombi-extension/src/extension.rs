@@ -0,0 +1,168 @@
+use tombi_config::{Config, TomlVersion};
+use tombi_future::{BoxFuture, Boxable};
+use tombi_schema_store::{Accessor, AccessorContext};
+use tower_lsp::lsp_types::CodeActionOrCommand;
+
+use crate::{
+    CodeLens, CommentContext, CompletionContent, CompletionHint, DocumentLink, HoverMetadata,
+    InlayHint, Location,
+};
+
+/// The document an extension hook is called for.
+#[derive(Debug, Clone, Copy)]
+pub struct ExtensionContext<'a> {
+    pub text_document_uri: &'a tombi_uri::Uri,
+    pub document_tree: &'a tombi_document_tree::DocumentTree,
+    pub toml_version: TomlVersion,
+    pub config: &'a Config,
+    pub offline: bool,
+    pub cache_options: Option<&'a tombi_cache::Options>,
+}
+
+/// A language feature provider for a specific kind of TOML file, such as `Cargo.toml`.
+///
+/// Hooks are only called for documents matching [`Extension::file_patterns`]
+/// while [`Extension::enabled`] is true, and default to contributing nothing.
+pub trait Extension: Send + Sync {
+    /// The extension name used in logs.
+    fn name(&self) -> &'static str;
+
+    /// File patterns of the documents this extension handles.
+    ///
+    /// Patterns without a `*` match the file name in any directory, like `fileMatch` of schemas.
+    fn file_patterns(&self) -> &[&str];
+
+    fn enabled(&self, _config: &Config) -> bool {
+        true
+    }
+
+    /// Start background work for an opened document.
+    ///
+    /// The returned task resolves to whether inlay hints should be refreshed.
+    fn did_open<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<tokio::task::JoinHandle<bool>>, tower_lsp::jsonrpc::Error>>
+    {
+        async { Ok(None) }.boxed()
+    }
+
+    fn hover<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _accessors: &'a [Accessor],
+        _position: tombi_text::Position,
+    ) -> BoxFuture<'a, Result<Option<HoverMetadata>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    fn completion<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _accessors: &'a [Accessor],
+        _position: tombi_text::Position,
+        _completion_hint: Option<CompletionHint>,
+        _comment_context: Option<&'a CommentContext>,
+    ) -> BoxFuture<'a, Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    fn code_action<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _root: &'a tombi_ast::Root,
+        _line_index: &'a tombi_text::LineIndex,
+        _accessors: &'a [Accessor],
+        _accessor_contexts: &'a [AccessorContext],
+    ) -> BoxFuture<'a, Result<Option<Vec<CodeActionOrCommand>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    fn code_lens<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<Vec<CodeLens>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    fn inlay_hint<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _visible_range: tombi_text::Range,
+    ) -> BoxFuture<'a, Result<Option<Vec<InlayHint>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    fn goto_definition<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    fn goto_declaration<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    fn references<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    /// The declaration at the accessors itself, included in references on request.
+    fn current_declaration(
+        &self,
+        _context: &ExtensionContext<'_>,
+        _accessors: &[Accessor],
+    ) -> Option<Location> {
+        None
+    }
+
+    /// The document symbol name of an array element, such as `name@version` for lockfile packages.
+    ///
+    /// Elements are named by their index when no extension names them.
+    fn document_symbol_name(
+        &self,
+        _context: &ExtensionContext<'_>,
+        _accessors: &[Accessor],
+        _value: &tombi_document_tree::Value,
+    ) -> Option<String> {
+        None
+    }
+
+    fn document_link<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<Vec<DocumentLink>>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    /// Answer a custom request of the client about the document, such as `tombi/cargoFeatureGraph`.
+    ///
+    /// `params` are the request params other than `textDocument`.
+    /// Returns `None` for the methods the extension does not handle.
+    fn custom_request<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+        _method: &'a str,
+        _params: &'a serde_json::Value,
+    ) -> BoxFuture<'a, Result<Option<serde_json::Value>, tower_lsp::jsonrpc::Error>> {
+        async { Ok(None) }.boxed()
+    }
+
+    /// Report problems the schema cannot express.
+    fn lint<'a>(
+        &'a self,
+        _context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Vec<tombi_diagnostic::Diagnostic>> {
+        async { Vec::new() }.boxed()
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use tombi_config::Config;
+
+use crate::Extension;
+
+/// The extensions available to the language server and the linter.
+#[derive(Clone, Default)]
+pub struct ExtensionRegistry {
+    entries: Vec<ExtensionEntry>,
+}
+
+#[derive(Clone)]
+struct ExtensionEntry {
+    extension: Arc<dyn Extension>,
+    file_patterns: Vec<glob::Pattern>,
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an extension.
+    ///
+    /// Extensions are called in registration order, which decides the winner
+    /// of features where only one result is used, such as hover.
+    pub fn register(&mut self, extension: impl Extension + 'static) {
+        self.register_arc(Arc::new(extension));
+    }
+
+    pub fn register_arc(&mut self, extension: Arc<dyn Extension>) {
+        let file_patterns = extension
+            .file_patterns()
+            .iter()
+            .filter_map(|pattern| {
+                let pattern = glob_pattern_for_file_pattern(pattern);
+                glob::Pattern::new(&pattern)
+                    .inspect_err(|err| {
+                        log::warn!(
+                            "Invalid file pattern {pattern:?} of {} extension: {err}",
+                            extension.name()
+                        )
+                    })
+                    .ok()
+            })
+            .collect();
+
+        self.entries.push(ExtensionEntry {
+            extension,
+            file_patterns,
+        });
+    }
+
+    #[inline]
+    pub fn with(mut self, extension: impl Extension + 'static) -> Self {
+        self.register(extension);
+        self
+    }
+
+    /// The enabled extensions handling the document.
+    pub fn extensions_for<'a>(
+        &'a self,
+        text_document_uri: &'a tombi_uri::Uri,
+        config: &'a Config,
+    ) -> impl Iterator<Item = &'a dyn Extension> + 'a {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                entry
+                    .file_patterns
+                    .iter()
+                    .any(|pattern| pattern.matches(text_document_uri.path()))
+            })
+            .map(|entry| entry.extension.as_ref())
+            .filter(|extension| extension.enabled(config))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl std::fmt::Debug for ExtensionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.entries.iter().map(|entry| entry.extension.name()))
+            .finish()
+    }
+}
+
+fn glob_pattern_for_file_pattern(pattern: &str) -> String {
+    if pattern.contains('*') || pattern.starts_with('/') {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    struct TestExtension;
+
+    impl Extension for TestExtension {
+        fn name(&self) -> &'static str {
+            "test"
+        }
+
+        fn file_patterns(&self) -> &[&str] {
+            &["Cargo.toml", "**/.cargo/config.toml"]
+        }
+
+        fn enabled(&self, config: &Config) -> bool {
+            config.toml_version.is_none()
+        }
+    }
+
+    fn extension_names(registry: &ExtensionRegistry, uri: &str, config: &Config) -> Vec<String> {
+        let uri = tombi_uri::Uri::from_str(uri).unwrap();
+        registry
+            .extensions_for(&uri, config)
+            .map(|extension| extension.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn extensions_for_matches_file_patterns() {
+        let registry = ExtensionRegistry::new().with(TestExtension);
+        let config = Config::default();
+
+        assert_eq!(
+            extension_names(&registry, "file:///project/Cargo.toml", &config),
+            vec!["test"]
+        );
+        assert_eq!(
+            extension_names(&registry, "file:///project/.cargo/config.toml", &config),
+            vec!["test"]
+        );
+        assert!(extension_names(&registry, "file:///project/pyproject.toml", &config).is_empty());
+        assert!(extension_names(&registry, "file:///project/MyCargo.toml.bak", &config).is_empty());
+    }
+
+    #[test]
+    fn extensions_for_skips_disabled_extensions() {
+        let registry = ExtensionRegistry::new().with(TestExtension);
+        let mut config = Config::default();
+        config.toml_version = Some(tombi_config::TomlVersion::V1_0_0);
+
+        assert!(extension_names(&registry, "file:///project/Cargo.toml", &config).is_empty());
+    }
+}
@@ -1,5 +1,8 @@
+mod code_lens;
 mod completion;
 mod document_link;
+mod extension;
+mod extension_registry;
 mod hover;
 mod inlay_hint;
 mod json_cache;
@@ -8,8 +11,11 @@ mod location;
 pub mod remote_cache;
 mod text_edit;
 
+pub use code_lens::*;
 pub use completion::*;
 pub use document_link::*;
+pub use extension::{Extension, ExtensionContext};
+pub use extension_registry::ExtensionRegistry;
 pub use hover::*;
 pub use inlay_hint::*;
 pub use json_cache::{file_cache_version, get_or_load_json};
@@ -23,5 +29,3 @@ pub use text_edit::TextEdit;
 pub use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOrCommand, DocumentChanges, OneOf, WorkspaceEdit,
 };
-
-pub trait Extension {}
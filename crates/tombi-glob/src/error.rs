@@ -24,6 +24,9 @@ pub enum Error {
     #[error("Search root path is not a directory: '{path}'")]
     RootPathNotDirectory { path: PathBuf },
 
+    #[error("Failed to run `git {command}`: {message}")]
+    GitCommand { command: String, message: String },
+
     #[error("Failed to acquire thread synchronization lock")]
     LockError,
 }
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use tombi_config::{Config, ConfigLevel, FilesOptions, GlobPattern, config_base_dir};
 
-use crate::WalkDir;
+use crate::{GitFilter, WalkDir};
 
 /// Input source for TOML files.
 ///
@@ -45,13 +45,7 @@ impl FileSearch {
         config_path: Option<&std::path::Path>,
         config_level: ConfigLevel,
     ) -> Self {
-        let root = match config_level {
-            ConfigLevel::Project => config_path
-                .and_then(config_base_dir)
-                .map(PathBuf::from)
-                .unwrap_or_else(|| PathBuf::from(".")),
-            _ => PathBuf::from("."),
-        };
+        let root = search_root(config_path, config_level);
         let files_options = config.files.clone().unwrap_or_default();
 
         match FileInputType::from(files) {
@@ -108,6 +102,76 @@ impl FileSearch {
         }
     }
 
+    /// Search the files selected by the git filter instead of walking the project.
+    ///
+    /// The selected files are still limited to the file arguments and to `files.include`/`files.exclude`.
+    pub async fn new_with_git_filter<T: AsRef<str>>(
+        files: &[T],
+        config: &Config,
+        config_path: Option<&std::path::Path>,
+        config_level: ConfigLevel,
+        git_filter: &GitFilter,
+    ) -> Self {
+        if FileInputType::from(files) == FileInputType::Stdin {
+            return FileSearch::Stdin;
+        }
+
+        let root = search_root(config_path, config_level);
+        let changed_paths = match git_filter.changed_paths(&root).await {
+            Ok(changed_paths) => changed_paths,
+            Err(err) => return FileSearch::Files(vec![FileSearchEntry::Error(err)]),
+        };
+        log::debug!("Files selected by {git_filter:?}: {changed_paths:?}");
+
+        FileSearch::new_with_paths(files, config, config_path, config_level, changed_paths)
+    }
+
+    /// Search the given paths instead of walking the project, e.g. the files changed while watching.
+    ///
+    /// The paths are still limited to the existing files, to the file arguments and to `files.include`/`files.exclude`.
+    pub fn new_with_paths<T: AsRef<str>>(
+        files: &[T],
+        config: &Config,
+        config_path: Option<&std::path::Path>,
+        config_level: ConfigLevel,
+        paths: Vec<PathBuf>,
+    ) -> Self {
+        if FileInputType::from(files) == FileInputType::Stdin {
+            return FileSearch::Stdin;
+        }
+
+        let root = search_root(config_path, config_level);
+        let root = root.canonicalize().unwrap_or(root);
+        let files_options = config.files.clone().unwrap_or_default();
+        let include_patterns = files_options.include.unwrap_or_default();
+        let exclude_patterns = files_options.exclude.unwrap_or_default();
+
+        FileSearch::Files(
+            paths
+                .into_iter()
+                .filter(|path| {
+                    let path_for_patterns = crate::pattern::path_for_patterns(path, &root);
+
+                    path.is_file()
+                        && (include_patterns.is_empty()
+                            || crate::pattern::matches_any_pattern(
+                                path_for_patterns.as_ref(),
+                                &include_patterns,
+                            ))
+                        && !crate::pattern::matches_any_pattern(
+                            path_for_patterns.as_ref(),
+                            &exclude_patterns,
+                        )
+                        && (files.is_empty()
+                            || files.iter().any(|file_input| {
+                                matches_file_input(path, &path_for_patterns, file_input.as_ref())
+                            }))
+                })
+                .map(FileSearchEntry::Found)
+                .collect(),
+        )
+    }
+
     pub fn len(&self) -> usize {
         match self {
             FileSearch::Stdin => 1,
@@ -138,6 +202,27 @@ pub async fn search_pattern_matched_paths<P: AsRef<std::path::Path>>(
     }
 }
 
+fn search_root(config_path: Option<&std::path::Path>, config_level: ConfigLevel) -> PathBuf {
+    match config_level {
+        ConfigLevel::Project => config_path
+            .and_then(config_base_dir)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(".")),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Check if the path is selected by a file argument: a glob pattern, a file or a directory.
+fn matches_file_input(path: &Path, path_for_patterns: &str, file_input: &str) -> bool {
+    if is_glob_pattern(file_input) {
+        return crate::pattern::matches_any_pattern(path_for_patterns, &[file_input]);
+    }
+
+    let file_input = PathBuf::from(file_input);
+    let file_input = file_input.canonicalize().unwrap_or(file_input);
+    path.starts_with(file_input)
+}
+
 fn is_excluded(path: &Path, root: &Path, exclude_patterns: Option<&[GlobPattern]>) -> bool {
     exclude_patterns
         .is_some_and(|patterns| crate::pattern::path_matches_patterns(path, root, patterns))
@@ -0,0 +1,203 @@
+use std::path::{Path, PathBuf};
+
+/// Select the files touched in the git working tree instead of walking the whole project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitFilter {
+    /// Files changed in the working tree or in the index, and untracked files.
+    Changed,
+
+    /// Files staged in the index.
+    Staged,
+
+    /// Files changed since the merge base with the revision, including uncommitted and untracked files.
+    Since(String),
+}
+
+impl GitFilter {
+    /// Get the absolute paths of the existing files selected by the filter.
+    ///
+    /// `cwd` is any directory inside the git repository.
+    pub async fn changed_paths(&self, cwd: &Path) -> Result<Vec<PathBuf>, crate::Error> {
+        let toplevel = PathBuf::from(
+            git(cwd, &["rev-parse", "--show-toplevel"])
+                .await?
+                .trim_end_matches(['\n', '\r']),
+        );
+
+        // `--diff-filter=d` drops deleted files, there is nothing to check in them.
+        let mut outputs = Vec::new();
+        match self {
+            GitFilter::Changed => {
+                outputs.push(git(cwd, &["diff", "--name-only", "-z", "--diff-filter=d"]).await?);
+                outputs.push(
+                    git(
+                        cwd,
+                        &["diff", "--name-only", "-z", "--diff-filter=d", "--cached"],
+                    )
+                    .await?,
+                );
+                outputs.push(untracked_files(cwd).await?);
+            }
+            GitFilter::Staged => {
+                outputs.push(
+                    git(
+                        cwd,
+                        &["diff", "--name-only", "-z", "--diff-filter=d", "--cached"],
+                    )
+                    .await?,
+                );
+            }
+            GitFilter::Since(rev) => {
+                outputs.push(
+                    git(
+                        cwd,
+                        &[
+                            "diff",
+                            "--name-only",
+                            "-z",
+                            "--diff-filter=d",
+                            "--merge-base",
+                            rev,
+                        ],
+                    )
+                    .await?,
+                );
+                outputs.push(untracked_files(cwd).await?);
+            }
+        }
+
+        let mut paths = outputs
+            .iter()
+            .flat_map(|output| output.split('\0'))
+            .filter(|path| !path.is_empty())
+            .map(|path| toplevel.join(path))
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths.dedup();
+
+        Ok(paths)
+    }
+}
+
+async fn untracked_files(cwd: &Path) -> Result<String, crate::Error> {
+    git(
+        cwd,
+        &[
+            "ls-files",
+            "-z",
+            "--others",
+            "--exclude-standard",
+            "--full-name",
+        ],
+    )
+    .await
+}
+
+async fn git(cwd: &Path, args: &[&str]) -> Result<String, crate::Error> {
+    let output = tokio::process::Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .await
+        .map_err(|err| crate::Error::GitCommand {
+            command: args.join(" "),
+            message: err.to_string(),
+        })?;
+
+    if !output.status.success() {
+        return Err(crate::Error::GitCommand {
+            command: args.join(" "),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_git(root: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args([
+                "-c",
+                "user.name=tombi",
+                "-c",
+                "user.email=tombi@example.com",
+            ])
+            .args(args)
+            .current_dir(root)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn file_names(paths: Vec<PathBuf>) -> Vec<String> {
+        paths
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn select_changed_staged_and_since_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+
+        run_git(root, &["init", "-q", "-b", "main"]);
+        std::fs::write(root.join("committed.toml"), "a = 1\n").unwrap();
+        std::fs::write(root.join("deleted.toml"), "a = 1\n").unwrap();
+        run_git(root, &["add", "."]);
+        run_git(root, &["commit", "-q", "-m", "initial"]);
+
+        run_git(root, &["checkout", "-q", "-b", "feature"]);
+        std::fs::write(root.join("feature.toml"), "a = 1\n").unwrap();
+        run_git(root, &["add", "."]);
+        run_git(root, &["commit", "-q", "-m", "feature"]);
+
+        std::fs::write(root.join("committed.toml"), "a = 2\n").unwrap();
+        std::fs::write(root.join("staged.toml"), "a = 1\n").unwrap();
+        run_git(root, &["add", "staged.toml"]);
+        std::fs::write(root.join("untracked.toml"), "a = 1\n").unwrap();
+        std::fs::remove_file(root.join("deleted.toml")).unwrap();
+
+        assert_eq!(
+            file_names(GitFilter::Changed.changed_paths(root).await.unwrap()),
+            vec!["committed.toml", "staged.toml", "untracked.toml"]
+        );
+        assert_eq!(
+            file_names(GitFilter::Staged.changed_paths(root).await.unwrap()),
+            vec!["staged.toml"]
+        );
+        assert_eq!(
+            file_names(
+                GitFilter::Since("main".to_string())
+                    .changed_paths(root)
+                    .await
+                    .unwrap()
+            ),
+            vec![
+                "committed.toml",
+                "feature.toml",
+                "staged.toml",
+                "untracked.toml"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_revision() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        run_git(temp_dir.path(), &["init", "-q"]);
+
+        assert!(
+            GitFilter::Since("unknown".to_string())
+                .changed_paths(temp_dir.path())
+                .await
+                .is_err()
+        );
+    }
+}
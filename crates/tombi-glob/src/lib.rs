@@ -1,6 +1,7 @@
 mod error;
 mod file_match;
 mod file_search;
+mod git;
 mod pattern;
 mod walk_dir;
 
@@ -12,6 +13,7 @@ use tombi_config::{FormatOptions, LintOptions, OverrideFilesOptions, config_base
 pub use error::Error;
 pub use file_match::{MatchResult, matches_file_patterns};
 pub use file_search::{FileInputType, FileSearch, FileSearchEntry, search_pattern_matched_paths};
+pub use git::GitFilter;
 pub use walk_dir::WalkDir;
 
 pub fn get_format_options(
@@ -70,6 +72,17 @@ pub fn get_lint_options(
     Some(config.merge_lint(None))
 }
 
+/// Get the index of the `[[overrides]]` item that applies to the file.
+pub fn get_override_index(
+    config: &tombi_config::Config,
+    text_document_path: &Path,
+    config_path: Option<&Path>,
+) -> Option<usize> {
+    config.overrides()?.iter().position(|override_item| {
+        matches_override_files(text_document_path, config_path, &override_item.files)
+    })
+}
+
 /// Check if a path matches override files patterns
 fn matches_override_files(
     text_document_path: &Path,
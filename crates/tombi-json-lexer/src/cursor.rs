@@ -6,6 +6,7 @@ pub struct Cursor<'a> {
     current_position: tombi_text::Position,
     token_start_offset: tombi_text::Offset,
     token_start_position: tombi_text::Position,
+    format: crate::JsonFormat,
 }
 
 pub(crate) const EOF_CHAR: char = '\0';
@@ -22,9 +23,20 @@ impl<'a> Cursor<'a> {
             current_position: Default::default(),
             token_start_offset: Default::default(),
             token_start_position: Default::default(),
+            format: Default::default(),
         }
     }
 
+    pub fn with_format(mut self, format: crate::JsonFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn format(&self) -> crate::JsonFormat {
+        self.format
+    }
+
     #[inline]
     pub(crate) fn current(&self) -> char {
         self.current_char
@@ -17,6 +17,7 @@ pub enum ErrorKind {
     UnexpectedEscapeSequence,
     InvalidUnicodeEscapeSequence,
     InvalidLineBreak,
+    InvalidComment,
 }
 
 impl Error {
@@ -0,0 +1,21 @@
+/// The flavor of JSON accepted by the lexer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFormat {
+    /// Strict JSON ([RFC 8259](https://www.rfc-editor.org/rfc/rfc8259)).
+    #[default]
+    Json,
+
+    /// JSON with `//` and `/* */` comments.
+    Jsonc,
+
+    /// [JSON5](https://spec.json5.org/): comments, single-quoted strings,
+    /// unquoted keys and extended number literals.
+    Json5,
+}
+
+impl JsonFormat {
+    #[inline]
+    pub fn allows_comments(&self) -> bool {
+        matches!(self, Self::Jsonc | Self::Json5)
+    }
+}
@@ -1,11 +1,13 @@
 mod cursor;
 mod error;
+mod format;
 mod lexed;
 mod token;
 
 use cursor::Cursor;
 use error::ErrorKind::*;
 pub use error::{Error, ErrorKind};
+pub use format::JsonFormat;
 pub use lexed::Lexed;
 pub use token::Token;
 use tombi_json_syntax::{SyntaxKind, T};
@@ -22,14 +24,19 @@ macro_rules! regex {
 regex!(
     REGEX_INTEGER_DEC = r"^-?(:?[1-9](:?[0-9])*|0)$";
     REGEX_FLOAT = r"^-?[0-9]+(:?(:?\.[0-9]+)?[eE][+-]?[0-9]+|\.[0-9]+)$";
+    REGEX_JSON5_NUMBER = r"^[+-]?(:?0[xX][0-9a-fA-F]+|Infinity|NaN|(:?[0-9]+\.?[0-9]*|\.[0-9]+)(:?[eE][+-]?[0-9]+)?)";
 );
 
 pub fn lex(source: &str) -> Lexed {
+    lex_with_format(source, JsonFormat::Json)
+}
+
+pub fn lex_with_format(source: &str, format: JsonFormat) -> Lexed {
     let mut lexed = Lexed::default();
     let mut last_offset = tombi_text::Offset::default();
     let mut last_position = tombi_text::Position::default();
 
-    for result in tokenize(source) {
+    for result in tokenize_with_format(source, format) {
         let (last_span, last_range) = lexed.push_result_token(result);
         last_offset = last_span.end;
         last_position = last_range.end;
@@ -50,7 +57,14 @@ pub fn lex(source: &str) -> Lexed {
 }
 
 pub fn tokenize(source: &str) -> impl Iterator<Item = Result<Token, crate::Error>> + '_ {
-    let mut cursor = Cursor::new(source);
+    tokenize_with_format(source, JsonFormat::Json)
+}
+
+pub fn tokenize_with_format(
+    source: &str,
+    format: JsonFormat,
+) -> impl Iterator<Item = Result<Token, crate::Error>> + '_ {
+    let mut cursor = Cursor::new(source).with_format(format);
 
     std::iter::from_fn(move || {
         let token = cursor.advance_token();
@@ -84,6 +98,14 @@ impl Cursor<'_> {
             ',' => Ok(Token::new(T![,], self.pop_span_range())),
             ':' => Ok(Token::new(T![:], self.pop_span_range())),
             '"' => self.string(),
+            // JSONC and JSON5 comments
+            '/' if self.format().allows_comments() && matches!(self.peek(1), '/' | '*') => {
+                self.comment()
+            }
+            // JSON5 extensions
+            '\'' if self.format() == JsonFormat::Json5 => self.string(),
+            '+' | '.' if self.format() == JsonFormat::Json5 => self.number(),
+            c if self.format() == JsonFormat::Json5 && is_identifier_start(c) => self.identifier(),
             // JSON number
             '0'..='9' | '-' => self.number(),
             // JSON keywords
@@ -156,9 +178,59 @@ impl Cursor<'_> {
         Ok(Token::new(SyntaxKind::LINE_BREAK, self.pop_span_range()))
     }
 
+    fn comment(&mut self) -> Result<Token, crate::Error> {
+        debug_assert!(self.current() == '/');
+
+        if self.peek(1) == '/' {
+            self.eat_while(|c| !is_line_break(c));
+            return Ok(Token::new(SyntaxKind::COMMENT, self.pop_span_range()));
+        }
+
+        self.bump();
+        while let Some(c) = self.bump() {
+            if c == '*' && self.peek(1) == '/' {
+                self.bump();
+                return Ok(Token::new(SyntaxKind::COMMENT, self.pop_span_range()));
+            }
+        }
+
+        Err(crate::Error::new(InvalidComment, self.pop_span_range()))
+    }
+
+    fn identifier(&mut self) -> Result<Token, crate::Error> {
+        let identifier = self.peek_with_current_while(is_identifier_continue);
+        let length = identifier.chars().count();
+        if length > 1 {
+            self.eat_n(length - 1);
+        }
+
+        let kind = match identifier.as_str() {
+            "true" | "false" => SyntaxKind::BOOLEAN,
+            "null" => SyntaxKind::NULL,
+            "Infinity" | "NaN" => SyntaxKind::NUMBER,
+            _ => SyntaxKind::IDENTIFIER,
+        };
+
+        Ok(Token::new(kind, self.pop_span_range()))
+    }
+
     fn number(&mut self) -> Result<Token, crate::Error> {
         let line = self.peek_with_current_while(|c| !is_token_separator(c));
 
+        if self.format() == JsonFormat::Json5 {
+            if let Some(m) = REGEX_JSON5_NUMBER.find(&line)
+                && m.end() == line.len()
+            {
+                if m.end() > 1 {
+                    self.eat_n(m.end() - 1);
+                }
+                return Ok(Token::new(SyntaxKind::NUMBER, self.pop_span_range()));
+            }
+            self.eat_while(|c| !is_token_separator(c));
+
+            return Err(crate::Error::new(InvalidNumber, self.pop_span_range()));
+        }
+
         if let Some(m) = REGEX_FLOAT.find(&line) {
             debug_assert!(m.start() == 0);
             if m.end() > 1 {
@@ -179,12 +251,13 @@ impl Cursor<'_> {
     }
 
     fn string(&mut self) -> Result<Token, crate::Error> {
-        debug_assert!(self.current() == '"');
+        let quote = self.current();
+        debug_assert!(matches!(quote, '"' | '\''));
 
         let mut first_error: Option<ErrorKind> = None;
         while let Some(c) = self.bump() {
             match c {
-                _ if c == '"' => {
+                _ if c == quote => {
                     if let Some(error_kind) = first_error {
                         return Err(crate::Error::new(error_kind, self.pop_span_range()));
                     }
@@ -197,6 +270,8 @@ impl Cursor<'_> {
                 '\\' => match self.bump() {
                     Some(escape_char) => match escape_char {
                         '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't' => {}
+                        // JSON5 allows any character to be escaped, including line breaks.
+                        _ if self.format() == JsonFormat::Json5 && escape_char != 'u' => {}
                         'u' => {
                             let mut valid_unicode = true;
                             for _i in 0..4 {
@@ -247,5 +322,18 @@ fn is_line_break(c: char) -> bool {
 fn is_token_separator(c: char) -> bool {
     is_whitespace(c)
         || is_line_break(c)
-        || matches!(c, '{' | '}' | '[' | ']' | ',' | ':' | '"' | '\0')
+        || matches!(
+            c,
+            '{' | '}' | '[' | ']' | ',' | ':' | '"' | '\'' | '/' | '\0'
+        )
+}
+
+#[inline]
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '_' | '$')
+}
+
+#[inline]
+fn is_identifier_continue(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$')
 }
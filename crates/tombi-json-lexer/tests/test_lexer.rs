@@ -3,18 +3,29 @@
 // using macros to define test cases in a declarative way.
 
 use itertools::Itertools;
-use tombi_json_lexer::{ErrorKind, Token, tokenize};
+use tombi_json_lexer::{ErrorKind, JsonFormat, Token, tokenize_with_format};
 use tombi_json_syntax::SyntaxKind::*;
 
 macro_rules! test_tokens {
     {#[test]fn $name:ident($source:expr) -> [
         $(Token($kind:expr, $text:literal),)*
+    ];} => {
+        test_tokens! {
+            #[test]
+            fn $name($source, JsonFormat::Json) -> [
+                $(Token($kind, $text),)*
+            ];
+        }
+    };
+
+    {#[test]fn $name:ident($source:expr, $format:expr) -> [
+        $(Token($kind:expr, $text:literal),)*
     ];} => {
         #[test]
         fn $name() {
             tombi_test_lib::init_log();
 
-            let tokens = tokenize($source).collect_vec();
+            let tokens = tokenize_with_format($source, $format).collect_vec();
             let (expected, _) = [
                 $(
                     ($kind, $text),
@@ -49,7 +60,7 @@ macro_rules! test_token {
         fn $name() {
             let source = textwrap::dedent($source);
             let source = source.trim();
-            let tokens = tokenize(&source).collect_vec();
+            let tokens = tokenize_with_format(&source, JsonFormat::Json).collect_vec();
             let start_position = tombi_text::Position::MIN;
             let end_position = start_position + tombi_text::RelativePosition::of(source);
 
@@ -75,7 +86,7 @@ macro_rules! test_token {
         fn $name() {
             let source = textwrap::dedent($source);
             let source = source.trim();
-            let tokens = tokenize(&source).collect_vec();
+            let tokens = tokenize_with_format(&source, JsonFormat::Json).collect_vec();
             let start_position = tombi_text::Position::MIN;
             let end_position = start_position + tombi_text::RelativePosition::of(source);
 
@@ -459,3 +470,39 @@ test_tokens! {
         Token(BRACE_END, "}"),
     ];
 }
+
+// JSONC and JSON5 extensions
+test_tokens! {
+    #[test]
+    fn jsonc_comments("// line\n{/* block */}", JsonFormat::Jsonc) -> [
+        Token(COMMENT, "// line"),
+        Token(LINE_BREAK, "\n"),
+        Token(BRACE_START, "{"),
+        Token(COMMENT, "/* block */"),
+        Token(BRACE_END, "}"),
+    ];
+}
+
+test_tokens! {
+    #[test]
+    fn json5_extensions("{key: 'value', hex: 0x1F, inf: -Infinity}", JsonFormat::Json5) -> [
+        Token(BRACE_START, "{"),
+        Token(IDENTIFIER, "key"),
+        Token(COLON, ":"),
+        Token(WHITESPACE, " "),
+        Token(STRING, "'value'"),
+        Token(COMMA, ","),
+        Token(WHITESPACE, " "),
+        Token(IDENTIFIER, "hex"),
+        Token(COLON, ":"),
+        Token(WHITESPACE, " "),
+        Token(NUMBER, "0x1F"),
+        Token(COMMA, ","),
+        Token(WHITESPACE, " "),
+        Token(IDENTIFIER, "inf"),
+        Token(COLON, ":"),
+        Token(WHITESPACE, " "),
+        Token(NUMBER, "-Infinity"),
+        Token(BRACE_END, "}"),
+    ];
+}
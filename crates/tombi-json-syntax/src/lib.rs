@@ -22,9 +22,12 @@ pub enum SyntaxKind {
     STRING,
     BOOLEAN,
     NULL,
+    // JSON5 unquoted object keys
+    IDENTIFIER,
     // Trivia
     WHITESPACE,
     LINE_BREAK,
+    COMMENT,
     // Nodes
     ROOT,
     ARRAY,
@@ -40,7 +43,10 @@ pub enum SyntaxKind {
 impl SyntaxKind {
     #[inline]
     pub fn is_trivia(self) -> bool {
-        matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::LINE_BREAK)
+        matches!(
+            self,
+            SyntaxKind::WHITESPACE | SyntaxKind::LINE_BREAK | SyntaxKind::COMMENT
+        )
     }
 }
 
@@ -0,0 +1,35 @@
+pub use tombi_json_lexer::JsonFormat;
+
+/// The source format of a document parsed into a [`ValueNode`](crate::ValueNode).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SourceFormat {
+    #[default]
+    Json,
+    Jsonc,
+    Json5,
+    Yaml,
+}
+
+impl SourceFormat {
+    /// Guess the format from a file extension, such as `jsonc` or `yml`.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "jsonc" => Some(Self::Jsonc),
+            "json5" => Some(Self::Json5),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Guess the format from the extension of a file path or URL path.
+    ///
+    /// Unknown extensions are treated as JSON.
+    pub fn from_path(path: &str) -> Self {
+        path.rsplit('/')
+            .next()
+            .and_then(|file_name| file_name.rsplit_once('.'))
+            .and_then(|(_, extension)| Self::from_extension(extension))
+            .unwrap_or_default()
+    }
+}
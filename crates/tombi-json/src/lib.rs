@@ -1,9 +1,12 @@
+mod format;
 mod node;
 mod parser;
+mod yaml;
 
+pub use format::{JsonFormat, SourceFormat};
 pub use node::{ArrayNode, BoolNode, NullNode, NumberNode, ObjectNode, StringNode, ValueNode};
-pub use parser::{Error as ParserError, parse};
-pub use tombi_json_value::{Number, Object, Value};
+pub use parser::{Error as ParserError, ErrorKind as ParserErrorKind, parse};
+pub use tombi_json_value::{Map, Number, Object, Value};
 pub use tombi_text::Range;
 
 use serde::de::{
@@ -4,6 +4,8 @@ use itertools::Itertools;
 use tombi_json_value::{Number, Object, Value};
 use tombi_text::Range;
 
+use crate::{JsonFormat, SourceFormat};
+
 /// A JSON value with source code position information
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValueNode {
@@ -143,6 +145,25 @@ impl ValueNode {
         reader.read_to_string(&mut s)?;
         Ok(crate::parser::parse(&s)?)
     }
+
+    pub fn from_reader_with_format<R>(reader: R, format: SourceFormat) -> Result<Self, crate::Error>
+    where
+        R: std::io::Read,
+    {
+        let mut reader = std::io::BufReader::new(reader);
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Self::from_str_with_format(&s, format)
+    }
+
+    pub fn from_str_with_format(s: &str, format: SourceFormat) -> Result<Self, crate::Error> {
+        Ok(match format {
+            SourceFormat::Json => crate::parser::parse(s)?,
+            SourceFormat::Jsonc => crate::parser::parse_with_format(s, JsonFormat::Jsonc)?,
+            SourceFormat::Json5 => crate::parser::parse_with_format(s, JsonFormat::Json5)?,
+            SourceFormat::Yaml => crate::yaml::parse(s)?,
+        })
+    }
 }
 
 impl std::str::FromStr for ValueNode {
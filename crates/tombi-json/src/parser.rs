@@ -1,8 +1,8 @@
 mod error;
 
 use crate::{ArrayNode, BoolNode, NullNode, NumberNode, ObjectNode, StringNode, ValueNode};
-pub use error::Error;
-use tombi_json_lexer::{Lexed, Token, lex};
+pub use error::{Error, ErrorKind};
+use tombi_json_lexer::{JsonFormat, Lexed, Token, lex_with_format};
 use tombi_json_syntax::{SyntaxKind, T};
 use tombi_json_value::Number;
 use tombi_text::Range;
@@ -10,21 +10,31 @@ use tombi_text::Range;
 /// Parser for JSON documents
 pub struct Parser<'a> {
     source: &'a str,
+    format: JsonFormat,
     lexed: Lexed,
     position: usize,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(source: &'a str) -> Self {
-        let lexed = lex(source);
+        Self::with_format(source, JsonFormat::Json)
+    }
+
+    pub fn with_format(source: &'a str, format: JsonFormat) -> Self {
+        let lexed = lex_with_format(source, format);
         Self {
             source,
+            format,
             lexed,
             position: 0,
         }
     }
 
     pub fn parse(&mut self) -> Result<ValueNode, crate::parser::Error> {
+        if let Some(error) = self.lexed.errors.first() {
+            return Err(error.clone().into());
+        }
+
         // Skip leading trivia
         while let Some(token) = self.peek() {
             if token.kind().is_trivia() {
@@ -49,10 +59,13 @@ impl<'a> Parser<'a> {
         if let Some(token) = self.peek()
             && token.kind() != SyntaxKind::EOF
         {
-            return Err(Error::UnexpectedToken {
-                expected: SyntaxKind::EOF,
-                actual: token.kind(),
-            });
+            return Err(Error::new(
+                ErrorKind::UnexpectedToken {
+                    expected: SyntaxKind::EOF,
+                    actual: token.kind(),
+                },
+                token.range(),
+            ));
         }
 
         Ok(root)
@@ -64,6 +77,7 @@ impl<'a> Parser<'a> {
             Some(token) if token.kind() == SyntaxKind::STRING => {
                 let span = token.span();
                 let range = token.range();
+                let error = |kind| Error::new(kind, range);
                 // Get the string and advance the position
                 let raw_str = &self.source[span.start.into()..span.end.into()];
                 self.advance();
@@ -96,7 +110,7 @@ impl<'a> Parser<'a> {
                                             code_point =
                                                 code_point * 16 + hex.to_digit(16).unwrap();
                                         }
-                                        _ => return Err(Error::InvalidUnicodeEscape),
+                                        _ => return Err(error(ErrorKind::InvalidUnicodeEscape)),
                                     }
                                 }
 
@@ -111,7 +125,11 @@ impl<'a> Parser<'a> {
                                                     low_surrogate = low_surrogate * 16
                                                         + hex.to_digit(16).unwrap();
                                                 }
-                                                _ => return Err(Error::InvalidUnicodeEscape),
+                                                _ => {
+                                                    return Err(error(
+                                                        ErrorKind::InvalidUnicodeEscape,
+                                                    ));
+                                                }
                                             }
                                         }
 
@@ -123,26 +141,56 @@ impl<'a> Parser<'a> {
 
                                             match std::char::from_u32(unicode_code_point) {
                                                 Some(unicode_char) => processed.push(unicode_char),
-                                                None => return Err(Error::InvalidUnicodeCodePoint),
+                                                None => {
+                                                    return Err(error(
+                                                        ErrorKind::InvalidUnicodeCodePoint,
+                                                    ));
+                                                }
                                             }
                                         } else {
-                                            return Err(Error::InvalidUnicodeCodePoint);
+                                            return Err(error(ErrorKind::InvalidUnicodeCodePoint));
                                         }
                                     } else {
-                                        return Err(Error::InvalidUnicodeCodePoint);
+                                        return Err(error(ErrorKind::InvalidUnicodeCodePoint));
                                     }
                                 } else if (0xDC00..=0xDFFF).contains(&code_point) {
                                     // Low surrogate without high surrogate
-                                    return Err(Error::InvalidUnicodeCodePoint);
+                                    return Err(error(ErrorKind::InvalidUnicodeCodePoint));
                                 } else {
                                     // Regular Unicode code point
                                     match std::char::from_u32(code_point) {
                                         Some(unicode_char) => processed.push(unicode_char),
-                                        None => return Err(Error::InvalidUnicodeCodePoint),
+                                        None => {
+                                            return Err(error(ErrorKind::InvalidUnicodeCodePoint));
+                                        }
                                     }
                                 }
                             }
-                            _ => return Err(Error::InvalidEscapeSequence),
+                            Some(c) if self.format == JsonFormat::Json5 => match c {
+                                '\'' => processed.push('\''),
+                                'v' => processed.push('\u{000B}'),
+                                '0' => processed.push('\0'),
+                                'x' => {
+                                    // Hexadecimal escape sequence: \xXX
+                                    let hex = chars.by_ref().take(2).collect::<String>();
+                                    match u8::from_str_radix(&hex, 16) {
+                                        Ok(code_point) if hex.len() == 2 => {
+                                            processed.push(char::from(code_point))
+                                        }
+                                        _ => return Err(error(ErrorKind::InvalidEscapeSequence)),
+                                    }
+                                }
+                                // Line continuation
+                                '\r' => {
+                                    chars.next_if_eq(&'\n');
+                                }
+                                '\n' | '\u{2028}' | '\u{2029}' => {}
+                                c if c.is_ascii_digit() => {
+                                    return Err(error(ErrorKind::InvalidEscapeSequence));
+                                }
+                                c => processed.push(c),
+                            },
+                            _ => return Err(error(ErrorKind::InvalidEscapeSequence)),
                         }
                     } else {
                         processed.push(c);
@@ -154,67 +202,116 @@ impl<'a> Parser<'a> {
                     range,
                 })
             }
-            Some(token) => Err(Error::UnexpectedToken {
-                expected: SyntaxKind::STRING,
-                actual: token.kind(),
+            Some(token) => Err(Error::new(
+                ErrorKind::UnexpectedToken {
+                    expected: SyntaxKind::STRING,
+                    actual: token.kind(),
+                },
+                token.range(),
+            )),
+            None => Err(self.unexpected_eof()),
+        }
+    }
+
+    /// Parse an object key.
+    ///
+    /// JSON5 also allows unquoted identifiers as keys.
+    fn parse_key(&mut self) -> Result<StringNode, crate::parser::Error> {
+        let Some(token) = self.peek() else {
+            return Err(self.unexpected_eof());
+        };
+
+        match token.kind() {
+            SyntaxKind::STRING => self.parse_string(),
+            SyntaxKind::IDENTIFIER
+            | SyntaxKind::BOOLEAN
+            | SyntaxKind::NULL
+            | SyntaxKind::NUMBER
+                if self.format == JsonFormat::Json5
+                    && self
+                        .token_text(token)
+                        .starts_with(|c: char| c.is_alphabetic()) =>
+            {
+                let value = self.token_text(token).to_string();
+                let range = token.range();
+                self.advance();
+
+                Ok(StringNode { value, range })
+            }
+            kind => Err(Error::new(
+                ErrorKind::UnexpectedToken {
+                    expected: SyntaxKind::STRING,
+                    actual: kind,
+                },
+                token.range(),
+            )),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<ValueNode, crate::parser::Error> {
+        let token = self.peek().unwrap();
+        let range = token.range();
+        let num_str = self.token_text(token);
+
+        let (sign, digits) = match num_str.strip_prefix('-') {
+            Some(digits) => (-1, digits),
+            None => (1, num_str.strip_prefix('+').unwrap_or(num_str)),
+        };
+        let value = match digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+        {
+            // JSON5 hexadecimal integer
+            Some(hex) => i64::from_str_radix(hex, 16)
+                .ok()
+                .map(|n| Number::from(sign * n)),
+            // Parse as f64
+            None => digits.parse::<f64>().ok().map(|n| {
+                if n.is_nan() || n.is_infinite() {
+                    // Fallback for NaN or infinity
+                    if sign < 0 {
+                        Number::from(-0.0)
+                    } else {
+                        Number::from(0.0)
+                    }
+                } else {
+                    Number::from_f64(sign as f64 * n)
+                }
             }),
-            None => Err(Error::UnexpectedEof),
+        };
+        self.advance();
+
+        match value {
+            Some(value) => Ok(ValueNode::Number(NumberNode { value, range })),
+            None => Err(Error::new(ErrorKind::InvalidValue, range)),
         }
     }
 
     fn parse_value(&mut self) -> Result<ValueNode, crate::parser::Error> {
         match self.peek() {
-            Some(token) => {
-                match token.kind() {
-                    SyntaxKind::STRING => self.parse_string().map(ValueNode::String),
-                    SyntaxKind::NUMBER => {
-                        let token = self.peek().unwrap();
-                        let span = token.span();
-                        let range = token.range();
-                        let num_str = &self.source[span.start.into()..span.end.into()];
-                        self.advance();
-
-                        // Parse as f64
-                        match num_str.parse::<f64>() {
-                            Ok(n) => {
-                                let num = if n.is_nan() || n.is_infinite() {
-                                    // Fallback for NaN or infinity
-                                    if num_str.starts_with('-') {
-                                        Number::from(-0.0)
-                                    } else {
-                                        Number::from(0.0)
-                                    }
-                                } else {
-                                    Number::from_f64(n)
-                                };
+            Some(token) => match token.kind() {
+                SyntaxKind::STRING => self.parse_string().map(ValueNode::String),
+                SyntaxKind::NUMBER => self.parse_number(),
+                SyntaxKind::NULL => {
+                    let token = self.peek().unwrap();
+                    let range = token.range();
+                    self.advance();
+                    Ok(ValueNode::Null(NullNode { range }))
+                }
+                SyntaxKind::BOOLEAN => {
+                    let token = self.peek().unwrap();
+                    let range = token.range();
+                    let value = self.token_text(token) == "true";
+                    self.advance();
 
-                                Ok(ValueNode::Number(NumberNode { value: num, range }))
-                            }
-                            Err(_) => Err(Error::InvalidValue),
-                        }
-                    }
-                    SyntaxKind::NULL => {
-                        let token = self.peek().unwrap();
-                        let range = token.range();
-                        self.advance();
-                        Ok(ValueNode::Null(NullNode { range }))
-                    }
-                    SyntaxKind::BOOLEAN => {
-                        let token = self.peek().unwrap();
-                        let span = token.span();
-                        let range = token.range();
-                        let bool_str = &self.source[span.start.into()..span.end.into()];
-                        let value = bool_str == "true";
-                        self.advance();
-
-                        Ok(ValueNode::Bool(BoolNode { value, range }))
-                    }
-                    T!['['] => self.parse_array(),
-                    T!['{'] => self.parse_object(),
-                    _ => Err(Error::InvalidValue),
+                    Ok(ValueNode::Bool(BoolNode { value, range }))
                 }
-            }
-            None => Err(Error::UnexpectedEof),
+                T!['['] => self.parse_array(),
+                T!['{'] => self.parse_object(),
+                SyntaxKind::EOF => Err(self.unexpected_eof()),
+                _ => Err(Error::new(ErrorKind::InvalidValue, token.range())),
+            },
+            None => Err(self.unexpected_eof()),
         }
     }
 
@@ -258,12 +355,7 @@ impl<'a> Parser<'a> {
 
                     return Ok(ValueNode::Array(array_node));
                 }
-                _ => {
-                    return Err(Error::UnexpectedToken {
-                        expected: T![']'],
-                        actual: self.peek_kind().unwrap_or(SyntaxKind::EOF),
-                    });
-                }
+                _ => return Err(self.unexpected_token(T![']'])),
             }
 
             // Check if we've reached the end of the array
@@ -305,22 +397,14 @@ impl<'a> Parser<'a> {
         // Parse object members
         loop {
             // Parse key (must be a string)
-            let Some(token) = self.peek() else {
-                return Err(Error::UnexpectedEof);
-            };
-
-            if token.kind() != SyntaxKind::STRING {
-                return Err(Error::UnexpectedToken {
-                    expected: SyntaxKind::STRING,
-                    actual: token.kind(),
-                });
-            }
-
-            let key = self.parse_string()?;
+            let key = self.parse_key()?;
 
             // Check for duplicate keys
             if properties.contains_key(&key) {
-                return Err(Error::DuplicateKey(key.value));
+                return Err(Error::new(
+                    ErrorKind::DuplicateKey(key.value.clone()),
+                    key.range,
+                ));
             }
 
             // Expect colon
@@ -348,12 +432,7 @@ impl<'a> Parser<'a> {
 
                     return Ok(ValueNode::Object(object_node));
                 }
-                _ => {
-                    return Err(Error::UnexpectedToken {
-                        expected: T!['}'],
-                        actual: self.peek_kind().unwrap_or(SyntaxKind::EOF),
-                    });
-                }
+                _ => return Err(self.unexpected_token(T!['}'])),
             }
 
             // Check if we've reached the end of the object
@@ -373,6 +452,11 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn token_text(&self, token: &Token) -> &'a str {
+        let span = token.span();
+        &self.source[span.start.into()..span.end.into()]
+    }
+
     fn peek(&self) -> Option<&Token> {
         self.lexed.tokens.get(self.position)
     }
@@ -404,13 +488,33 @@ impl<'a> Parser<'a> {
     fn expect(&mut self, kind: SyntaxKind) -> Result<&Token, crate::parser::Error> {
         match self.peek() {
             Some(token) if token.kind() == kind => Ok(self.advance().unwrap()),
-            Some(token) => Err(Error::UnexpectedToken {
-                expected: kind,
-                actual: token.kind(),
-            }),
-            None => Err(Error::UnexpectedEof),
+            _ => Err(self.unexpected_token(kind)),
+        }
+    }
+
+    fn unexpected_token(&self, expected: SyntaxKind) -> crate::parser::Error {
+        match self.peek() {
+            Some(token) if token.kind() != SyntaxKind::EOF => Error::new(
+                ErrorKind::UnexpectedToken {
+                    expected,
+                    actual: token.kind(),
+                },
+                token.range(),
+            ),
+            _ => self.unexpected_eof(),
         }
     }
+
+    fn unexpected_eof(&self) -> crate::parser::Error {
+        let range = self
+            .lexed
+            .tokens
+            .last()
+            .map(|token| Range::at(token.range().end))
+            .unwrap_or_default();
+
+        Error::new(ErrorKind::UnexpectedEof, range)
+    }
 }
 
 /// Parse a JSON string into a Tree
@@ -419,6 +523,15 @@ pub fn parse(source: &str) -> Result<ValueNode, crate::parser::Error> {
     parser.parse()
 }
 
+/// Parse a JSON, JSONC or JSON5 string into a Tree
+pub fn parse_with_format(
+    source: &str,
+    format: JsonFormat,
+) -> Result<ValueNode, crate::parser::Error> {
+    let mut parser = Parser::with_format(source, format);
+    parser.parse()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -504,4 +617,65 @@ mod tests {
         let value_node = parse(source).unwrap();
         assert!(value_node.is_object());
     }
+
+    #[test]
+    fn test_parse_error_range() {
+        let source = "{\n  \"a\": 1,\n  \"b\": }";
+        let error = parse(source).unwrap_err();
+        pretty_assertions::assert_eq!(error.range(), Range::new((2, 7).into(), (2, 8).into()));
+        pretty_assertions::assert_eq!(error.to_string(), "Invalid value at line 3, column 8");
+    }
+
+    #[test]
+    fn test_parse_jsonc() {
+        let source = r#"
+        // schema for foo
+        {
+            /* the name */
+            "name": "foo", // trailing
+            "items": [1, 2,],
+        }
+        "#;
+        assert!(parse(source).is_err());
+
+        let value_node = parse_with_format(source, JsonFormat::Jsonc).unwrap();
+        pretty_assertions::assert_eq!(
+            value_node
+                .as_object()
+                .and_then(|object| object.get("name")?.as_str()),
+            Some("foo")
+        );
+    }
+
+    #[test]
+    fn test_parse_json5() {
+        let source = r#"
+        {
+            name: 'foo\'s',
+            hex: 0x1F,
+            positive: +1,
+            fraction: .5,
+            'quoted': "line \
+continued",
+        }
+        "#;
+        assert!(parse_with_format(source, JsonFormat::Jsonc).is_err());
+
+        let value_node = parse_with_format(source, JsonFormat::Json5).unwrap();
+        let object = value_node.as_object().unwrap();
+        pretty_assertions::assert_eq!(
+            object.get("name").and_then(ValueNode::as_str),
+            Some("foo's")
+        );
+        pretty_assertions::assert_eq!(object.get("hex").and_then(ValueNode::as_i64), Some(31));
+        pretty_assertions::assert_eq!(object.get("positive").and_then(ValueNode::as_i64), Some(1));
+        pretty_assertions::assert_eq!(
+            object.get("fraction").and_then(ValueNode::as_f64),
+            Some(0.5)
+        );
+        pretty_assertions::assert_eq!(
+            object.get("quoted").and_then(ValueNode::as_str),
+            Some("line continued")
+        );
+    }
 }
@@ -1,10 +1,17 @@
-use tombi_json_lexer::Error as LexerError;
+use tombi_json_lexer::ErrorKind as LexerErrorKind;
 use tombi_json_syntax::SyntaxKind;
 
 #[derive(Debug, thiserror::Error)]
-pub enum Error {
-    #[error("Lexer error")]
-    Lexer,
+#[error("{kind} at line {line}, column {column}", line = .range.start.line + 1, column = .range.start.column + 1)]
+pub struct Error {
+    kind: ErrorKind,
+    range: tombi_text::Range,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("Lexer error: {0:?}")]
+    Lexer(LexerErrorKind),
     #[error("Unexpected token: expected {expected:?}, got {actual:?}")]
     UnexpectedToken {
         expected: SyntaxKind,
@@ -24,10 +31,29 @@ pub enum Error {
     InvalidUnicodeEscape,
     #[error("Invalid Unicode code point")]
     InvalidUnicodeCodePoint,
+    #[error("Invalid YAML: {0}")]
+    InvalidYaml(String),
+}
+
+impl Error {
+    #[inline]
+    pub fn new(kind: ErrorKind, range: tombi_text::Range) -> Self {
+        Self { kind, range }
+    }
+
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    #[inline]
+    pub fn range(&self) -> tombi_text::Range {
+        self.range
+    }
 }
 
-impl From<LexerError> for Error {
-    fn from(_: LexerError) -> Self {
-        Error::Lexer
+impl From<tombi_json_lexer::Error> for Error {
+    fn from(error: tombi_json_lexer::Error) -> Self {
+        Self::new(ErrorKind::Lexer(error.kind()), error.range())
     }
 }
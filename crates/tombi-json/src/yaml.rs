@@ -0,0 +1,397 @@
+use tombi_json_value::{Map, Number};
+use tombi_text::{Position, Range, RelativePosition};
+use yaml_rust2::{
+    Event,
+    parser::{Parser, Tag},
+    scanner::{Marker, TScalarStyle},
+};
+
+use crate::{
+    ArrayNode, BoolNode, NullNode, NumberNode, ObjectNode, StringNode, ValueNode,
+    parser::{Error, ErrorKind},
+};
+
+/// Parse a YAML document into a Tree.
+///
+/// Scalars are resolved with the YAML 1.2 core schema,
+/// and aliases are expanded to a copy of their anchored value.
+pub fn parse(source: &str) -> Result<ValueNode, crate::parser::Error> {
+    YamlParser::new(source).parse()
+}
+
+enum Collection {
+    Array {
+        items: Vec<ValueNode>,
+        start: Position,
+        anchor_id: usize,
+    },
+    Object {
+        properties: Map<StringNode, ValueNode>,
+        key: Option<StringNode>,
+        start: Position,
+        anchor_id: usize,
+    },
+}
+
+struct YamlParser<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+    anchors: tombi_hashmap::HashMap<usize, ValueNode>,
+    stack: Vec<Collection>,
+}
+
+impl<'a> YamlParser<'a> {
+    fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self {
+            source,
+            line_starts,
+            anchors: Default::default(),
+            stack: Vec::new(),
+        }
+    }
+
+    fn parse(mut self) -> Result<ValueNode, crate::parser::Error> {
+        let mut parser = Parser::new_from_str(self.source);
+
+        loop {
+            let (event, marker) = parser.next_token().map_err(|error| {
+                Error::new(
+                    ErrorKind::InvalidYaml(error.info().to_string()),
+                    Range::at(self.position(error.marker())),
+                )
+            })?;
+            let start = self.position(&marker);
+
+            let value = match event {
+                Event::StreamEnd => {
+                    return Ok(ValueNode::Null(NullNode {
+                        range: Range::at(start),
+                    }));
+                }
+                Event::Scalar(value, style, anchor_id, tag) => {
+                    let value = self.scalar(value, style, tag.as_ref(), &marker);
+                    self.anchor(anchor_id, &value);
+                    value
+                }
+                Event::Alias(anchor_id) => match self.anchors.get(&anchor_id) {
+                    Some(value) => value.clone(),
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidYaml("unknown anchor".to_string()),
+                            Range::at(start),
+                        ));
+                    }
+                },
+                Event::SequenceStart(anchor_id, _) => {
+                    self.stack.push(Collection::Array {
+                        items: Vec::new(),
+                        start,
+                        anchor_id,
+                    });
+                    continue;
+                }
+                Event::MappingStart(anchor_id, _) => {
+                    self.stack.push(Collection::Object {
+                        properties: Map::new(),
+                        key: None,
+                        start,
+                        anchor_id,
+                    });
+                    continue;
+                }
+                Event::SequenceEnd | Event::MappingEnd => match self.stack.pop() {
+                    Some(Collection::Array {
+                        items,
+                        start,
+                        anchor_id,
+                    }) => {
+                        let value = ValueNode::Array(ArrayNode {
+                            items,
+                            range: Range::new(start, self.position(&marker)),
+                        });
+                        self.anchor(anchor_id, &value);
+                        value
+                    }
+                    Some(Collection::Object {
+                        properties,
+                        start,
+                        anchor_id,
+                        ..
+                    }) => {
+                        let value = ValueNode::Object(ObjectNode {
+                            properties,
+                            range: Range::new(start, self.position(&marker)),
+                        });
+                        self.anchor(anchor_id, &value);
+                        value
+                    }
+                    None => continue,
+                },
+                Event::Nothing | Event::StreamStart | Event::DocumentStart | Event::DocumentEnd => {
+                    continue;
+                }
+            };
+
+            // Only the first document is used.
+            if let Some(value) = self.push(value)? {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Add the value to the current collection, or return it when it is the document root.
+    fn push(&mut self, value: ValueNode) -> Result<Option<ValueNode>, crate::parser::Error> {
+        match self.stack.last_mut() {
+            None => Ok(Some(value)),
+            Some(Collection::Array { items, .. }) => {
+                items.push(value);
+                Ok(None)
+            }
+            Some(Collection::Object {
+                properties, key, ..
+            }) => {
+                match key.take() {
+                    Some(key) => {
+                        properties.insert(key, value);
+                    }
+                    None => {
+                        let ValueNode::String(new_key) = value else {
+                            let range = value.range();
+                            return Err(Error::new(
+                                ErrorKind::InvalidYaml(
+                                    "only scalar keys are supported".to_string(),
+                                ),
+                                range,
+                            ));
+                        };
+                        if properties.contains_key(&new_key) {
+                            return Err(Error::new(
+                                ErrorKind::DuplicateKey(new_key.value.clone()),
+                                new_key.range,
+                            ));
+                        }
+                        *key = Some(new_key);
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    fn anchor(&mut self, anchor_id: usize, value: &ValueNode) {
+        if anchor_id > 0 {
+            self.anchors.insert(anchor_id, value.clone());
+        }
+    }
+
+    fn scalar(
+        &self,
+        value: String,
+        style: TScalarStyle,
+        tag: Option<&Tag>,
+        marker: &Marker,
+    ) -> ValueNode {
+        let start = self.position(marker);
+        let range = Range::new(
+            start,
+            start + RelativePosition::of(self.scalar_text(marker, style, &value)),
+        );
+
+        // Keys and plain scalars in a mapping are always strings.
+        let is_key = matches!(
+            self.stack.last(),
+            Some(Collection::Object { key: None, .. })
+        );
+        if style != TScalarStyle::Plain || is_key || tag.is_some_and(|tag| tag.suffix == "str") {
+            return ValueNode::String(StringNode { value, range });
+        }
+
+        match value.as_str() {
+            "" | "~" | "null" | "Null" | "NULL" => ValueNode::Null(NullNode { range }),
+            "true" | "True" | "TRUE" => ValueNode::Bool(BoolNode { value: true, range }),
+            "false" | "False" | "FALSE" => ValueNode::Bool(BoolNode {
+                value: false,
+                range,
+            }),
+            _ => match parse_number(&value) {
+                Some(number) => ValueNode::Number(NumberNode {
+                    value: number,
+                    range,
+                }),
+                None => ValueNode::String(StringNode { value, range }),
+            },
+        }
+    }
+
+    /// The source text of the scalar starting at the marker.
+    fn scalar_text(&self, marker: &Marker, style: TScalarStyle, value: &str) -> &'a str {
+        let rest = &self.source[self.offset(marker)..];
+        let end = match style {
+            TScalarStyle::DoubleQuoted => {
+                let mut escaped = false;
+                rest.char_indices()
+                    .skip(1)
+                    .find(|(_, c)| {
+                        let is_end = *c == '"' && !escaped;
+                        escaped = *c == '\\' && !escaped;
+                        is_end
+                    })
+                    .map(|(index, _)| index + 1)
+            }
+            TScalarStyle::SingleQuoted => {
+                let mut chars = rest.char_indices().skip(1).peekable();
+                loop {
+                    match chars.next() {
+                        Some((_, '\'')) if chars.next_if(|(_, c)| *c == '\'').is_some() => {}
+                        Some((index, '\'')) => break Some(index + 1),
+                        Some(_) => {}
+                        None => break None,
+                    }
+                }
+            }
+            // Plain scalars have no escapes, so the first line of the value is the source text.
+            TScalarStyle::Plain => value.lines().next().map(str::len),
+            TScalarStyle::Literal | TScalarStyle::Folded => rest.find(['\n', '\r']),
+        };
+
+        &rest[..end.unwrap_or(rest.len())]
+    }
+
+    fn position(&self, marker: &Marker) -> Position {
+        let line = marker.line().saturating_sub(1);
+        Position::new(line as tombi_text::Line, marker.col() as tombi_text::Column)
+    }
+
+    fn offset(&self, marker: &Marker) -> usize {
+        let line = marker.line().saturating_sub(1);
+        let Some(line_start) = self.line_starts.get(line) else {
+            return self.source.len();
+        };
+        self.source[*line_start..]
+            .char_indices()
+            .nth(marker.col())
+            .map(|(index, _)| line_start + index)
+            .unwrap_or(self.source.len())
+    }
+}
+
+/// Parse a plain scalar as a YAML 1.2 core schema number.
+///
+/// `.inf` and `.nan` have no JSON number, so they are kept as strings
+/// and fail where the schema needs a number.
+fn parse_number(value: &str) -> Option<Number> {
+    if let Some(hex) = value.strip_prefix("0x") {
+        return i64::from_str_radix(hex, 16).ok().map(Number::from);
+    }
+    if let Some(octal) = value.strip_prefix("0o") {
+        return i64::from_str_radix(octal, 8).ok().map(Number::from);
+    }
+    if !value.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'))
+        || !value.contains(|c: char| c.is_ascii_digit())
+    {
+        return None;
+    }
+    if let Ok(integer) = value.parse::<i64>() {
+        return Some(Number::from(integer));
+    }
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|float| float.is_finite())
+        .map(Number::Float)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_yaml() {
+        let source = r#"# schema for foo
+type: object
+properties:
+  name: &name
+    type: string
+    description: "The name"
+  alias: *name
+  count:
+    type: integer
+    minimum: 0
+    default: ~
+required: [name]
+"#;
+        let value_node = parse(source).unwrap();
+        let object = value_node.as_object().unwrap();
+        pretty_assertions::assert_eq!(
+            object.get("type").and_then(ValueNode::as_str),
+            Some("object")
+        );
+
+        let properties = object
+            .get("properties")
+            .and_then(ValueNode::as_object)
+            .unwrap();
+        pretty_assertions::assert_eq!(properties.get("alias"), properties.get("name"));
+
+        let count = properties
+            .get("count")
+            .and_then(ValueNode::as_object)
+            .unwrap();
+        pretty_assertions::assert_eq!(count.get("minimum").and_then(ValueNode::as_i64), Some(0));
+        assert!(count.get("default").is_some_and(ValueNode::is_null));
+
+        let description = properties
+            .get("name")
+            .and_then(|name| name.as_object()?.get("description"))
+            .unwrap();
+        pretty_assertions::assert_eq!(
+            description.range(),
+            Range::new((5, 17).into(), (5, 27).into())
+        );
+    }
+
+    #[test]
+    fn test_parse_yaml_error_range() {
+        let source = "type: object\nproperties:\n  name: [string\n";
+        let error = parse(source).unwrap_err();
+        pretty_assertions::assert_eq!(error.range().start.line, 3);
+    }
+
+    fn maximum(source: &str) -> ValueNode {
+        parse(source)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .get("maximum")
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_parse_yaml_infinity_as_string() {
+        let ValueNode::String(maximum) = maximum("maximum: .inf\n") else {
+            panic!("`.inf` should be kept as a string");
+        };
+        pretty_assertions::assert_eq!(maximum.value, ".inf");
+    }
+
+    #[test]
+    fn test_parse_yaml_negative_infinity_as_string() {
+        let ValueNode::String(maximum) = maximum("maximum: -.inf\n") else {
+            panic!("`-.inf` should be kept as a string");
+        };
+        pretty_assertions::assert_eq!(maximum.value, "-.inf");
+    }
+
+    #[test]
+    fn test_parse_yaml_nan_as_string() {
+        let ValueNode::String(maximum) = maximum("maximum: .nan\n") else {
+            panic!("`.nan` should be kept as a string");
+        };
+        pretty_assertions::assert_eq!(maximum.value, ".nan");
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::BTreeMap;
+
+use tombi_accessor::{Accessor, Accessors};
+use tombi_config::TomlVersion;
+use tombi_document_tree::IntoDocumentTreeAndErrors;
+
+/// The accepted diagnostics of existing files.
+///
+/// Diagnostics are recorded by their accessor path and code instead of their position,
+/// so that a baseline survives unrelated edits of the file.
+///
+/// ```json
+/// {
+///   "files": {
+///     "Cargo.toml": [
+///       { "accessors": "package.edition", "code": "deprecated" }
+///     ]
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Baseline {
+    pub files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct BaselineEntry {
+    /// The accessor path of the value the diagnostic is reported on, e.g. `dependencies.serde`.
+    pub accessors: String,
+
+    /// The diagnostic code, e.g. `deprecated`.
+    pub code: String,
+}
+
+impl Baseline {
+    /// Record the diagnostics of the file.
+    pub fn insert(
+        &mut self,
+        file: impl Into<String>,
+        source: &str,
+        toml_version: TomlVersion,
+        diagnostics: &[tombi_diagnostic::Diagnostic],
+    ) {
+        if diagnostics.is_empty() {
+            return;
+        }
+
+        let entries = self.files.entry(file.into()).or_default();
+        entries.extend(baseline_entries(source, toml_version, diagnostics));
+        entries.sort();
+    }
+
+    /// Split the diagnostics of the file into new diagnostics and baselined diagnostics.
+    ///
+    /// Each baseline entry accepts one diagnostic, so additional diagnostics
+    /// with the same accessor path and code are still reported as new.
+    pub fn partition(
+        &self,
+        file: &str,
+        source: &str,
+        toml_version: TomlVersion,
+        diagnostics: Vec<tombi_diagnostic::Diagnostic>,
+    ) -> (
+        Vec<tombi_diagnostic::Diagnostic>,
+        Vec<tombi_diagnostic::Diagnostic>,
+    ) {
+        let Some(baseline_entries_of_file) = self.files.get(file) else {
+            return (diagnostics, Vec::new());
+        };
+
+        let mut remaining = baseline_entries_of_file.clone();
+        let entries = baseline_entries(source, toml_version, &diagnostics);
+
+        let mut new_diagnostics = Vec::new();
+        let mut baselined_diagnostics = Vec::new();
+        for (diagnostic, entry) in diagnostics.into_iter().zip(entries) {
+            if let Some(index) = remaining.iter().position(|remaining| *remaining == entry) {
+                remaining.swap_remove(index);
+                baselined_diagnostics.push(diagnostic);
+            } else {
+                new_diagnostics.push(diagnostic);
+            }
+        }
+
+        (new_diagnostics, baselined_diagnostics)
+    }
+
+    /// The number of recorded diagnostics.
+    pub fn len(&self) -> usize {
+        self.files.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.values().all(Vec::is_empty)
+    }
+
+    /// The key of the file in the baseline: its path relative to the baseline file,
+    /// with `/` separators.
+    pub fn file_key(baseline_path: &std::path::Path, file_path: &std::path::Path) -> String {
+        let baseline_dir = baseline_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(std::path::Path::new("."));
+        let baseline_dir = baseline_dir
+            .canonicalize()
+            .unwrap_or_else(|_| baseline_dir.to_path_buf());
+        let file_path = file_path
+            .canonicalize()
+            .unwrap_or_else(|_| file_path.to_path_buf());
+
+        file_path
+            .strip_prefix(&baseline_dir)
+            .unwrap_or(&file_path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+fn baseline_entries(
+    source: &str,
+    toml_version: TomlVersion,
+    diagnostics: &[tombi_diagnostic::Diagnostic],
+) -> Vec<BaselineEntry> {
+    let (root, _) = tombi_parser::parse(source).into_root_and_errors();
+    let document_tree =
+        tombi_document_tree::Value::from(root.into_document_tree_and_errors(toml_version).tree);
+
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut accessors = Vec::new();
+            collect_accessors(&document_tree, diagnostic.position(), &mut accessors);
+            BaselineEntry {
+                accessors: Accessors::from(accessors).to_string(),
+                code: diagnostic.code().to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Collect the accessors of the innermost value at the position.
+fn collect_accessors(
+    value: &tombi_document_tree::Value,
+    position: tombi_text::Position,
+    accessors: &mut Vec<Accessor>,
+) {
+    match value {
+        tombi_document_tree::Value::Table(table) => {
+            for (key, value) in table.key_values() {
+                if key.range().contains(position) || value.range().contains(position) {
+                    accessors.push(Accessor::Key(key.value.clone()));
+                    collect_accessors(value, position, accessors);
+                    return;
+                }
+            }
+        }
+        tombi_document_tree::Value::Array(array) => {
+            for (index, value) in array.values().iter().enumerate() {
+                if value.range().contains(position) {
+                    accessors.push(Accessor::Index(index));
+                    collect_accessors(value, position, accessors);
+                    return;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(code: &str, line: u32, column: u32) -> tombi_diagnostic::Diagnostic {
+        tombi_diagnostic::Diagnostic::new_warning(
+            "message",
+            code,
+            tombi_text::Range::at(tombi_text::Position::new(line, column)),
+        )
+    }
+
+    const SOURCE: &str = r#"
+[package]
+name = "tombi"
+authors = ["a", "b"]
+
+[dependencies]
+serde.version = "1.0"
+"#;
+
+    #[test]
+    fn baseline_entries_use_accessors() {
+        let entries = baseline_entries(
+            SOURCE,
+            TomlVersion::default(),
+            &[
+                diagnostic("deprecated", 2, 7),
+                diagnostic("string-pattern", 3, 17),
+                diagnostic("key-not-allowed", 6, 6),
+                diagnostic("tables-out-of-order", 0, 0),
+            ],
+        );
+
+        pretty_assertions::assert_eq!(
+            entries
+                .into_iter()
+                .map(|entry| (entry.accessors, entry.code))
+                .collect::<Vec<_>>(),
+            vec![
+                ("package.name".to_string(), "deprecated".to_string()),
+                (
+                    "package.authors[1]".to_string(),
+                    "string-pattern".to_string()
+                ),
+                (
+                    "dependencies.serde.version".to_string(),
+                    "key-not-allowed".to_string()
+                ),
+                ("".to_string(), "tables-out-of-order".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn partition_ignores_line_changes() {
+        let mut baseline = Baseline::default();
+        baseline.insert(
+            "Cargo.toml",
+            SOURCE,
+            TomlVersion::default(),
+            &[diagnostic("deprecated", 2, 7)],
+        );
+
+        let source = format!("# A new comment\n{SOURCE}");
+        let (new_diagnostics, baselined_diagnostics) = baseline.partition(
+            "Cargo.toml",
+            &source,
+            TomlVersion::default(),
+            vec![
+                diagnostic("deprecated", 3, 7),
+                diagnostic("deprecated", 4, 1),
+            ],
+        );
+
+        pretty_assertions::assert_eq!(baselined_diagnostics, vec![diagnostic("deprecated", 3, 7)]);
+        pretty_assertions::assert_eq!(new_diagnostics, vec![diagnostic("deprecated", 4, 1)]);
+    }
+
+    #[test]
+    fn partition_counts_duplicate_entries() {
+        let mut baseline = Baseline::default();
+        baseline.insert(
+            "Cargo.toml",
+            SOURCE,
+            TomlVersion::default(),
+            &[diagnostic("deprecated", 2, 7)],
+        );
+
+        let (new_diagnostics, baselined_diagnostics) = baseline.partition(
+            "Cargo.toml",
+            SOURCE,
+            TomlVersion::default(),
+            vec![
+                diagnostic("deprecated", 2, 7),
+                diagnostic("deprecated", 2, 8),
+            ],
+        );
+
+        pretty_assertions::assert_eq!(baselined_diagnostics.len(), 1);
+        pretty_assertions::assert_eq!(new_diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn partition_of_unknown_file() {
+        let baseline = Baseline::default();
+
+        let (new_diagnostics, baselined_diagnostics) = baseline.partition(
+            "Cargo.toml",
+            SOURCE,
+            TomlVersion::default(),
+            vec![diagnostic("deprecated", 2, 7)],
+        );
+
+        pretty_assertions::assert_eq!(new_diagnostics.len(), 1);
+        assert!(baselined_diagnostics.is_empty());
+    }
+}
@@ -14,6 +14,22 @@ pub enum DiagnosticKind {
     MissingArrayComma,
     #[error("missing ','")]
     MissingInlineTableComma,
+    #[error(
+        "Defining an array of inline tables alongside `[[array of tables]]` headers is discouraged"
+    )]
+    NoInlineTableInArrayOfTablesContext,
+    #[error("Inline table is longer than {max_width} characters; prefer a table section")]
+    PreferTableOverLongInlineTable { max_width: u8 },
+    #[error("Nesting depth {actual} exceeds the maximum of {max_depth}")]
+    MaxNestingDepth { max_depth: u8, actual: usize },
+    #[error("Defining the same table with both dotted keys and table headers is discouraged")]
+    NoMixedKeyStyles,
+    #[error("Empty table is discouraged")]
+    NoEmptyTables,
+    #[error("Duplicate array value")]
+    NoDuplicateArrayValues,
+    #[error("`{directive} = true` suppresses nothing. Please remove it.")]
+    UnusedDirective { directive: String },
 }
 
 #[derive(Debug)]
@@ -33,6 +49,17 @@ impl Diagnostic {
             DiagnosticKind::ForbiddenInlineTableLastComma => "forbidden-inline-table-last-comma",
             DiagnosticKind::MissingArrayComma => "missing-array-comma",
             DiagnosticKind::MissingInlineTableComma => "missing-inline-table-comma",
+            DiagnosticKind::NoInlineTableInArrayOfTablesContext => {
+                "no-inline-table-in-array-of-tables-context"
+            }
+            DiagnosticKind::PreferTableOverLongInlineTable { .. } => {
+                "prefer-table-over-long-inline-table"
+            }
+            DiagnosticKind::MaxNestingDepth { .. } => "max-nesting-depth",
+            DiagnosticKind::NoMixedKeyStyles => "no-mixed-key-styles",
+            DiagnosticKind::NoEmptyTables => "no-empty-tables",
+            DiagnosticKind::NoDuplicateArrayValues => "no-duplicate-array-values",
+            DiagnosticKind::UnusedDirective { .. } => "unused-directive",
         }
     }
 }
@@ -1,9 +1,12 @@
+mod baseline;
 mod diagnostic;
 mod error;
 mod lint;
 mod linter;
 mod rule;
+mod unused_directive;
 
+pub use baseline::{Baseline, BaselineEntry};
 pub use diagnostic::{Diagnostic, DiagnosticKind};
 pub use error::{Error, ErrorKind};
 use lint::Lint;
@@ -32,6 +35,7 @@ macro_rules! test_lint {
                 pub config: Option<tombi_config::Config>,
                 pub schema_path: Option<std::path::PathBuf>,
                 pub source_path: Option<std::path::PathBuf>,
+                pub report_unused_directives: bool,
             }
 
             #[allow(unused)]
@@ -81,6 +85,16 @@ macro_rules! test_lint {
                 }
             }
 
+            /// Report unused comment directives for the test case.
+            #[allow(unused)]
+            pub struct ReportUnusedDirectives;
+
+            impl ApplyTestArg for ReportUnusedDirectives {
+                fn apply(self, args: &mut TestArgs) {
+                    args.report_unused_directives = true;
+                }
+            }
+
             #[allow(unused_mut)]
             let mut args = TestArgs::default();
             $(
@@ -111,7 +125,8 @@ macro_rules! test_lint {
                 &args.options,
                 Some(itertools::Either::Right(source_path.as_path())),
                 &schema_store,
-            );
+            )
+            .report_unused_directives(args.report_unused_directives);
 
             match linter.lint($source).await {
                 Ok(_) => {}
@@ -146,6 +161,7 @@ macro_rules! test_lint {
                 pub config: Option<tombi_config::Config>,
                 pub schema_path: Option<std::path::PathBuf>,
                 pub source_path: Option<std::path::PathBuf>,
+                pub report_unused_directives: bool,
             }
 
             #[allow(unused)]
@@ -195,6 +211,16 @@ macro_rules! test_lint {
                 }
             }
 
+            /// Report unused comment directives for the test case.
+            #[allow(unused)]
+            pub struct ReportUnusedDirectives;
+
+            impl ApplyTestArg for ReportUnusedDirectives {
+                fn apply(self, args: &mut TestArgs) {
+                    args.report_unused_directives = true;
+                }
+            }
+
             #[allow(unused_mut)]
             let mut args = TestArgs::default();
             $(
@@ -225,7 +251,8 @@ macro_rules! test_lint {
                 &args.options,
                 Some(itertools::Either::Right(source_path.as_path())),
                 &schema_store,
-            );
+            )
+            .report_unused_directives(args.report_unused_directives);
 
             let result = linter.lint($source).await;
             match result {
@@ -265,6 +292,7 @@ macro_rules! test_lint {
                 pub config: Option<tombi_config::Config>,
                 pub schema_path: Option<std::path::PathBuf>,
                 pub source_path: Option<std::path::PathBuf>,
+                pub report_unused_directives: bool,
             }
 
             #[allow(unused)]
@@ -314,6 +342,16 @@ macro_rules! test_lint {
                 }
             }
 
+            /// Report unused comment directives for the test case.
+            #[allow(unused)]
+            pub struct ReportUnusedDirectives;
+
+            impl ApplyTestArg for ReportUnusedDirectives {
+                fn apply(self, args: &mut TestArgs) {
+                    args.report_unused_directives = true;
+                }
+            }
+
             #[allow(unused_mut)]
             let mut args = TestArgs::default();
             $(
@@ -344,7 +382,8 @@ macro_rules! test_lint {
                 &args.options,
                 Some(itertools::Either::Right(source_path.as_path())),
                 &schema_store,
-            );
+            )
+            .report_unused_directives(args.report_unused_directives);
 
             let result = linter.lint($source).await;
             match result {
@@ -7,6 +7,7 @@ impl Lint for tombi_ast::ArrayOfTable {
         async move {
             crate::rule::DottedKeysOutOfOrderRule::check(self, l).await;
             crate::rule::TrailingCommaRule::check(self, l).await;
+            crate::rule::MaxNestingDepthRule::check(self, l).await;
 
             for key_value in self.key_values() {
                 key_value.lint(l).await;
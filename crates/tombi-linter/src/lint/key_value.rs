@@ -1,10 +1,12 @@
 use tombi_future::Boxable;
 
-use crate::Lint;
+use crate::{Lint, rule::Rule};
 
 impl Lint for tombi_ast::KeyValue {
     fn lint<'a: 'b, 'b>(&'a self, l: &'a mut crate::Linter<'_>) -> tombi_future::BoxFuture<'b, ()> {
         async move {
+            crate::rule::MaxNestingDepthRule::check(self, l).await;
+
             if let Some(value) = self.value() {
                 value.lint(l).await;
             }
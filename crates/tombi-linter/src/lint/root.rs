@@ -7,6 +7,7 @@ impl Lint for tombi_ast::Root {
         async move {
             crate::rule::DottedKeysOutOfOrderRule::check(self, l).await;
             crate::rule::TablesOutOfOrderRule::check(self, l).await;
+            crate::rule::NoMixedKeyStylesRule::check(self, l).await;
             crate::rule::TrailingCommaRule::check(self, l).await;
 
             for key_value in self.key_values() {
@@ -7,6 +7,8 @@ impl Lint for tombi_ast::Table {
         async move {
             crate::rule::DottedKeysOutOfOrderRule::check(self, l).await;
             crate::rule::TrailingCommaRule::check(self, l).await;
+            crate::rule::NoEmptyTablesRule::check(self, l).await;
+            crate::rule::MaxNestingDepthRule::check(self, l).await;
 
             for key_value in self.key_values() {
                 key_value.lint(l).await;
@@ -6,6 +6,8 @@ impl Lint for tombi_ast::Array {
     fn lint<'a: 'b, 'b>(&'a self, l: &'a mut crate::Linter<'_>) -> tombi_future::BoxFuture<'b, ()> {
         async move {
             crate::rule::MissingCommaRule::check(self, l).await;
+            crate::rule::NoDuplicateArrayValuesRule::check(self, l).await;
+            crate::rule::NoInlineTableInArrayOfTablesContextRule::check(self, l).await;
 
             for value in self.values() {
                 value.lint(l).await;
@@ -8,6 +8,8 @@ impl Lint for tombi_ast::InlineTable {
             crate::rule::MissingCommaRule::check(self, l).await;
             crate::rule::DottedKeysOutOfOrderRule::check(self, l).await;
             crate::rule::InlineTableTomlVersionRule::check(self, l).await;
+            crate::rule::NoEmptyTablesRule::check(self, l).await;
+            crate::rule::PreferTableOverLongInlineTableRule::check(self, l).await;
 
             for key_value in self.key_values() {
                 key_value.lint(l).await;
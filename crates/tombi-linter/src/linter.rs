@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use itertools::Either;
+use itertools::{Either, Itertools};
 use tombi_config::TomlVersion;
 use tombi_diagnostic::{Diagnostic, SetDiagnostics};
 use tombi_document_tree::IntoDocumentTreeAndErrors;
@@ -14,6 +14,8 @@ pub struct Linter<'a> {
     source_text: Cow<'a, str>,
     source_uri_or_path: Option<Either<&'a tombi_uri::Uri, &'a std::path::Path>>,
     schema_store: &'a tombi_schema_store::SchemaStore,
+    report_unused_directives: bool,
+    ignore_lint_disabled: bool,
     pub(crate) diagnostics: Vec<tombi_diagnostic::Diagnostic>,
 }
 
@@ -30,10 +32,24 @@ impl<'a> Linter<'a> {
             source_text: Cow::Borrowed(""),
             source_uri_or_path,
             schema_store,
+            report_unused_directives: false,
+            ignore_lint_disabled: false,
             diagnostics: Vec::new(),
         }
     }
 
+    /// Report the comment directives that suppress no diagnostics.
+    ///
+    /// ```toml
+    /// # tombi: lint.rules.no-empty-tables.disabled = true
+    /// [table]
+    /// key = "value"
+    /// ```
+    pub fn report_unused_directives(mut self, report_unused_directives: bool) -> Self {
+        self.report_unused_directives = report_unused_directives;
+        self
+    }
+
     pub async fn lint(mut self, source: &str) -> Result<(), Vec<Diagnostic>> {
         self.source_text = Cow::Borrowed(source);
 
@@ -42,6 +58,12 @@ impl<'a> Linter<'a> {
             error.set_diagnostics(&mut self.diagnostics);
         }
 
+        let unused_directive_diagnostics = if self.report_unused_directives {
+            self.unused_directive_diagnostics(&root, source).await
+        } else {
+            Vec::new()
+        };
+
         let (source_schema, tombi_document_comment_directive) = {
             let (source_schema, error_with_range) =
                 tombi_schema_store::lint_source_schema_from_ast(
@@ -66,7 +88,8 @@ impl<'a> Linter<'a> {
             (source_schema, tombi_document_comment_directive)
         };
 
-        if let Some(tombi_document_comment_directive) = &tombi_document_comment_directive
+        if !self.ignore_lint_disabled
+            && let Some(tombi_document_comment_directive) = &tombi_document_comment_directive
             && let Some(lint) = &tombi_document_comment_directive.lint
             && lint.disabled.unwrap_or_default()
         {
@@ -85,7 +108,12 @@ impl<'a> Linter<'a> {
                         log::info!("Skip linting for stdin due to `lint.disable`");
                     }
                 }
-                return Ok(());
+                self.extend_diagnostics(unused_directive_diagnostics);
+                return if self.diagnostics.is_empty() {
+                    Ok(())
+                } else {
+                    Err(self.diagnostics)
+                };
             }
         }
 
@@ -155,6 +183,20 @@ impl<'a> Linter<'a> {
             }
         }
 
+        // The validator already reports unused schema rule suppressions as `unused-noqa`.
+        let unused_noqa_ranges = self
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.code() == "unused-noqa")
+            .map(|diagnostic| diagnostic.range())
+            .collect_vec();
+        self.extend_diagnostics(
+            unused_directive_diagnostics
+                .into_iter()
+                .filter(|diagnostic| !unused_noqa_ranges.contains(&diagnostic.range))
+                .collect_vec(),
+        );
+
         if self.diagnostics.is_empty() {
             Ok(())
         } else {
@@ -183,6 +225,36 @@ impl<'a> Linter<'a> {
         diagnostics.set_diagnostics(&mut self.diagnostics);
     }
 
+    /// Lint the document again without its suppressions,
+    /// and report the suppressions that no diagnostic needed.
+    async fn unused_directive_diagnostics(
+        &self,
+        root: &tombi_ast::Root,
+        source: &str,
+    ) -> Vec<crate::Diagnostic> {
+        let directives = crate::unused_directive::collect_suppression_directives(root);
+        if directives.is_empty() {
+            return Vec::new();
+        }
+
+        let unsuppressed_source =
+            crate::unused_directive::strip_suppression_directives(source, &directives);
+        let mut linter = Linter::new(
+            self.toml_version,
+            &self.options,
+            self.source_uri_or_path,
+            self.schema_store,
+        );
+        linter.ignore_lint_disabled = true;
+
+        let unsuppressed_diagnostics = Box::pin(linter.lint(&unsuppressed_source))
+            .await
+            .err()
+            .unwrap_or_default();
+
+        crate::unused_directive::unused_directive_diagnostics(directives, &unsuppressed_diagnostics)
+    }
+
     fn apply_lint_rules_to_diagnostics(&self, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
         let Some(key_empty_severity) = self
             .options
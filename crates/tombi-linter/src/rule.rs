@@ -1,14 +1,43 @@
 mod dotted_keys_out_of_order;
 mod inline_table_toml_version;
+mod max_nesting_depth;
 mod missing_comma;
+mod no_duplicate_array_values;
+mod no_empty_tables;
+mod no_inline_table_in_array_of_tables_context;
+mod no_mixed_key_styles;
+mod prefer_table_over_long_inline_table;
 mod tables_out_of_order;
 mod trailing_comma;
 pub use dotted_keys_out_of_order::DottedKeysOutOfOrderRule;
 pub use inline_table_toml_version::InlineTableTomlVersionRule;
+pub use max_nesting_depth::MaxNestingDepthRule;
 pub use missing_comma::MissingCommaRule;
+pub use no_duplicate_array_values::NoDuplicateArrayValuesRule;
+pub use no_empty_tables::NoEmptyTablesRule;
+pub use no_inline_table_in_array_of_tables_context::NoInlineTableInArrayOfTablesContextRule;
+pub use no_mixed_key_styles::NoMixedKeyStylesRule;
+pub use prefer_table_over_long_inline_table::PreferTableOverLongInlineTableRule;
 pub use tables_out_of_order::TablesOutOfOrderRule;
+use tombi_ast::AstNode;
 pub use trailing_comma::TrailingCommaRule;
 
 pub trait Rule<N> {
     async fn check(node: &N, l: &mut crate::Linter<'_>);
 }
+
+/// The comment directives of the key-value whose value is `node`.
+///
+/// ```toml
+/// # tombi: lint.rules.no-empty-tables.disabled = true
+/// key = {}
+/// ```
+fn parent_key_value_comment_directives(
+    node: &impl AstNode,
+) -> Vec<tombi_ast::TombiValueCommentDirective> {
+    node.syntax()
+        .parent()
+        .and_then(tombi_ast::KeyValue::cast)
+        .map(|key_value| key_value.comment_directives().collect())
+        .unwrap_or_default()
+}
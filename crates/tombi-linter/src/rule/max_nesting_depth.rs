@@ -0,0 +1,273 @@
+use tombi_ast::AstNode;
+use tombi_comment_directive::value::{
+    ArrayOfTableCommonFormatRules, ArrayOfTableCommonLintRules, TableCommonFormatRules,
+    TableCommonLintRules, WarnRuleOptions,
+};
+use tombi_comment_directive_serde::get_comment_directive_content;
+use tombi_config::SeverityLevel;
+
+use crate::Rule;
+
+pub struct MaxNestingDepthRule;
+
+impl Rule<tombi_ast::Table> for MaxNestingDepthRule {
+    async fn check(node: &tombi_ast::Table, l: &mut crate::Linter<'_>) {
+        let Some(header) = node.header() else {
+            return;
+        };
+
+        let comment_directive = get_comment_directive_content::<
+            TableCommonFormatRules,
+            TableCommonLintRules,
+        >(node.comment_directives());
+
+        check_max_nesting_depth(
+            header.keys().count(),
+            header.range(),
+            comment_directive
+                .as_ref()
+                .and_then(|comment_directive| comment_directive.lint_rules())
+                .and_then(|rules| rules.value.max_nesting_depth.as_ref()),
+            l,
+        );
+    }
+}
+
+impl Rule<tombi_ast::ArrayOfTable> for MaxNestingDepthRule {
+    async fn check(node: &tombi_ast::ArrayOfTable, l: &mut crate::Linter<'_>) {
+        let Some(header) = node.header() else {
+            return;
+        };
+
+        let comment_directive = get_comment_directive_content::<
+            ArrayOfTableCommonFormatRules,
+            ArrayOfTableCommonLintRules,
+        >(node.comment_directives());
+
+        check_max_nesting_depth(
+            header.keys().count(),
+            header.range(),
+            comment_directive
+                .as_ref()
+                .and_then(|comment_directive| comment_directive.lint_rules())
+                .and_then(|rules| rules.value.table.max_nesting_depth.as_ref()),
+            l,
+        );
+    }
+}
+
+impl Rule<tombi_ast::KeyValue> for MaxNestingDepthRule {
+    async fn check(node: &tombi_ast::KeyValue, l: &mut crate::Linter<'_>) {
+        let Some(keys) = node.keys() else {
+            return;
+        };
+
+        let Some(max_depth) = max_depth(l) else {
+            return;
+        };
+
+        // Only the outermost key-value that exceeds the limit is reported.
+        let parent_depth = parent_depth(node);
+        if parent_depth > max_depth as usize {
+            return;
+        }
+
+        let keys_count = keys.keys().count();
+        let depth = if node.value().as_ref().is_some_and(contains_table) {
+            parent_depth + keys_count
+        } else {
+            (parent_depth + keys_count).saturating_sub(1)
+        };
+
+        let comment_directive = get_comment_directive_content::<
+            TableCommonFormatRules,
+            TableCommonLintRules,
+        >(node.comment_directives());
+
+        check_max_nesting_depth(
+            depth,
+            keys.range(),
+            comment_directive
+                .as_ref()
+                .and_then(|comment_directive| comment_directive.lint_rules())
+                .and_then(|rules| rules.value.max_nesting_depth.as_ref()),
+            l,
+        );
+    }
+}
+
+fn max_depth(l: &crate::Linter<'_>) -> Option<u8> {
+    l.options()
+        .rules
+        .as_ref()
+        .and_then(|rules| rules.max_nesting_depth)
+        .map(|rule_options| rule_options.max_depth.unwrap_or_default().value())
+}
+
+/// The number of keys from the root to the table that contains the key-value.
+fn parent_depth(key_value: &tombi_ast::KeyValue) -> usize {
+    key_value
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .map(|ancestor| {
+            if let Some(key_value) = tombi_ast::KeyValue::cast(ancestor.clone()) {
+                key_value.keys().map(|keys| keys.keys().count())
+            } else if let Some(table) = tombi_ast::Table::cast(ancestor.clone()) {
+                table.header().map(|header| header.keys().count())
+            } else if let Some(array_of_table) = tombi_ast::ArrayOfTable::cast(ancestor) {
+                array_of_table.header().map(|header| header.keys().count())
+            } else {
+                None
+            }
+            .unwrap_or_default()
+        })
+        .sum()
+}
+
+fn contains_table(value: &tombi_ast::Value) -> bool {
+    match value {
+        tombi_ast::Value::InlineTable(_) => true,
+        tombi_ast::Value::Array(array) => array.values().any(|value| contains_table(&value)),
+        _ => false,
+    }
+}
+
+fn check_max_nesting_depth(
+    depth: usize,
+    range: tombi_text::Range,
+    rule_directive: Option<&WarnRuleOptions>,
+    l: &mut crate::Linter<'_>,
+) {
+    let Some(rule_options) = l
+        .options()
+        .rules
+        .as_ref()
+        .and_then(|rules| rules.max_nesting_depth)
+    else {
+        return;
+    };
+
+    let level = rule_options.level.unwrap_or_default();
+    if level == SeverityLevel::Off {
+        return;
+    }
+
+    let max_depth = rule_options.max_depth.unwrap_or_default().value();
+    if depth <= max_depth as usize {
+        return;
+    }
+
+    if rule_directive
+        .and_then(|rule| rule.disabled)
+        .unwrap_or_default()
+    {
+        return;
+    }
+
+    l.extend_diagnostics(crate::Diagnostic {
+        kind: crate::DiagnosticKind::MaxNestingDepth {
+            max_depth,
+            actual: depth,
+        },
+        level: level.into(),
+        range,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_lint;
+
+    fn lint_options(max_depth: u8) -> crate::LintOptions {
+        crate::LintOptions {
+            rules: Some(tombi_config::LintRules {
+                max_nesting_depth: Some(tombi_config::MaxNestingDepthRuleOptions {
+                    level: None,
+                    max_depth: Some(tombi_config::MaxNestingDepth::try_from(max_depth).unwrap()),
+                }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    test_lint! {
+        #[test]
+        fn deep_table_is_allowed_by_default(
+            r#"
+            [a.b.c.d.e.f]
+            g = 1
+            "#,
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn table_header_too_deep(
+            r#"
+            [a.b.c]
+            d = 1
+            "#,
+            lint_options(2),
+        ) -> Err([crate::DiagnosticKind::MaxNestingDepth { max_depth: 2, actual: 3 }])
+    }
+
+    test_lint! {
+        #[test]
+        fn inline_table_in_table_too_deep(
+            r#"
+            [a.b]
+            c = 1
+            d = { e = 1 }
+            "#,
+            lint_options(2),
+        ) -> Err([crate::DiagnosticKind::MaxNestingDepth { max_depth: 2, actual: 3 }])
+    }
+
+    test_lint! {
+        #[test]
+        fn dotted_keys_too_deep(
+            r#"
+            [a]
+            b.c.d = 1
+            "#,
+            lint_options(2),
+        ) -> Err([crate::DiagnosticKind::MaxNestingDepth { max_depth: 2, actual: 3 }])
+    }
+
+    test_lint! {
+        #[test]
+        fn nested_inline_tables_report_outermost_only(
+            r#"
+            a = { b = { c = { d = 1 } } }
+            "#,
+            lint_options(2),
+        ) -> Err([crate::DiagnosticKind::MaxNestingDepth { max_depth: 2, actual: 3 }])
+    }
+
+    test_lint! {
+        #[test]
+        fn key_values_within_depth(
+            r#"
+            a.b = 1
+            c = { d = 1 }
+
+            [e]
+            f = 1
+            "#,
+            lint_options(2),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn table_header_too_deep_with_leading_comment_directive(
+            r#"
+            # tombi: lint.rules.max-nesting-depth.disabled = true
+            [a.b.c]
+            d = 1
+            "#,
+            lint_options(2),
+        ) -> Ok(_)
+    }
+}
@@ -0,0 +1,172 @@
+use tombi_comment_directive::value::{ArrayCommonFormatRules, ArrayCommonLintRules};
+use tombi_comment_directive_serde::get_comment_directive_content;
+use tombi_config::SeverityLevel;
+use tombi_document_tree::IntoDocumentTreeAndErrors;
+
+use crate::Rule;
+
+pub struct NoDuplicateArrayValuesRule;
+
+impl Rule<tombi_ast::Array> for NoDuplicateArrayValuesRule {
+    async fn check(node: &tombi_ast::Array, l: &mut crate::Linter<'_>) {
+        let level = l
+            .options()
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.no_duplicate_array_values)
+            .unwrap_or_default();
+
+        if level == SeverityLevel::Off {
+            return;
+        }
+
+        let comment_directive = get_comment_directive_content::<
+            ArrayCommonFormatRules,
+            ArrayCommonLintRules,
+        >(itertools::chain!(
+            crate::rule::parent_key_value_comment_directives(node),
+            node.comment_directives()
+        ));
+
+        if comment_directive
+            .as_ref()
+            .and_then(|comment_directive| comment_directive.lint_rules())
+            .and_then(|rules| rules.value.no_duplicate_array_values.as_ref())
+            .and_then(|rule| rule.disabled)
+            .unwrap_or_default()
+        {
+            return;
+        }
+
+        let toml_version = l.toml_version();
+        let mut seen_values: Vec<tombi_document_tree::Value> = Vec::new();
+
+        for value in node.values() {
+            let range = value.range();
+            let (value, errors) = value.into_document_tree_and_errors(toml_version).into();
+            if !errors.is_empty() {
+                continue;
+            }
+
+            if seen_values
+                .iter()
+                .any(|seen_value| is_same_value(seen_value, &value))
+            {
+                l.extend_diagnostics(crate::Diagnostic {
+                    kind: crate::DiagnosticKind::NoDuplicateArrayValues,
+                    level: level.into(),
+                    range,
+                });
+            } else {
+                seen_values.push(value);
+            }
+        }
+    }
+}
+
+/// Compare values regardless of their notation, such as `1` and `0x1`, or `"a"` and `'a'`.
+fn is_same_value(a: &tombi_document_tree::Value, b: &tombi_document_tree::Value) -> bool {
+    use tombi_document_tree::Value;
+
+    match (a, b) {
+        (Value::Boolean(a), Value::Boolean(b)) => a.value() == b.value(),
+        (Value::Integer(a), Value::Integer(b)) => a.value() == b.value(),
+        (Value::Float(a), Value::Float(b)) => a.value() == b.value(),
+        (Value::String(a), Value::String(b)) => a.value() == b.value(),
+        (Value::OffsetDateTime(a), Value::OffsetDateTime(b)) => a.value() == b.value(),
+        (Value::LocalDateTime(a), Value::LocalDateTime(b)) => a.value() == b.value(),
+        (Value::LocalDate(a), Value::LocalDate(b)) => a.value() == b.value(),
+        (Value::LocalTime(a), Value::LocalTime(b)) => a.value() == b.value(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.values().len() == b.values().len()
+                && a.values()
+                    .iter()
+                    .zip(b.values())
+                    .all(|(a, b)| is_same_value(a, b))
+        }
+        (Value::Table(a), Value::Table(b)) => {
+            a.len() == b.len()
+                && a.key_values().iter().all(|(key, a)| {
+                    b.get(key.value.as_str())
+                        .is_some_and(|b| is_same_value(a, b))
+                })
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultOff};
+
+    use crate::test_lint;
+
+    fn lint_options() -> crate::LintOptions {
+        crate::LintOptions {
+            rules: Some(tombi_config::LintRules {
+                no_duplicate_array_values: Some(SeverityLevelDefaultOff::from(SeverityLevel::Warn)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    test_lint! {
+        #[test]
+        fn duplicate_values_are_allowed_by_default(
+            r#"
+            features = ["std", "std"]
+            "#,
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn duplicate_string_values(
+            r#"
+            features = ["std", "derive", 'std']
+            "#,
+            lint_options(),
+        ) -> Err([crate::DiagnosticKind::NoDuplicateArrayValues])
+    }
+
+    test_lint! {
+        #[test]
+        fn duplicate_integer_values_in_different_notation(
+            r#"
+            numbers = [1, 0x1, 2]
+            "#,
+            lint_options(),
+        ) -> Err([crate::DiagnosticKind::NoDuplicateArrayValues])
+    }
+
+    test_lint! {
+        #[test]
+        fn duplicate_inline_table_values(
+            r#"
+            points = [{ x = 1, y = 2 }, { y = 2, x = 1 }]
+            "#,
+            lint_options(),
+        ) -> Err([crate::DiagnosticKind::NoDuplicateArrayValues])
+    }
+
+    test_lint! {
+        #[test]
+        fn unique_values(
+            r#"
+            features = ["std", "derive"]
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn duplicate_values_with_leading_comment_directive(
+            r#"
+            # tombi: lint.rules.no-duplicate-array-values.disabled = true
+            features = ["std", "std"]
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+}
@@ -0,0 +1,167 @@
+use tombi_ast::AstNode;
+use tombi_comment_directive::value::{
+    InlineTableCommonFormatRules, InlineTableCommonLintRules, TableCommonFormatRules,
+    TableCommonLintRules,
+};
+use tombi_comment_directive_serde::get_comment_directive_content;
+use tombi_config::SeverityLevel;
+
+use crate::Rule;
+
+pub struct NoEmptyTablesRule;
+
+impl Rule<tombi_ast::Table> for NoEmptyTablesRule {
+    async fn check(node: &tombi_ast::Table, l: &mut crate::Linter<'_>) {
+        if node.key_values().next().is_some() {
+            return;
+        }
+
+        let comment_directive = get_comment_directive_content::<
+            TableCommonFormatRules,
+            TableCommonLintRules,
+        >(node.comment_directives());
+
+        if comment_directive
+            .as_ref()
+            .and_then(|comment_directive| comment_directive.lint_rules())
+            .and_then(|rules| rules.value.no_empty_tables.as_ref())
+            .and_then(|rule| rule.disabled)
+            .unwrap_or_default()
+        {
+            return;
+        }
+
+        report_empty_table(
+            node.header()
+                .map(|header| header.range())
+                .unwrap_or_else(|| node.range()),
+            l,
+        );
+    }
+}
+
+impl Rule<tombi_ast::InlineTable> for NoEmptyTablesRule {
+    async fn check(node: &tombi_ast::InlineTable, l: &mut crate::Linter<'_>) {
+        if node.key_values().next().is_some() {
+            return;
+        }
+
+        let comment_directive = get_comment_directive_content::<
+            InlineTableCommonFormatRules,
+            InlineTableCommonLintRules,
+        >(itertools::chain!(
+            crate::rule::parent_key_value_comment_directives(node),
+            node.comment_directives()
+        ));
+
+        if comment_directive
+            .as_ref()
+            .and_then(|comment_directive| comment_directive.lint_rules())
+            .and_then(|rules| rules.value.0.no_empty_tables.as_ref())
+            .and_then(|rule| rule.disabled)
+            .unwrap_or_default()
+        {
+            return;
+        }
+
+        report_empty_table(node.syntax().range(), l);
+    }
+}
+
+fn report_empty_table(range: tombi_text::Range, l: &mut crate::Linter<'_>) {
+    let level = l
+        .options()
+        .rules
+        .as_ref()
+        .and_then(|rules| rules.no_empty_tables)
+        .unwrap_or_default();
+
+    if level == SeverityLevel::Off {
+        return;
+    }
+
+    l.extend_diagnostics(crate::Diagnostic {
+        kind: crate::DiagnosticKind::NoEmptyTables,
+        level: level.into(),
+        range,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultOff};
+
+    use crate::test_lint;
+
+    fn lint_options() -> crate::LintOptions {
+        crate::LintOptions {
+            rules: Some(tombi_config::LintRules {
+                no_empty_tables: Some(SeverityLevelDefaultOff::from(SeverityLevel::Warn)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    test_lint! {
+        #[test]
+        fn empty_table_is_allowed_by_default(
+            r#"
+            [table]
+            "#,
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn empty_table(
+            r#"
+            [table]
+            "#,
+            lint_options(),
+        ) -> Err([crate::DiagnosticKind::NoEmptyTables])
+    }
+
+    test_lint! {
+        #[test]
+        fn empty_inline_table(
+            r#"
+            key = {}
+            "#,
+            lint_options(),
+        ) -> Err([crate::DiagnosticKind::NoEmptyTables])
+    }
+
+    test_lint! {
+        #[test]
+        fn non_empty_tables(
+            r#"
+            key = { a = 1 }
+
+            [table]
+            b = 2
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn empty_table_with_leading_comment_directive(
+            r#"
+            # tombi: lint.rules.no-empty-tables.disabled = true
+            [table]
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn empty_inline_table_with_trailing_comment_directive(
+            r#"
+            key = {} # tombi: lint.rules.no-empty-tables.disabled = true
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+}
@@ -0,0 +1,142 @@
+use tombi_ast::AstNode;
+use tombi_comment_directive::value::{ArrayCommonFormatRules, ArrayCommonLintRules};
+use tombi_comment_directive_serde::get_comment_directive_content;
+use tombi_config::SeverityLevel;
+
+use crate::Rule;
+
+pub struct NoInlineTableInArrayOfTablesContextRule;
+
+impl Rule<tombi_ast::Array> for NoInlineTableInArrayOfTablesContextRule {
+    async fn check(node: &tombi_ast::Array, l: &mut crate::Linter<'_>) {
+        let level = l
+            .options()
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.no_inline_table_in_array_of_tables_context)
+            .unwrap_or_default();
+
+        if level == SeverityLevel::Off {
+            return;
+        }
+
+        let mut values = node.values().peekable();
+        if values.peek().is_none()
+            || !values.all(|value| matches!(value, tombi_ast::Value::InlineTable(_)))
+        {
+            return;
+        }
+
+        let has_array_of_tables = node
+            .syntax()
+            .ancestors()
+            .find_map(tombi_ast::Root::cast)
+            .is_some_and(|root| {
+                root.items()
+                    .any(|item| matches!(item, tombi_ast::RootItem::ArrayOfTable(_)))
+            });
+
+        if !has_array_of_tables {
+            return;
+        }
+
+        let comment_directive = get_comment_directive_content::<
+            ArrayCommonFormatRules,
+            ArrayCommonLintRules,
+        >(itertools::chain!(
+            crate::rule::parent_key_value_comment_directives(node),
+            node.comment_directives()
+        ));
+
+        if comment_directive
+            .as_ref()
+            .and_then(|comment_directive| comment_directive.lint_rules())
+            .and_then(|rules| {
+                rules
+                    .value
+                    .no_inline_table_in_array_of_tables_context
+                    .as_ref()
+            })
+            .and_then(|rule| rule.disabled)
+            .unwrap_or_default()
+        {
+            return;
+        }
+
+        l.extend_diagnostics(crate::Diagnostic {
+            kind: crate::DiagnosticKind::NoInlineTableInArrayOfTablesContext,
+            level: level.into(),
+            range: node.range(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultOff};
+
+    use crate::test_lint;
+
+    fn lint_options() -> crate::LintOptions {
+        crate::LintOptions {
+            rules: Some(tombi_config::LintRules {
+                no_inline_table_in_array_of_tables_context: Some(SeverityLevelDefaultOff::from(
+                    SeverityLevel::Warn,
+                )),
+                ..Default::default()
+            }),
+        }
+    }
+
+    test_lint! {
+        #[test]
+        fn inline_array_of_tables_with_array_of_tables_header(
+            r#"
+            [[fruits]]
+            name = "apple"
+
+            [vegetables]
+            items = [{ name = "carrot" }]
+            "#,
+            lint_options(),
+        ) -> Err([crate::DiagnosticKind::NoInlineTableInArrayOfTablesContext])
+    }
+
+    test_lint! {
+        #[test]
+        fn inline_array_of_tables_without_array_of_tables_header(
+            r#"
+            [vegetables]
+            items = [{ name = "carrot" }]
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn mixed_array_with_array_of_tables_header(
+            r#"
+            [[fruits]]
+            name = "apple"
+            tags = ["red", { name = "sweet" }]
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn inline_array_of_tables_with_leading_comment_directive(
+            r#"
+            [[fruits]]
+            name = "apple"
+
+            [vegetables]
+            # tombi: lint.rules.no-inline-table-in-array-of-tables-context.disabled = true
+            items = [{ name = "carrot" }]
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+}
@@ -0,0 +1,262 @@
+use tombi_comment_directive::value::{RootTableCommonLintRules, TableCommonFormatRules};
+use tombi_comment_directive_serde::get_comment_directive_content;
+use tombi_config::{SeverityLevel, TomlVersion};
+
+use crate::Rule;
+
+pub struct NoMixedKeyStylesRule;
+
+impl Rule<tombi_ast::Root> for NoMixedKeyStylesRule {
+    async fn check(node: &tombi_ast::Root, l: &mut crate::Linter<'_>) {
+        let level = l
+            .options()
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.no_mixed_key_styles)
+            .unwrap_or_default();
+
+        if level == SeverityLevel::Off {
+            return;
+        }
+
+        let comment_directive = get_comment_directive_content::<
+            TableCommonFormatRules,
+            RootTableCommonLintRules,
+        >(node.comment_directives());
+
+        if comment_directive
+            .as_ref()
+            .and_then(|comment_directive| comment_directive.lint_rules())
+            .and_then(|rules| rules.value.no_mixed_key_styles.as_ref())
+            .and_then(|rule| rule.disabled)
+            .unwrap_or_default()
+        {
+            return;
+        }
+
+        let toml_version = l.toml_version();
+
+        // Table paths defined by headers, such as `[a.b]` and `[[a.b]]`.
+        let mut header_paths: Vec<(Vec<String>, tombi_text::Range)> = Vec::new();
+        // Table paths implicitly defined by dotted keys, such as `a.b.c = 1`.
+        let mut dotted_paths: Vec<(Vec<Vec<String>>, tombi_text::Range)> = Vec::new();
+
+        collect_dotted_paths(&[], node.key_values(), toml_version, &mut dotted_paths);
+
+        for item in node.table_or_array_of_tables() {
+            let (header, key_values) = match item {
+                tombi_ast::TableOrArrayOfTable::Table(table) => {
+                    (table.header(), table.key_values().collect::<Vec<_>>())
+                }
+                tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) => (
+                    array_of_table.header(),
+                    array_of_table.key_values().collect::<Vec<_>>(),
+                ),
+            };
+            let Some(header) = header else {
+                continue;
+            };
+            let Some(header_path) = key_texts(&header, toml_version) else {
+                continue;
+            };
+
+            collect_dotted_paths(
+                &header_path,
+                key_values.into_iter(),
+                toml_version,
+                &mut dotted_paths,
+            );
+            header_paths.push((header_path, header.range()));
+        }
+
+        // A table defined by dotted keys, such as `fruit.apple` by `fruit.apple.color = "red"`,
+        // may be extended by a header like `[fruit.apple.texture]`, which mixes both styles.
+        let is_extended_by_header = |dotted_path: &[String]| {
+            header_paths
+                .iter()
+                .any(|(header_path, _)| header_path.starts_with(dotted_path))
+        };
+
+        let mut ranges = Vec::new();
+        for (paths, range) in &dotted_paths {
+            if paths.iter().any(|path| is_extended_by_header(path)) {
+                ranges.push(*range);
+            }
+        }
+        for (header_path, range) in &header_paths {
+            if dotted_paths.iter().any(|(paths, _)| {
+                paths
+                    .iter()
+                    .any(|dotted_path| header_path.starts_with(dotted_path))
+            }) {
+                ranges.push(*range);
+            }
+        }
+
+        ranges.sort_by_key(|range| range.start);
+        for range in ranges {
+            l.extend_diagnostics(crate::Diagnostic {
+                kind: crate::DiagnosticKind::NoMixedKeyStyles,
+                level: level.into(),
+                range,
+            });
+        }
+    }
+}
+
+fn key_texts(keys: &tombi_ast::Keys, toml_version: TomlVersion) -> Option<Vec<String>> {
+    keys.keys()
+        .map(|key| key.try_to_raw_text(toml_version).ok())
+        .collect()
+}
+
+fn collect_dotted_paths(
+    parent_path: &[String],
+    key_values: impl Iterator<Item = tombi_ast::KeyValue>,
+    toml_version: TomlVersion,
+    dotted_paths: &mut Vec<(Vec<Vec<String>>, tombi_text::Range)>,
+) {
+    for key_value in key_values {
+        let Some(keys) = key_value.keys() else {
+            continue;
+        };
+        let Some(key_path) = key_texts(&keys, toml_version) else {
+            continue;
+        };
+        if key_path.len() < 2 {
+            continue;
+        }
+
+        let paths = (1..key_path.len())
+            .map(|len| {
+                parent_path
+                    .iter()
+                    .chain(&key_path[..len])
+                    .cloned()
+                    .collect()
+            })
+            .collect();
+
+        dotted_paths.push((paths, keys.range()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultOff};
+
+    use crate::test_lint;
+
+    fn lint_options() -> crate::LintOptions {
+        crate::LintOptions {
+            rules: Some(tombi_config::LintRules {
+                no_mixed_key_styles: Some(SeverityLevelDefaultOff::from(SeverityLevel::Warn)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    test_lint! {
+        #[test]
+        fn mixed_key_styles_are_allowed_by_default(
+            r#"
+            fruit.apple.color = "red"
+
+            [fruit.apple.texture]
+            smooth = true
+            "#,
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn root_dotted_keys_extended_by_header(
+            r#"
+            fruit.apple.color = "red"
+
+            [fruit.apple.texture]
+            smooth = true
+            "#,
+            lint_options(),
+        ) -> Err([
+            crate::DiagnosticKind::NoMixedKeyStyles,
+            crate::DiagnosticKind::NoMixedKeyStyles,
+        ])
+    }
+
+    test_lint! {
+        #[test]
+        fn table_dotted_keys_extended_by_header(
+            r#"
+            [fruit]
+            apple.color = "red"
+
+            [fruit.apple.texture]
+            smooth = true
+            "#,
+            lint_options(),
+        ) -> Err([
+            crate::DiagnosticKind::NoMixedKeyStyles,
+            crate::DiagnosticKind::NoMixedKeyStyles,
+        ])
+    }
+
+    test_lint! {
+        #[test]
+        fn root_dotted_key_extended_by_sibling_header(
+            r#"
+            a.b = 1
+
+            [a.c]
+            d = 2
+            "#,
+            lint_options(),
+        ) -> Err([
+            crate::DiagnosticKind::NoMixedKeyStyles,
+            crate::DiagnosticKind::NoMixedKeyStyles,
+        ])
+    }
+
+    test_lint! {
+        #[test]
+        fn headers_only(
+            r#"
+            [fruit.apple]
+            color = "red"
+
+            [fruit.apple.texture]
+            smooth = true
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn dotted_keys_for_unrelated_tables(
+            r#"
+            [fruit]
+            apple.color = "red"
+
+            [fruit.orange]
+            smooth = true
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn mixed_key_styles_with_root_comment_directive(
+            r#"
+            # tombi: lint.rules.no-mixed-key-styles.disabled = true
+
+            fruit.apple.color = "red"
+
+            [fruit.apple.texture]
+            smooth = true
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+}
@@ -0,0 +1,114 @@
+use tombi_ast::AstNode;
+use tombi_comment_directive::value::{InlineTableCommonFormatRules, InlineTableCommonLintRules};
+use tombi_comment_directive_serde::get_comment_directive_content;
+use tombi_config::SeverityLevel;
+
+use crate::Rule;
+
+pub struct PreferTableOverLongInlineTableRule;
+
+impl Rule<tombi_ast::InlineTable> for PreferTableOverLongInlineTableRule {
+    async fn check(node: &tombi_ast::InlineTable, l: &mut crate::Linter<'_>) {
+        let Some(rule_options) = l
+            .options()
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.prefer_table_over_long_inline_table)
+        else {
+            return;
+        };
+
+        let level = rule_options.level.unwrap_or_default();
+        if level == SeverityLevel::Off {
+            return;
+        }
+
+        let max_width = rule_options.max_width.unwrap_or_default().value();
+        let width = l.source_text()[node.syntax().span()].chars().count();
+        if width <= max_width as usize {
+            return;
+        }
+
+        let comment_directive = get_comment_directive_content::<
+            InlineTableCommonFormatRules,
+            InlineTableCommonLintRules,
+        >(itertools::chain!(
+            crate::rule::parent_key_value_comment_directives(node),
+            node.comment_directives()
+        ));
+
+        if comment_directive
+            .as_ref()
+            .and_then(|comment_directive| comment_directive.lint_rules())
+            .and_then(|rules| rules.value.0.prefer_table_over_long_inline_table.as_ref())
+            .and_then(|rule| rule.disabled)
+            .unwrap_or_default()
+        {
+            return;
+        }
+
+        l.extend_diagnostics(crate::Diagnostic {
+            kind: crate::DiagnosticKind::PreferTableOverLongInlineTable { max_width },
+            level: level.into(),
+            range: node.range(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_lint;
+
+    fn lint_options(max_width: u8) -> crate::LintOptions {
+        crate::LintOptions {
+            rules: Some(tombi_config::LintRules {
+                prefer_table_over_long_inline_table: Some(
+                    tombi_config::PreferTableOverLongInlineTableRuleOptions {
+                        level: None,
+                        max_width: Some(tombi_config::LineWidth::try_from(max_width).unwrap()),
+                    },
+                ),
+                ..Default::default()
+            }),
+        }
+    }
+
+    test_lint! {
+        #[test]
+        fn long_inline_table_is_allowed_by_default(
+            r#"
+            dependency = { version = "1.0.0", features = ["derive", "std"], default-features = false }
+            "#,
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn long_inline_table(
+            r#"
+            dependency = { version = "1.0.0", features = ["derive", "std"] }
+            "#,
+            lint_options(30),
+        ) -> Err([crate::DiagnosticKind::PreferTableOverLongInlineTable { max_width: 30 }])
+    }
+
+    test_lint! {
+        #[test]
+        fn short_inline_table(
+            r#"
+            dependency = { version = "1.0.0" }
+            "#,
+            lint_options(30),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn long_inline_table_with_trailing_comment_directive(
+            r#"
+            dependency = { version = "1.0.0", features = ["derive", "std"] } # tombi: lint.rules.prefer-table-over-long-inline-table.disabled = true
+            "#,
+            lint_options(30),
+        ) -> Ok(_)
+    }
+}
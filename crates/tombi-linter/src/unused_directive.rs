@@ -0,0 +1,331 @@
+use tombi_ast::{AstNode, AstToken};
+use tombi_comment_directive::TOMBI_COMMENT_DIRECTIVE_TOML_VERSION;
+use tombi_document_tree::{TryIntoDocumentTree, dig_keys};
+use tombi_text::RelativePosition;
+
+/// A comment directive that suppresses lint diagnostics.
+///
+/// ```toml
+/// #:tombi lint.disabled = true
+///
+/// # tombi: lint.rules.no-empty-tables.disabled = true
+/// [table]
+/// ```
+#[derive(Debug)]
+pub(crate) struct SuppressionDirective {
+    /// The suppression as written in the directive, e.g. `lint.rules.no-empty-tables.disabled`.
+    directive: String,
+
+    /// The rule name, or `None` for the document-level `lint.disabled`.
+    rule_name: Option<String>,
+
+    /// The range of `disabled = true` in the directive.
+    range: tombi_text::Range,
+
+    /// The span of the whole comment holding the directive.
+    comment_span: tombi_text::Span,
+
+    /// The range of the node the directive is attached to.
+    target_range: tombi_text::Range,
+}
+
+/// Collect the `lint.rules.<rule>.disabled = true` value directives
+/// and the `lint.disabled = true` document directive of the document.
+pub(crate) fn collect_suppression_directives(root: &tombi_ast::Root) -> Vec<SuppressionDirective> {
+    let mut directives = Vec::new();
+
+    for directive in root.tombi_document_comment_directives() {
+        let Some(document_tree) = parse_directive_content(&directive.content) else {
+            continue;
+        };
+        let Some((key, tombi_document_tree::Value::Boolean(disabled))) =
+            dig_keys(&document_tree, &["lint", "disabled"])
+        else {
+            continue;
+        };
+        if !disabled.value() {
+            continue;
+        }
+
+        directives.push(SuppressionDirective {
+            directive: "lint.disabled".to_string(),
+            rule_name: None,
+            range: to_source_range(directive.content_range, key.range() + disabled.range()),
+            comment_span: tombi_text::Span::default(),
+            target_range: root.range(),
+        });
+    }
+
+    for comment in root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter_map(tombi_ast::Comment::cast)
+    {
+        let Some(directive) = comment.get_tombi_value_directive() else {
+            continue;
+        };
+        // Comment groups only wrap the comments, so look for the commented node.
+        let Some(target_range) = comment
+            .syntax()
+            .parent_ancestors()
+            .find(|node| {
+                let kind = node.kind();
+                tombi_ast::Root::can_cast(kind)
+                    || tombi_ast::Table::can_cast(kind)
+                    || tombi_ast::ArrayOfTable::can_cast(kind)
+                    || tombi_ast::KeyValue::can_cast(kind)
+                    || tombi_ast::Array::can_cast(kind)
+                    || tombi_ast::InlineTable::can_cast(kind)
+            })
+            .map(|node| node.range())
+        else {
+            continue;
+        };
+        let Some(document_tree) = parse_directive_content(&directive.content) else {
+            continue;
+        };
+        let Some((_, tombi_document_tree::Value::Table(rules))) =
+            dig_keys(&document_tree, &["lint", "rules"])
+        else {
+            continue;
+        };
+
+        for (rule_key, rule_value) in rules.key_values() {
+            // `unused-noqa` only controls reporting, it suppresses no lint diagnostics.
+            if rule_key.value == "unused-noqa" {
+                continue;
+            }
+            let tombi_document_tree::Value::Table(rule) = rule_value else {
+                continue;
+            };
+            let Some((key, tombi_document_tree::Value::Boolean(disabled))) =
+                rule.get_key_value("disabled")
+            else {
+                continue;
+            };
+            if !disabled.value() {
+                continue;
+            }
+
+            directives.push(SuppressionDirective {
+                directive: format!("lint.rules.{}.disabled", rule_key.value),
+                rule_name: Some(rule_key.value.clone()),
+                range: to_source_range(directive.content_range, key.range() + disabled.range()),
+                comment_span: comment.syntax().span(),
+                target_range,
+            });
+        }
+    }
+
+    directives
+}
+
+/// Remove the comments holding value-level suppressions from the source.
+///
+/// Only the comment text is removed, so the ranges of the other tokens are unchanged.
+pub(crate) fn strip_suppression_directives(
+    source: &str,
+    directives: &[SuppressionDirective],
+) -> String {
+    let mut comment_spans = directives
+        .iter()
+        .filter(|directive| directive.rule_name.is_some())
+        .map(|directive| directive.comment_span)
+        .collect::<Vec<_>>();
+    comment_spans.sort_by_key(|span| span.start);
+    comment_spans.dedup();
+
+    let mut stripped = String::with_capacity(source.len());
+    let mut last = 0;
+    for span in comment_spans {
+        stripped.push_str(&source[last..usize::from(span.start)]);
+        stripped.push('#');
+        last = usize::from(span.end);
+    }
+    stripped.push_str(&source[last..]);
+
+    stripped
+}
+
+/// Report the suppressions that no diagnostic of the unsuppressed document needed.
+pub(crate) fn unused_directive_diagnostics(
+    directives: Vec<SuppressionDirective>,
+    unsuppressed_diagnostics: &[tombi_diagnostic::Diagnostic],
+) -> Vec<crate::Diagnostic> {
+    directives
+        .into_iter()
+        .filter(|directive| {
+            !unsuppressed_diagnostics.iter().any(|diagnostic| {
+                directive.target_range.intersects(diagnostic.range())
+                    && directive
+                        .rule_name
+                        .as_deref()
+                        .is_none_or(|rule_name| rule_code(rule_name) == diagnostic.code())
+            })
+        })
+        .map(|directive| crate::Diagnostic {
+            kind: crate::DiagnosticKind::UnusedDirective {
+                directive: directive.directive,
+            },
+            level: tombi_config::SeverityLevel::Warn,
+            range: directive.range,
+        })
+        .collect()
+}
+
+/// The diagnostic code reported by the rule.
+fn rule_code(rule_name: &str) -> &str {
+    match rule_name {
+        "const-value" => "const",
+        _ => rule_name,
+    }
+}
+
+fn parse_directive_content(content: &str) -> Option<tombi_document_tree::DocumentTree> {
+    tombi_parser::parse(content)
+        .try_into_root()
+        .ok()?
+        .try_into_document_tree(TOMBI_COMMENT_DIRECTIVE_TOML_VERSION)
+        .ok()
+}
+
+fn to_source_range(
+    content_range: tombi_text::Range,
+    range: tombi_text::Range,
+) -> tombi_text::Range {
+    tombi_text::Range::new(
+        content_range.start + RelativePosition::from(range.start),
+        content_range.start + RelativePosition::from(range.end),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultOff};
+
+    use crate::test_lint;
+
+    fn lint_options() -> crate::LintOptions {
+        crate::LintOptions {
+            rules: Some(tombi_config::LintRules {
+                no_empty_tables: Some(SeverityLevelDefaultOff::from(SeverityLevel::Warn)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    test_lint! {
+        #[test]
+        fn unused_directive_is_not_reported_by_default(
+            r#"
+            # tombi: lint.rules.no-empty-tables.disabled = true
+            [table]
+            key = "value"
+            "#,
+            lint_options(),
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn unused_rule_directive(
+            r#"
+            # tombi: lint.rules.no-empty-tables.disabled = true
+            [table]
+            key = "value"
+            "#,
+            lint_options(),
+            ReportUnusedDirectives,
+        ) -> Err([crate::DiagnosticKind::UnusedDirective {
+            directive: "lint.rules.no-empty-tables.disabled".to_string(),
+        }])
+    }
+
+    test_lint! {
+        #[test]
+        fn used_rule_directive(
+            r#"
+            # tombi: lint.rules.no-empty-tables.disabled = true
+            [table]
+            "#,
+            lint_options(),
+            ReportUnusedDirectives,
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn rule_directive_of_other_table_is_unused(
+            r#"
+            # tombi: lint.rules.no-empty-tables.disabled = true
+            [table1]
+            key = "value"
+
+            [table2]
+            "#,
+            lint_options(),
+            ReportUnusedDirectives,
+        ) -> Diagnostics([
+            { code: "no-empty-tables", level: tombi_diagnostic::Level::WARNING },
+            { code: "unused-directive", level: tombi_diagnostic::Level::WARNING },
+        ])
+    }
+
+    test_lint! {
+        #[test]
+        fn unused_root_rule_directive(
+            r#"
+            # tombi: lint.rules.tables-out-of-order.disabled = true
+
+            [a]
+            [b]
+            "#,
+            ReportUnusedDirectives,
+        ) -> Err([crate::DiagnosticKind::UnusedDirective {
+            directive: "lint.rules.tables-out-of-order.disabled".to_string(),
+        }])
+    }
+
+    test_lint! {
+        #[test]
+        fn used_root_rule_directive(
+            r#"
+            # tombi: lint.rules.tables-out-of-order.disabled = true
+
+            [a.b]
+            [c]
+            [a.c]
+            "#,
+            ReportUnusedDirectives,
+        ) -> Ok(_)
+    }
+
+    test_lint! {
+        #[test]
+        fn unused_document_lint_disabled(
+            r#"
+            #:tombi lint.disabled = true
+
+            key = "value"
+            "#,
+            ReportUnusedDirectives,
+        ) -> Err([crate::DiagnosticKind::UnusedDirective {
+            directive: "lint.disabled".to_string(),
+        }])
+    }
+
+    test_lint! {
+        #[test]
+        fn used_document_lint_disabled(
+            r#"
+            #:tombi lint.disabled = true
+
+            [a.b]
+            [c]
+            [a.c]
+            "#,
+            ReportUnusedDirectives,
+        ) -> Ok(_)
+    }
+}
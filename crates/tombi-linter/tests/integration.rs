@@ -42,6 +42,8 @@ mod string_format_test_schema;
 mod table_const_enum_test_schema;
 #[path = "integration/tombi_schema.rs"]
 mod tombi_schema;
+#[path = "integration/toml_schema_test_schema.rs"]
+mod toml_schema_test_schema;
 #[path = "integration/tuple_items_test_schema.rs"]
 mod tuple_items_test_schema;
 #[path = "integration/unevaluated_items_test_schema.rs"]
@@ -0,0 +1,29 @@
+use tombi_linter::test_lint;
+use tombi_test_lib::toml_schema_test_schema_path;
+
+test_lint! {
+    #[test]
+    fn test_toml_schema_accepts_valid_document(
+        r#"
+        name = "app"
+        port = 8080
+        "#,
+        SchemaPath(toml_schema_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_toml_schema_rejects_type_mismatch(
+        r#"
+        name = "app"
+        port = "8080"
+        "#,
+        SchemaPath(toml_schema_test_schema_path()),
+    ) -> Err([
+        tombi_validator::DiagnosticKind::TypeMismatch {
+            expected: tombi_schema_store::ValueType::Integer,
+            actual: tombi_document_tree::ValueType::String,
+        },
+    ])
+}
@@ -3,17 +3,22 @@ use std::sync::Arc;
 use itertools::Either;
 use tombi_comment_directive::document::TombiDocumentDirectiveContent;
 use tombi_config::{Config, TomlVersion};
+use tombi_extension::ExtensionRegistry;
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{
-    CodeActionParams, CodeActionResponse, CompletionParams, CompletionResponse,
-    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
-    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
-    DocumentDiagnosticParams, DocumentDiagnosticReportResult, DocumentLink, DocumentLinkParams,
+    CodeActionParams, CodeActionResponse, CodeLens, CodeLensParams, CompletionParams,
+    CompletionResponse, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReportResult,
+    DocumentHighlight, DocumentHighlightParams, DocumentLink, DocumentLinkParams,
     DocumentSymbolParams, DocumentSymbolResponse, FoldingRange, FoldingRangeParams,
     GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, InitializeParams,
-    InitializeResult, InitializedParams, InlayHint, InlayHintParams, ReferenceParams,
-    SemanticTokensParams, SemanticTokensResult, TextDocumentIdentifier, Url,
-    WorkspaceDiagnosticParams, WorkspaceDiagnosticReportResult,
+    InitializeResult, InitializedParams, InlayHint, InlayHintParams, LinkedEditingRangeParams,
+    LinkedEditingRanges, ReferenceParams, SelectionRange, SelectionRangeParams,
+    SemanticTokensDeltaParams, SemanticTokensFullDeltaResult, SemanticTokensParams,
+    SemanticTokensRangeParams, SemanticTokensRangeResult, SemanticTokensResult, SymbolInformation,
+    TextDocumentIdentifier, Url, WorkspaceDiagnosticParams, WorkspaceDiagnosticReportResult,
+    WorkspaceSymbolParams,
     request::{
         GotoDeclarationParams, GotoDeclarationResponse, GotoTypeDefinitionParams,
         GotoTypeDefinitionResponse,
@@ -22,24 +27,31 @@ use tower_lsp::lsp_types::{
 
 use crate::{
     config_manager::{ConfigManager, ConfigSchemaStore, DefaultConfigSource},
+    diagnostic::BaselineCache,
     document::DocumentSource,
+    extension::default_extension_registry,
     goto_definition::try_get_goto_definition_response,
     goto_type_definition::try_get_type_definition_response,
     handler::{
-        AssociateSchemaParams, GetBuiltInSchemaParams, GetStatusResponse, GetTomlVersionResponse,
-        ListSchemasParams, ListSchemasResponse, RefreshCacheParams, TomlVersionSource,
-        handle_associate_schema, handle_code_action, handle_completion, handle_diagnostic,
-        handle_did_change, handle_did_change_configuration, handle_did_change_watched_files,
-        handle_did_close, handle_did_open, handle_did_save, handle_document_link,
-        handle_document_symbol, handle_folding_range, handle_formatting,
+        AssociateSchemaParams, ExtensionRequestParams, GetBuiltInSchemaParams, GetStatusResponse,
+        GetTomlVersionResponse, ListSchemasParams, ListSchemasResponse, RefreshCacheParams,
+        TomlVersionSource, handle_associate_schema, handle_code_action, handle_code_lens,
+        handle_completion, handle_diagnostic, handle_did_change, handle_did_change_configuration,
+        handle_did_change_watched_files, handle_did_close, handle_did_open, handle_did_save,
+        handle_document_highlight, handle_document_link, handle_document_symbol,
+        handle_extension_request, handle_folding_range, handle_formatting,
         handle_get_built_in_schema, handle_get_status, handle_get_toml_version,
         handle_goto_declaration, handle_goto_definition, handle_goto_type_definition, handle_hover,
-        handle_initialize, handle_initialized, handle_inlay_hint, handle_list_schemas,
-        handle_references, handle_refresh_cache, handle_semantic_tokens_full, handle_shutdown,
-        handle_update_config, handle_update_schema, handle_workspace_diagnostic, push_diagnostics,
+        handle_initialize, handle_initialized, handle_inlay_hint, handle_linked_editing_range,
+        handle_list_schemas, handle_references, handle_refresh_cache, handle_selection_range,
+        handle_semantic_tokens_full, handle_semantic_tokens_full_delta,
+        handle_semantic_tokens_range, handle_shutdown, handle_update_config, handle_update_schema,
+        handle_workspace_diagnostic, handle_workspace_symbol, push_diagnostics,
     },
     references::try_get_reference_locations,
+    semantic_tokens::SemanticTokensCache,
     workspace_diagnostic::WorkspaceDiagnosticsCache,
+    workspace_symbol::WorkspaceSymbolIndex,
 };
 
 use tombi_text::EncodingKind;
@@ -54,6 +66,10 @@ pub struct Backend {
         Arc<tokio::sync::RwLock<tombi_hashmap::HashMap<tombi_uri::Uri, DocumentSource>>>,
     pub config_manager: Arc<ConfigManager>,
     pub workspace_diagnostics_cache: Arc<tokio::sync::RwLock<WorkspaceDiagnosticsCache>>,
+    pub baseline_cache: Arc<tokio::sync::RwLock<BaselineCache>>,
+    pub workspace_symbol_index: Arc<tokio::sync::RwLock<WorkspaceSymbolIndex>>,
+    pub semantic_tokens_cache: Arc<tokio::sync::RwLock<SemanticTokensCache>>,
+    pub extension_registry: Arc<ExtensionRegistry>,
 }
 
 #[derive(Debug)]
@@ -100,6 +116,10 @@ impl Backend {
             document_sources: Default::default(),
             config_manager: Arc::new(ConfigManager::new(options)),
             workspace_diagnostics_cache: Default::default(),
+            baseline_cache: Default::default(),
+            workspace_symbol_index: Default::default(),
+            semantic_tokens_cache: Default::default(),
+            extension_registry: Arc::new(default_extension_registry()),
         }
     }
 
@@ -287,6 +307,15 @@ impl Backend {
         handle_refresh_cache(self, params).await
     }
 
+    /// `tombi/cargoFeatureGraph`, answered by the Cargo extension.
+    #[inline]
+    pub async fn cargo_feature_graph(
+        &self,
+        params: ExtensionRequestParams,
+    ) -> Result<Option<serde_json::Value>, tower_lsp::jsonrpc::Error> {
+        handle_extension_request(self, "tombi/cargoFeatureGraph", params).await
+    }
+
     #[inline]
     pub async fn list_schemas(
         &self,
@@ -384,6 +413,20 @@ impl tower_lsp::LanguageServer for Backend {
         handle_semantic_tokens_full(self, params).await
     }
 
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> Result<Option<SemanticTokensFullDeltaResult>, tower_lsp::jsonrpc::Error> {
+        handle_semantic_tokens_full_delta(self, params).await
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>, tower_lsp::jsonrpc::Error> {
+        handle_semantic_tokens_range(self, params).await
+    }
+
     async fn document_symbol(
         &self,
         params: DocumentSymbolParams,
@@ -391,6 +434,20 @@ impl tower_lsp::LanguageServer for Backend {
         handle_document_symbol(self, params).await
     }
 
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>, tower_lsp::jsonrpc::Error> {
+        handle_workspace_symbol(self, params).await
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>, tower_lsp::jsonrpc::Error> {
+        handle_document_highlight(self, params).await
+    }
+
     async fn document_link(
         &self,
         params: DocumentLinkParams,
@@ -452,6 +509,20 @@ impl tower_lsp::LanguageServer for Backend {
         handle_folding_range(self, params).await
     }
 
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>, tower_lsp::jsonrpc::Error> {
+        handle_selection_range(self, params).await
+    }
+
+    async fn linked_editing_range(
+        &self,
+        params: LinkedEditingRangeParams,
+    ) -> Result<Option<LinkedEditingRanges>, tower_lsp::jsonrpc::Error> {
+        handle_linked_editing_range(self, params).await
+    }
+
     async fn formatting(
         &self,
         params: tower_lsp::lsp_types::DocumentFormattingParams,
@@ -495,6 +566,31 @@ impl tower_lsp::LanguageServer for Backend {
         handle_code_action(self, params).await
     }
 
+    async fn code_lens(
+        &self,
+        params: CodeLensParams,
+    ) -> Result<Option<Vec<CodeLens>>, tower_lsp::jsonrpc::Error> {
+        let text_document_uri = params.text_document.uri.clone().into();
+        let line_index = {
+            let Ok(document_sources) = self.document_sources.try_read() else {
+                return Ok(None);
+            };
+            let Some(document_source) = document_sources.get(&text_document_uri) else {
+                return Ok(None);
+            };
+            document_source.line_index_arc()
+        };
+
+        handle_code_lens(self, params).await.map(|response| {
+            response.map(|code_lenses| {
+                code_lenses
+                    .into_iter()
+                    .map(|code_lens| code_lens.into_lsp(line_index.as_ref()))
+                    .collect()
+            })
+        })
+    }
+
     async fn diagnostic(
         &self,
         params: DocumentDiagnosticParams,
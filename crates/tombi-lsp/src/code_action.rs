@@ -1,3 +1,6 @@
+mod sort;
+mod structure;
+
 use tombi_ast::AstNode;
 use tombi_document_tree::{TableKind, dig_accessors};
 use tombi_schema_store::{Accessor, AccessorContext, AccessorKeyKind};
@@ -7,9 +10,27 @@ use tower_lsp::lsp_types::{
     TextDocumentEdit, TextEdit, WorkspaceEdit,
 };
 
+pub use sort::{
+    CodeActionSourceName, SOURCE_SORT_KEYS, sort_array_values_code_actions,
+    sort_keys_source_code_action, sort_table_keys_code_actions,
+};
+pub use structure::table_structure_code_actions;
+
 pub enum CodeActionRefactorRewriteName {
     DottedKeysToInlineTable,
     InlineTableToDottedKeys,
+    SortTableKeysAscending,
+    SortTableKeysBySchema,
+    SortTableKeysByVersion,
+    SortArrayValuesAscending,
+    SortArrayValuesByVersion,
+    ArrayOfTablesToInlineArray,
+    InlineArrayToArrayOfTables,
+    TableToInlineTable,
+    TableToDottedKeys,
+    InlineTableToTable,
+    DottedKeysToTable,
+    ExtractToTableSection,
 }
 
 impl std::fmt::Display for CodeActionRefactorRewriteName {
@@ -21,6 +42,42 @@ impl std::fmt::Display for CodeActionRefactorRewriteName {
             CodeActionRefactorRewriteName::InlineTableToDottedKeys => {
                 write!(f, "Convert Inline Table to Dotted Keys")
             }
+            CodeActionRefactorRewriteName::SortTableKeysAscending => {
+                write!(f, "Sort Table Keys Ascending")
+            }
+            CodeActionRefactorRewriteName::SortTableKeysBySchema => {
+                write!(f, "Sort Table Keys by Schema")
+            }
+            CodeActionRefactorRewriteName::SortTableKeysByVersion => {
+                write!(f, "Sort Table Keys by Version")
+            }
+            CodeActionRefactorRewriteName::SortArrayValuesAscending => {
+                write!(f, "Sort Array Values Ascending")
+            }
+            CodeActionRefactorRewriteName::SortArrayValuesByVersion => {
+                write!(f, "Sort Array Values by Version")
+            }
+            CodeActionRefactorRewriteName::ArrayOfTablesToInlineArray => {
+                write!(f, "Convert Array of Tables to Inline Array")
+            }
+            CodeActionRefactorRewriteName::InlineArrayToArrayOfTables => {
+                write!(f, "Convert Inline Array to Array of Tables")
+            }
+            CodeActionRefactorRewriteName::TableToInlineTable => {
+                write!(f, "Convert Table to Inline Table")
+            }
+            CodeActionRefactorRewriteName::TableToDottedKeys => {
+                write!(f, "Convert Table to Dotted Keys")
+            }
+            CodeActionRefactorRewriteName::InlineTableToTable => {
+                write!(f, "Convert Inline Table to Table")
+            }
+            CodeActionRefactorRewriteName::DottedKeysToTable => {
+                write!(f, "Convert Dotted Keys to Table")
+            }
+            CodeActionRefactorRewriteName::ExtractToTableSection => {
+                write!(f, "Extract to Table Section")
+            }
         }
     }
 }
@@ -0,0 +1,583 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use tombi_ast::{AstNode, DanglingCommentGroupOr};
+use tombi_config::TomlVersion;
+use tombi_document_tree::IntoDocumentTreeAndErrors;
+use tombi_schema_store::{Accessor, CurrentSchema, SchemaContext, ValueSchema};
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier,
+    TextDocumentEdit, TextEdit, WorkspaceEdit,
+};
+
+use super::CodeActionRefactorRewriteName;
+use crate::schema_resolver::resolve_current_schema;
+
+/// The code action kind to sort the keys of all the tables.
+pub const SOURCE_SORT_KEYS: CodeActionKind = CodeActionKind::new("source.sortKeys");
+
+pub enum CodeActionSourceName {
+    SortKeys,
+}
+
+impl std::fmt::Display for CodeActionSourceName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodeActionSourceName::SortKeys => write!(f, "Sort Keys of All Tables"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum KeysOrder {
+    Ascending,
+    Schema,
+    VersionSort,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ValuesOrder {
+    Ascending,
+    VersionSort,
+}
+
+/// The sort key of an array value. The values of an array are sorted only if they have the same type.
+#[derive(Debug)]
+enum SortableValue {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    OffsetDateTime(String),
+    LocalDateTime(String),
+    LocalDate(String),
+    LocalTime(String),
+}
+
+/// Sort the key-values of the table at the cursor, or only the selected ones.
+///
+/// The key-values separated by an empty line or a dangling comment are sorted separately.
+pub async fn sort_table_keys_code_actions(
+    text_document_uri: &tombi_uri::Uri,
+    line_index: &tombi_text::LineIndex,
+    root: &tombi_ast::Root,
+    range: tombi_text::Range,
+    schema_context: &SchemaContext<'_>,
+) -> Vec<CodeAction> {
+    let toml_version = schema_context.toml_version;
+    let selection = (!range.is_empty()).then_some(range);
+
+    let container = find_keys_container(root, range.start);
+    let groups = key_value_groups(&container)
+        .into_iter()
+        .map(|key_values| {
+            key_values
+                .into_iter()
+                .filter(|key_value| is_selected(selection, key_value.range()))
+                .filter_map(|key_value| {
+                    Some((
+                        key_value.get_accessors(toml_version)?,
+                        key_value.syntax().clone(),
+                    ))
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let schema_keys = match container_accessors(root, container.syntax(), toml_version) {
+        Some(accessors) => table_schema_keys(&accessors, schema_context).await,
+        None => None,
+    };
+
+    let mut code_actions = Vec::new();
+    for (title, order) in [
+        (
+            CodeActionRefactorRewriteName::SortTableKeysAscending,
+            KeysOrder::Ascending,
+        ),
+        (
+            CodeActionRefactorRewriteName::SortTableKeysBySchema,
+            KeysOrder::Schema,
+        ),
+        (
+            CodeActionRefactorRewriteName::SortTableKeysByVersion,
+            KeysOrder::VersionSort,
+        ),
+    ] {
+        if matches!(order, KeysOrder::Schema) && schema_keys.is_none() {
+            continue;
+        }
+        let schema_keys = schema_keys.as_deref().unwrap_or_default();
+
+        let edits = groups
+            .iter()
+            .flat_map(|entries| {
+                reorder_edits(
+                    entries,
+                    |a, b| compare_keys(a, b, order, schema_keys),
+                    line_index,
+                )
+            })
+            .collect_vec();
+
+        if !edits.is_empty() {
+            code_actions.push(sort_code_action(
+                title.to_string(),
+                CodeActionKind::REFACTOR_REWRITE,
+                text_document_uri,
+                edits,
+            ));
+        }
+    }
+
+    code_actions
+}
+
+/// Sort the values of the array at the cursor, or only the selected ones.
+///
+/// The schema of the array is not needed, but all the values must have the same type.
+pub fn sort_array_values_code_actions(
+    text_document_uri: &tombi_uri::Uri,
+    line_index: &tombi_text::LineIndex,
+    root: &tombi_ast::Root,
+    range: tombi_text::Range,
+    toml_version: TomlVersion,
+) -> Vec<CodeAction> {
+    let selection = (!range.is_empty()).then_some(range);
+
+    let Some(array) = root
+        .syntax()
+        .descendants()
+        .filter_map(tombi_ast::Array::cast)
+        .filter(|array| array.range().contains(range.start))
+        .last()
+    else {
+        return Vec::new();
+    };
+
+    let Some(groups) = array
+        .value_with_comma_groups()
+        .filter_map(DanglingCommentGroupOr::into_item_group)
+        .map(|group| {
+            group
+                .values()
+                .filter(|value| is_selected(selection, value.range()))
+                .map(|value| {
+                    let sortable_value = sortable_value(value.clone(), toml_version)?;
+                    Some((sortable_value, value.syntax().clone()))
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Vec::new();
+    };
+
+    let all_values = groups
+        .iter()
+        .flatten()
+        .map(|(value, _)| value)
+        .collect_vec();
+    let Some(first_value) = all_values.first() else {
+        return Vec::new();
+    };
+    if !all_values
+        .iter()
+        .all(|value| std::mem::discriminant(*value) == std::mem::discriminant(*first_value))
+    {
+        return Vec::new();
+    }
+
+    let mut code_actions = Vec::new();
+    for (title, order) in [
+        (
+            CodeActionRefactorRewriteName::SortArrayValuesAscending,
+            ValuesOrder::Ascending,
+        ),
+        (
+            CodeActionRefactorRewriteName::SortArrayValuesByVersion,
+            ValuesOrder::VersionSort,
+        ),
+    ] {
+        if matches!(order, ValuesOrder::VersionSort)
+            && !matches!(first_value, SortableValue::String(_))
+        {
+            continue;
+        }
+
+        let edits = groups
+            .iter()
+            .flat_map(|entries| {
+                reorder_edits(entries, |a, b| compare_values(a, b, order), line_index)
+            })
+            .collect_vec();
+
+        if !edits.is_empty() {
+            code_actions.push(sort_code_action(
+                title.to_string(),
+                CodeActionKind::REFACTOR_REWRITE,
+                text_document_uri,
+                edits,
+            ));
+        }
+    }
+
+    code_actions
+}
+
+/// Sort the keys of the root table and all the `[table]` and `[[array of tables]]` in ascending order.
+///
+/// With a selection, only the selected key-values are sorted.
+pub fn sort_keys_source_code_action(
+    text_document_uri: &tombi_uri::Uri,
+    line_index: &tombi_text::LineIndex,
+    root: &tombi_ast::Root,
+    range: tombi_text::Range,
+    toml_version: TomlVersion,
+) -> Option<CodeAction> {
+    let selection = (!range.is_empty()).then_some(range);
+
+    let containers = std::iter::once(KeysContainer::Root(root.clone())).chain(
+        root.table_or_array_of_tables().map(
+            |table_or_array_of_table| match table_or_array_of_table {
+                tombi_ast::TableOrArrayOfTable::Table(table) => KeysContainer::Table(table),
+                tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) => {
+                    KeysContainer::ArrayOfTable(array_of_table)
+                }
+            },
+        ),
+    );
+
+    let edits = containers
+        .flat_map(|container| key_value_groups(&container))
+        .flat_map(|key_values| {
+            let entries = key_values
+                .into_iter()
+                .filter(|key_value| is_selected(selection, key_value.range()))
+                .filter_map(|key_value| {
+                    Some((
+                        key_value.get_accessors(toml_version)?,
+                        key_value.syntax().clone(),
+                    ))
+                })
+                .collect_vec();
+            reorder_edits(
+                &entries,
+                |a, b| compare_keys(a, b, KeysOrder::Ascending, &[]),
+                line_index,
+            )
+        })
+        .collect_vec();
+
+    if edits.is_empty() {
+        return None;
+    }
+
+    Some(sort_code_action(
+        CodeActionSourceName::SortKeys.to_string(),
+        SOURCE_SORT_KEYS,
+        text_document_uri,
+        edits,
+    ))
+}
+
+enum KeysContainer {
+    Root(tombi_ast::Root),
+    Table(tombi_ast::Table),
+    ArrayOfTable(tombi_ast::ArrayOfTable),
+    InlineTable(tombi_ast::InlineTable),
+}
+
+impl KeysContainer {
+    fn syntax(&self) -> &tombi_syntax::SyntaxNode {
+        match self {
+            KeysContainer::Root(root) => root.syntax(),
+            KeysContainer::Table(table) => table.syntax(),
+            KeysContainer::ArrayOfTable(array_of_table) => array_of_table.syntax(),
+            KeysContainer::InlineTable(inline_table) => inline_table.syntax(),
+        }
+    }
+}
+
+/// The innermost table at the position, or the root table.
+fn find_keys_container(root: &tombi_ast::Root, position: tombi_text::Position) -> KeysContainer {
+    root.syntax()
+        .descendants()
+        .filter(|node| node.range().contains(position))
+        .filter_map(|node| {
+            if let Some(table) = tombi_ast::Table::cast(node.clone()) {
+                Some(KeysContainer::Table(table))
+            } else if let Some(array_of_table) = tombi_ast::ArrayOfTable::cast(node.clone()) {
+                Some(KeysContainer::ArrayOfTable(array_of_table))
+            } else {
+                tombi_ast::InlineTable::cast(node).map(KeysContainer::InlineTable)
+            }
+        })
+        .last()
+        .unwrap_or_else(|| KeysContainer::Root(root.clone()))
+}
+
+fn key_value_groups(container: &KeysContainer) -> Vec<Vec<tombi_ast::KeyValue>> {
+    match container {
+        KeysContainer::Root(root) => root
+            .key_value_groups()
+            .filter_map(DanglingCommentGroupOr::into_item_group)
+            .map(|group| group.into_key_values().collect_vec())
+            .collect_vec(),
+        KeysContainer::Table(table) => table
+            .key_value_groups()
+            .filter_map(DanglingCommentGroupOr::into_item_group)
+            .map(|group| group.into_key_values().collect_vec())
+            .collect_vec(),
+        KeysContainer::ArrayOfTable(array_of_table) => array_of_table
+            .key_value_groups()
+            .filter_map(DanglingCommentGroupOr::into_item_group)
+            .map(|group| group.into_key_values().collect_vec())
+            .collect_vec(),
+        KeysContainer::InlineTable(inline_table) => inline_table
+            .key_value_with_comma_groups()
+            .filter_map(DanglingCommentGroupOr::into_item_group)
+            .map(|group| group.into_key_values().collect_vec())
+            .collect_vec(),
+    }
+}
+
+/// The accessors of a table node, found from its ancestors.
+fn container_accessors(
+    root: &tombi_ast::Root,
+    node: &tombi_syntax::SyntaxNode,
+    toml_version: TomlVersion,
+) -> Option<Vec<Accessor>> {
+    let mut segments = Vec::new();
+    let mut child_range = node.range();
+
+    for ancestor in node.ancestors() {
+        if let Some(key_value) = tombi_ast::KeyValue::cast(ancestor.clone()) {
+            segments.push(key_value.get_accessors(toml_version)?);
+        } else if let Some(array) = tombi_ast::Array::cast(ancestor.clone()) {
+            let index = array
+                .values()
+                .position(|value| value.range() == child_range)?;
+            segments.push(vec![Accessor::Index(index)]);
+        } else if let Some(table) = tombi_ast::Table::cast(ancestor.clone()) {
+            segments.push(table.header()?.accessors(toml_version));
+            break;
+        } else if let Some(array_of_table) = tombi_ast::ArrayOfTable::cast(ancestor.clone()) {
+            let header_accessors = array_of_table.header()?.accessors(toml_version);
+            let index = root
+                .table_or_array_of_tables()
+                .take_while(|table_or_array_of_table| {
+                    table_or_array_of_table.syntax() != array_of_table.syntax()
+                })
+                .filter(|table_or_array_of_table| {
+                    matches!(
+                        table_or_array_of_table,
+                        tombi_ast::TableOrArrayOfTable::ArrayOfTable(other)
+                            if other.header().map(|header| header.accessors(toml_version))
+                                == Some(header_accessors.clone())
+                    )
+                })
+                .count();
+            segments.push(
+                header_accessors
+                    .into_iter()
+                    .chain(std::iter::once(Accessor::Index(index)))
+                    .collect_vec(),
+            );
+            break;
+        }
+        child_range = ancestor.range();
+    }
+
+    Some(segments.into_iter().rev().flatten().collect_vec())
+}
+
+/// The property keys of the table schema, in the schema order.
+async fn table_schema_keys(
+    accessors: &[Accessor],
+    schema_context: &SchemaContext<'_>,
+) -> Option<Vec<Accessor>> {
+    let current_schema = resolve_current_schema(accessors, schema_context).await?;
+
+    let schemas = match current_schema.value_schema.as_ref() {
+        ValueSchema::Table(table_schema) => return Some(table_schema.accessors().await),
+        ValueSchema::OneOf(schema) => &schema.schemas,
+        ValueSchema::AnyOf(schema) => &schema.schemas,
+        ValueSchema::AllOf(schema) => &schema.schemas,
+        _ => return None,
+    };
+
+    for CurrentSchema { value_schema, .. } in tombi_schema_store::resolve_and_collect_schemas(
+        schemas,
+        current_schema.schema_uri.clone(),
+        current_schema.definitions.clone(),
+        schema_context.store,
+        &schema_context.schema_visits,
+        accessors,
+    )
+    .await?
+    {
+        if let ValueSchema::Table(table_schema) = value_schema.as_ref() {
+            return Some(table_schema.accessors().await);
+        }
+    }
+
+    None
+}
+
+fn compare_keys(
+    a: &[Accessor],
+    b: &[Accessor],
+    order: KeysOrder,
+    schema_keys: &[Accessor],
+) -> Ordering {
+    match order {
+        KeysOrder::Ascending => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        KeysOrder::Schema => {
+            let schema_index = |accessors: &[Accessor]| {
+                accessors
+                    .first()
+                    .and_then(|accessor| schema_keys.iter().position(|key| key == accessor))
+                    .unwrap_or(usize::MAX)
+            };
+            schema_index(a).cmp(&schema_index(b))
+        }
+        KeysOrder::VersionSort => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| match (a, b) {
+                (Accessor::Key(a), Accessor::Key(b)) => tombi_version_sort::version_sort(a, b),
+                _ => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+    }
+}
+
+fn compare_values(a: &SortableValue, b: &SortableValue, order: ValuesOrder) -> Ordering {
+    match (a, b, order) {
+        (SortableValue::String(a), SortableValue::String(b), ValuesOrder::VersionSort) => {
+            tombi_version_sort::version_sort(a, b)
+        }
+        (SortableValue::Boolean(a), SortableValue::Boolean(b), _) => a.cmp(b),
+        (SortableValue::Integer(a), SortableValue::Integer(b), _) => a.cmp(b),
+        (SortableValue::Float(a), SortableValue::Float(b), _) => a.total_cmp(b),
+        (SortableValue::String(a), SortableValue::String(b), _)
+        | (SortableValue::OffsetDateTime(a), SortableValue::OffsetDateTime(b), _)
+        | (SortableValue::LocalDateTime(a), SortableValue::LocalDateTime(b), _)
+        | (SortableValue::LocalDate(a), SortableValue::LocalDate(b), _)
+        | (SortableValue::LocalTime(a), SortableValue::LocalTime(b), _) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+fn sortable_value(value: tombi_ast::Value, toml_version: TomlVersion) -> Option<SortableValue> {
+    match value.into_document_tree_and_errors(toml_version).tree {
+        tombi_document_tree::Value::Boolean(boolean) => {
+            Some(SortableValue::Boolean(boolean.value()))
+        }
+        tombi_document_tree::Value::Integer(integer) => {
+            Some(SortableValue::Integer(integer.value()))
+        }
+        tombi_document_tree::Value::Float(float) => Some(SortableValue::Float(float.value())),
+        tombi_document_tree::Value::String(string) => {
+            Some(SortableValue::String(string.value().to_string()))
+        }
+        tombi_document_tree::Value::OffsetDateTime(date_time) => {
+            Some(SortableValue::OffsetDateTime(date_time.value().to_string()))
+        }
+        tombi_document_tree::Value::LocalDateTime(date_time) => {
+            Some(SortableValue::LocalDateTime(date_time.value().to_string()))
+        }
+        tombi_document_tree::Value::LocalDate(date) => {
+            Some(SortableValue::LocalDate(date.value().to_string()))
+        }
+        tombi_document_tree::Value::LocalTime(time) => {
+            Some(SortableValue::LocalTime(time.value().to_string()))
+        }
+        _ => None,
+    }
+}
+
+fn is_selected(selection: Option<tombi_text::Range>, range: tombi_text::Range) -> bool {
+    selection
+        .is_none_or(|selection| selection.contains(range.start) && selection.contains(range.end))
+}
+
+/// The edits that move the nodes into the sorted order.
+///
+/// Each node takes the place of another one, so the comments and commas between them stay in place.
+fn reorder_edits<T>(
+    entries: &[(T, tombi_syntax::SyntaxNode)],
+    compare: impl Fn(&T, &T) -> Ordering,
+    line_index: &tombi_text::LineIndex,
+) -> Vec<TextEdit> {
+    let sorted_entries = entries
+        .iter()
+        .sorted_by(|(a, _), (b, _)| compare(a, b))
+        .collect_vec();
+
+    entries
+        .iter()
+        .zip(sorted_entries)
+        .filter(|((_, slot), (_, node))| slot.range() != node.range())
+        .filter_map(|((_, slot), (_, node))| {
+            let (range, _) = trimmed_range_and_text(slot)?;
+            let (_, new_text) = trimmed_range_and_text(node)?;
+            Some(TextEdit {
+                range: range.into_lsp(line_index),
+                new_text,
+            })
+        })
+        .collect_vec()
+}
+
+/// The range and text of the node without its leading and trailing whitespaces and line breaks.
+fn trimmed_range_and_text(node: &tombi_syntax::SyntaxNode) -> Option<(tombi_text::Range, String)> {
+    let tokens = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .collect_vec();
+    let is_trivia = |token: &tombi_syntax::SyntaxToken| {
+        matches!(
+            token.kind(),
+            tombi_syntax::SyntaxKind::WHITESPACE | tombi_syntax::SyntaxKind::LINE_BREAK
+        )
+    };
+
+    let first = tokens.iter().position(|token| !is_trivia(token))?;
+    let last = tokens.iter().rposition(|token| !is_trivia(token))?;
+
+    Some((
+        tombi_text::Range::new(tokens[first].range().start, tokens[last].range().end),
+        tokens[first..=last]
+            .iter()
+            .map(|token| token.text())
+            .collect(),
+    ))
+}
+
+fn sort_code_action(
+    title: String,
+    kind: CodeActionKind,
+    text_document_uri: &tombi_uri::Uri,
+    edits: Vec<TextEdit>,
+) -> CodeAction {
+    CodeAction {
+        title,
+        kind: Some(kind),
+        edit: Some(WorkspaceEdit {
+            changes: None,
+            document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier {
+                    uri: text_document_uri.to_owned().into(),
+                    version: None,
+                },
+                edits: edits.into_iter().map(OneOf::Left).collect_vec(),
+            }])),
+            change_annotations: None,
+        }),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,147 @@
+use itertools::Itertools;
+use tombi_ast::AstNode;
+use tombi_config::TomlVersion;
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier,
+    TextDocumentEdit, TextEdit, WorkspaceEdit,
+};
+
+use super::CodeActionRefactorRewriteName;
+
+/// Rewrite the table structure at the cursor into another form of TOML tables.
+///
+/// The header under the cursor is converted into an inline table, dotted keys or an inline array,
+/// and the key-value under the cursor is converted into a table section or an array of tables.
+pub fn table_structure_code_actions(
+    text_document_uri: &tombi_uri::Uri,
+    line_index: &tombi_text::LineIndex,
+    root: &tombi_ast::Root,
+    position: tombi_text::Position,
+    toml_version: TomlVersion,
+) -> Vec<CodeAction> {
+    let mut rewrites = Vec::new();
+
+    match root
+        .table_or_array_of_tables()
+        .find(|table_or_array_of_table| match table_or_array_of_table {
+            tombi_ast::TableOrArrayOfTable::Table(table) => table.contains_header(position),
+            tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) => {
+                array_of_table.contains_header(position)
+            }
+        }) {
+        Some(tombi_ast::TableOrArrayOfTable::Table(table)) => {
+            rewrites.push((
+                CodeActionRefactorRewriteName::TableToInlineTable,
+                tombi_ast_editor::refactor::table_to_inline_table(root, &table, toml_version),
+            ));
+            rewrites.push((
+                CodeActionRefactorRewriteName::TableToDottedKeys,
+                tombi_ast_editor::refactor::table_to_dotted_keys(root, &table, toml_version),
+            ));
+        }
+        Some(tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table)) => {
+            rewrites.push((
+                CodeActionRefactorRewriteName::ArrayOfTablesToInlineArray,
+                tombi_ast_editor::refactor::array_of_tables_to_inline_array(
+                    root,
+                    &array_of_table,
+                    toml_version,
+                ),
+            ));
+        }
+        None => {}
+    }
+
+    let key_values = root
+        .syntax()
+        .descendants()
+        .filter_map(tombi_ast::KeyValue::cast)
+        .filter(|key_value| key_value.range().contains(position))
+        .collect_vec();
+
+    if let Some(key_value) = key_values
+        .iter()
+        .rev()
+        .find(|key_value| matches!(key_value.value(), Some(tombi_ast::Value::InlineTable(_))))
+    {
+        let name = if is_table_key_value(key_value) {
+            CodeActionRefactorRewriteName::InlineTableToTable
+        } else {
+            CodeActionRefactorRewriteName::ExtractToTableSection
+        };
+        rewrites.push((
+            name,
+            tombi_ast_editor::refactor::extract_to_table_section(root, key_value, toml_version),
+        ));
+    }
+
+    if let Some(key_value) = key_values
+        .first()
+        .filter(|key_value| is_table_key_value(key_value))
+    {
+        if matches!(key_value.value(), Some(tombi_ast::Value::Array(_))) {
+            rewrites.push((
+                CodeActionRefactorRewriteName::InlineArrayToArrayOfTables,
+                tombi_ast_editor::refactor::inline_array_to_array_of_tables(
+                    root,
+                    key_value,
+                    toml_version,
+                ),
+            ));
+        }
+        rewrites.push((
+            CodeActionRefactorRewriteName::DottedKeysToTable,
+            tombi_ast_editor::refactor::dotted_keys_to_table(root, key_value, toml_version),
+        ));
+    }
+
+    rewrites
+        .into_iter()
+        .filter_map(|(name, edits)| {
+            Some(CodeAction {
+                title: name.to_string(),
+                kind: Some(CodeActionKind::REFACTOR_REWRITE),
+                edit: Some(WorkspaceEdit {
+                    changes: None,
+                    document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                        text_document: OptionalVersionedTextDocumentIdentifier {
+                            uri: text_document_uri.to_owned().into(),
+                            version: None,
+                        },
+                        edits: edits?
+                            .into_iter()
+                            .map(|edit| {
+                                OneOf::Left(TextEdit {
+                                    range: edit.range.into_lsp(line_index),
+                                    new_text: edit.new_text,
+                                })
+                            })
+                            .collect_vec(),
+                    }])),
+                    change_annotations: None,
+                }),
+                ..Default::default()
+            })
+        })
+        .collect_vec()
+}
+
+/// Whether the key-value belongs to a table section or the root table, not to an inline table.
+fn is_table_key_value(key_value: &tombi_ast::KeyValue) -> bool {
+    key_value
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find(|node| {
+            tombi_ast::InlineTable::can_cast(node.kind())
+                || tombi_ast::Array::can_cast(node.kind())
+                || tombi_ast::Table::can_cast(node.kind())
+                || tombi_ast::ArrayOfTable::can_cast(node.kind())
+                || tombi_ast::Root::can_cast(node.kind())
+        })
+        .is_some_and(|node| {
+            !tombi_ast::InlineTable::can_cast(node.kind())
+                && !tombi_ast::Array::can_cast(node.kind())
+        })
+}
@@ -1,9 +1,14 @@
 use itertools::{Either, Itertools};
+use tombi_extension::ExtensionContext;
 use tombi_glob::{MatchResult, matches_file_patterns};
 use tombi_text::{IntoLsp, LineIndex};
 
 use crate::{backend::Backend, config_manager::ConfigSchemaStore};
 
+mod baseline_cache;
+
+pub use baseline_cache::BaselineCache;
+
 #[derive(Debug, Clone)]
 pub struct DiagnosticsResult {
     pub diagnostics: Vec<tower_lsp::lsp_types::Diagnostic>,
@@ -76,13 +81,14 @@ pub async fn get_diagnostics_result(
         }
     }
 
-    let (text, version, toml_version, encoding_kind) = {
+    let (text, document_tree, version, toml_version, encoding_kind) = {
         let Ok(document_sources) = backend.document_sources.try_read() else {
             return None;
         };
         let document_source = document_sources.get(text_document_uri)?;
         (
             document_source.text_arc(),
+            document_source.document_tree(),
             document_source.version,
             document_source.toml_version,
             document_source.line_index().encoding_kind,
@@ -100,7 +106,7 @@ pub async fn get_diagnostics_result(
         return None;
     };
 
-    let diagnostics = match tombi_linter::Linter::new(
+    let mut diagnostics = match tombi_linter::Linter::new(
         toml_version,
         &lint_options,
         Some(Either::Left(text_document_uri)),
@@ -110,14 +116,54 @@ pub async fn get_diagnostics_result(
     .await
     {
         Ok(_) => Vec::new(),
-        Err(diagnostics) => {
-            let line_index = LineIndex::new(text.as_ref(), encoding_kind);
+        Err(diagnostics) => diagnostics,
+    };
+
+    let extension_context = ExtensionContext {
+        text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(text_document_uri, &config)
+    {
+        diagnostics.extend(extension.lint(&extension_context).await);
+    }
+
+    let diagnostics = if diagnostics.is_empty() {
+        Vec::new()
+    } else {
+        let line_index = LineIndex::new(text.as_ref(), encoding_kind);
+        let diagnostics = diagnostics.into_iter().unique().collect_vec();
+        let (diagnostics, baselined_diagnostics) = match (
+            load_baseline(backend, &config, config_path.as_deref()).await,
+            text_document_path.as_deref(),
+        ) {
+            (Some((baseline_path, baseline)), Some(text_document_path)) => baseline.partition(
+                &tombi_linter::Baseline::file_key(&baseline_path, text_document_path),
+                text.as_ref(),
+                toml_version,
+                diagnostics,
+            ),
+            _ => (diagnostics, Vec::new()),
+        };
+
+        itertools::chain!(
             diagnostics
                 .into_iter()
-                .unique()
-                .map(|diagnostic| diagnostic.into_lsp(&line_index))
-                .collect_vec()
-        }
+                .map(|diagnostic| diagnostic.into_lsp(&line_index)),
+            baselined_diagnostics.into_iter().map(|diagnostic| {
+                tower_lsp::lsp_types::Diagnostic {
+                    tags: Some(vec![tower_lsp::lsp_types::DiagnosticTag::UNNECESSARY]),
+                    ..diagnostic.into_lsp(&line_index)
+                }
+            })
+        )
+        .collect_vec()
     };
 
     let diagnostics_result = DiagnosticsResult {
@@ -140,3 +186,55 @@ pub async fn get_diagnostics_result(
 
     Some(diagnostics_result)
 }
+
+/// Load the baseline file of `lsp.diagnostic.baseline`, reusing the parsed file while it is unchanged.
+async fn load_baseline(
+    backend: &Backend,
+    config: &tombi_config::Config,
+    config_path: Option<&std::path::Path>,
+) -> Option<(std::path::PathBuf, std::sync::Arc<tombi_linter::Baseline>)> {
+    let baseline = config
+        .lsp
+        .as_ref()
+        .and_then(|lsp| lsp.diagnostic.as_ref())
+        .and_then(|diagnostic| diagnostic.baseline.as_deref())?;
+
+    let baseline_path = match config_path.and_then(tombi_config::config_base_dir) {
+        Some(config_dir) => config_dir.join(baseline),
+        None => std::path::PathBuf::from(baseline),
+    };
+
+    let modified = tokio::fs::metadata(&baseline_path)
+        .await
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    if let Some(baseline) = backend
+        .baseline_cache
+        .read()
+        .await
+        .get(&baseline_path, modified)
+    {
+        return baseline.map(|baseline| (baseline_path, baseline));
+    }
+
+    let baseline = match tokio::fs::read_to_string(&baseline_path).await {
+        Ok(text) => match serde_json::from_str(&text) {
+            Ok(baseline) => Some(std::sync::Arc::new(baseline)),
+            Err(err) => {
+                log::warn!("Failed to parse baseline file {baseline_path:?}: {err}");
+                None
+            }
+        },
+        Err(err) => {
+            log::warn!("Failed to read baseline file {baseline_path:?}: {err}");
+            None
+        }
+    };
+    backend
+        .baseline_cache
+        .write()
+        .await
+        .insert(baseline_path.clone(), modified, baseline.clone());
+
+    baseline.map(|baseline| (baseline_path, baseline))
+}
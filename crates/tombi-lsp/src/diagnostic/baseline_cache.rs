@@ -0,0 +1,82 @@
+use std::{path::PathBuf, sync::Arc, time::SystemTime};
+
+/// The parsed baseline files of `lsp.diagnostic.baseline`.
+///
+/// Entries are dropped when the file changes on disk, when the watcher reports a change,
+/// or when the config is reloaded, so each diagnostics request does not re-read the file.
+#[derive(Debug, Default)]
+pub struct BaselineCache {
+    baselines: tombi_hashmap::HashMap<PathBuf, CachedBaseline>,
+}
+
+#[derive(Debug)]
+struct CachedBaseline {
+    modified: Option<SystemTime>,
+    baseline: Option<Arc<tombi_linter::Baseline>>,
+}
+
+impl BaselineCache {
+    /// Get the baseline if it was loaded from the file at its current modification time.
+    ///
+    /// The outer `None` means the file is not cached, the inner one that it failed to load.
+    pub fn get(
+        &self,
+        baseline_path: &std::path::Path,
+        modified: Option<SystemTime>,
+    ) -> Option<Option<Arc<tombi_linter::Baseline>>> {
+        self.baselines
+            .get(baseline_path)
+            .filter(|cached| cached.modified == modified)
+            .map(|cached| cached.baseline.clone())
+    }
+
+    pub fn insert(
+        &mut self,
+        baseline_path: PathBuf,
+        modified: Option<SystemTime>,
+        baseline: Option<Arc<tombi_linter::Baseline>>,
+    ) {
+        self.baselines
+            .insert(baseline_path, CachedBaseline { modified, baseline });
+    }
+
+    pub fn remove(&mut self, baseline_path: &std::path::Path) {
+        self.baselines.remove(baseline_path);
+    }
+
+    pub fn reset(&mut self) {
+        self.baselines.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn reuses_baseline_until_file_changes() {
+        let baseline_path = PathBuf::from("tombi-baseline.json");
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let mut cache = BaselineCache::default();
+        cache.insert(
+            baseline_path.clone(),
+            Some(modified),
+            Some(Arc::new(tombi_linter::Baseline::default())),
+        );
+
+        assert!(matches!(
+            cache.get(&baseline_path, Some(modified)),
+            Some(Some(_))
+        ));
+        assert!(
+            cache
+                .get(&baseline_path, Some(modified + Duration::from_secs(1)))
+                .is_none()
+        );
+
+        cache.remove(&baseline_path);
+        assert!(cache.get(&baseline_path, Some(modified)).is_none());
+    }
+}
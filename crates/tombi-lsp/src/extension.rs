@@ -0,0 +1,29 @@
+use tombi_extension::ExtensionRegistry;
+use tombi_extension_external::ExternalExtensions;
+
+/// The extensions built into Tombi, followed by the external extensions in the user config.
+pub fn default_extension_registry() -> ExtensionRegistry {
+    ExtensionRegistry::new()
+        .with(tombi_extension_tombi::TombiExtension)
+        .with(tombi_extension_cargo::CargoExtension)
+        .with(tombi_extension_pyproject::PyprojectExtension)
+        .with(user_external_extensions())
+}
+
+/// External extensions run their commands, so they are read only from the user or system config.
+///
+/// `[[extensions.external]]` in a project config is ignored,
+/// or opening an untrusted checkout would run the commands it names.
+fn user_external_extensions() -> ExternalExtensions {
+    match serde_tombi::config::load_user_or_system_with_path() {
+        Ok(Some((config, config_path))) => ExternalExtensions::new(
+            config.external_extensions(),
+            config_path.parent().unwrap_or(&config_path),
+        ),
+        Ok(None) => ExternalExtensions::default(),
+        Err(error) => {
+            log::error!("Failed to load external extensions: {error}");
+            ExternalExtensions::default()
+        }
+    }
+}
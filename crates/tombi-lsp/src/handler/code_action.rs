@@ -1,11 +1,17 @@
 use crate::{
     Backend,
-    code_action::{dot_keys_to_inline_table_code_action, inline_table_to_dot_keys_code_action},
+    code_action::{
+        dot_keys_to_inline_table_code_action, inline_table_to_dot_keys_code_action,
+        sort_array_values_code_actions, sort_keys_source_code_action, sort_table_keys_code_actions,
+        table_structure_code_actions,
+    },
     completion::get_completion_keys_with_context,
     config_manager::ConfigSchemaStore,
 };
+use itertools::Either;
 use tombi_document_tree::get_accessors;
-use tombi_schema_store::build_accessor_contexts;
+use tombi_extension::ExtensionContext;
+use tombi_schema_store::{SchemaContext, build_accessor_contexts};
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{CodeActionOrCommand, CodeActionParams};
 
@@ -55,22 +61,25 @@ pub async fn handle_code_action(
     let toml_version = document_source.toml_version;
     let line_index = document_source.line_index();
 
-    let position: tombi_text::Position = range.start.into_lsp(line_index);
-
-    let Some((keys, key_contexts)) =
-        get_completion_keys_with_context(&document_source.ast(), position, toml_version).await
-    else {
-        return Ok(None);
-    };
+    let range: tombi_text::Range = range.into_lsp(line_index);
+    let position = range.start;
 
     let root = document_source.ast();
     let document_tree = document_source.document_tree();
-    let accessors = get_accessors(&document_tree, &keys, position);
-    let mut key_contexts = key_contexts.into_iter();
-    let accessor_contexts = build_accessor_contexts(&accessors, &mut key_contexts);
 
     let mut code_actions = Vec::new();
 
+    let (accessors, accessor_contexts) =
+        match get_completion_keys_with_context(&root, position, toml_version).await {
+            Some((keys, key_contexts)) => {
+                let accessors = get_accessors(&document_tree, &keys, position);
+                let mut key_contexts = key_contexts.into_iter();
+                let accessor_contexts = build_accessor_contexts(&accessors, &mut key_contexts);
+                (accessors, accessor_contexts)
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+
     if let Some(code_action) = dot_keys_to_inline_table_code_action(
         &text_document_uri,
         line_index,
@@ -93,39 +102,79 @@ pub async fn handle_code_action(
         code_actions.push(CodeActionOrCommand::CodeAction(code_action));
     }
 
-    if config.cargo_extension_enabled()
-        && let Some(extension_code_actions) = tombi_extension_cargo::code_action(
+    code_actions.extend(
+        table_structure_code_actions(
             &text_document_uri,
             line_index,
             &root,
-            &document_tree,
-            &accessors,
-            &accessor_contexts,
-            document_source.toml_version,
-            config.cargo_extension_features(),
-            schema_store.offline(),
-            schema_store.cache_options(),
+            position,
+            toml_version,
         )
-        .await?
-    {
-        code_actions.extend(extension_code_actions);
-    }
+        .into_iter()
+        .map(CodeActionOrCommand::CodeAction),
+    );
+
+    let source_schema = schema_store
+        .resolve_source_schema_from_ast(&root, Some(Either::Left(&text_document_uri)))
+        .await
+        .ok()
+        .flatten();
+    let schema_context = SchemaContext::from_source_schema(
+        toml_version,
+        source_schema.as_ref(),
+        &schema_store,
+        None,
+    );
 
-    if config.pyproject_extension_enabled()
-        && let Some(extension_code_actions) = tombi_extension_pyproject::code_action(
+    code_actions.extend(
+        sort_table_keys_code_actions(
             &text_document_uri,
-            &root,
-            &document_tree,
-            &accessors,
-            document_source.toml_version,
             line_index,
-            config.pyproject_extension_features(),
-            schema_store.offline(),
-            schema_store.cache_options(),
+            &root,
+            range,
+            &schema_context,
         )
-        .await?
+        .await
+        .into_iter()
+        .map(CodeActionOrCommand::CodeAction),
+    );
+
+    code_actions.extend(
+        sort_array_values_code_actions(&text_document_uri, line_index, &root, range, toml_version)
+            .into_iter()
+            .map(CodeActionOrCommand::CodeAction),
+    );
+
+    if let Some(code_action) =
+        sort_keys_source_code_action(&text_document_uri, line_index, &root, range, toml_version)
+    {
+        code_actions.push(CodeActionOrCommand::CodeAction(code_action));
+    }
+
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
     {
-        code_actions.extend(extension_code_actions);
+        if let Some(extension_code_actions) = extension
+            .code_action(
+                &extension_context,
+                &root,
+                line_index,
+                &accessors,
+                &accessor_contexts,
+            )
+            .await?
+        {
+            code_actions.extend(extension_code_actions);
+        }
     }
 
     if code_actions.is_empty() {
@@ -0,0 +1,91 @@
+use tombi_extension::ExtensionContext;
+use tower_lsp::lsp_types::CodeLensParams;
+
+use crate::{Backend, config_manager::ConfigSchemaStore, handler::get_status::get_schema_status};
+
+/// The client command to open the schema of the schema code lens.
+const OPEN_SCHEMA_COMMAND: &str = "tombi.openSchema";
+
+pub async fn handle_code_lens(
+    backend: &Backend,
+    params: CodeLensParams,
+) -> Result<Option<Vec<tombi_extension::CodeLens>>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_code_lens");
+    log::trace!("{:?}", params);
+
+    let CodeLensParams { text_document, .. } = params;
+    let text_document_uri = text_document.uri.into();
+
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
+        .config_manager
+        .config_schema_store_for_uri(&text_document_uri)
+        .await;
+
+    if !config
+        .lsp
+        .as_ref()
+        .and_then(|server| server.code_lens.as_ref())
+        .and_then(|code_lens| code_lens.enabled)
+        .unwrap_or_default()
+        .value()
+    {
+        log::debug!("`server.code_lens.enabled` is false");
+        return Ok(None);
+    }
+
+    let (root, document_tree, toml_version) = {
+        let Ok(document_sources) = backend.document_sources.try_read() else {
+            return Ok(None);
+        };
+        let Some(document_source) = document_sources.get(&text_document_uri) else {
+            return Ok(None);
+        };
+        (
+            document_source.ast(),
+            document_source.document_tree(),
+            document_source.toml_version,
+        )
+    };
+
+    let mut code_lenses = Vec::new();
+
+    if let Some(schema) = get_schema_status(&schema_store, &root, &text_document_uri).await {
+        let title = match &schema.title {
+            Some(title) => format!("schema: {title}"),
+            None => format!("schema: {}", schema.uri),
+        };
+        code_lenses.push(tombi_extension::CodeLens {
+            range: tombi_text::Range::at(tombi_text::Position::MIN),
+            title,
+            command: Some(OPEN_SCHEMA_COMMAND.to_string()),
+            arguments: Some(vec![serde_json::Value::String(schema.uri.to_string())]),
+        });
+    }
+
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
+    {
+        if let Some(extension_code_lenses) = extension.code_lens(&extension_context).await? {
+            code_lenses.extend(extension_code_lenses);
+        }
+    }
+
+    if code_lenses.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(code_lenses))
+    }
+}
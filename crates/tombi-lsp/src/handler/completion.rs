@@ -1,5 +1,5 @@
 use itertools::Either;
-use tombi_extension::{CommentContext, CompletionContent, CompletionHint};
+use tombi_extension::{CommentContext, CompletionContent, CompletionHint, ExtensionContext};
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{
     CompletionContext, CompletionParams, CompletionTriggerKind, TextDocumentPositionParams,
@@ -180,54 +180,30 @@ pub async fn handle_completion(
     };
 
     let accessors = tombi_document_tree::get_accessors(&document_tree, &keys, position);
-    let offline = schema_store.offline();
-    let cache_options = schema_store.cache_options();
-    if config.tombi_extension_enabled()
-        && let Some(items) = tombi_extension_tombi::completion(
-            &text_document_uri,
-            &document_tree,
-            position,
-            &accessors,
-            toml_version,
-            completion_hint,
-            comment_context.as_ref(),
-            config.tombi_extension_features(),
-        )
-        .await?
-    {
-        completion_items.extend(items);
-    }
-    if config.cargo_extension_enabled()
-        && let Some(items) = tombi_extension_cargo::completion(
-            &text_document_uri,
-            &document_tree,
-            position,
-            &accessors,
-            toml_version,
-            completion_hint,
-            comment_context.as_ref(),
-            offline,
-            cache_options,
-            config.cargo_extension_features(),
-        )
-        .await?
-    {
-        completion_items.extend(items);
-    }
-    if config.pyproject_extension_enabled()
-        && let Some(items) = tombi_extension_pyproject::completion(
-            &text_document_uri,
-            &document_tree,
-            position,
-            &accessors,
-            toml_version,
-            completion_hint,
-            comment_context.as_ref(),
-            config.pyproject_extension_features(),
-        )
-        .await?
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
     {
-        completion_items.extend(items);
+        if let Some(items) = extension
+            .completion(
+                &extension_context,
+                &accessors,
+                position,
+                completion_hint,
+                comment_context.as_ref(),
+            )
+            .await?
+        {
+            completion_items.extend(items);
+        }
     }
 
     if comment_context.is_some() {
@@ -23,6 +23,7 @@ pub async fn handle_did_change_configuration(
             Ok(config) => {
                 log::info!("Updating editor config: {:?}", config);
                 backend.config_manager.update_editor_config(config).await;
+                backend.baseline_cache.write().await.reset();
             }
             Err(err) => {
                 log::error!("Failed to parse editor config: {}", err);
@@ -24,6 +24,11 @@ pub async fn handle_did_change_watched_files(
 
         log::debug!("Detected {:?} via watcher: {}", change.typ, uri);
 
+        backend.workspace_symbol_index.write().await.remove(&uri);
+        if let Ok(path) = uri.to_file_path() {
+            backend.baseline_cache.write().await.remove(&path);
+        }
+
         match change.typ {
             FileChangeType::DELETED => {
                 {
@@ -23,4 +23,10 @@ pub async fn handle_did_close(backend: &Backend, params: DidCloseTextDocumentPar
         .write()
         .await
         .close(&text_document_uri);
+
+    backend
+        .semantic_tokens_cache
+        .write()
+        .await
+        .remove(&text_document_uri);
 }
@@ -1,6 +1,7 @@
+use tombi_extension::ExtensionContext;
 use tower_lsp::lsp_types::DidOpenTextDocumentParams;
 
-use crate::{backend::Backend, document::DocumentSource};
+use crate::{backend::Backend, config_manager::ConfigSchemaStore, document::DocumentSource};
 
 pub async fn handle_did_open(backend: &Backend, params: DidOpenTextDocumentParams) {
     log::info!("handle_did_open");
@@ -33,39 +34,32 @@ pub async fn handle_did_open(backend: &Backend, params: DidOpenTextDocumentParam
         .await
         .clear(&text_document_uri);
 
-    let config_schema_store = backend
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
         .config_manager
         .config_schema_store_for_uri(&text_document_uri)
         .await;
-    let offline = config_schema_store.schema_store.offline();
-    let cache_options = config_schema_store.schema_store.cache_options();
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
 
     let mut cache_warming_handle: Option<tokio::task::JoinHandle<bool>> = None;
-
-    if config_schema_store.config.cargo_extension_enabled()
-        && let Ok(Some(handle)) = tombi_extension_cargo::did_open(
-            &text_document_uri,
-            document_tree.as_ref(),
-            toml_version,
-            offline,
-            cache_options,
-            config_schema_store.config.cargo_extension_features(),
-        )
-        .await
-    {
-        cache_warming_handle = Some(handle)
-    } else if config_schema_store.config.pyproject_extension_enabled()
-        && let Ok(Some(handle)) = tombi_extension_pyproject::did_open(
-            &text_document_uri,
-            document_tree.as_ref(),
-            toml_version,
-            offline,
-            cache_options,
-            config_schema_store.config.pyproject_extension_features(),
-        )
-        .await
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
     {
-        cache_warming_handle = Some(handle)
+        if let Ok(Some(handle)) = extension.did_open(&extension_context).await {
+            cache_warming_handle = Some(handle);
+            break;
+        }
     }
 
     // Publish diagnostics for the opened document
@@ -0,0 +1,58 @@
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::{
+    DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams, TextDocumentPositionParams,
+};
+
+use crate::{backend::Backend, key_occurrence::collect_key_occurrences};
+
+pub async fn handle_document_highlight(
+    backend: &Backend,
+    params: DocumentHighlightParams,
+) -> Result<Option<Vec<DocumentHighlight>>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_document_highlight");
+    log::trace!("{:?}", params);
+
+    let DocumentHighlightParams {
+        text_document_position_params:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ..
+    } = params;
+    let text_document_uri = text_document.uri.into();
+
+    let Ok(document_sources) = backend.document_sources.try_read() else {
+        return Ok(None);
+    };
+    let Some(document_source) = document_sources.get(&text_document_uri) else {
+        return Ok(None);
+    };
+
+    let line_index = document_source.line_index();
+    let position = position.into_lsp(line_index);
+
+    let occurrences = collect_key_occurrences(&document_source.ast(), document_source.toml_version);
+    let Some(current) = occurrences
+        .iter()
+        .find(|occurrence| occurrence.range.contains(position))
+    else {
+        return Ok(None);
+    };
+
+    // The last key defines the value, the other keys extend the tables on the way.
+    let highlights = occurrences
+        .iter()
+        .filter(|occurrence| occurrence.path == current.path)
+        .map(|occurrence| DocumentHighlight {
+            range: occurrence.range.into_lsp(line_index),
+            kind: Some(if occurrence.is_last {
+                DocumentHighlightKind::WRITE
+            } else {
+                DocumentHighlightKind::TEXT
+            }),
+        })
+        .collect();
+
+    Ok(Some(highlights))
+}
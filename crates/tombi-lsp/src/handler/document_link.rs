@@ -1,5 +1,5 @@
 use tombi_ast::SchemaDocumentCommentDirective;
-use tombi_extension::get_tombi_github_uri;
+use tombi_extension::{ExtensionContext, get_tombi_github_uri};
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{DocumentLink, DocumentLinkParams};
 
@@ -15,7 +15,11 @@ pub async fn handle_document_link(
     let DocumentLinkParams { text_document, .. } = params;
     let text_document_uri = text_document.uri.into();
 
-    let ConfigSchemaStore { config, .. } = backend
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
         .config_manager
         .config_schema_store_for_uri(&text_document_uri)
         .await;
@@ -64,52 +68,25 @@ pub async fn handle_document_link(
 
     let document_tree = document_source.document_tree();
 
-    if config.cargo_extension_enabled()
-        && let Some(locations) = tombi_extension_cargo::document_link(
-            &text_document_uri,
-            &document_tree,
-            toml_version,
-            config.cargo_extension_features(),
-        )
-        .await?
-    {
-        document_links.extend(
-            locations
-                .into_iter()
-                .map(|location| location.into_lsp(line_index)),
-        );
-    }
-
-    if config.tombi_extension_enabled()
-        && let Some(locations) = tombi_extension_tombi::document_link(
-            &text_document_uri,
-            &document_tree,
-            toml_version,
-            config.tombi_extension_features(),
-        )
-        .await?
-    {
-        document_links.extend(
-            locations
-                .into_iter()
-                .map(|location| location.into_lsp(line_index)),
-        );
-    }
-
-    if config.pyproject_extension_enabled()
-        && let Some(locations) = tombi_extension_pyproject::document_link(
-            &text_document_uri,
-            &document_tree,
-            toml_version,
-            config.pyproject_extension_features(),
-        )
-        .await?
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
     {
-        document_links.extend(
-            locations
-                .into_iter()
-                .map(|location| location.into_lsp(line_index)),
-        );
+        if let Some(locations) = extension.document_link(&extension_context).await? {
+            document_links.extend(
+                locations
+                    .into_iter()
+                    .map(|location| location.into_lsp(line_index)),
+            );
+        }
     }
 
     if document_links.is_empty() {
@@ -1,9 +1,12 @@
+use itertools::Itertools;
+use tombi_extension::ExtensionContext;
+use tombi_schema_store::Accessor;
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{
     DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, SymbolKind,
 };
 
-use crate::backend::Backend;
+use crate::{backend::Backend, config_manager::ConfigSchemaStore};
 
 pub async fn handle_document_symbol(
     backend: &Backend,
@@ -16,6 +19,15 @@ pub async fn handle_document_symbol(
 
     let text_document_uri = text_document.uri.into();
 
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
+        .config_manager
+        .config_schema_store_for_uri(&text_document_uri)
+        .await;
+
     let Ok(document_sources) = backend.document_sources.try_read() else {
         return Ok(None);
     };
@@ -26,19 +38,51 @@ pub async fn handle_document_symbol(
     let document_tree = document_source.document_tree();
     let line_index = document_source.line_index();
 
-    let symbols = create_symbols(&document_tree, line_index);
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version: document_source.toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    let extensions = backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
+        .collect_vec();
+
+    let symbols = create_symbols(&document_tree, line_index, &|accessors, value| {
+        extensions.iter().find_map(|extension| {
+            extension.document_symbol_name(&extension_context, accessors, value)
+        })
+    });
 
     Ok(Some(DocumentSymbolResponse::Nested(symbols)))
 }
 
+/// Names an array element from its accessors, falling back to its index.
+type ArrayElementName<'a> = dyn Fn(&[Accessor], &tombi_document_tree::Value) -> Option<String> + 'a;
+
 fn create_symbols(
     tree: &tombi_document_tree::DocumentTree,
     line_index: &tombi_text::LineIndex,
+    array_element_name: &ArrayElementName<'_>,
 ) -> Vec<DocumentSymbol> {
     let mut symbols: Vec<DocumentSymbol> = vec![];
+    let mut accessors = vec![];
 
     for (key, value) in tree.key_values() {
-        symbols_for_value(key.to_string(), value, None, line_index, &mut symbols);
+        accessors.push(Accessor::Key(key.value.clone()));
+        symbols_for_value(
+            key.to_string(),
+            value,
+            None,
+            line_index,
+            &mut accessors,
+            array_element_name,
+            &mut symbols,
+        );
+        accessors.pop();
     }
 
     symbols
@@ -50,6 +94,8 @@ fn symbols_for_value(
     value: &tombi_document_tree::Value,
     parent_key_range: Option<tombi_text::Range>,
     line_index: &tombi_text::LineIndex,
+    accessors: &mut Vec<Accessor>,
+    array_element_name: &ArrayElementName<'_>,
     symbols: &mut Vec<DocumentSymbol>,
 ) {
     use tombi_document_tree::Value::*;
@@ -121,13 +167,17 @@ fn symbols_for_value(
         Array(array) => {
             let mut children = vec![];
             for (index, value) in array.values().iter().enumerate() {
+                accessors.push(Accessor::Index(index));
                 symbols_for_value(
-                    format!("[{index}]"),
+                    array_element_name(accessors, value).unwrap_or_else(|| format!("[{index}]")),
                     value,
                     Some(value.symbol_range()),
                     line_index,
+                    accessors,
+                    array_element_name,
                     &mut children,
                 );
+                accessors.pop();
             }
 
             symbols.push(DocumentSymbol {
@@ -144,13 +194,17 @@ fn symbols_for_value(
         Table(table) => {
             let mut children = vec![];
             for (key, value) in table.key_values() {
+                accessors.push(Accessor::Key(key.value.clone()));
                 symbols_for_value(
                     key.to_string(),
                     value,
                     Some(key.range()),
                     line_index,
+                    accessors,
+                    array_element_name,
                     &mut children,
                 );
+                accessors.pop();
             }
 
             symbols.push(DocumentSymbol {
@@ -0,0 +1,74 @@
+use tombi_extension::ExtensionContext;
+use tower_lsp::lsp_types::TextDocumentIdentifier;
+
+use crate::{Backend, config_manager::ConfigSchemaStore};
+
+/// The params of a custom request about a document, answered by an extension.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionRequestParams {
+    pub text_document: TextDocumentIdentifier,
+    #[serde(flatten)]
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Pass a custom request to the extensions of the document, returning the first answer.
+pub async fn handle_extension_request(
+    backend: &Backend,
+    method: &str,
+    params: ExtensionRequestParams,
+) -> Result<Option<serde_json::Value>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_extension_request: {method}");
+    log::trace!("{:?}", params);
+
+    let ExtensionRequestParams {
+        text_document,
+        params,
+    } = params;
+    let text_document_uri: tombi_uri::Uri = text_document.uri.into();
+    let params = serde_json::Value::Object(params);
+
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
+        .config_manager
+        .config_schema_store_for_uri(&text_document_uri)
+        .await;
+
+    let (document_tree, toml_version) = {
+        let Ok(document_sources) = backend.document_sources.try_read() else {
+            return Ok(None);
+        };
+        let Some(document_source) = document_sources.get(&text_document_uri) else {
+            return Ok(None);
+        };
+        (
+            document_source.document_tree(),
+            document_source.toml_version,
+        )
+    };
+
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
+    {
+        if let Some(result) = extension
+            .custom_request(&extension_context, method, &params)
+            .await?
+        {
+            return Ok(Some(result));
+        }
+    }
+
+    Ok(None)
+}
@@ -34,56 +34,8 @@ pub async fn handle_get_status(
                 .text_document_toml_version_and_source(&text_document_uri, document_source.text())
                 .await;
 
-            let root = document_source.ast();
-            // resolve_source_schema_from_ast checks the comment directive first,
-            // then falls back to other methods.
-            let schema_uri = match schema_store
-                .resolve_source_schema_from_ast(&root, Some(Either::Left(&text_document_uri)))
-                .await
-            {
-                Ok(Some(source_schema)) => source_schema
-                    .root_schema
-                    .as_ref()
-                    .map(|s| s.schema_uri.clone()),
-                _ => None,
-            };
-
-            let schema = if let Some(schema_uri) = schema_uri {
-                let schemas = schema_store.list_schemas().await;
-                if let Some(schema) = schemas.iter().find(|s| s.schema_uri == schema_uri) {
-                    Some(SchemaStatus {
-                        title: schema.title.clone(),
-                        description: schema.description.clone(),
-                        uri: schema_uri,
-                    })
-                } else {
-                    match schema_store.try_get_document_schema(&schema_uri).await {
-                        Ok(Some(doc_schema)) => {
-                            let (title, description) =
-                                if let Some(value_schema) = &doc_schema.value_schema {
-                                    (
-                                        value_schema.title().map(|s| s.to_string()),
-                                        value_schema.description().map(|s| s.to_string()),
-                                    )
-                                } else {
-                                    (None, None)
-                                };
-                            Some(SchemaStatus {
-                                title,
-                                description,
-                                uri: schema_uri,
-                            })
-                        }
-                        _ => Some(SchemaStatus {
-                            title: None,
-                            description: None,
-                            uri: schema_uri,
-                        }),
-                    }
-                }
-            } else {
-                None
-            };
+            let schema =
+                get_schema_status(&schema_store, &document_source.ast(), &text_document_uri).await;
 
             (toml_version, source, schema)
         } else {
@@ -109,6 +61,58 @@ pub async fn handle_get_status(
     })
 }
 
+/// Get the schema associated with the document and its title.
+pub(crate) async fn get_schema_status(
+    schema_store: &tombi_schema_store::SchemaStore,
+    root: &tombi_ast::Root,
+    text_document_uri: &tombi_uri::Uri,
+) -> Option<SchemaStatus> {
+    // resolve_source_schema_from_ast checks the comment directive first,
+    // then falls back to other methods.
+    let schema_uri = match schema_store
+        .resolve_source_schema_from_ast(root, Some(Either::Left(text_document_uri)))
+        .await
+    {
+        Ok(Some(source_schema)) => source_schema
+            .root_schema
+            .as_ref()
+            .map(|s| s.schema_uri.clone()),
+        _ => None,
+    }?;
+
+    let schemas = schema_store.list_schemas().await;
+    if let Some(schema) = schemas.iter().find(|s| s.schema_uri == schema_uri) {
+        Some(SchemaStatus {
+            title: schema.title.clone(),
+            description: schema.description.clone(),
+            uri: schema_uri,
+        })
+    } else {
+        match schema_store.try_get_document_schema(&schema_uri).await {
+            Ok(Some(doc_schema)) => {
+                let (title, description) = if let Some(value_schema) = &doc_schema.value_schema {
+                    (
+                        value_schema.title().map(|s| s.to_string()),
+                        value_schema.description().map(|s| s.to_string()),
+                    )
+                } else {
+                    (None, None)
+                };
+                Some(SchemaStatus {
+                    title,
+                    description,
+                    uri: schema_uri,
+                })
+            }
+            _ => Some(SchemaStatus {
+                title: None,
+                description: None,
+                uri: schema_uri,
+            }),
+        }
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetStatusResponse {
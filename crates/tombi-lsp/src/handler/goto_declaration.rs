@@ -1,3 +1,4 @@
+use tombi_extension::ExtensionContext;
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::TextDocumentPositionParams;
 use tower_lsp::lsp_types::request::GotoDeclarationParams;
@@ -23,7 +24,11 @@ pub async fn handle_goto_declaration(
     } = params;
     let text_document_uri = text_document.uri.into();
 
-    let ConfigSchemaStore { config, .. } = backend
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
         .config_manager
         .config_schema_store_for_uri(&text_document_uri)
         .await;
@@ -60,30 +65,24 @@ pub async fn handle_goto_declaration(
     let document_tree = document_source.document_tree();
     let accessors = tombi_document_tree::get_accessors(&document_tree, &keys, position);
 
-    if config.cargo_extension_enabled()
-        && let Some(locations) = tombi_extension_cargo::goto_declaration(
-            &text_document_uri,
-            &document_tree,
-            &accessors,
-            toml_version,
-            config.cargo_extension_features(),
-        )
-        .await?
-    {
-        return Ok(locations.into());
-    }
-
-    if config.pyproject_extension_enabled()
-        && let Some(locations) = tombi_extension_pyproject::goto_declaration(
-            &text_document_uri,
-            &document_tree,
-            &accessors,
-            toml_version,
-            config.pyproject_extension_features(),
-        )
-        .await?
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
     {
-        return Ok(locations.into());
+        if let Some(locations) = extension
+            .goto_declaration(&extension_context, &accessors)
+            .await?
+        {
+            return Ok(locations.into());
+        }
     }
 
     Ok(None)
@@ -1,3 +1,4 @@
+use tombi_extension::ExtensionContext;
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{GotoDefinitionParams, TextDocumentPositionParams};
 
@@ -22,7 +23,11 @@ pub async fn handle_goto_definition(
     } = params;
     let text_document_uri = text_document.uri.into();
 
-    let ConfigSchemaStore { config, .. } = backend
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
         .config_manager
         .config_schema_store_for_uri(&text_document_uri)
         .await;
@@ -63,43 +68,24 @@ pub async fn handle_goto_definition(
     let document_tree = document_source.document_tree();
     let accessors = tombi_document_tree::get_accessors(&document_tree, &keys, position);
 
-    if config.cargo_extension_enabled()
-        && let Some(locations) = tombi_extension_cargo::goto_definition(
-            &text_document_uri,
-            &document_tree,
-            &accessors,
-            toml_version,
-            config.cargo_extension_features(),
-        )
-        .await?
-    {
-        return Ok(locations.into());
-    }
-
-    if config.pyproject_extension_enabled()
-        && let Some(locations) = tombi_extension_pyproject::goto_definition(
-            &text_document_uri,
-            &document_tree,
-            &accessors,
-            toml_version,
-            config.pyproject_extension_features(),
-        )
-        .await?
-    {
-        return Ok(locations.into());
-    }
-
-    if config.tombi_extension_enabled()
-        && let Some(locations) = tombi_extension_tombi::goto_definition(
-            &text_document_uri,
-            &document_tree,
-            &accessors,
-            toml_version,
-            config.tombi_extension_features(),
-        )
-        .await?
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
     {
-        return Ok(locations.into());
+        if let Some(locations) = extension
+            .goto_definition(&extension_context, &accessors)
+            .await?
+        {
+            return Ok(locations.into());
+        }
     }
 
     Ok(Default::default())
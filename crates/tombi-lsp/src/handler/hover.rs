@@ -1,7 +1,7 @@
 use itertools::{Either, Itertools};
 use tombi_ast::{AstNode, DanglingCommentGroupOr, algo::ancestors_at_position};
 use tombi_document_tree::IntoDocumentTreeAndErrors;
-use tombi_extension::{HoverMetadata, HoverTextChange};
+use tombi_extension::{ExtensionContext, HoverMetadata, HoverTextChange};
 use tombi_schema_store::SchemaContext;
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{HoverParams, TextDocumentPositionParams};
@@ -101,99 +101,28 @@ pub async fn handle_hover(
         hover_value_content.range = range;
 
         let accessors = tombi_document_tree::get_accessors(&document_tree, &keys, position);
-        let offline = schema_store.offline();
-        let cache_options = schema_store.cache_options();
-        let tombi_hover_enabled = config
-            .tombi_extension_features()
-            .and_then(|features| features.lsp())
-            .and_then(|lsp| lsp.hover())
-            .map(|hover| hover.enabled())
-            .unwrap_or_default()
-            .value();
-        let cargo_dependency_detail_hover_enabled = config
-            .cargo_extension_features()
-            .and_then(|features| features.lsp())
-            .and_then(|lsp| lsp.hover())
-            .and_then(|hover| hover.dependency_detail())
-            .map(|dependency_detail| dependency_detail.enabled())
-            .unwrap_or_default()
-            .value();
-        let cargo_default_features_hover_enabled = config
-            .cargo_extension_features()
-            .and_then(|features| features.lsp())
-            .and_then(|lsp| lsp.hover())
-            .and_then(|hover| hover.default_features())
-            .map(|default_features| default_features.enabled())
-            .unwrap_or_default()
-            .value();
-        let cargo_feature_dependencies_hover_enabled = config
-            .cargo_extension_features()
-            .and_then(|features| features.lsp())
-            .and_then(|lsp| lsp.hover())
-            .and_then(|hover| hover.feature_dependencies())
-            .map(|feature_dependencies| feature_dependencies.enabled())
-            .unwrap_or_default()
-            .value();
-        let pyproject_dependency_detail_hover_enabled = config
-            .pyproject_extension_features()
-            .and_then(|features| features.lsp())
-            .and_then(|lsp| lsp.hover())
-            .and_then(|hover| hover.dependency_detail())
-            .map(|dependency_detail| dependency_detail.enabled())
-            .unwrap_or_default()
-            .value();
-
-        let extension_hover = if tombi_hover_enabled {
-            tombi_extension_tombi::hover(
-                &text_document_uri,
-                &document_tree,
-                &accessors,
-                position,
-                toml_version,
-                offline,
-            )
-            .await?
-        } else {
-            None
-        };
-        let extension_hover = match extension_hover {
-            some @ Some(_) => some,
-            None if cargo_dependency_detail_hover_enabled
-                || cargo_default_features_hover_enabled
-                || cargo_feature_dependencies_hover_enabled =>
-            {
-                tombi_extension_cargo::hover(
-                    &text_document_uri,
-                    &document_tree,
-                    &accessors,
-                    position,
-                    toml_version,
-                    offline,
-                    cache_options,
-                    cargo_dependency_detail_hover_enabled,
-                    cargo_feature_dependencies_hover_enabled,
-                    cargo_default_features_hover_enabled,
-                )
-                .await?
-            }
-            None => None,
+        let extension_context = ExtensionContext {
+            text_document_uri: &text_document_uri,
+            document_tree: &document_tree,
+            toml_version,
+            config: &config,
+            offline: schema_store.offline(),
+            cache_options: schema_store.cache_options(),
         };
-        let extension_hover = match extension_hover {
-            some @ Some(_) => some,
-            None if pyproject_dependency_detail_hover_enabled => {
-                tombi_extension_pyproject::hover(
-                    &text_document_uri,
-                    &document_tree,
-                    &accessors,
-                    position,
-                    toml_version,
-                    offline,
-                    cache_options,
-                )
+
+        let mut extension_hover = None;
+        for extension in backend
+            .extension_registry
+            .extensions_for(&text_document_uri, &config)
+        {
+            if let Some(metadata) = extension
+                .hover(&extension_context, &accessors, position)
                 .await?
+            {
+                extension_hover = Some(metadata);
+                break;
             }
-            None => None,
-        };
+        }
 
         if let Some(metadata) = extension_hover {
             apply_hover_metadata(hover_value_content, metadata);
@@ -1,11 +1,12 @@
 use tombi_text::EncodingKind;
 use tower_lsp::lsp_types::{
-    ClientCapabilities, ClientInfo, CodeActionProviderCapability, CompletionOptions,
-    CompletionOptionsCompletionItem, DeclarationCapability, DiagnosticOptions,
+    ClientCapabilities, ClientInfo, CodeActionProviderCapability, CodeLensOptions,
+    CompletionOptions, CompletionOptionsCompletionItem, DeclarationCapability, DiagnosticOptions,
     DiagnosticServerCapabilities, DocumentLinkOptions, FileOperationFilter, FileOperationPattern,
     FileOperationPatternKind, FileOperationRegistrationOptions, FoldingRangeProviderCapability,
     HoverProviderCapability, InitializeParams, InitializeResult, InlayHintOptions,
-    InlayHintServerCapabilities, OneOf, SemanticTokensFullOptions, SemanticTokensLegend,
+    InlayHintServerCapabilities, LinkedEditingRangeServerCapabilities, OneOf,
+    SelectionRangeProviderCapability, SemanticTokensFullOptions, SemanticTokensLegend,
     SemanticTokensOptions, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
     TextDocumentSyncKind, TextDocumentSyncOptions, TextDocumentSyncSaveOptions,
     TypeDefinitionProviderCapability, WorkDoneProgressOptions,
@@ -16,7 +17,7 @@ use tower_lsp::lsp_types::{
 use crate::{
     Backend,
     backend::{BackendCapabilities, DiagnosticMode},
-    semantic_tokens::SUPPORTED_TOKEN_TYPES,
+    semantic_tokens::{SUPPORTED_TOKEN_MODIFIERS, SUPPORTED_TOKEN_TYPES},
 };
 
 pub async fn handle_initialize(
@@ -120,6 +121,9 @@ pub fn server_capabilities(
             },
         ))),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
         completion_provider: Some(CompletionOptions {
             trigger_characters: Some(vec![
                 ".".into(),
@@ -156,15 +160,20 @@ pub fn server_capabilities(
         declaration_provider: Some(DeclarationCapability::Simple(true)),
         definition_provider: Some(OneOf::Left(true)),
         document_symbol_provider: Some(OneOf::Left(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
         document_formatting_provider: Some(OneOf::Left(true)),
         folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+        document_highlight_provider: Some(OneOf::Left(true)),
+        linked_editing_range_provider: Some(LinkedEditingRangeServerCapabilities::Simple(true)),
         semantic_tokens_provider: Some(
             SemanticTokensOptions {
                 legend: SemanticTokensLegend {
                     token_types: SUPPORTED_TOKEN_TYPES.to_vec(),
-                    token_modifiers: vec![],
+                    token_modifiers: SUPPORTED_TOKEN_MODIFIERS.to_vec(),
                 },
-                full: Some(SemanticTokensFullOptions::Bool(true)),
+                range: Some(true),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
                 ..Default::default()
             }
             .into(),
@@ -1,3 +1,4 @@
+use tombi_extension::ExtensionContext;
 use tombi_text::FromLsp;
 use tower_lsp::lsp_types::InlayHintParams;
 
@@ -40,32 +41,24 @@ pub async fn handle_inlay_hint(
         )
     };
 
-    if config.cargo_inlay_hint_enabled()
-        && let Some(hints) = tombi_extension_cargo::inlay_hint(
-            &text_document_uri,
-            &document_tree,
-            visible_range,
-            toml_version,
-            schema_store.offline(),
-            schema_store.cache_options(),
-            config.cargo_extension_features(),
-        )
-        .await?
-    {
-        return Ok(Some(hints));
-    }
-
-    if config.pyproject_inlay_hint_enabled()
-        && let Some(hints) = tombi_extension_pyproject::inlay_hint(
-            &text_document_uri,
-            &document_tree,
-            visible_range,
-            toml_version,
-            config.pyproject_extension_features(),
-        )
-        .await?
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+    for extension in backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
     {
-        return Ok(Some(hints));
+        if let Some(hints) = extension
+            .inlay_hint(&extension_context, visible_range)
+            .await?
+        {
+            return Ok(Some(hints));
+        }
     }
 
     Ok(None)
@@ -0,0 +1,62 @@
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::{
+    LinkedEditingRangeParams, LinkedEditingRanges, TextDocumentPositionParams,
+};
+
+use crate::{backend::Backend, key_occurrence::collect_key_occurrences};
+
+pub async fn handle_linked_editing_range(
+    backend: &Backend,
+    params: LinkedEditingRangeParams,
+) -> Result<Option<LinkedEditingRanges>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_linked_editing_range");
+    log::trace!("{:?}", params);
+
+    let LinkedEditingRangeParams {
+        text_document_position_params:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ..
+    } = params;
+    let text_document_uri = text_document.uri.into();
+
+    let Ok(document_sources) = backend.document_sources.try_read() else {
+        return Ok(None);
+    };
+    let Some(document_source) = document_sources.get(&text_document_uri) else {
+        return Ok(None);
+    };
+
+    let line_index = document_source.line_index();
+    let position = position.into_lsp(line_index);
+
+    let occurrences = collect_key_occurrences(&document_source.ast(), document_source.toml_version);
+    let Some(current) = occurrences
+        .iter()
+        .find(|occurrence| occurrence.is_header && occurrence.range.contains(position))
+    else {
+        return Ok(None);
+    };
+
+    // Linked ranges must have the same text, so `[a.b]` and `["a".c]` are not linked.
+    let ranges = occurrences
+        .iter()
+        .filter(|occurrence| {
+            occurrence.is_header
+                && occurrence.path == current.path
+                && occurrence.text == current.text
+        })
+        .map(|occurrence| occurrence.range.into_lsp(line_index))
+        .collect::<Vec<_>>();
+
+    if ranges.len() < 2 {
+        return Ok(None);
+    }
+
+    Ok(Some(LinkedEditingRanges {
+        ranges,
+        word_pattern: None,
+    }))
+}
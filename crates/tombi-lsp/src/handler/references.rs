@@ -1,3 +1,5 @@
+use itertools::Itertools;
+use tombi_extension::ExtensionContext;
 use tombi_hashmap::IndexSet;
 use tombi_text::IntoLsp;
 use tower_lsp::lsp_types::{ReferenceParams, TextDocumentPositionParams};
@@ -24,7 +26,11 @@ pub async fn handle_references(
     } = params;
     let text_document_uri = text_document.uri.into();
 
-    let ConfigSchemaStore { config, .. } = backend
+    let ConfigSchemaStore {
+        config,
+        schema_store,
+        ..
+    } = backend
         .config_manager
         .config_schema_store_for_uri(&text_document_uri)
         .await;
@@ -61,74 +67,40 @@ pub async fn handle_references(
     let document_tree = document_source.document_tree();
     let accessors = tombi_document_tree::get_accessors(&document_tree, &keys, position);
 
-    let locations = if config.cargo_extension_enabled()
-        && let Some(locations) = tombi_extension_cargo::references(
-            &text_document_uri,
-            &document_tree,
-            &accessors,
-            toml_version,
-            config.cargo_extension_features(),
-        )
-        .await?
-    {
-        locations
-    } else if config.pyproject_extension_enabled()
-        && let Some(locations) = tombi_extension_pyproject::references(
-            &text_document_uri,
-            &document_tree,
-            &accessors,
-            toml_version,
-            config.pyproject_extension_features(),
-        )
-        .await?
-    {
-        locations
-    } else {
-        Vec::new()
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config: &config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
     };
+    let extensions = backend
+        .extension_registry
+        .extensions_for(&text_document_uri, &config)
+        .collect_vec();
+
+    let mut locations = Vec::new();
+    for extension in &extensions {
+        if let Some(extension_locations) =
+            extension.references(&extension_context, &accessors).await?
+        {
+            locations = extension_locations;
+            break;
+        }
+    }
 
     let locations = if context.include_declaration {
         let mut location_set: IndexSet<_> = locations.into_iter().collect();
-        if config.cargo_extension_enabled() && text_document_uri.path().ends_with("Cargo.toml") {
-            if let Some(declaration_locations) = tombi_extension_cargo::goto_declaration(
-                &text_document_uri,
-                &document_tree,
-                &accessors,
-                toml_version,
-                config.cargo_extension_features(),
-            )
-            .await?
-            {
-                location_set.extend(declaration_locations);
-            }
-
-            if let Some(location) = tombi_extension_cargo::get_current_declaration(
-                &document_tree,
-                &accessors,
-                &text_document_uri,
-            ) {
-                location_set.insert(location);
-            }
-        } else if config.pyproject_extension_enabled()
-            && text_document_uri.path().ends_with("pyproject.toml")
-        {
-            if let Some(declaration_locations) = tombi_extension_pyproject::goto_declaration(
-                &text_document_uri,
-                &document_tree,
-                &accessors,
-                toml_version,
-                config.pyproject_extension_features(),
-            )
-            .await?
+        for extension in &extensions {
+            if let Some(declaration_locations) = extension
+                .goto_declaration(&extension_context, &accessors)
+                .await?
             {
                 location_set.extend(declaration_locations);
             }
 
-            if let Some(location) = tombi_extension_pyproject::get_current_declaration(
-                &document_tree,
-                &accessors,
-                &text_document_uri,
-            ) {
+            if let Some(location) = extension.current_declaration(&extension_context, &accessors) {
                 location_set.insert(location);
             }
         }
@@ -0,0 +1,134 @@
+use tombi_ast::AstNode;
+use tombi_rg_tree::TokenAtOffset;
+use tombi_syntax::SyntaxKind;
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::{SelectionRange, SelectionRangeParams};
+
+use crate::backend::Backend;
+
+pub async fn handle_selection_range(
+    backend: &Backend,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_selection_range");
+    log::trace!("{:?}", params);
+
+    let SelectionRangeParams {
+        text_document,
+        positions,
+        ..
+    } = params;
+    let text_document_uri = text_document.uri.into();
+
+    let Ok(document_sources) = backend.document_sources.try_read() else {
+        return Ok(None);
+    };
+    let Some(document_source) = document_sources.get(&text_document_uri) else {
+        return Ok(None);
+    };
+
+    let root = document_source.ast();
+    let line_index = document_source.line_index();
+
+    Ok(Some(
+        positions
+            .into_iter()
+            .map(|position| {
+                let position = position.into_lsp(line_index);
+                create_selection_ranges(&root, position)
+                    .into_iter()
+                    .rev()
+                    .fold(None, |parent, range| {
+                        Some(SelectionRange {
+                            range: range.into_lsp(line_index),
+                            parent: parent.map(Box::new),
+                        })
+                    })
+                    .unwrap_or_else(|| SelectionRange {
+                        range: tombi_text::Range::at(position).into_lsp(line_index),
+                        parent: None,
+                    })
+            })
+            .collect(),
+    ))
+}
+
+/// The ranges to expand the selection through, from the innermost to the outermost.
+///
+/// e.g. key → dotted keys → key-value → key-value group → table → document.
+fn create_selection_ranges(
+    root: &tombi_ast::Root,
+    position: tombi_text::Position,
+) -> Vec<tombi_text::Range> {
+    let token = match root.syntax().token_at_position(position) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if matches!(
+                right.kind(),
+                SyntaxKind::WHITESPACE | SyntaxKind::LINE_BREAK
+            ) {
+                left
+            } else {
+                right
+            }
+        }
+        TokenAtOffset::None => return Vec::new(),
+    };
+
+    let mut ranges = Vec::new();
+    if !matches!(
+        token.kind(),
+        SyntaxKind::WHITESPACE | SyntaxKind::LINE_BREAK
+    ) {
+        ranges.push(token.range());
+    }
+    for node in token.parent_ancestors() {
+        let Some(range) = trimmed_range(&node) else {
+            continue;
+        };
+        if ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    }
+
+    ranges
+}
+
+/// The range of the node without its leading and trailing whitespaces and line breaks.
+fn trimmed_range(node: &tombi_syntax::SyntaxNode) -> Option<tombi_text::Range> {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| {
+            !matches!(
+                token.kind(),
+                SyntaxKind::WHITESPACE | SyntaxKind::LINE_BREAK
+            )
+        });
+
+    let first = tokens.next()?;
+    let last = tokens.last().unwrap_or_else(|| first.clone());
+
+    Some(tombi_text::Range::new(
+        first.range().start,
+        last.range().end,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_ranges_of_key() {
+        let source = "[table]\nkey1 = 1\nkey2.name = \"value\"\n\nkey3 = 3\n";
+        let (root, _) = tombi_parser::parse(source).into_root_and_errors();
+
+        let ranges = create_selection_ranges(&root, tombi_text::Position::new(2, 6));
+
+        pretty_assertions::assert_eq!(
+            ranges.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["2:5..2:9", "2:0..2:9", "2:0..2:19", "1:0..2:19", "0:0..4:8"]
+        );
+    }
+}
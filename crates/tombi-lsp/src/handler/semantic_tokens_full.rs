@@ -1,8 +1,16 @@
-use tower_lsp::lsp_types::{SemanticTokens, SemanticTokensParams, SemanticTokensResult};
+use itertools::Either;
+use tombi_schema_store::SchemaContext;
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::{
+    SemanticToken, SemanticTokens, SemanticTokensParams, SemanticTokensResult,
+};
 
 use crate::{
     backend::Backend,
-    semantic_tokens::{AppendSemanticTokens, SemanticTokensBuilder},
+    config_manager::ConfigSchemaStore,
+    semantic_tokens::{
+        AppendSemanticTokens, SemanticTokensBuilder, collect_schema_token_modifiers,
+    },
 };
 
 pub async fn handle_semantic_tokens_full(
@@ -15,22 +23,68 @@ pub async fn handle_semantic_tokens_full(
     let SemanticTokensParams { text_document, .. } = params;
     let text_document_uri: tombi_uri::Uri = text_document.uri.into();
 
-    let document_sources = backend.document_sources.read().await;
-    let Some(document_source) = document_sources.get(&text_document_uri) else {
+    let Some(tokens) = build_semantic_tokens(backend, &text_document_uri, None).await else {
         return Ok(None);
     };
-    let line_index = document_source.line_index();
-
-    let mut tokens_builder = SemanticTokensBuilder::new(text_document_uri, line_index);
 
-    document_source
-        .ast()
-        .append_semantic_tokens(&mut tokens_builder);
-
-    let tokens = tokens_builder.build();
+    let result_id = backend
+        .semantic_tokens_cache
+        .write()
+        .await
+        .insert(text_document_uri, tokens.clone());
 
     Ok(Some(SemanticTokensResult::Tokens(SemanticTokens {
-        result_id: None,
+        result_id: Some(result_id),
         data: tokens,
     })))
 }
+
+/// Build the semantic tokens of the document, only in `range` if it is given.
+pub(crate) async fn build_semantic_tokens(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    range: Option<tower_lsp::lsp_types::Range>,
+) -> Option<Vec<SemanticToken>> {
+    let ConfigSchemaStore { schema_store, .. } = backend
+        .config_manager
+        .config_schema_store_for_uri(text_document_uri)
+        .await;
+
+    // Only clone the document here, so that the lock is not held while tokenizing.
+    let (root, document_tree, line_index, toml_version) = {
+        let document_sources = backend.document_sources.read().await;
+        let document_source = document_sources.get(text_document_uri)?;
+        (
+            document_source.ast(),
+            document_source.document_tree(),
+            document_source.line_index_arc(),
+            document_source.toml_version,
+        )
+    };
+
+    let range = range.map(|range| range.into_lsp(&line_index));
+
+    let source_schema = schema_store
+        .resolve_source_schema_from_ast(&root, Some(Either::Left(text_document_uri)))
+        .await
+        .ok()
+        .flatten();
+    let schema_context = SchemaContext::from_source_schema(
+        toml_version,
+        source_schema.as_ref(),
+        &schema_store,
+        None,
+    );
+    // Only the modifiers of the requested range are needed for `semanticTokens/range`.
+    let modifiers = collect_schema_token_modifiers(&document_tree, &schema_context, range).await;
+
+    let mut tokens_builder = SemanticTokensBuilder::new(text_document_uri.clone(), &line_index)
+        .with_modifiers(modifiers);
+    if let Some(range) = range {
+        tokens_builder = tokens_builder.with_range(range);
+    }
+
+    root.append_semantic_tokens(&mut tokens_builder);
+
+    Some(tokens_builder.build())
+}
@@ -0,0 +1,50 @@
+use tower_lsp::lsp_types::{
+    SemanticTokens, SemanticTokensDelta, SemanticTokensDeltaParams, SemanticTokensFullDeltaResult,
+};
+
+use crate::{
+    backend::Backend, handler::semantic_tokens_full::build_semantic_tokens,
+    semantic_tokens::semantic_tokens_edits,
+};
+
+pub async fn handle_semantic_tokens_full_delta(
+    backend: &Backend,
+    params: SemanticTokensDeltaParams,
+) -> Result<Option<SemanticTokensFullDeltaResult>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_semantic_tokens_full_delta");
+    log::trace!("{:?}", params);
+
+    let SemanticTokensDeltaParams {
+        text_document,
+        previous_result_id,
+        ..
+    } = params;
+    let text_document_uri: tombi_uri::Uri = text_document.uri.into();
+
+    let Some(tokens) = build_semantic_tokens(backend, &text_document_uri, None).await else {
+        return Ok(None);
+    };
+
+    let mut semantic_tokens_cache = backend.semantic_tokens_cache.write().await;
+    let edits = semantic_tokens_cache
+        .get(&text_document_uri, &previous_result_id)
+        .map(|previous_tokens| semantic_tokens_edits(previous_tokens, &tokens));
+    let result_id = semantic_tokens_cache.insert(text_document_uri, tokens.clone());
+
+    // The client has no tokens of the previous result id, so send all of them.
+    let Some(edits) = edits else {
+        return Ok(Some(SemanticTokensFullDeltaResult::Tokens(
+            SemanticTokens {
+                result_id: Some(result_id),
+                data: tokens,
+            },
+        )));
+    };
+
+    Ok(Some(SemanticTokensFullDeltaResult::TokensDelta(
+        SemanticTokensDelta {
+            result_id: Some(result_id),
+            edits,
+        },
+    )))
+}
@@ -0,0 +1,27 @@
+use tower_lsp::lsp_types::{SemanticTokens, SemanticTokensRangeParams, SemanticTokensRangeResult};
+
+use crate::{backend::Backend, handler::semantic_tokens_full::build_semantic_tokens};
+
+pub async fn handle_semantic_tokens_range(
+    backend: &Backend,
+    params: SemanticTokensRangeParams,
+) -> Result<Option<SemanticTokensRangeResult>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_semantic_tokens_range");
+    log::trace!("{:?}", params);
+
+    let SemanticTokensRangeParams {
+        text_document,
+        range,
+        ..
+    } = params;
+    let text_document_uri: tombi_uri::Uri = text_document.uri.into();
+
+    let Some(tokens) = build_semantic_tokens(backend, &text_document_uri, Some(range)).await else {
+        return Ok(None);
+    };
+
+    Ok(Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
+        result_id: None,
+        data: tokens,
+    })))
+}
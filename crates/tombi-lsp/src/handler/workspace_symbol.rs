@@ -0,0 +1,43 @@
+use tower_lsp::lsp_types::{Location, SymbolInformation, WorkspaceSymbolParams};
+
+use crate::{
+    backend::Backend, workspace_diagnostic::collect_workspace_diagnostic_targets,
+    workspace_symbol::matches_workspace_symbol_query,
+};
+
+pub async fn handle_workspace_symbol(
+    backend: &Backend,
+    params: WorkspaceSymbolParams,
+) -> Result<Option<Vec<SymbolInformation>>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_workspace_symbol");
+    log::trace!("{:?}", params);
+
+    let WorkspaceSymbolParams { query, .. } = params;
+
+    // Load the workspace files that have never been opened.
+    collect_workspace_diagnostic_targets(backend).await;
+
+    let document_sources = backend.document_sources.read().await;
+    let mut workspace_symbol_index = backend.workspace_symbol_index.write().await;
+
+    let mut symbols = Vec::new();
+    for (text_document_uri, document_source) in document_sources.iter() {
+        for symbol in workspace_symbol_index
+            .get_or_index(text_document_uri, document_source)
+            .iter()
+            .filter(|symbol| matches_workspace_symbol_query(&symbol.name, &query))
+        {
+            #[allow(deprecated)]
+            symbols.push(SymbolInformation {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                location: Location::new(text_document_uri.clone().into(), symbol.range),
+                container_name: symbol.container_name.clone(),
+            });
+        }
+    }
+
+    Ok(Some(symbols))
+}
@@ -0,0 +1,135 @@
+use tombi_ast::AstNode;
+use tombi_config::TomlVersion;
+
+/// A key of a table header or of a key-value, with the full key path up to the key.
+///
+/// ```toml
+/// [a.b]   # `a` is `a`, `b` is `a.b`
+/// c.d = 1 # `c` is `a.b.c`, `d` is `a.b.c.d`
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyOccurrence {
+    pub path: Vec<String>,
+    pub range: tombi_text::Range,
+    pub text: String,
+
+    /// The key is in a `[table]` or `[[array.of.tables]]` header.
+    pub is_header: bool,
+
+    /// The key is the last key of its dotted keys, i.e. it defines the value.
+    pub is_last: bool,
+}
+
+pub fn collect_key_occurrences(
+    root: &tombi_ast::Root,
+    toml_version: TomlVersion,
+) -> Vec<KeyOccurrence> {
+    let mut occurrences = Vec::new();
+
+    for keys in root
+        .syntax()
+        .descendants()
+        .filter_map(tombi_ast::Keys::cast)
+    {
+        let parent = keys.syntax().parent();
+        let is_header = parent.as_ref().is_some_and(|parent| {
+            tombi_ast::Table::can_cast(parent.kind())
+                || tombi_ast::ArrayOfTable::can_cast(parent.kind())
+        });
+        let mut path = match parent.and_then(tombi_ast::KeyValue::cast) {
+            Some(key_value) => parent_key_path(key_value.syntax(), toml_version),
+            None if is_header => Vec::new(),
+            None => continue,
+        };
+
+        let keys = keys.keys().collect::<Vec<_>>();
+        let len = keys.len();
+        for (index, key) in keys.into_iter().enumerate() {
+            path.push(key.to_raw_text(toml_version));
+            occurrences.push(KeyOccurrence {
+                path: path.clone(),
+                range: key.range(),
+                text: key.syntax().text().to_string(),
+                is_header,
+                is_last: index + 1 == len,
+            });
+        }
+    }
+
+    occurrences
+}
+
+/// The key path of the table the node belongs to.
+fn parent_key_path(node: &tombi_syntax::SyntaxNode, toml_version: TomlVersion) -> Vec<String> {
+    for ancestor in node.ancestors().skip(1) {
+        if let Some(table) = tombi_ast::Table::cast(ancestor.clone()) {
+            return keys_path(table.header(), toml_version);
+        }
+        if let Some(array_of_table) = tombi_ast::ArrayOfTable::cast(ancestor.clone()) {
+            return keys_path(array_of_table.header(), toml_version);
+        }
+        if let Some(key_value) = tombi_ast::KeyValue::cast(ancestor) {
+            let mut path = parent_key_path(key_value.syntax(), toml_version);
+            path.extend(keys_path(key_value.keys(), toml_version));
+            return path;
+        }
+    }
+
+    Vec::new()
+}
+
+fn keys_path(keys: Option<tombi_ast::Keys>, toml_version: TomlVersion) -> Vec<String> {
+    keys.map(|keys| {
+        keys.keys()
+            .map(|key| key.to_raw_text(toml_version))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_occurrence_paths() {
+        let source = r#"
+a.b = 1
+
+[c.d]
+e = { f = 1 }
+g = [{ h = 1 }]
+
+[["i"]]
+j = 1
+"#;
+        let (root, _) = tombi_parser::parse(source).into_root_and_errors();
+
+        let occurrences = collect_key_occurrences(&root, TomlVersion::default())
+            .into_iter()
+            .map(|occurrence| {
+                (
+                    occurrence.path.join("."),
+                    occurrence.is_header,
+                    occurrence.is_last,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        pretty_assertions::assert_eq!(
+            occurrences,
+            vec![
+                ("a".to_string(), false, false),
+                ("a.b".to_string(), false, true),
+                ("c".to_string(), true, false),
+                ("c.d".to_string(), true, true),
+                ("c.d.e".to_string(), false, true),
+                ("c.d.e.f".to_string(), false, true),
+                ("c.d.g".to_string(), false, true),
+                ("c.d.g.h".to_string(), false, true),
+                ("i".to_string(), true, true),
+                ("i.j".to_string(), false, true),
+            ]
+        );
+    }
+}
@@ -5,9 +5,11 @@ mod completion;
 mod config_manager;
 mod diagnostic;
 mod document;
+mod extension;
 mod goto_definition;
 mod goto_type_definition;
 mod hover;
+mod key_occurrence;
 mod location;
 mod references;
 mod remote_file;
@@ -15,10 +17,12 @@ mod schema_resolver;
 mod semantic_tokens;
 mod workspace_config;
 mod workspace_diagnostic;
+mod workspace_symbol;
 
 pub mod handler {
     mod associate_schema;
     mod code_action;
+    mod code_lens;
     mod completion;
     mod diagnostic;
     mod did_change;
@@ -27,8 +31,10 @@ pub mod handler {
     mod did_close;
     mod did_open;
     mod did_save;
+    mod document_highlight;
     mod document_link;
     mod document_symbol;
+    mod extension_request;
     mod folding_range;
     mod formatting;
     mod get_built_in_schema;
@@ -41,17 +47,23 @@ pub mod handler {
     mod initialize;
     mod initialized;
     mod inlay_hint;
+    mod linked_editing_range;
     mod list_schemas;
     mod references;
     mod refresh_cache;
+    mod selection_range;
     mod semantic_tokens_full;
+    mod semantic_tokens_full_delta;
+    mod semantic_tokens_range;
     mod shutdown;
     mod update_config;
     mod update_schema;
     mod workspace_diagnostic;
+    mod workspace_symbol;
 
     pub use associate_schema::{AssociateSchemaParams, handle_associate_schema};
     pub use code_action::handle_code_action;
+    pub use code_lens::handle_code_lens;
     pub use completion::handle_completion;
     pub use diagnostic::{handle_diagnostic, push_diagnostics};
     pub use did_change::handle_did_change;
@@ -60,8 +72,10 @@ pub mod handler {
     pub use did_close::handle_did_close;
     pub use did_open::handle_did_open;
     pub use did_save::handle_did_save;
+    pub use document_highlight::handle_document_highlight;
     pub use document_link::handle_document_link;
     pub use document_symbol::handle_document_symbol;
+    pub use extension_request::{ExtensionRequestParams, handle_extension_request};
     pub use folding_range::handle_folding_range;
     pub use formatting::handle_formatting;
     pub use get_built_in_schema::{GetBuiltInSchemaParams, handle_get_built_in_schema};
@@ -76,14 +90,19 @@ pub mod handler {
     pub use initialize::handle_initialize;
     pub use initialized::handle_initialized;
     pub use inlay_hint::handle_inlay_hint;
+    pub use linked_editing_range::handle_linked_editing_range;
     pub use list_schemas::{ListSchemasParams, ListSchemasResponse, handle_list_schemas};
     pub use references::handle_references;
     pub use refresh_cache::{RefreshCacheParams, handle_refresh_cache};
+    pub use selection_range::handle_selection_range;
     pub use semantic_tokens_full::handle_semantic_tokens_full;
+    pub use semantic_tokens_full_delta::handle_semantic_tokens_full_delta;
+    pub use semantic_tokens_range::handle_semantic_tokens_range;
     pub use shutdown::handle_shutdown;
     pub use update_config::handle_update_config;
     pub use update_schema::handle_update_schema;
     pub use workspace_diagnostic::handle_workspace_diagnostic;
+    pub use workspace_symbol::handle_workspace_symbol;
 }
 
 pub use backend::Backend;
@@ -91,6 +110,7 @@ pub(crate) use comment_directive::{
     DOCUMENT_SCHEMA_DIRECTIVE_DESCRIPTION, DOCUMENT_SCHEMA_DIRECTIVE_TITLE,
     DOCUMENT_TOMBI_DIRECTIVE_DESCRIPTION, DOCUMENT_TOMBI_DIRECTIVE_TITLE,
 };
+pub use extension::default_extension_registry;
 pub use hover::HoverContent;
 
 /// Run TOML Language Server
@@ -124,6 +144,7 @@ pub async fn serve(_args: impl Into<Args>, offline: bool, no_cache: bool) {
     .custom_method("tombi/updateConfig", Backend::update_config)
     .custom_method("tombi/associateSchema", Backend::associate_schema)
     .custom_method("tombi/refreshCache", Backend::refresh_cache)
+    .custom_method("tombi/cargoFeatureGraph", Backend::cargo_feature_graph)
     .finish();
 
     tower_lsp::Server::new(stdin, stdout, socket)
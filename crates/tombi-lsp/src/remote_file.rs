@@ -156,8 +156,8 @@ async fn fetch_remote_content(url: &Url) -> Result<String, tower_lsp::jsonrpc::E
         }
     };
 
-    // Check if the content is valid JSON
-    tombi_json::ValueNode::from_str(&content).map_err(|e| {
+    // Check if the content is a valid schema
+    tombi_schema_store::parse_schema_value(&url.clone().into(), &content).map_err(|e| {
         log::error!("Failed to parse {url} content: {}", e);
         tower_lsp::jsonrpc::Error::new(tower_lsp::jsonrpc::ErrorCode::InternalError)
     })?;
@@ -131,7 +131,7 @@ fn is_leaf_array_element(value: &Value) -> bool {
     }
 }
 
-async fn resolve_current_schema(
+pub(crate) async fn resolve_current_schema(
     accessors: &[Accessor],
     schema_context: &SchemaContext<'_>,
 ) -> Option<CurrentSchema<'static>> {
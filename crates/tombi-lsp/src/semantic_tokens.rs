@@ -1,16 +1,22 @@
 mod array;
 mod array_or_table;
 mod builder;
+mod cache;
 mod comment;
 mod inline_table;
 mod key;
 mod key_value;
 mod root;
+mod schema_modifier;
 mod table;
+mod token_modifier;
 mod token_type;
 mod value;
 
 pub use builder::SemanticTokensBuilder;
+pub use cache::{SemanticTokensCache, semantic_tokens_edits};
+pub use schema_modifier::collect_schema_token_modifiers;
+pub use token_modifier::{SUPPORTED_TOKEN_MODIFIERS, TokenModifier};
 pub use token_type::{SUPPORTED_TOKEN_TYPES, TokenType};
 
 pub trait AppendSemanticTokens {
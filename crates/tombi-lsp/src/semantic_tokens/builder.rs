@@ -3,12 +3,15 @@ use tombi_text::FromLsp;
 use tower_lsp::lsp_types::SemanticToken;
 use unicode_segmentation::UnicodeSegmentation;
 
-use super::token_type::TokenType;
+use super::{schema_modifier::TokenModifiers, token_type::TokenType};
 
 pub struct SemanticTokensBuilder<'a> {
     tokens: Vec<SemanticToken>,
     last_range: tombi_text::Range,
     line_index: &'a tombi_text::LineIndex,
+    /// Only the tokens in this range are built, for `semanticTokens/range`.
+    range: Option<tombi_text::Range>,
+    modifiers: TokenModifiers,
     pub text_document_uri: tombi_uri::Uri,
 }
 
@@ -18,12 +21,32 @@ impl<'a> SemanticTokensBuilder<'a> {
             tokens: Vec::new(),
             last_range: tombi_text::Range::default(),
             line_index,
+            range: None,
+            modifiers: TokenModifiers::default(),
             text_document_uri,
         }
     }
 
+    pub fn with_range(mut self, range: tombi_text::Range) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    pub fn with_modifiers(mut self, modifiers: TokenModifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    fn is_out_of_range(&self, range: tombi_text::Range) -> bool {
+        self.range
+            .is_some_and(|requested_range| !requested_range.intersects(range))
+    }
+
     pub fn add_token(&mut self, token_type: TokenType, elem: tombi_syntax::SyntaxElement) {
         let range: tombi_text::Range = elem.range();
+        if self.is_out_of_range(range) {
+            return;
+        }
         let (delta_line, delta_start) =
             delta_line_and_start(self.last_range.start, range.start, self.line_index);
 
@@ -33,7 +56,7 @@ impl<'a> SemanticTokensBuilder<'a> {
             delta_start,
             length: token_length(range, self.line_index),
             token_type: token_type as u32,
-            token_modifiers_bitset: 0,
+            token_modifiers_bitset: self.modifiers.get(range),
         });
 
         self.last_range = range;
@@ -45,6 +68,9 @@ impl<'a> SemanticTokensBuilder<'a> {
         directive_range: tombi_text::Range,
     ) {
         let comment_range = comment.as_ref().syntax().range();
+        if self.is_out_of_range(comment_range) {
+            return;
+        }
         let directive_range =
             tower_lsp::lsp_types::Range::from_lsp(directive_range, self.line_index);
         let (delta_line, delta_start) =
@@ -0,0 +1,145 @@
+use tower_lsp::lsp_types::{SemanticToken, SemanticTokensEdit};
+
+/// The last semantic tokens sent for each document, used to answer `semanticTokens/full/delta`.
+#[derive(Debug, Default)]
+pub struct SemanticTokensCache {
+    next_result_id: u64,
+    results: tombi_hashmap::HashMap<tombi_uri::Uri, CachedSemanticTokens>,
+}
+
+#[derive(Debug)]
+struct CachedSemanticTokens {
+    result_id: String,
+    tokens: Vec<SemanticToken>,
+}
+
+impl SemanticTokensCache {
+    /// Store the tokens of the document and return their result id.
+    pub fn insert(
+        &mut self,
+        text_document_uri: tombi_uri::Uri,
+        tokens: Vec<SemanticToken>,
+    ) -> String {
+        self.next_result_id += 1;
+        let result_id = self.next_result_id.to_string();
+        self.results.insert(
+            text_document_uri,
+            CachedSemanticTokens {
+                result_id: result_id.clone(),
+                tokens,
+            },
+        );
+
+        result_id
+    }
+
+    /// Get the tokens of the document if they are the ones of the result id.
+    pub fn get(
+        &self,
+        text_document_uri: &tombi_uri::Uri,
+        result_id: &str,
+    ) -> Option<&[SemanticToken]> {
+        self.results
+            .get(text_document_uri)
+            .filter(|cached| cached.result_id == result_id)
+            .map(|cached| cached.tokens.as_slice())
+    }
+
+    pub fn remove(&mut self, text_document_uri: &tombi_uri::Uri) {
+        self.results.remove(text_document_uri);
+    }
+}
+
+/// The edit that turns the previous tokens into the current ones.
+///
+/// The unchanged tokens at the start and the end are kept, and everything between them is replaced.
+/// `start` and `delete_count` index the flattened token data, where each token has 5 numbers.
+pub fn semantic_tokens_edits(
+    previous: &[SemanticToken],
+    current: &[SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    let prefix_len = previous
+        .iter()
+        .zip(current)
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    let suffix_len = previous[prefix_len..]
+        .iter()
+        .rev()
+        .zip(current[prefix_len..].iter().rev())
+        .take_while(|(previous, current)| previous == current)
+        .count();
+
+    let deleted = &previous[prefix_len..previous.len() - suffix_len];
+    let inserted = &current[prefix_len..current.len() - suffix_len];
+    if deleted.is_empty() && inserted.is_empty() {
+        return Vec::new();
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let (start, delete_count) = ((prefix_len * 5) as u32, (deleted.len() * 5) as u32);
+
+    vec![SemanticTokensEdit {
+        start,
+        delete_count,
+        data: (!inserted.is_empty()).then(|| inserted.to_vec()),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(delta_line: u32, token_type: u32) -> SemanticToken {
+        SemanticToken {
+            delta_line,
+            delta_start: 0,
+            length: 1,
+            token_type,
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    #[test]
+    fn replaces_only_the_changed_tokens() {
+        let previous = vec![token(0, 0), token(1, 1), token(1, 2), token(1, 3)];
+        let current = vec![
+            token(0, 0),
+            token(1, 4),
+            token(1, 5),
+            token(1, 2),
+            token(1, 3),
+        ];
+
+        pretty_assertions::assert_eq!(
+            semantic_tokens_edits(&previous, &current),
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 5,
+                data: Some(vec![token(1, 4), token(1, 5)]),
+            }]
+        );
+    }
+
+    #[test]
+    fn no_edit_for_the_same_tokens() {
+        let tokens = vec![token(0, 0), token(1, 1)];
+
+        assert!(semantic_tokens_edits(&tokens, &tokens).is_empty());
+    }
+
+    #[test]
+    fn deletes_removed_tokens() {
+        let previous = vec![token(0, 0), token(1, 1), token(1, 1)];
+        let current = vec![token(0, 0), token(1, 1)];
+
+        pretty_assertions::assert_eq!(
+            semantic_tokens_edits(&previous, &current),
+            vec![SemanticTokensEdit {
+                start: 10,
+                delete_count: 5,
+                data: None,
+            }]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use std::borrow::Cow;
+
+use tombi_document_tree::Value;
+use tombi_future::Boxable;
+use tombi_schema_store::{
+    Accessor, CurrentSchema, ReferableValueSchemas, SchemaAccessor, SchemaContext, TableSchema,
+    ValueSchema,
+};
+
+use super::TokenModifier;
+use crate::schema_resolver::resolve_array_item_schema;
+
+/// The token modifiers of the keys and values, found from the document schema.
+#[derive(Debug, Default)]
+pub struct TokenModifiers(tombi_hashmap::HashMap<tombi_text::Range, u32>);
+
+impl TokenModifiers {
+    pub fn get(&self, range: tombi_text::Range) -> u32 {
+        self.0.get(&range).copied().unwrap_or_default()
+    }
+
+    fn add(&mut self, range: tombi_text::Range, modifier: TokenModifier) {
+        *self.0.entry(range).or_default() |= modifier.bit();
+    }
+}
+
+/// Collect the `deprecated` keys and values, and the values fixed by a schema `const`.
+///
+/// A `oneOf`, `anyOf` or `allOf` is followed through its first schema of the value type.
+/// If `range` is given, only the keys and values in it are looked up.
+pub async fn collect_schema_token_modifiers(
+    document_tree: &tombi_document_tree::DocumentTree,
+    schema_context: &SchemaContext<'_>,
+    range: Option<tombi_text::Range>,
+) -> TokenModifiers {
+    let mut modifiers = TokenModifiers::default();
+
+    let Some(document_schema) = schema_context.root_schema else {
+        return modifiers;
+    };
+    let Some(value_schema) = &document_schema.value_schema else {
+        return modifiers;
+    };
+    let current_schema = CurrentSchema {
+        value_schema: value_schema.clone(),
+        schema_uri: Cow::Owned(document_schema.schema_uri.clone()),
+        definitions: Cow::Owned(document_schema.definitions.clone()),
+    };
+
+    for (key, value) in document_tree.key_values() {
+        if is_out_of_range(range, Some(key.range()), value) {
+            continue;
+        }
+        let accessors = [Accessor::Key(key.value.clone())];
+        let property_schema = match current_schema.value_schema.as_ref() {
+            ValueSchema::Table(table_schema) => {
+                resolve_property_schema(table_schema, &key.value, &current_schema, schema_context)
+                    .await
+            }
+            _ => None,
+        };
+        collect_value_modifiers(
+            value,
+            Some(key.range()),
+            property_schema,
+            &accessors,
+            schema_context,
+            range,
+            &mut modifiers,
+        )
+        .await;
+    }
+
+    modifiers
+}
+
+fn collect_value_modifiers<'a: 'b, 'b>(
+    value: &'a Value,
+    key_range: Option<tombi_text::Range>,
+    current_schema: Option<CurrentSchema<'static>>,
+    accessors: &'a [Accessor],
+    schema_context: &'a SchemaContext<'a>,
+    range: Option<tombi_text::Range>,
+    modifiers: &'a mut TokenModifiers,
+) -> tombi_future::BoxFuture<'b, ()> {
+    async move {
+        let current_schema = match schema_context.get_subschema(accessors, None).await {
+            Some(Ok(document_schema)) => {
+                document_schema
+                    .value_schema
+                    .as_ref()
+                    .map(|value_schema| CurrentSchema {
+                        value_schema: value_schema.clone(),
+                        schema_uri: Cow::Owned(document_schema.schema_uri.clone()),
+                        definitions: Cow::Owned(document_schema.definitions.clone()),
+                    })
+            }
+            _ => current_schema,
+        };
+        let Some(current_schema) = current_schema else {
+            return;
+        };
+
+        if current_schema.value_schema.deprecated().await == Some(true) {
+            if let Some(key_range) = key_range {
+                modifiers.add(key_range, TokenModifier::DEPRECATED);
+            }
+            if is_scalar(value) {
+                modifiers.add(value.range(), TokenModifier::DEPRECATED);
+            }
+        }
+
+        let Some(current_schema) =
+            value_type_schema(value, current_schema, accessors, schema_context).await
+        else {
+            return;
+        };
+
+        if is_scalar(value) && has_const_value(&current_schema.value_schema) {
+            modifiers.add(value.range(), TokenModifier::READONLY);
+        }
+
+        match (value, current_schema.value_schema.as_ref()) {
+            (Value::Table(table), ValueSchema::Table(table_schema)) => {
+                for (key, value) in table.key_values() {
+                    if is_out_of_range(range, Some(key.range()), value) {
+                        continue;
+                    }
+                    let accessors = accessors
+                        .iter()
+                        .cloned()
+                        .chain(std::iter::once(Accessor::Key(key.value.clone())))
+                        .collect::<Vec<_>>();
+                    let property_schema = resolve_property_schema(
+                        table_schema,
+                        &key.value,
+                        &current_schema,
+                        schema_context,
+                    )
+                    .await;
+                    collect_value_modifiers(
+                        value,
+                        Some(key.range()),
+                        property_schema,
+                        &accessors,
+                        schema_context,
+                        range,
+                        modifiers,
+                    )
+                    .await;
+                }
+            }
+            (Value::Array(array), ValueSchema::Array(array_schema)) => {
+                for (index, value) in array.values().iter().enumerate() {
+                    if is_out_of_range(range, None, value) {
+                        continue;
+                    }
+                    let accessors = accessors
+                        .iter()
+                        .cloned()
+                        .chain(std::iter::once(Accessor::Index(index)))
+                        .collect::<Vec<_>>();
+                    let item_schema = resolve_array_item_schema(
+                        index,
+                        array_schema,
+                        &current_schema,
+                        schema_context,
+                    )
+                    .await;
+                    collect_value_modifiers(
+                        value,
+                        None,
+                        item_schema,
+                        &accessors,
+                        schema_context,
+                        range,
+                        modifiers,
+                    )
+                    .await;
+                }
+            }
+            _ => {}
+        }
+    }
+    .boxed()
+}
+
+/// The schema of a table key, from `properties`, `patternProperties` or `additionalProperties`.
+async fn resolve_property_schema(
+    table_schema: &TableSchema,
+    key: &str,
+    current_schema: &CurrentSchema<'_>,
+    schema_context: &SchemaContext<'_>,
+) -> Option<CurrentSchema<'static>> {
+    let schema_accessor = SchemaAccessor::Key(key.to_string());
+    if table_schema
+        .properties
+        .read()
+        .await
+        .contains_key(&schema_accessor)
+    {
+        return table_schema
+            .resolve_property_schema(
+                &schema_accessor,
+                current_schema.schema_uri.clone(),
+                current_schema.definitions.clone(),
+                schema_context.store,
+            )
+            .await
+            .inspect_err(|err| log::warn!("{err}"))
+            .ok()
+            .flatten();
+    }
+
+    if let Some(pattern_properties) = &table_schema.pattern_properties {
+        let pattern_keys = pattern_properties
+            .read()
+            .await
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        for pattern_key in pattern_keys {
+            if tombi_regex::Regex::new(&pattern_key).is_ok_and(|pattern| pattern.is_match(key)) {
+                return table_schema
+                    .resolve_pattern_property_schema(
+                        &pattern_key,
+                        current_schema.schema_uri.clone(),
+                        current_schema.definitions.clone(),
+                        schema_context.store,
+                    )
+                    .await
+                    .inspect_err(|err| log::warn!("{err}"))
+                    .ok()
+                    .flatten();
+            }
+        }
+    }
+
+    let (_, additional_property_schema) = table_schema.additional_property_schema.as_ref()?;
+    tombi_schema_store::resolve_schema_item(
+        additional_property_schema,
+        current_schema.schema_uri.clone(),
+        current_schema.definitions.clone(),
+        schema_context.store,
+    )
+    .await
+    .inspect_err(|err| log::warn!("{err}"))
+    .ok()
+    .flatten()
+    .map(CurrentSchema::into_owned)
+}
+
+/// Pick the schema of the value type, looking into a composite schema.
+async fn value_type_schema(
+    value: &Value,
+    current_schema: CurrentSchema<'static>,
+    accessors: &[Accessor],
+    schema_context: &SchemaContext<'_>,
+) -> Option<CurrentSchema<'static>> {
+    let schemas: &ReferableValueSchemas = match current_schema.value_schema.as_ref() {
+        ValueSchema::OneOf(schema) => &schema.schemas,
+        ValueSchema::AnyOf(schema) => &schema.schemas,
+        ValueSchema::AllOf(schema) => &schema.schemas,
+        _ => return Some(current_schema),
+    };
+
+    tombi_schema_store::resolve_and_collect_schemas(
+        schemas,
+        current_schema.schema_uri.clone(),
+        current_schema.definitions.clone(),
+        schema_context.store,
+        &schema_context.schema_visits,
+        accessors,
+    )
+    .await?
+    .into_iter()
+    .find(|schema| matches_value_type(value, &schema.value_schema))
+    .map(CurrentSchema::into_owned)
+}
+
+fn matches_value_type(value: &Value, value_schema: &ValueSchema) -> bool {
+    matches!(
+        (value, value_schema),
+        (Value::Boolean(_), ValueSchema::Boolean(_))
+            | (Value::Integer(_), ValueSchema::Integer(_))
+            | (Value::Float(_), ValueSchema::Float(_))
+            | (Value::String(_), ValueSchema::String(_))
+            | (Value::OffsetDateTime(_), ValueSchema::OffsetDateTime(_))
+            | (Value::LocalDateTime(_), ValueSchema::LocalDateTime(_))
+            | (Value::LocalDate(_), ValueSchema::LocalDate(_))
+            | (Value::LocalTime(_), ValueSchema::LocalTime(_))
+            | (Value::Array(_), ValueSchema::Array(_))
+            | (Value::Table(_), ValueSchema::Table(_))
+    )
+}
+
+fn has_const_value(value_schema: &ValueSchema) -> bool {
+    match value_schema {
+        ValueSchema::Boolean(schema) => schema.const_value.is_some(),
+        ValueSchema::Integer(schema) => schema.const_value.is_some(),
+        ValueSchema::Float(schema) => schema.const_value.is_some(),
+        ValueSchema::String(schema) => schema.const_value.is_some(),
+        ValueSchema::OffsetDateTime(schema) => schema.const_value.is_some(),
+        ValueSchema::LocalDateTime(schema) => schema.const_value.is_some(),
+        ValueSchema::LocalDate(schema) => schema.const_value.is_some(),
+        ValueSchema::LocalTime(schema) => schema.const_value.is_some(),
+        _ => false,
+    }
+}
+
+/// Whether the key and the value are outside of the requested range.
+fn is_out_of_range(
+    range: Option<tombi_text::Range>,
+    key_range: Option<tombi_text::Range>,
+    value: &Value,
+) -> bool {
+    range.is_some_and(|range| {
+        let entry_range = match key_range {
+            Some(key_range) => key_range + value.range(),
+            None => value.range(),
+        };
+        !range.intersects(entry_range)
+    })
+}
+
+/// A value written as a single token.
+fn is_scalar(value: &Value) -> bool {
+    !matches!(
+        value,
+        Value::Array(_) | Value::Table(_) | Value::Incomplete { .. }
+    )
+}
@@ -0,0 +1,23 @@
+use tower_lsp::lsp_types::SemanticTokenModifier;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy)]
+pub enum TokenModifier {
+    /// The key or value is `deprecated` in the schema.
+    DEPRECATED,
+
+    /// The value is the schema `const` value.
+    READONLY,
+}
+
+pub const SUPPORTED_TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::DEPRECATED,
+    SemanticTokenModifier::READONLY,
+];
+
+impl TokenModifier {
+    /// The bit of the modifier in `token_modifiers_bitset`.
+    pub const fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
@@ -0,0 +1,241 @@
+use std::sync::Arc;
+
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::SymbolKind;
+
+use crate::document::DocumentSource;
+
+/// A table, array of tables entry or key of a document, named by its full key path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkspaceSymbolEntry {
+    /// The full key path, e.g. `profile.release` or `bin[0].name`.
+    pub name: String,
+
+    /// The key path of the parent, e.g. `profile`.
+    pub container_name: Option<String>,
+
+    pub kind: SymbolKind,
+
+    pub range: tower_lsp::lsp_types::Range,
+}
+
+/// The symbols of the workspace documents.
+///
+/// The symbols of a document are rebuilt when its document tree changes.
+#[derive(Debug, Default)]
+pub struct WorkspaceSymbolIndex {
+    documents: tombi_hashmap::HashMap<tombi_uri::Uri, IndexedDocument>,
+}
+
+#[derive(Debug)]
+struct IndexedDocument {
+    document_tree: Arc<tombi_document_tree::DocumentTree>,
+    symbols: Arc<[WorkspaceSymbolEntry]>,
+}
+
+impl WorkspaceSymbolIndex {
+    pub fn get_or_index(
+        &mut self,
+        text_document_uri: &tombi_uri::Uri,
+        document_source: &DocumentSource,
+    ) -> Arc<[WorkspaceSymbolEntry]> {
+        let document_tree = document_source.document_tree();
+
+        if let Some(indexed) = self.documents.get(text_document_uri)
+            && Arc::ptr_eq(&indexed.document_tree, &document_tree)
+        {
+            return indexed.symbols.clone();
+        }
+
+        let symbols: Arc<[WorkspaceSymbolEntry]> =
+            create_workspace_symbols(&document_tree, document_source.line_index()).into();
+        self.documents.insert(
+            text_document_uri.clone(),
+            IndexedDocument {
+                document_tree,
+                symbols: symbols.clone(),
+            },
+        );
+
+        symbols
+    }
+
+    pub fn remove(&mut self, text_document_uri: &tombi_uri::Uri) {
+        self.documents.remove(text_document_uri);
+    }
+}
+
+pub fn create_workspace_symbols(
+    document_tree: &tombi_document_tree::DocumentTree,
+    line_index: &tombi_text::LineIndex,
+) -> Vec<WorkspaceSymbolEntry> {
+    let mut symbols = Vec::new();
+    for (key, value) in document_tree.key_values() {
+        collect_symbols(
+            key.to_string(),
+            None,
+            value,
+            Some(key.range()),
+            line_index,
+            &mut symbols,
+        );
+    }
+
+    symbols
+}
+
+fn collect_symbols(
+    name: String,
+    container_name: Option<&str>,
+    value: &tombi_document_tree::Value,
+    key_range: Option<tombi_text::Range>,
+    line_index: &tombi_text::LineIndex,
+    symbols: &mut Vec<WorkspaceSymbolEntry>,
+) {
+    use tombi_document_tree::Value::*;
+
+    let kind = match value {
+        Boolean(_) => SymbolKind::BOOLEAN,
+        Integer(_) | Float(_) => SymbolKind::NUMBER,
+        String(_) | OffsetDateTime(_) | LocalDateTime(_) | LocalDate(_) | LocalTime(_) => {
+            SymbolKind::STRING
+        }
+        Array(_) => SymbolKind::ARRAY,
+        Table(_) => SymbolKind::OBJECT,
+        Incomplete { .. } => return,
+    };
+    let range = match key_range {
+        Some(key_range) => key_range + value.symbol_range(),
+        None => value.symbol_range(),
+    };
+
+    symbols.push(WorkspaceSymbolEntry {
+        name: name.clone(),
+        container_name: container_name.map(ToOwned::to_owned),
+        kind,
+        range: range.into_lsp(line_index),
+    });
+
+    match value {
+        Array(array) => {
+            // Only the tables of an array are symbols, e.g. the entries of `[[bin]]`.
+            for (index, value) in array.values().iter().enumerate() {
+                if matches!(value, Table(_)) {
+                    collect_symbols(
+                        format!("{name}[{index}]"),
+                        Some(&name),
+                        value,
+                        None,
+                        line_index,
+                        symbols,
+                    );
+                }
+            }
+        }
+        Table(table) => {
+            for (key, value) in table.key_values() {
+                collect_symbols(
+                    format!("{name}.{key}"),
+                    Some(&name),
+                    value,
+                    Some(key.range()),
+                    line_index,
+                    symbols,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Check if the symbol name contains the query, ignoring case.
+pub fn matches_workspace_symbol_query(name: &str, query: &str) -> bool {
+    query.is_empty() || name.to_lowercase().contains(&query.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_document_tree::IntoDocumentTreeAndErrors;
+
+    use super::*;
+
+    #[test]
+    fn workspace_symbol_names() {
+        let source = r#"
+[package]
+name = "tombi"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+
+[profile.release]
+lto = true
+
+[[bin]]
+name = "tombi"
+"#;
+        let (root, _) = tombi_parser::parse(source).into_root_and_errors();
+        let document_tree = root
+            .into_document_tree_and_errors(tombi_config::TomlVersion::default())
+            .tree;
+        let line_index = tombi_text::LineIndex::new(source, tombi_text::EncodingKind::default());
+
+        let symbols = create_workspace_symbols(&document_tree, &line_index)
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.container_name, symbol.kind))
+            .collect::<Vec<_>>();
+
+        pretty_assertions::assert_eq!(
+            symbols,
+            vec![
+                ("package".into(), None, SymbolKind::OBJECT),
+                (
+                    "package.name".into(),
+                    Some("package".into()),
+                    SymbolKind::STRING
+                ),
+                ("dependencies".into(), None, SymbolKind::OBJECT),
+                (
+                    "dependencies.serde".into(),
+                    Some("dependencies".into()),
+                    SymbolKind::OBJECT
+                ),
+                (
+                    "dependencies.serde.version".into(),
+                    Some("dependencies.serde".into()),
+                    SymbolKind::STRING
+                ),
+                (
+                    "dependencies.serde.features".into(),
+                    Some("dependencies.serde".into()),
+                    SymbolKind::ARRAY
+                ),
+                ("profile".into(), None, SymbolKind::OBJECT),
+                (
+                    "profile.release".into(),
+                    Some("profile".into()),
+                    SymbolKind::OBJECT
+                ),
+                (
+                    "profile.release.lto".into(),
+                    Some("profile.release".into()),
+                    SymbolKind::BOOLEAN
+                ),
+                ("bin".into(), None, SymbolKind::ARRAY),
+                ("bin[0]".into(), Some("bin".into()), SymbolKind::OBJECT),
+                (
+                    "bin[0].name".into(),
+                    Some("bin[0]".into()),
+                    SymbolKind::STRING
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn workspace_symbol_query() {
+        assert!(matches_workspace_symbol_query("profile.release", ""));
+        assert!(matches_workspace_symbol_query("profile.release", "Release"));
+        assert!(!matches_workspace_symbol_query("profile.release", "serde"));
+    }
+}
@@ -0,0 +1,125 @@
+mod cargo_feature_graph {
+    use tombi_lsp::{
+        Backend,
+        handler::{ExtensionRequestParams, handle_did_open, handle_extension_request},
+    };
+    use tower_lsp::{
+        LspService,
+        lsp_types::{DidOpenTextDocumentParams, TextDocumentIdentifier, TextDocumentItem, Url},
+    };
+
+    const CARGO_TOML: &str = r#"
+[package]
+name = "app"
+
+[dependencies]
+serde = { version = "1", optional = true }
+
+[features]
+default = ["std"]
+std = ["serde?/std"]
+"#;
+
+    async fn cargo_feature_graph(
+        params: serde_json::Value,
+    ) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let temp_dir = tempfile::tempdir()?;
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        std::fs::write(&cargo_toml_path, CARGO_TOML)?;
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = Url::from_file_path(&cargo_toml_path).map_err(|_| {
+            format!(
+                "Failed to convert path to URL: {}",
+                cargo_toml_path.display()
+            )
+        })?;
+
+        handle_did_open(
+            backend,
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "toml".to_string(),
+                    version: 0,
+                    text: CARGO_TOML.to_string(),
+                },
+            },
+        )
+        .await;
+
+        let serde_json::Value::Object(params) = params else {
+            return Err("params must be an object".into());
+        };
+
+        Ok(handle_extension_request(
+            backend,
+            "tombi/cargoFeatureGraph",
+            ExtensionRequestParams {
+                text_document: TextDocumentIdentifier { uri },
+                params,
+            },
+        )
+        .await?)
+    }
+
+    #[tokio::test]
+    async fn returns_feature_graph_as_json() -> Result<(), Box<dyn std::error::Error>> {
+        let Some(response) = cargo_feature_graph(serde_json::json!({})).await? else {
+            panic!("Cargo feature graph is not returned");
+        };
+
+        pretty_assertions::assert_eq!(
+            response,
+            serde_json::json!({
+                "package": "app",
+                "nodes": [
+                    { "id": "default", "kind": "feature" },
+                    { "id": "std", "kind": "feature" },
+                    { "id": "serde", "kind": "implicit-feature" },
+                    { "id": "serde/std", "kind": "dependency-feature" },
+                    { "id": "dep:serde", "kind": "optional-dependency" },
+                ],
+                "edges": [
+                    { "from": "serde", "to": "dep:serde", "weak": false },
+                    { "from": "default", "to": "std", "weak": false },
+                    { "from": "std", "to": "serde/std", "weak": true },
+                ],
+            })
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_feature_graph_as_dot() -> Result<(), Box<dyn std::error::Error>> {
+        let Some(response) = cargo_feature_graph(serde_json::json!({ "format": "dot" })).await?
+        else {
+            panic!("Cargo feature graph is not returned");
+        };
+
+        let Some(dot) = response["dot"].as_str() else {
+            panic!("Cargo feature graph is not rendered as DOT");
+        };
+
+        pretty_assertions::assert_eq!(
+            dot,
+            r#"digraph "app" {
+    "default" [shape=box];
+    "std" [shape=box];
+    "serde" [shape=box, style=dashed];
+    "serde/std" [shape=note];
+    "dep:serde" [shape=ellipse];
+    "serde" -> "dep:serde";
+    "default" -> "std";
+    "std" -> "serde/std" [style=dashed];
+}"#
+        );
+
+        Ok(())
+    }
+}
@@ -218,6 +218,7 @@ mod refactor_rewrite {
                 r#"
             foo = { bar = █1, baz = 2 }
             "#,
+                Select(CodeActionRefactorRewriteName::InlineTableToDottedKeys),
             ) -> Ok(None);
         }
 
@@ -227,8 +228,557 @@ mod refactor_rewrite {
                 r#"
             foo = { bar = █1, baz = 2 } # comment
             "#,
+                Select(CodeActionRefactorRewriteName::InlineTableToDottedKeys),
+            ) -> Ok(None);
+        }
+    }
+
+    mod sort {
+        use tombi_lsp::code_action::{CodeActionRefactorRewriteName, CodeActionSourceName};
+        use tombi_test_lib::project_root_path;
+
+        use crate::test_code_action_refactor_rewrite;
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_table_keys_ascending(
+                r#"
+                [foo]
+                c = 3
+                a█ = 1
+                b = 2
+                "#,
+                Select(CodeActionRefactorRewriteName::SortTableKeysAscending),
+            ) -> Ok(Some(
+                r#"
+                [foo]
+                a = 1
+                b = 2
+                c = 3
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_table_keys_ascending_keeps_groups(
+                r#"
+                b█ = 2
+                a = 1
+
+                d = 4
+                c = 3
+                "#,
+                Select(CodeActionRefactorRewriteName::SortTableKeysAscending),
+            ) -> Ok(Some(
+                r#"
+                a = 1
+                b = 2
+
+                c = 3
+                d = 4
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_table_keys_ascending_with_comment(
+                r#"
+                b█ = 2 # comment b
+                a = 1 # comment a
+                "#,
+                Select(CodeActionRefactorRewriteName::SortTableKeysAscending),
+            ) -> Ok(Some(
+                r#"
+                a = 1 # comment a
+                b = 2 # comment b
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_inline_table_keys_ascending(
+                r#"
+                foo = { c = 3, b█ = 2, a = 1 }
+                "#,
+                Select(CodeActionRefactorRewriteName::SortTableKeysAscending),
+            ) -> Ok(Some(
+                r#"
+                foo = { a = 1, b = 2, c = 3 }
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_table_keys_by_version(
+                r#"
+                [foo]
+                key10 = 10
+                key2█ = 2
+                key1 = 1
+                "#,
+                Select(CodeActionRefactorRewriteName::SortTableKeysByVersion),
+            ) -> Ok(Some(
+                r#"
+                [foo]
+                key1 = 1
+                key2 = 2
+                key10 = 10
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_table_keys_by_schema(
+                r#"
+                [package]
+                version█ = "0.1.0"
+                edition = "2021"
+                name = "foo"
+                "#,
+                Select(CodeActionRefactorRewriteName::SortTableKeysBySchema),
+                project_root_path().join("Cargo.toml"),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+                version = "0.1.0"
+                edition = "2021"
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_table_keys_by_schema_without_schema(
+                r#"
+                b█ = 2
+                a = 1
+                "#,
+                Select(CodeActionRefactorRewriteName::SortTableKeysBySchema),
+            ) -> Ok(None);
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_table_keys_ascending_already_sorted(
+                r#"
+                a█ = 1
+                b = 2
+                "#,
+            ) -> Ok(None);
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_array_values_ascending(
+                r#"
+                foo = [3, █1, 2]
+                "#,
+                Select(CodeActionRefactorRewriteName::SortArrayValuesAscending),
+            ) -> Ok(Some(
+                r#"
+                foo = [1, 2, 3]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_multiline_array_values_ascending(
+                r#"
+                foo = [
+                  "c",
+                  "a█", # comment a
+                  "b",
+                ]
+                "#,
+                Select(CodeActionRefactorRewriteName::SortArrayValuesAscending),
+            ) -> Ok(Some(
+                r#"
+                foo = [
+                  "a",
+                  "b", # comment a
+                  "c",
+                ]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_array_values_by_version(
+                r#"
+                foo = ["v1.10", █"v1.2", "v1.1"]
+                "#,
+                Select(CodeActionRefactorRewriteName::SortArrayValuesByVersion),
+            ) -> Ok(Some(
+                r#"
+                foo = ["v1.1", "v1.2", "v1.10"]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_array_values_mixed_types(
+                r#"
+                foo = [3, █"a", 1]
+                "#,
+            ) -> Ok(None);
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn sort_keys_of_all_tables(
+                r#"
+                z█ = 1
+                y = 2
+
+                [foo]
+                b = 2
+                a = 1
+
+                [[bar]]
+                d = 4
+                c = 3
+                "#,
+                Select(CodeActionSourceName::SortKeys),
+            ) -> Ok(Some(
+                r#"
+                y = 2
+                z = 1
+
+                [foo]
+                a = 1
+                b = 2
+
+                [[bar]]
+                c = 3
+                d = 4
+                "#
+            ));
+        }
+    }
+
+    mod table_structure {
+        use tombi_lsp::code_action::CodeActionRefactorRewriteName;
+
+        use crate::test_code_action_refactor_rewrite;
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn array_of_tables_to_inline_array(
+                r#"
+                [[bi█n]]
+                name = "foo"
+
+                [[bin]]
+                name = "bar"
+                path = "src/bar.rs"
+                "#,
+                Select(CodeActionRefactorRewriteName::ArrayOfTablesToInlineArray),
+            ) -> Ok(Some(
+                r#"
+                bin = [
+                  { name = "foo" },
+                  { name = "bar", path = "src/bar.rs" },
+                ]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn array_of_tables_to_inline_array_with_comment(
+                r#"
+                version = 1
+
+                # foo
+                [[bin]] # foo trailing
+                name = "foo"
+
+                [[bi█n]]
+                name = "bar"
+                "#,
+                Select(CodeActionRefactorRewriteName::ArrayOfTablesToInlineArray),
+            ) -> Ok(Some(
+                r#"
+                version = 1
+                bin = [
+                  # foo
+                  { name = "foo" }, # foo trailing
+                  { name = "bar" },
+                ]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn array_of_tables_to_inline_array_under_parent_table(
+                r#"
+                [package]
+                name = "foo"
+
+                [[package.bi█n]]
+                name = "bar"
+                "#,
+                Select(CodeActionRefactorRewriteName::ArrayOfTablesToInlineArray),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+                bin = [
+                  { name = "bar" },
+                ]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn array_of_tables_with_sub_table_to_inline_array(
+                r#"
+                [[bi█n]]
+                name = "foo"
+
+                [bin.metadata]
+                bar = 1
+                "#,
+            ) -> Ok(None);
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn inline_array_to_array_of_tables(
+                r#"
+                bi█n = [{ name = "foo" }, { name = "bar", path = "src/bar.rs" }]
+                "#,
+                Select(CodeActionRefactorRewriteName::InlineArrayToArrayOfTables),
+            ) -> Ok(Some(
+                r#"
+                [[bin]]
+                name = "foo"
+
+                [[bin]]
+                name = "bar"
+                path = "src/bar.rs"
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn inline_array_to_array_of_tables_with_comment(
+                r#"
+                [package]
+                name = "foo"
+                bi█n = [
+                  # foo
+                  { name = "foo" }, # foo trailing
+                  { name = "bar" },
+                ]
+
+                [dependencies]
+                "#,
+                Select(CodeActionRefactorRewriteName::InlineArrayToArrayOfTables),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+
+                # foo
+                [[package.bin]] # foo trailing
+                name = "foo"
+
+                [[package.bin]]
+                name = "bar"
+
+                [dependencies]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn inline_array_to_array_of_tables_with_comments_around_comma(
+                r#"
+                bi█n = [
+                  { name = "foo" } # foo
+                  , # comma
+                  { name = "bar" },
+                ]
+                "#,
+            ) -> Ok(None);
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn table_to_inline_table(
+                r#"
+                [package]
+                name = "foo"
+
+                [package.metadata█]
+                bar = 1
+                baz = "qux"
+                "#,
+                Select(CodeActionRefactorRewriteName::TableToInlineTable),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+                metadata = { bar = 1, baz = "qux" }
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn table_to_inline_table_without_parent_table(
+                r#"
+                [tool.fo█o]
+                bar = 1
+                "#,
+                Select(CodeActionRefactorRewriteName::TableToInlineTable),
+            ) -> Ok(Some(
+                r#"
+                tool.foo = { bar = 1 }
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn table_to_dotted_keys(
+                r#"
+                [package]
+                name = "foo"
+
+                # metadata
+                [package.metadata█]
+                bar = 1 # bar
+                baz = "qux"
+                "#,
+                Select(CodeActionRefactorRewriteName::TableToDottedKeys),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+                # metadata
+                metadata.bar = 1 # bar
+                metadata.baz = "qux"
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn table_with_sub_table_to_dotted_keys(
+                r#"
+                [fo█o]
+                bar = 1
+
+                [foo.baz]
+                qux = 2
+                "#,
             ) -> Ok(None);
         }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn inline_table_to_table(
+                r#"
+                packa█ge = { name = "foo", version = "0.1.0" }
+                "#,
+                Select(CodeActionRefactorRewriteName::InlineTableToTable),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+                version = "0.1.0"
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn extract_to_table_section(
+                r#"
+                package = { name = "foo", metadata = { ba█r = 1 } }
+                "#,
+                Select(CodeActionRefactorRewriteName::ExtractToTableSection),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+
+                [package.metadata]
+                bar = 1
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn extract_to_table_section_in_table(
+                r#"
+                [package]
+                name = "foo"
+                metadata = { bar = { ba█z = 1 } }
+
+                [dependencies]
+                "#,
+                Select(CodeActionRefactorRewriteName::ExtractToTableSection),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo"
+
+                [package.metadata.bar]
+                baz = 1
+
+                [dependencies]
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn extract_to_table_section_with_comments_around_comma(
+                r#"
+                #:tombi toml-version = "v1.1.0"
+                package = {
+                  name = "foo" # name
+                  , # comma
+                  metadata = { ba█r = 1 },
+                }
+                "#,
+                Select(CodeActionRefactorRewriteName::ExtractToTableSection),
+            ) -> Ok(None);
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn dotted_keys_to_table(
+                r#"
+                package.na█me = "foo" # name
+                package.version = "0.1.0"
+                "#,
+                Select(CodeActionRefactorRewriteName::DottedKeysToTable),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "foo" # name
+                version = "0.1.0"
+                "#
+            ));
+        }
     }
 
     mod cargo_toml {
@@ -0,0 +1,87 @@
+mod code_lens {
+    use tombi_lsp::{
+        Backend,
+        handler::{handle_code_lens, handle_did_open},
+    };
+    use tower_lsp::{
+        LspService,
+        lsp_types::{
+            CodeLensParams, DidOpenTextDocumentParams, PartialResultParams, TextDocumentIdentifier,
+            TextDocumentItem, Url, WorkDoneProgressParams,
+        },
+    };
+
+    async fn collect_code_lenses(
+        file_name: &str,
+        source: &str,
+    ) -> Result<Vec<tombi_extension::CodeLens>, Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let temp_dir = tempfile::tempdir()?;
+        let source_path = temp_dir.path().join(file_name);
+        std::fs::write(&source_path, source)?;
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = Url::from_file_path(&source_path)
+            .map_err(|_| format!("Failed to convert path to URL: {}", source_path.display()))?;
+
+        handle_did_open(
+            backend,
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "toml".to_string(),
+                    version: 0,
+                    text: source.to_string(),
+                },
+            },
+        )
+        .await;
+
+        Ok(handle_code_lens(
+            backend,
+            CodeLensParams {
+                text_document: TextDocumentIdentifier { uri },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn schema_code_lens_opens_the_associated_schema() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let code_lenses = collect_code_lenses(
+            "test.toml",
+            "#:schema tombi://www.schemastore.org/tombi.json\n\n[format]\n",
+        )
+        .await?;
+
+        pretty_assertions::assert_eq!(
+            code_lenses,
+            vec![tombi_extension::CodeLens {
+                range: tombi_text::Range::at(tombi_text::Position::MIN),
+                title: "schema: Tombi".to_string(),
+                command: Some("tombi.openSchema".to_string()),
+                arguments: Some(vec![serde_json::Value::String(
+                    "tombi://www.schemastore.org/tombi.json".to_string()
+                )]),
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_code_lens_without_schema() -> Result<(), Box<dyn std::error::Error>> {
+        let code_lenses = collect_code_lenses("test.toml", "key = \"value\"\n").await?;
+
+        assert!(code_lenses.is_empty());
+
+        Ok(())
+    }
+}
@@ -213,6 +213,7 @@ mod completion_labels {
                 "#,
                 SchemaPath(tombi_schema_path()),
             ) -> Ok([
+                "external",
                 "tombi-toml/cargo",
                 "tombi-toml/pyproject",
                 "tombi-toml/tombi",
@@ -509,6 +510,7 @@ mod completion_labels {
                 SchemaPath(tombi_schema_path()),
             ) -> Ok([
                 "code-action",
+                "code-lens",
                 "diagnostic",
                 "document-link",
                 "formatting",
@@ -533,6 +535,7 @@ mod completion_labels {
                 SchemaPath(tombi_schema_path()),
             ) -> Ok([
                 "code-action",
+                "code-lens",
                 "diagnostic",
                 "document-link",
                 "goto-declaration",
@@ -556,6 +559,7 @@ mod completion_labels {
                 "#,
                 SchemaPath(tombi_schema_path()),
             ) -> Ok([
+                "code-lens",
                 "completion",
                 "diagnostic",
                 "document-link",
@@ -1649,6 +1653,47 @@ mod completion_labels {
             ]);
         }
 
+        test_completion_labels! {
+            #[tokio::test]
+            async fn cargo_target_cfg_key(
+                r#"
+                [target.'cfg(target_█)'.dependencies]
+                "#,
+                SourcePath(project_root_path().join("Cargo.toml")),
+                SchemaPath(cargo_schema_path()),
+            ) -> Ok([
+                "target_os",
+                "target_arch",
+                "target_family",
+                "target_env",
+                "target_vendor",
+                "target_pointer_width",
+                "target_endian",
+                "target_abi",
+                "build-dependencies",
+                "dependencies",
+                "dev-dependencies",
+            ]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn cargo_target_cfg_value(
+                r#"
+                [target.'cfg(target_family = "█")'.dependencies]
+                "#,
+                SourcePath(project_root_path().join("Cargo.toml")),
+                SchemaPath(cargo_schema_path()),
+            ) -> Ok([
+                "unix",
+                "wasm",
+                "windows",
+                "build-dependencies",
+                "dependencies",
+                "dev-dependencies",
+            ]);
+        }
+
         test_completion_labels! {
             #[tokio::test]
             async fn cargo_dependencies_and_next_section(
@@ -2404,6 +2449,56 @@ mod completion_labels {
         }
     }
 
+    mod cargo_config {
+        use super::*;
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn cargo_config_net_keys(
+                r#"
+                [net]
+                █
+                "#,
+                SourcePath(project_root_path().join(".cargo/config.toml")),
+            ) -> Ok([
+                "retry",
+                "git-fetch-with-cli",
+                "offline",
+                "ssh",
+                "$key",
+            ]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn cargo_config_target_triple(
+                r#"
+                [target.wasm32-unknown-█]
+                "#,
+                SourcePath(project_root_path().join(".cargo/config.toml")),
+            ) -> Ok([
+                "wasm32-unknown-emscripten",
+                "wasm32-unknown-unknown",
+                "$key",
+            ]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn rust_toolchain_channel(
+                r#"
+                [toolchain]
+                channel = "n█"
+                "#,
+                SourcePath(project_root_path().join("rust-toolchain.toml")),
+            ) -> Ok([
+                "\"stable\"",
+                "\"beta\"",
+                "\"nightly\"",
+            ]);
+        }
+    }
+
     mod untagged_union {
         use tombi_test_lib::untagged_union_schema_path;
 
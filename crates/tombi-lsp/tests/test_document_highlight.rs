@@ -0,0 +1,193 @@
+mod document_highlight_tests {
+    use super::*;
+    use tower_lsp::lsp_types::DocumentHighlightKind;
+
+    test_document_highlight!(
+        #[tokio::test]
+        async fn key_value(
+            r#"
+            key█ = 1
+            "#,
+        ) -> [
+            ((0, 0), (0, 3), DocumentHighlightKind::WRITE),
+        ];
+    );
+
+    test_document_highlight!(
+        #[tokio::test]
+        async fn dotted_key_and_header_of_same_table(
+            r#"
+            [fruit]
+            apple█.color = "red"
+
+            [fruit.apple.texture]
+            smooth = true
+            "#,
+        ) -> [
+            ((1, 0), (1, 5), DocumentHighlightKind::TEXT),
+            ((3, 7), (3, 12), DocumentHighlightKind::TEXT),
+        ];
+    );
+
+    test_document_highlight!(
+        #[tokio::test]
+        async fn header_and_dotted_key_of_same_table(
+            r#"
+            fruit.apple.color = "red"
+
+            [fruit█.apple.texture]
+            smooth = true
+            "#,
+        ) -> [
+            ((0, 0), (0, 5), DocumentHighlightKind::TEXT),
+            ((2, 1), (2, 6), DocumentHighlightKind::TEXT),
+        ];
+    );
+
+    test_document_highlight!(
+        #[tokio::test]
+        async fn quoted_and_bare_keys(
+            r#"
+            [a.b]
+            c = 1
+
+            ["a"█.d]
+            e = 2
+            "#,
+        ) -> [
+            ((0, 1), (0, 2), DocumentHighlightKind::TEXT),
+            ((3, 1), (3, 4), DocumentHighlightKind::TEXT),
+        ];
+    );
+
+    test_document_highlight!(
+        #[tokio::test]
+        async fn array_of_tables_headers(
+            r#"
+            [[aot]]
+            name = "a"
+
+            [[aot█]]
+            name = "b"
+
+            [other]
+            aot = 1
+            "#,
+        ) -> [
+            ((0, 2), (0, 5), DocumentHighlightKind::WRITE),
+            ((3, 2), (3, 5), DocumentHighlightKind::WRITE),
+        ];
+    );
+
+    test_document_highlight!(
+        #[tokio::test]
+        async fn keys_of_array_of_tables(
+            r#"
+            [[aot]]
+            name█ = "a"
+
+            [[aot]]
+            name = "b"
+            "#,
+        ) -> [
+            ((1, 0), (1, 4), DocumentHighlightKind::WRITE),
+            ((4, 0), (4, 4), DocumentHighlightKind::WRITE),
+        ];
+    );
+
+    test_document_highlight!(
+        #[tokio::test]
+        async fn outside_of_keys(
+            r#"
+            key = 1█
+            "#,
+        ) -> [];
+    );
+
+    #[macro_export]
+    macro_rules! test_document_highlight {
+        (#[tokio::test] async fn $name:ident($source:expr $(,)?) -> [$($expected:expr),* $(,)?];) => {
+            #[tokio::test]
+            async fn $name() -> Result<(), Box<dyn std::error::Error>> {
+                use itertools::Itertools;
+                use tombi_lsp::handler::{handle_did_open, handle_document_highlight};
+                use tombi_lsp::Backend;
+                use tombi_text::IntoLsp;
+                use tower_lsp::{
+                    lsp_types::{
+                        DidOpenTextDocumentParams, DocumentHighlightParams, PartialResultParams,
+                        TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams, Url,
+                        WorkDoneProgressParams,
+                    },
+                    LspService,
+                };
+
+                tombi_test_lib::init_log();
+
+                let (service, _) = LspService::new(|client| {
+                    Backend::new(client, &tombi_lsp::backend::Options::default())
+                });
+                let backend = service.inner();
+
+                let toml_file_url =
+                    Url::from_file_path(tombi_test_lib::project_root_path().join("test.toml"))
+                        .expect("failed to convert file path to URL");
+
+                let mut toml_text = textwrap::dedent($source).trim().to_string();
+                let Some(index) = toml_text.as_str().find("█") else {
+                    return Err("failed to find position marker (█) in the test data".into());
+                };
+                toml_text.remove(index);
+                let line_index =
+                    tombi_text::LineIndex::new(&toml_text, tombi_text::EncodingKind::Utf16);
+
+                handle_did_open(
+                    backend,
+                    DidOpenTextDocumentParams {
+                        text_document: TextDocumentItem {
+                            uri: toml_file_url.clone(),
+                            language_id: "toml".to_string(),
+                            version: 0,
+                            text: toml_text.clone(),
+                        },
+                    },
+                )
+                .await;
+
+                let params = DocumentHighlightParams {
+                    text_document_position_params: TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier { uri: toml_file_url },
+                        position: (tombi_text::Position::default()
+                            + tombi_text::RelativePosition::of(&toml_text[..index]))
+                        .into_lsp(&line_index),
+                    },
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                };
+
+                let result = handle_document_highlight(backend, params)
+                    .await
+                    .expect("document highlight request failed");
+
+                let expected: Vec<((u32, u32), (u32, u32), DocumentHighlightKind)> =
+                    vec![$($expected),*];
+                let actual: Vec<((u32, u32), (u32, u32), DocumentHighlightKind)> = result
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|highlight| {
+                        (
+                            (highlight.range.start.line, highlight.range.start.character),
+                            (highlight.range.end.line, highlight.range.end.character),
+                            highlight.kind.unwrap_or(DocumentHighlightKind::TEXT),
+                        )
+                    })
+                    .sorted_by_key(|(start, end, _)| (*start, *end))
+                    .collect();
+
+                pretty_assertions::assert_eq!(actual, expected);
+
+                Ok(())
+            }
+        };
+    }
+}
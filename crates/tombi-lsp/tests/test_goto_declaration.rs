@@ -28,6 +28,17 @@ mod goto_declaration_tests {
             ) -> Ok([project_root_path().join("Cargo.toml")]);
         );
 
+        test_goto_declaration!(
+            #[tokio::test]
+            async fn lints_workspace(
+                r#"
+                [lints]
+                workspace█ = true
+                "#,
+                SourcePath(project_root_path().join("crates/test-crate/Cargo.toml")),
+            ) -> Ok([project_root_path().join("Cargo.toml")]);
+        );
+
         test_goto_declaration!(
             #[tokio::test]
             async fn feature_key_collects_same_file_and_workspace_usages(
@@ -348,6 +348,7 @@ mod goto_definition_tests {
                 SourcePath(project_root_path().join("Cargo.toml")),
             ) -> Ok([
                 project_root_path().join("extensions/tombi-extension-cargo/Cargo.toml"),
+                project_root_path().join("extensions/tombi-extension-external/Cargo.toml"),
                 project_root_path().join("extensions/tombi-extension-pyproject/Cargo.toml"),
                 project_root_path().join("extensions/tombi-extension-tombi/Cargo.toml"),
             ]);
@@ -805,6 +806,37 @@ mod goto_definition_tests {
                 ),
             ) -> Ok([]);
         );
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn cargo_lock_dependency_jumps_to_package_entry(
+                r#"
+                [[package]]
+                name = "consumer"
+                version = "0.1.0"
+                dependencies = ["provider█"]
+
+                [[package]]
+                name = "provider"
+                version = "0.1.0"
+                "#,
+                SourcePath(cargo_feature_navigation_fixture_path().join("workspace/Cargo.lock")),
+            ) -> Ok([cargo_feature_navigation_fixture_path().join("workspace/Cargo.lock")]);
+        );
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn cargo_lock_local_package_jumps_to_member_manifest(
+                r#"
+                [[package]]
+                name = "provider█"
+                version = "0.1.0"
+                "#,
+                SourcePath(cargo_feature_navigation_fixture_path().join("workspace/Cargo.lock")),
+            ) -> Ok([
+                cargo_feature_navigation_fixture_path().join("workspace/provider/Cargo.toml")
+            ]);
+        );
     }
 
     mod pyproject_schema {
@@ -944,6 +976,54 @@ mod goto_definition_tests {
                 SourcePath(project_root_path().join("pyproject.toml")),
             ) -> Ok([project_root_path().join("python")]);
         );
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn uv_lock_dependency_jumps_to_package_entry(
+                r#"
+                [[package]]
+                name = "workspace"
+                version = "0.1.0"
+                source = { virtual = "." }
+                dependencies = [{ name = "anyio█" }]
+
+                [[package]]
+                name = "anyio"
+                version = "4.6.2"
+                source = { registry = "https://pypi.org/simple" }
+                "#,
+                SourcePath(project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/uv.lock")),
+            ) -> Ok([project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/uv.lock")]);
+        );
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn uv_lock_editable_package_jumps_to_member_pyproject(
+                r#"
+                [[package]]
+                name = "app1█"
+                version = "0.1.0"
+                source = { editable = "members/app1" }
+                "#,
+                SourcePath(project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/uv.lock")),
+            ) -> Ok([project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/members/app1/pyproject.toml")]);
+        );
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn uv_lock_registry_package_jumps_to_requirements(
+                r#"
+                [[package]]
+                name = "anyio█"
+                version = "4.6.2"
+                source = { registry = "https://pypi.org/simple" }
+                "#,
+                SourcePath(project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/uv.lock")),
+            ) -> Ok([
+                project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/pyproject.toml"),
+                project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/members/app3/pyproject.toml"),
+            ]);
+        );
     }
 
     mod pyproject_workspace_dependencies {
@@ -98,6 +98,22 @@ mod goto_type_definition_tests {
         );
     }
 
+    mod toml_schema {
+        use super::*;
+        use tombi_test_lib::toml_schema_test_schema_path;
+
+        test_goto_type_definition!(
+            #[tokio::test]
+            async fn toml_schema_property_key(
+                r#"
+                name = "app"
+                port█ = 8080
+                "#,
+                SchemaPath(toml_schema_test_schema_path()),
+            ) -> Ok(toml_schema_test_schema_path());
+        );
+    }
+
     mod pyproject_schema {
         use super::*;
 
@@ -640,15 +640,17 @@ mod hover_keys_value {
             async fn cargo_workspace_dependency_hover_metadata(
                 r#"
                 [dependencies]
-                tombi-extension-cargo█ = { workspace = true }
+                base█ = { workspace = true }
                 "#,
-                SourcePath(tombi_test_lib::project_root_path().join("crates/tombi-lsp/Cargo.toml")),
+                SourcePath(tombi_test_lib::project_root_path().join(
+                    "crates/tombi-lsp/tests/fixtures/cargo-lock-dependents/app/Cargo.toml"
+                )),
                 SchemaPath(cargo_schema_path()),
             ) -> Ok({
-                "Keys": "dependencies.tombi-extension-cargo",
+                "Keys": "dependencies.base",
                 "Value": "(String | Table)?",
-                "Title": Some("tombi-extension-cargo"),
-                "Description": Some("Tombi extension for Cargo.toml"),
+                "Title": Some("base"),
+                "Description": Some("Shared base of the workspace\n\n`base 0.1.0` is pulled in by:\n- `app 0.1.0`"),
             });
         );
 
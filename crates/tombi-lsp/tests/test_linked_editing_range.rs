@@ -0,0 +1,179 @@
+mod linked_editing_range_tests {
+    use super::*;
+
+    test_linked_editing_range!(
+        #[tokio::test]
+        async fn array_of_tables_headers(
+            r#"
+            [[aot█]]
+            name = "a"
+
+            [[aot]]
+            name = "b"
+
+            [[aot]]
+            name = "c"
+            "#,
+        ) -> [
+            ((0, 2), (0, 5)),
+            ((3, 2), (3, 5)),
+            ((6, 2), (6, 5)),
+        ];
+    );
+
+    test_linked_editing_range!(
+        #[tokio::test]
+        async fn parent_key_of_sub_table_headers(
+            r#"
+            [fruit.apple]
+            color = "red"
+
+            [fruit█.orange]
+            color = "orange"
+            "#,
+        ) -> [
+            ((0, 1), (0, 6)),
+            ((3, 1), (3, 6)),
+        ];
+    );
+
+    test_linked_editing_range!(
+        #[tokio::test]
+        async fn dotted_key_and_header_of_same_table_are_not_linked(
+            r#"
+            [fruit]
+            apple.color = "red"
+
+            [fruit.apple█.texture]
+            smooth = true
+            "#,
+        ) -> [];
+    );
+
+    test_linked_editing_range!(
+        #[tokio::test]
+        async fn quoted_and_bare_keys_are_not_linked(
+            r#"
+            [a█.b]
+            c = 1
+
+            ["a".d]
+            e = 2
+            "#,
+        ) -> [];
+    );
+
+    test_linked_editing_range!(
+        #[tokio::test]
+        async fn same_quoted_keys_are_linked(
+            r#"
+            ["a"█.b]
+            c = 1
+
+            ["a".d]
+            e = 2
+            "#,
+        ) -> [
+            ((0, 1), (0, 4)),
+            ((3, 1), (3, 4)),
+        ];
+    );
+
+    test_linked_editing_range!(
+        #[tokio::test]
+        async fn key_of_key_value(
+            r#"
+            [[aot]]
+            name█ = "a"
+
+            [[aot]]
+            name = "b"
+            "#,
+        ) -> [];
+    );
+
+    #[macro_export]
+    macro_rules! test_linked_editing_range {
+        (#[tokio::test] async fn $name:ident($source:expr $(,)?) -> [$($expected:expr),* $(,)?];) => {
+            #[tokio::test]
+            async fn $name() -> Result<(), Box<dyn std::error::Error>> {
+                use itertools::Itertools;
+                use tombi_lsp::handler::{handle_did_open, handle_linked_editing_range};
+                use tombi_lsp::Backend;
+                use tombi_text::IntoLsp;
+                use tower_lsp::{
+                    lsp_types::{
+                        DidOpenTextDocumentParams, LinkedEditingRangeParams,
+                        TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams, Url,
+                        WorkDoneProgressParams,
+                    },
+                    LspService,
+                };
+
+                tombi_test_lib::init_log();
+
+                let (service, _) = LspService::new(|client| {
+                    Backend::new(client, &tombi_lsp::backend::Options::default())
+                });
+                let backend = service.inner();
+
+                let toml_file_url =
+                    Url::from_file_path(tombi_test_lib::project_root_path().join("test.toml"))
+                        .expect("failed to convert file path to URL");
+
+                let mut toml_text = textwrap::dedent($source).trim().to_string();
+                let Some(index) = toml_text.as_str().find("█") else {
+                    return Err("failed to find position marker (█) in the test data".into());
+                };
+                toml_text.remove(index);
+                let line_index =
+                    tombi_text::LineIndex::new(&toml_text, tombi_text::EncodingKind::Utf16);
+
+                handle_did_open(
+                    backend,
+                    DidOpenTextDocumentParams {
+                        text_document: TextDocumentItem {
+                            uri: toml_file_url.clone(),
+                            language_id: "toml".to_string(),
+                            version: 0,
+                            text: toml_text.clone(),
+                        },
+                    },
+                )
+                .await;
+
+                let params = LinkedEditingRangeParams {
+                    text_document_position_params: TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier { uri: toml_file_url },
+                        position: (tombi_text::Position::default()
+                            + tombi_text::RelativePosition::of(&toml_text[..index]))
+                        .into_lsp(&line_index),
+                    },
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                };
+
+                let result = handle_linked_editing_range(backend, params)
+                    .await
+                    .expect("linked editing range request failed");
+
+                let expected: Vec<((u32, u32), (u32, u32))> = vec![$($expected),*];
+                let actual: Vec<((u32, u32), (u32, u32))> = result
+                    .map(|linked_editing_ranges| linked_editing_ranges.ranges)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|range| {
+                        (
+                            (range.start.line, range.start.character),
+                            (range.end.line, range.end.character),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+
+                pretty_assertions::assert_eq!(actual, expected);
+
+                Ok(())
+            }
+        };
+    }
+}
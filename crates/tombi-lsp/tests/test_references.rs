@@ -410,6 +410,7 @@ mod references_tests {
                 SourcePath(project_root_path().join("Cargo.toml")),
             ) -> Ok([
                 project_root_path().join("crates/tombi-formatter/Cargo.toml"),
+                project_root_path().join("crates/tombi-lsp/Cargo.toml"),
             ]);
         );
 
@@ -454,6 +455,28 @@ mod references_tests {
                 cargo_feature_navigation_fixture_path().join("workspace/weak-consumer/Cargo.toml"),
             ]);
         );
+
+        test_references!(
+            #[tokio::test]
+            async fn dependency_key_lists_workspace_and_other_member_usages(
+                r#"
+                [package]
+                name = "consumer"
+                version = "0.1.0"
+                edition = "2024"
+
+                [dependencies]
+                provider█ = { workspace = true, features = ["jsonschema"] }
+                "#,
+                SourcePath(
+                    cargo_feature_navigation_fixture_path().join("workspace/consumer/Cargo.toml")
+                ),
+            ) -> Ok([
+                cargo_feature_navigation_fixture_path().join("workspace/Cargo.toml"),
+                cargo_feature_navigation_fixture_path().join("workspace/renamed-consumer/Cargo.toml"),
+                cargo_feature_navigation_fixture_path().join("workspace/weak-consumer/Cargo.toml"),
+            ]);
+        );
     }
 
     mod pyproject_schema {
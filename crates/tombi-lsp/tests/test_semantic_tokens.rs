@@ -0,0 +1,206 @@
+mod semantic_tokens {
+    use tombi_lsp::{
+        Backend,
+        handler::{
+            handle_did_open, handle_semantic_tokens_full, handle_semantic_tokens_full_delta,
+            handle_semantic_tokens_range,
+        },
+    };
+    use tower_lsp::{
+        LspService,
+        lsp_types::{
+            DidOpenTextDocumentParams, PartialResultParams, Position, Range, SemanticToken,
+            SemanticTokensDeltaParams, SemanticTokensFullDeltaResult, SemanticTokensParams,
+            SemanticTokensRangeParams, SemanticTokensRangeResult, SemanticTokensResult,
+            TextDocumentIdentifier, TextDocumentItem, Url, WorkDoneProgressParams,
+        },
+    };
+
+    const DEPRECATED: u32 = 1;
+    const READONLY: u32 = 1 << 1;
+
+    async fn open_document(
+        temp_dir: &tempfile::TempDir,
+        source: &str,
+    ) -> Result<(LspService<Backend>, Url), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        std::fs::write(
+            temp_dir.path().join("schema.json"),
+            r#"{
+  "type": "object",
+  "properties": {
+    "old": { "type": "string", "deprecated": true },
+    "kind": { "type": "string", "const": "fixed" },
+    "name": { "type": "string" }
+  }
+}"#,
+        )?;
+        let source_path = temp_dir.path().join("test.toml");
+        std::fs::write(&source_path, source)?;
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+
+        let uri = Url::from_file_path(&source_path)
+            .map_err(|_| format!("Failed to convert path to URL: {}", source_path.display()))?;
+
+        handle_did_open(
+            service.inner(),
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "toml".to_string(),
+                    version: 0,
+                    text: source.to_string(),
+                },
+            },
+        )
+        .await;
+
+        Ok((service, uri))
+    }
+
+    async fn full_tokens(
+        backend: &Backend,
+        uri: &Url,
+    ) -> Result<(Option<String>, Vec<SemanticToken>), Box<dyn std::error::Error>> {
+        let Some(SemanticTokensResult::Tokens(tokens)) = handle_semantic_tokens_full(
+            backend,
+            SemanticTokensParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        else {
+            return Err("semantic tokens are not found".into());
+        };
+
+        Ok((tokens.result_id, tokens.data))
+    }
+
+    #[tokio::test]
+    async fn schema_modifiers() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::tempdir()?;
+        let (service, uri) = open_document(
+            &temp_dir,
+            "#:schema ./schema.json\n\nold = \"a\"\nkind = \"fixed\"\nname = \"b\"\n",
+        )
+        .await?;
+
+        let (_, tokens) = full_tokens(service.inner(), &uri).await?;
+        let modifiers = tokens
+            .iter()
+            .skip(3)
+            .map(|token| token.token_modifiers_bitset)
+            .collect::<Vec<_>>();
+
+        pretty_assertions::assert_eq!(
+            modifiers,
+            vec![DEPRECATED, 0, DEPRECATED, 0, 0, READONLY, 0, 0, 0]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn range_tokens() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::tempdir()?;
+        let (service, uri) = open_document(&temp_dir, "a = 1\nb = 2\nc = 3\n").await?;
+
+        let Some(SemanticTokensRangeResult::Tokens(tokens)) = handle_semantic_tokens_range(
+            service.inner(),
+            SemanticTokensRangeParams {
+                text_document: TextDocumentIdentifier { uri },
+                range: Range::new(Position::new(1, 0), Position::new(1, 5)),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        else {
+            return Err("semantic tokens are not found".into());
+        };
+
+        pretty_assertions::assert_eq!(
+            tokens
+                .data
+                .iter()
+                .map(|token| (token.delta_line, token.delta_start, token.length))
+                .collect::<Vec<_>>(),
+            vec![(1, 0, 1), (0, 2, 1), (0, 2, 1)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn range_schema_modifiers() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::tempdir()?;
+        let (service, uri) = open_document(
+            &temp_dir,
+            "#:schema ./schema.json\n\nold = \"a\"\nkind = \"fixed\"\nname = \"b\"\n",
+        )
+        .await?;
+
+        let Some(SemanticTokensRangeResult::Tokens(tokens)) = handle_semantic_tokens_range(
+            service.inner(),
+            SemanticTokensRangeParams {
+                text_document: TextDocumentIdentifier { uri },
+                range: Range::new(Position::new(3, 0), Position::new(3, 14)),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        else {
+            return Err("semantic tokens are not found".into());
+        };
+
+        pretty_assertions::assert_eq!(
+            tokens
+                .data
+                .iter()
+                .map(|token| token.token_modifiers_bitset)
+                .collect::<Vec<_>>(),
+            vec![0, 0, READONLY]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delta_tokens() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::tempdir()?;
+        let (service, uri) = open_document(&temp_dir, "a = 1\n").await?;
+        let backend = service.inner();
+
+        let (Some(result_id), _) = full_tokens(backend, &uri).await? else {
+            return Err("result id is not found".into());
+        };
+
+        let delta_params = |previous_result_id: String| SemanticTokensDeltaParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            previous_result_id,
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        let Some(SemanticTokensFullDeltaResult::TokensDelta(delta)) =
+            handle_semantic_tokens_full_delta(backend, delta_params(result_id.clone())).await?
+        else {
+            return Err("semantic tokens delta is not found".into());
+        };
+        assert!(delta.edits.is_empty());
+        assert_ne!(delta.result_id, Some(result_id.clone()));
+
+        // The previous result id is outdated, so all the tokens are sent.
+        assert!(matches!(
+            handle_semantic_tokens_full_delta(backend, delta_params(result_id)).await?,
+            Some(SemanticTokensFullDeltaResult::Tokens(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -103,6 +103,9 @@ pub enum Error {
     #[error("schema must be an object or boolean: {schema_uri}")]
     SchemaMustBeObjectOrBoolean { schema_uri: SchemaUri },
 
+    #[error("failed to write schema bundle: {path}, reason: {reason}")]
+    SchemaBundleWriteFailed { path: PathBuf, reason: String },
+
     #[error(transparent)]
     CacheError(#[from] tombi_cache::Error),
 }
@@ -137,6 +140,7 @@ impl Error {
             Self::UnsupportedReference { .. } => "unsupported-reference",
             Self::UnsupportedUriScheme { .. } => "unsupported-url-scheme",
             Self::SchemaMustBeObjectOrBoolean { .. } => "schema-must-be-object-or-boolean",
+            Self::SchemaBundleWriteFailed { .. } => "schema-bundle-write-failed",
             Self::CacheError(error) => error.code(),
         }
     }
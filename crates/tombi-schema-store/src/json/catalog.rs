@@ -10,3 +10,29 @@ pub const DEFAULT_CATALOG_URL: &str = concat!(
 pub struct JsonCatalog {
     pub schemas: Vec<JsonCatalogSchema>,
 }
+
+impl JsonCatalog {
+    /// Parse a catalog whose schema urls may be relative to the catalog itself.
+    ///
+    /// Local catalogs, such as the ones written by `tombi schema pull`,
+    /// refer to their schemas with paths relative to the catalog file.
+    pub fn from_str_with_base_uri(
+        content: &str,
+        catalog_uri: &tombi_uri::CatalogUri,
+    ) -> Result<Self, serde_json::Error> {
+        let mut value: serde_json::Value = serde_json::from_str(content)?;
+        if let Some(schemas) = value
+            .get_mut("schemas")
+            .and_then(serde_json::Value::as_array_mut)
+        {
+            for schema in schemas {
+                if let Some(url) = schema.get_mut("url")
+                    && let Some(joined) = url.as_str().and_then(|url| catalog_uri.join(url).ok())
+                {
+                    *url = serde_json::Value::String(joined.to_string());
+                }
+            }
+        }
+        serde_json::from_value(value)
+    }
+}
@@ -6,7 +6,10 @@ mod keyword_support;
 pub mod macros;
 mod options;
 mod schema;
+mod schema_bundle;
+mod schema_source;
 mod store;
+mod sub_schema;
 mod value_type;
 mod x_taplo;
 
@@ -17,6 +20,8 @@ pub use json_schema_dialect::JsonSchemaDialect;
 pub use keyword_support::*;
 pub use options::Options;
 pub use schema::*;
+pub use schema_bundle::{SCHEMA_BUNDLE_CATALOG_FILE_NAME, SchemaBundle};
+pub use schema_source::{is_toml_schema_uri, parse_schema_value};
 pub use store::{AssociateSchemaOptions, SchemaStore};
 pub use tombi_accessor::{Accessor, AccessorContext, AccessorKeyKind, Accessors, KeyContext};
 pub use value_type::ValueType;
@@ -413,6 +413,29 @@ pub struct PropertySchema {
     pub property_schema: Referable<ValueSchema>,
 }
 
+/// Where a schema association comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaSource {
+    /// `[[schemas]]` in the config file.
+    Config,
+
+    /// A catalog of `schema.catalog.paths`.
+    Catalog,
+
+    /// An editor extension, through the `tombi/associateSchema` request.
+    Extension,
+}
+
+impl std::fmt::Display for SchemaSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Config => write!(f, "config"),
+            Self::Catalog => write!(f, "catalog"),
+            Self::Extension => write!(f, "extension"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Schema {
     pub title: Option<String>,
@@ -424,6 +447,7 @@ pub struct Schema {
     pub toml_version: Option<tombi_config::TomlVersion>,
     pub schema_uri: tombi_uri::SchemaUri,
     pub catalog_uri: Option<Arc<tombi_uri::CatalogUri>>,
+    pub source: SchemaSource,
     pub include: Vec<String>,
     pub exclude: Option<Vec<String>>,
     pub sub_root_accessors: Option<Vec<PatternAccessor>>,
@@ -0,0 +1,483 @@
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+
+use crate::{CatalogUri, SchemaStore, SchemaUri};
+
+/// The file name of the catalog written in a schema bundle.
+pub const SCHEMA_BUNDLE_CATALOG_FILE_NAME: &str = "catalog.json";
+
+const SCHEMA_CATALOG_SCHEMA_URL: &str = "https://json.schemastore.org/schema-catalog.json";
+
+/// A schema catalog written to a local directory with all its remote schemas.
+#[derive(Debug)]
+pub struct SchemaBundle {
+    /// The path of the bundled catalog.
+    pub catalog_path: PathBuf,
+
+    /// The number of the bundled schemas, including the transitive `$ref` targets.
+    pub schema_count: usize,
+
+    /// The errors of the schemas that could not be bundled.
+    pub errors: Vec<crate::Error>,
+}
+
+impl SchemaStore {
+    /// Download a catalog, its TOML schemas and their transitive remote `$ref` targets into `out_dir`.
+    ///
+    /// The remote references are rewritten to relative paths,
+    /// so the bundled `catalog.json` resolves every schema locally.
+    pub async fn pull_schema_bundle(
+        &self,
+        catalog_uri: &CatalogUri,
+        out_dir: &Path,
+    ) -> Result<SchemaBundle, crate::Error> {
+        let Some(catalog) = self.load_catalog_from_uri(catalog_uri).await? else {
+            return Err(crate::Error::CatalogUriFetchFailed {
+                catalog_uri: catalog_uri.clone(),
+                reason: "offline mode".to_string(),
+            });
+        };
+
+        let catalog_schemas = catalog
+            .schemas
+            .into_iter()
+            .filter(|schema| {
+                schema
+                    .file_match
+                    .iter()
+                    .any(|pattern| pattern.ends_with(".toml"))
+            })
+            .collect_vec();
+
+        let mut visited = tombi_hashmap::HashSet::default();
+        let mut pending = catalog_schemas
+            .iter()
+            .map(|schema| schema.url.clone())
+            .filter(|schema_uri| is_remote_uri(schema_uri) && visited.insert(schema_uri.clone()))
+            .collect_vec();
+
+        let mut schema_values = tombi_hashmap::HashMap::default();
+        let mut errors = Vec::new();
+        while !pending.is_empty() {
+            let results = futures::future::join_all(std::mem::take(&mut pending).into_iter().map(
+                |schema_uri| async move {
+                    let result = self.fetch_schema_value(&schema_uri).await;
+                    (schema_uri, result)
+                },
+            ))
+            .await;
+
+            for (schema_uri, result) in results {
+                let schema_value = match result {
+                    Ok(Some(schema_value)) => schema_value,
+                    Ok(None) => {
+                        errors.push(crate::Error::SchemaFetchFailed {
+                            schema_uri,
+                            reason: "offline mode".to_string(),
+                        });
+                        continue;
+                    }
+                    Err(err) => {
+                        errors.push(err);
+                        continue;
+                    }
+                };
+                let mut schema_value =
+                    match tombi_json::from_value_node::<serde_json::Value>(schema_value) {
+                        Ok(schema_value) => schema_value,
+                        Err(err) => {
+                            errors.push(crate::Error::SchemaFileParseFailed {
+                                schema_uri,
+                                reason: err.to_string(),
+                            });
+                            continue;
+                        }
+                    };
+
+                let base_uri = schema_base_uri(&schema_value, &schema_uri);
+                visit_references(&mut schema_value, &mut |reference| {
+                    if let Some((target_uri, _)) = resolve_remote_reference(&base_uri, reference)
+                        && visited.insert(target_uri.clone())
+                    {
+                        pending.push(target_uri);
+                    }
+                });
+                schema_values.insert(schema_uri, schema_value);
+            }
+        }
+
+        // References to the schemas that failed to be fetched are left remote.
+        let bundled_uris = schema_values
+            .keys()
+            .cloned()
+            .collect::<tombi_hashmap::HashSet<_>>();
+        for (schema_uri, schema_value) in &mut schema_values {
+            let local_path = bundle_schema_path(schema_uri);
+            let base_uri = schema_base_uri(schema_value, schema_uri);
+            if let Some(object) = schema_value.as_object_mut()
+                && object
+                    .get("$id")
+                    .and_then(serde_json::Value::as_str)
+                    .and_then(|id| schema_uri.join(id).ok())
+                    .is_some_and(|id| matches!(id.scheme(), "http" | "https"))
+            {
+                object.remove("$id");
+            }
+
+            visit_references(schema_value, &mut |reference| {
+                if let Some((target_uri, fragment)) = resolve_remote_reference(&base_uri, reference)
+                    && bundled_uris.contains(&target_uri)
+                {
+                    let target_path = relative_path(&local_path, &bundle_schema_path(&target_uri));
+                    *reference = match fragment {
+                        Some(fragment) => format!("{target_path}#{fragment}"),
+                        None => target_path,
+                    };
+                }
+            });
+
+            write_json(&out_dir.join(&local_path), schema_value)?;
+        }
+
+        let catalog_path = out_dir.join(SCHEMA_BUNDLE_CATALOG_FILE_NAME);
+        let schemas = catalog_schemas
+            .into_iter()
+            .filter_map(|schema| {
+                let url = if is_remote_uri(&schema.url) {
+                    if !bundled_uris.contains(&schema.url) {
+                        return None;
+                    }
+                    bundle_schema_path(&schema.url)
+                } else {
+                    schema.url.to_string()
+                };
+                Some(serde_json::json!({
+                    "name": schema.name,
+                    "description": schema.description,
+                    "fileMatch": schema.file_match,
+                    "url": url,
+                }))
+            })
+            .collect_vec();
+        write_json(
+            &catalog_path,
+            &serde_json::json!({
+                "$schema": SCHEMA_CATALOG_SCHEMA_URL,
+                "version": 1,
+                "schemas": schemas,
+            }),
+        )?;
+
+        Ok(SchemaBundle {
+            catalog_path,
+            schema_count: schema_values.len(),
+            errors,
+        })
+    }
+}
+
+fn is_remote_uri(uri: &SchemaUri) -> bool {
+    matches!(uri.scheme(), "http" | "https")
+}
+
+/// The base uri of the relative references, which is overridden by the root `$id`.
+fn schema_base_uri(schema_value: &serde_json::Value, schema_uri: &SchemaUri) -> SchemaUri {
+    schema_value
+        .get("$id")
+        .and_then(serde_json::Value::as_str)
+        .and_then(|id| schema_uri.join(id).ok())
+        .map(SchemaUri::from)
+        .unwrap_or_else(|| schema_uri.clone())
+}
+
+/// Resolve a `$ref` to the remote document it points to and its fragment.
+fn resolve_remote_reference(
+    base_uri: &SchemaUri,
+    reference: &str,
+) -> Option<(SchemaUri, Option<String>)> {
+    if reference.starts_with('#') {
+        return None;
+    }
+    let mut target_uri = SchemaUri::from(base_uri.join(reference).ok()?);
+    if !is_remote_uri(&target_uri) {
+        return None;
+    }
+    let fragment = target_uri
+        .fragment()
+        .filter(|fragment| !fragment.is_empty())
+        .map(ToString::to_string);
+    target_uri.set_fragment(None);
+
+    Some((target_uri, fragment))
+}
+
+fn visit_references(value: &mut serde_json::Value, f: &mut impl FnMut(&mut String)) {
+    match value {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    serde_json::Value::String(reference)
+                        if key == "$ref" || key == "$dynamicRef" =>
+                    {
+                        f(reference)
+                    }
+                    _ => visit_references(value, f),
+                }
+            }
+        }
+        serde_json::Value::Array(array) => {
+            for value in array {
+                visit_references(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The bundle relative path of a remote schema.
+///
+/// The path always ends with `.json`, because the schemas are written as JSON
+/// whatever the format they were authored in.
+fn bundle_schema_path(schema_uri: &SchemaUri) -> String {
+    let mut path = format!("schemas/{}", schema_uri.host_str().unwrap_or_default());
+    if let Some(port) = schema_uri.port() {
+        path.push_str(&format!("_{port}"));
+    }
+    path.push_str(schema_uri.path());
+    if path.ends_with('/') {
+        path.push_str("index");
+    }
+    if let Some(query) = schema_uri.query() {
+        path.push('_');
+        path.push_str(query);
+    }
+
+    let mut path = path
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' | '/' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+    if !path.ends_with(".json") {
+        path.push_str(".json");
+    }
+    path
+}
+
+/// The `/` separated path of `to` relative to the directory of `from`.
+fn relative_path(from: &str, to: &str) -> String {
+    let from_dirs = from.split('/').collect_vec();
+    let from_dirs = &from_dirs[..from_dirs.len() - 1];
+    let to_components = to.split('/').collect_vec();
+
+    let common_len = from_dirs
+        .iter()
+        .zip(&to_components)
+        .take_while(|(from, to)| from == to)
+        .count();
+
+    std::iter::repeat_n("..", from_dirs.len() - common_len)
+        .chain(to_components[common_len..].iter().copied())
+        .join("/")
+}
+
+fn write_json(path: &Path, value: &serde_json::Value) -> Result<(), crate::Error> {
+    let write = || -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(value).map_err(std::io::Error::other)?;
+        std::fs::write(path, content + "\n")
+    };
+
+    write().map_err(|err| crate::Error::SchemaBundleWriteFailed {
+        path: path.to_path_buf(),
+        reason: err.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_schema_path_keeps_host_and_path() {
+        let schema_uri: SchemaUri = "https://json.schemastore.org/cargo.json".parse().unwrap();
+        pretty_assertions::assert_eq!(
+            bundle_schema_path(&schema_uri),
+            "schemas/json.schemastore.org/cargo.json"
+        );
+
+        let schema_uri: SchemaUri = "https://example.com/schemas/app.yaml?v=2".parse().unwrap();
+        pretty_assertions::assert_eq!(
+            bundle_schema_path(&schema_uri),
+            "schemas/example.com/schemas/app.yaml_v_2.json"
+        );
+    }
+
+    #[test]
+    fn relative_path_between_bundled_schemas() {
+        pretty_assertions::assert_eq!(
+            relative_path(
+                "schemas/json.schemastore.org/cargo.json",
+                "schemas/json.schemastore.org/partial.json"
+            ),
+            "partial.json"
+        );
+        pretty_assertions::assert_eq!(
+            relative_path(
+                "schemas/json.schemastore.org/cargo.json",
+                "schemas/example.com/defs/name.json"
+            ),
+            "../example.com/defs/name.json"
+        );
+    }
+
+    fn write_cached_schema(cache_home: &tombi_test_lib::TestCacheHome, url: &str, schema: &str) {
+        let path = cache_home
+            .tombi_cache_dir_path()
+            .join(url.replace("://", "/"));
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, schema).unwrap();
+    }
+
+    fn read_json(path: &Path) -> serde_json::Value {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn pull_schema_bundle_from_local_catalog() {
+        let cache_home = tombi_test_lib::TestCacheHome::new();
+        write_cached_schema(
+            &cache_home,
+            "https://example.com/schemas/app.json",
+            r##"{
+                "$id": "https://example.com/schemas/app.json",
+                "type": "object",
+                "properties": {
+                    "name": { "$ref": "defs/name.json#/definitions/name" },
+                    "common": { "$ref": "https://cdn.example.org/common.json" },
+                    "missing": { "$ref": "https://missing.example.org/missing.json" },
+                    "local": { "$ref": "#/definitions/local" }
+                },
+                "definitions": { "local": { "type": "string" } }
+            }"##,
+        );
+        write_cached_schema(
+            &cache_home,
+            "https://example.com/schemas/defs/name.json",
+            r##"{
+                "definitions": {
+                    "name": { "type": "string" },
+                    "app": { "$ref": "../app.json" }
+                }
+            }"##,
+        );
+        write_cached_schema(
+            &cache_home,
+            "https://cdn.example.org/common.json",
+            r##"{
+                "$id": "https://cdn.example.org/common.json",
+                "properties": { "app": { "$ref": "https://example.com/schemas/app.json#/properties/name" } }
+            }"##,
+        );
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog_path = temp_dir.path().join("source-catalog.json");
+        std::fs::write(
+            &catalog_path,
+            r#"{
+                "schemas": [
+                    {
+                        "name": "app",
+                        "description": "App config",
+                        "fileMatch": ["app.toml"],
+                        "url": "https://example.com/schemas/app.json"
+                    },
+                    {
+                        "name": "tombi",
+                        "description": "Tombi config",
+                        "fileMatch": ["tombi.toml"],
+                        "url": "tombi://www.schemastore.org/tombi.json"
+                    },
+                    {
+                        "name": "package.json",
+                        "description": "Not a TOML schema",
+                        "fileMatch": ["package.json"],
+                        "url": "https://json.schemastore.org/package.json"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let schema_store = SchemaStore::new_with_options(crate::Options {
+            offline: Some(true),
+            ..Default::default()
+        });
+        let catalog_uri = CatalogUri::from(tombi_uri::Uri::from_file_path(&catalog_path).unwrap());
+        let out_dir = temp_dir.path().join("bundle");
+        let bundle = schema_store
+            .pull_schema_bundle(&catalog_uri, &out_dir)
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(bundle.schema_count, 3);
+        pretty_assertions::assert_eq!(bundle.errors.len(), 1);
+        assert!(matches!(
+            &bundle.errors[0],
+            crate::Error::SchemaFetchFailed { schema_uri, .. }
+                if schema_uri.as_str() == "https://missing.example.org/missing.json"
+        ));
+
+        // Remote references are rewritten relative to each bundled schema,
+        // and the remote `$id` is removed not to override the local base uri.
+        let app = read_json(&out_dir.join("schemas/example.com/schemas/app.json"));
+        assert!(app.get("$id").is_none());
+        pretty_assertions::assert_eq!(
+            app["properties"],
+            serde_json::json!({
+                "name": { "$ref": "defs/name.json#/definitions/name" },
+                "common": { "$ref": "../../cdn.example.org/common.json" },
+                "missing": { "$ref": "https://missing.example.org/missing.json" },
+                "local": { "$ref": "#/definitions/local" }
+            })
+        );
+        let name = read_json(&out_dir.join("schemas/example.com/schemas/defs/name.json"));
+        pretty_assertions::assert_eq!(
+            name["definitions"]["app"],
+            serde_json::json!({ "$ref": "../app.json" })
+        );
+        let common = read_json(&out_dir.join("schemas/cdn.example.org/common.json"));
+        assert!(common.get("$id").is_none());
+        pretty_assertions::assert_eq!(
+            common["properties"]["app"],
+            serde_json::json!({ "$ref": "../example.com/schemas/app.json#/properties/name" })
+        );
+
+        let bundle_catalog_uri =
+            CatalogUri::from(tombi_uri::Uri::from_file_path(&bundle.catalog_path).unwrap());
+        let catalog = schema_store
+            .load_catalog_from_uri(&bundle_catalog_uri)
+            .await
+            .unwrap()
+            .unwrap();
+        pretty_assertions::assert_eq!(
+            catalog
+                .schemas
+                .iter()
+                .map(|schema| schema.url.to_string())
+                .collect_vec(),
+            vec![
+                tombi_uri::Uri::from_file_path(
+                    out_dir.join("schemas/example.com/schemas/app.json")
+                )
+                .unwrap()
+                .to_string(),
+                "tombi://www.schemastore.org/tombi.json".to_string(),
+            ]
+        );
+    }
+}
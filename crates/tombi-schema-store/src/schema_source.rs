@@ -0,0 +1,195 @@
+#[cfg(feature = "toml-schema")]
+use tombi_config::TomlVersion;
+#[cfg(feature = "toml-schema")]
+use tombi_document_tree::TryIntoDocumentTree;
+use tombi_json::ValueNode;
+#[cfg(feature = "toml-schema")]
+use tombi_json::{ArrayNode, BoolNode, NullNode, NumberNode, ObjectNode, StringNode};
+use tombi_uri::SchemaUri;
+
+/// Parse the source text of a schema into a [`ValueNode`].
+///
+/// The format is guessed from the extension of the schema URI path:
+/// `.toml` schemas are converted from the TOML document tree,
+/// and `.jsonc`, `.json5`, `.yaml` and `.yml` schemas are supported in addition to JSON.
+/// `.toml` schemas need the `toml-schema` feature.
+pub fn parse_schema_value(schema_uri: &SchemaUri, source: &str) -> Result<ValueNode, crate::Error> {
+    let result = if is_toml_schema_uri(schema_uri) {
+        parse_toml_schema_value(source)
+    } else {
+        ValueNode::from_str_with_format(
+            source,
+            tombi_json::SourceFormat::from_path(schema_uri.path()),
+        )
+        .map_err(|err| err.to_string())
+    };
+
+    result.map_err(|reason| crate::Error::SchemaFileParseFailed {
+        schema_uri: schema_uri.to_owned(),
+        reason,
+    })
+}
+
+/// Whether the schema is authored in TOML, such as `schemas/app.schema.toml`.
+pub fn is_toml_schema_uri(schema_uri: &tombi_uri::Uri) -> bool {
+    schema_uri
+        .path()
+        .rsplit('/')
+        .next()
+        .and_then(|file_name| file_name.rsplit_once('.'))
+        .is_some_and(|(_, extension)| extension.eq_ignore_ascii_case("toml"))
+}
+
+#[cfg(not(feature = "toml-schema"))]
+fn parse_toml_schema_value(_source: &str) -> Result<ValueNode, String> {
+    Err("schemas authored in TOML are not supported in this build".to_string())
+}
+
+#[cfg(feature = "toml-schema")]
+fn parse_toml_schema_value(source: &str) -> Result<ValueNode, String> {
+    let root = tombi_parser::parse(source)
+        .try_into_root()
+        .map_err(|errors| match errors.first() {
+            Some(error) => format_error(error.to_message(), error.range()),
+            None => "invalid TOML".to_string(),
+        })?;
+
+    let document_tree: tombi_document_tree::DocumentTree = root
+        .try_into_document_tree(TomlVersion::latest())
+        .map_err(|errors| match errors.first() {
+            Some(error) => format_error(error.to_string(), error.range()),
+            None => "invalid TOML".to_string(),
+        })?;
+
+    let table: tombi_document_tree::Table = document_tree.into();
+    let range = table.range();
+
+    Ok(ValueNode::Object(ObjectNode {
+        properties: table_properties(&table),
+        range,
+    }))
+}
+
+#[cfg(feature = "toml-schema")]
+fn format_error(message: String, range: tombi_text::Range) -> String {
+    format!(
+        "{message} at line {}, column {}",
+        range.start.line + 1,
+        range.start.column + 1
+    )
+}
+
+#[cfg(feature = "toml-schema")]
+fn table_properties(table: &tombi_document_tree::Table) -> tombi_json::Map<StringNode, ValueNode> {
+    table
+        .key_values()
+        .iter()
+        .map(|(key, value)| {
+            (
+                StringNode {
+                    value: key.value.clone(),
+                    range: key.range(),
+                },
+                value_node(value),
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "toml-schema")]
+fn value_node(value: &tombi_document_tree::Value) -> ValueNode {
+    let range = value.range();
+    match value {
+        tombi_document_tree::Value::Boolean(boolean) => ValueNode::Bool(BoolNode {
+            value: boolean.value(),
+            range,
+        }),
+        tombi_document_tree::Value::Integer(integer) => ValueNode::Number(NumberNode {
+            value: integer.value().into(),
+            range,
+        }),
+        tombi_document_tree::Value::Float(float) => ValueNode::Number(NumberNode {
+            value: float.value().into(),
+            range,
+        }),
+        tombi_document_tree::Value::String(string) => ValueNode::String(StringNode {
+            value: string.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::OffsetDateTime(date_time) => ValueNode::String(StringNode {
+            value: date_time.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::LocalDateTime(date_time) => ValueNode::String(StringNode {
+            value: date_time.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::LocalDate(date) => ValueNode::String(StringNode {
+            value: date.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::LocalTime(time) => ValueNode::String(StringNode {
+            value: time.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::Array(array) => ValueNode::Array(ArrayNode {
+            items: array.values().iter().map(value_node).collect(),
+            range,
+        }),
+        tombi_document_tree::Value::Table(table) => ValueNode::Object(ObjectNode {
+            properties: table_properties(table),
+            range,
+        }),
+        tombi_document_tree::Value::Incomplete { .. } => ValueNode::Null(NullNode { range }),
+    }
+}
+
+#[cfg(all(test, feature = "toml-schema"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toml_schema_is_converted_with_ranges() {
+        let schema_uri: SchemaUri = "file:///schemas/app.schema.toml".parse().unwrap();
+        let value_node = parse_schema_value(
+            &schema_uri,
+            r#"
+type = "object"
+required = ["name"]
+
+[properties.name]
+type = "string"
+minLength = 1
+"#,
+        )
+        .unwrap();
+
+        let object = value_node.as_object().unwrap();
+        pretty_assertions::assert_eq!(
+            object.get("type").and_then(ValueNode::as_str),
+            Some("object")
+        );
+
+        let name = object
+            .get("properties")
+            .and_then(|properties| properties.as_object()?.get("name"))
+            .and_then(ValueNode::as_object)
+            .unwrap();
+        pretty_assertions::assert_eq!(name.get("minLength").and_then(ValueNode::as_i64), Some(1));
+        pretty_assertions::assert_eq!(
+            name.get("type").map(ValueNode::range),
+            Some(tombi_text::Range::new((5, 7).into(), (5, 15).into()))
+        );
+    }
+
+    #[test]
+    fn toml_schema_parse_error_points_to_line() {
+        let schema_uri: SchemaUri = "file:///schemas/app.schema.toml".parse().unwrap();
+        let Err(crate::Error::SchemaFileParseFailed { reason, .. }) =
+            parse_schema_value(&schema_uri, "type = \"object\"\ntype = \"array\"\n")
+        else {
+            panic!("expected a schema parse error");
+        };
+        assert!(reason.contains("at line 2"), "{reason}");
+    }
+}
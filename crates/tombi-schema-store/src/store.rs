@@ -4,7 +4,7 @@ use crate::resolve_json_pointer;
 use crate::{
     AllOfSchema, AnyOfSchema, CatalogUri, DocumentSchema, OneOfSchema, PatternAccessor,
     PatternAccessors, SourceSchema, SubSchemaUriMap, ValueSchema, get_tombi_schemastore_content,
-    http_client::HttpClient, json::JsonCatalog,
+    http_client::HttpClient, json::JsonCatalog, parse_schema_value,
 };
 use itertools::{Either, Itertools};
 use tokio::sync::RwLock;
@@ -215,6 +215,7 @@ impl SchemaStore {
                 overrides: schema_overrides(schema),
                 schema_uri,
                 catalog_uri: None,
+                source: crate::SchemaSource::Config,
                 include: schema.include().to_vec(),
                 exclude: schema.exclude().map(|exclude| exclude.to_vec()),
                 toml_version: schema.toml_version(),
@@ -250,9 +251,11 @@ impl SchemaStore {
 
                 log::debug!("load catalog from file: {}", catalog_uri);
 
-                serde_json::from_str(&content).map_err(|err| crate::Error::InvalidJsonFormat {
-                    uri: catalog_uri.deref().clone(),
-                    reason: err.to_string(),
+                JsonCatalog::from_str_with_base_uri(&content, catalog_uri).map_err(|err| {
+                    crate::Error::InvalidJsonFormat {
+                        uri: catalog_uri.deref().clone(),
+                        reason: err.to_string(),
+                    }
                 })?
             }
             "http" | "https" => {
@@ -361,6 +364,7 @@ impl SchemaStore {
                     overrides: Default::default(),
                     schema_uri: schema.url,
                     catalog_uri: Some(catalog_uri.clone()),
+                    source: crate::SchemaSource::Catalog,
                     include: schema.file_match,
                     exclude: None,
                     toml_version: None,
@@ -418,17 +422,12 @@ impl SchemaStore {
                     });
                 }
 
-                let file = std::fs::File::open(&schema_path)
+                let content = std::fs::read_to_string(&schema_path)
                     .map_err(|_| crate::Error::SchemaFileReadFailed { schema_path })?;
 
                 log::debug!("fetch schema from file: {}", schema_uri);
 
-                Ok(Some(tombi_json::ValueNode::from_reader(file).map_err(
-                    |err| crate::Error::SchemaFileParseFailed {
-                        schema_uri: schema_uri.to_owned(),
-                        reason: err.to_string(),
-                    },
-                )?))
+                Ok(Some(parse_schema_value(schema_uri, &content)?))
             }
             "http" | "https" => {
                 let schema_cache_path = get_cache_file_path(schema_uri).await;
@@ -483,14 +482,9 @@ impl SchemaStore {
                     log::warn!("{err}");
                 }
 
-                Ok(Some(
-                    tombi_json::ValueNode::from_reader(std::io::Cursor::new(bytes)).map_err(
-                        |err| crate::Error::SchemaFileParseFailed {
-                            schema_uri: schema_uri.to_owned(),
-                            reason: err.to_string(),
-                        },
-                    )?,
-                ))
+                let content = String::from_utf8_lossy(&bytes);
+
+                Ok(Some(parse_schema_value(schema_uri, &content)?))
             }
             "tombi" => {
                 let Some(content) = get_tombi_schemastore_content(schema_uri) else {
@@ -793,34 +787,7 @@ impl SchemaStore {
         &self,
         source_path: &std::path::Path,
     ) -> Result<Option<SourceSchema>, crate::Error> {
-        let canonicalized_source_path = canonicalize_path_for_matching(source_path);
-
-        // Get the base directory for relative path conversion
-        let base_dir_path = self.base_dir_path.read().await;
-
-        // Determine the path to use for pattern matching without per-call filesystem I/O
-        let path_for_matching = base_dir_path
-            .as_deref()
-            .and_then(|base_dir_path| {
-                canonicalized_source_path
-                    .strip_prefix(base_dir_path)
-                    .ok()
-                    .map(|relative_source_path| relative_source_path.to_path_buf())
-            })
-            .unwrap_or_else(|| canonicalized_source_path.clone());
-
-        let schemas = self.schemas.read().await;
-        let matching_schemas = schemas
-            .iter()
-            .filter(|schema| {
-                matches_schema_patterns(
-                    &schema.include,
-                    schema.exclude.as_deref(),
-                    &path_for_matching,
-                    &canonicalized_source_path,
-                )
-            })
-            .collect_vec();
+        let matching_schemas = self.list_matching_schemas(source_path).await;
 
         let mut source_schema: Option<SourceSchema> = None;
         for matching_schema in matching_schemas {
@@ -1050,6 +1017,7 @@ impl SchemaStore {
             overrides: Default::default(),
             schema_uri,
             catalog_uri: None,
+            source: crate::SchemaSource::Extension,
             include,
             exclude: None,
             toml_version: options.toml_version,
@@ -1069,6 +1037,40 @@ impl SchemaStore {
     pub async fn list_schemas(&self) -> Vec<crate::Schema> {
         self.schemas.read().await.clone()
     }
+
+    /// List the schemas whose file patterns match the source file, in order of precedence.
+    pub async fn list_matching_schemas(&self, source_path: &std::path::Path) -> Vec<crate::Schema> {
+        let canonicalized_source_path = canonicalize_path_for_matching(source_path);
+
+        // Get the base directory for relative path conversion
+        let base_dir_path = self.base_dir_path.read().await;
+
+        // Determine the path to use for pattern matching without per-call filesystem I/O
+        let path_for_matching = base_dir_path
+            .as_deref()
+            .and_then(|base_dir_path| {
+                canonicalized_source_path
+                    .strip_prefix(base_dir_path)
+                    .ok()
+                    .map(|relative_source_path| relative_source_path.to_path_buf())
+            })
+            .unwrap_or_else(|| canonicalized_source_path.clone());
+
+        self.schemas
+            .read()
+            .await
+            .iter()
+            .filter(|schema| {
+                matches_schema_patterns(
+                    &schema.include,
+                    schema.exclude.as_deref(),
+                    &path_for_matching,
+                    &canonicalized_source_path,
+                )
+            })
+            .cloned()
+            .collect_vec()
+    }
 }
 
 async fn schema_cache_version(schema_uri: &SchemaUri) -> Option<SchemaCacheVersion> {
@@ -1216,14 +1218,7 @@ async fn load_json_schema_from_cache(
     {
         log::debug!("load schema from cache: {}", schema_uri);
 
-        return Ok(Some(
-            tombi_json::ValueNode::from_str(&schema_cache_content).map_err(|err| {
-                crate::Error::SchemaFileParseFailed {
-                    schema_uri: schema_uri.to_owned(),
-                    reason: err.to_string(),
-                }
-            })?,
-        ));
+        return Ok(Some(parse_schema_value(schema_uri, &schema_cache_content)?));
     }
 
     Ok(None)
@@ -1402,6 +1397,71 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn yaml_and_jsonc_schemas_are_parsed() {
+        for (extension, content) in [
+            (
+                "yaml",
+                "# comment\ntype: object\nproperties:\n  name:\n    type: string\n",
+            ),
+            (
+                "jsonc",
+                r#"{
+                    // comment
+                    "type": "object",
+                    "properties": { "name": { "type": "string" } },
+                }"#,
+            ),
+        ] {
+            let schema_path = std::env::temp_dir().join(format!(
+                "tombi_schema_format_{}_{}.{extension}",
+                std::process::id(),
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_nanos()
+            ));
+            std::fs::write(&schema_path, content).unwrap();
+
+            let schema_uri = SchemaUri::from_file_path(&schema_path).unwrap();
+            let document_schema = SchemaStore::new()
+                .try_get_document_schema(&schema_uri)
+                .await
+                .unwrap()
+                .unwrap();
+
+            std::assert_matches!(
+                document_schema.value_schema.as_deref(),
+                Some(ValueSchema::Table(_))
+            );
+
+            let _ = std::fs::remove_file(schema_path);
+        }
+    }
+
+    #[tokio::test]
+    async fn schema_parse_error_points_to_line() {
+        let schema_path = std::env::temp_dir().join(format!(
+            "tombi_schema_parse_error_{}_{}.yml",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::write(&schema_path, "type: object\nrequired: [name\n").unwrap();
+
+        let schema_uri = SchemaUri::from_file_path(&schema_path).unwrap();
+        let Err(crate::Error::SchemaFileParseFailed { reason, .. }) =
+            SchemaStore::new().fetch_schema_value(&schema_uri).await
+        else {
+            panic!("expected a schema parse error");
+        };
+        assert!(reason.contains("at line 3"), "{reason}");
+
+        let _ = std::fs::remove_file(schema_path);
+    }
+
     #[tokio::test]
     async fn fragment_pointer_resolves_boolean_schema() {
         let schema_path = std::env::temp_dir().join(format!(
@@ -0,0 +1,230 @@
+use tombi_future::{BoxFuture, Boxable};
+
+use crate::{
+    Accessor, CurrentSchema, SchemaAccessor, SchemaStore, SchemaUri, SchemaVisits, ValueSchema,
+    resolve_and_collect_schemas, resolve_schema_item,
+};
+
+impl SchemaStore {
+    /// Resolve the subschema of a document schema at the accessors.
+    ///
+    /// `$ref`s are followed, and the first branch of a composite schema that has the accessor wins.
+    pub async fn resolve_sub_schema(
+        &self,
+        schema_uri: &SchemaUri,
+        accessors: &[Accessor],
+    ) -> Result<Option<CurrentSchema<'static>>, crate::Error> {
+        let Some(document_schema) = self.try_get_document_schema(schema_uri).await? else {
+            return Ok(None);
+        };
+        let Some(mut current_schema) = document_schema
+            .as_current_schema()
+            .map(CurrentSchema::into_owned)
+        else {
+            return Ok(None);
+        };
+
+        for accessor in accessors {
+            match self
+                .resolve_accessor_schema(current_schema, accessor, &SchemaVisits::default())
+                .await?
+            {
+                Some(sub_schema) => current_schema = sub_schema,
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(current_schema))
+    }
+
+    /// Fetch the JSON value the resolved schema was parsed from.
+    pub async fn fetch_current_schema_value(
+        &self,
+        current_schema: &CurrentSchema<'_>,
+    ) -> Result<Option<tombi_json::ValueNode>, crate::Error> {
+        let mut schema_uri = current_schema.schema_uri.as_ref().clone();
+        schema_uri.set_fragment(None);
+
+        let Some(schema_value) = self.fetch_schema_value(&schema_uri).await? else {
+            return Ok(None);
+        };
+
+        Ok(find_value_node(
+            schema_value,
+            current_schema.value_schema.range(),
+        ))
+    }
+
+    fn resolve_accessor_schema<'a: 'b, 'b>(
+        &'a self,
+        current_schema: CurrentSchema<'static>,
+        accessor: &'a Accessor,
+        schema_visits: &'a SchemaVisits,
+    ) -> BoxFuture<'b, Result<Option<CurrentSchema<'static>>, crate::Error>> {
+        async move {
+            let schemas = match (current_schema.value_schema.as_ref(), accessor) {
+                (ValueSchema::Table(table_schema), Accessor::Key(key)) => {
+                    if let Some(property_schema) = table_schema
+                        .resolve_property_schema(
+                            &SchemaAccessor::Key(key.clone()),
+                            current_schema.schema_uri.clone(),
+                            current_schema.definitions.clone(),
+                            self,
+                        )
+                        .await?
+                    {
+                        return Ok(Some(property_schema));
+                    }
+                    if let Some((_, additional_property_schema)) =
+                        &table_schema.additional_property_schema
+                    {
+                        return resolve_schema_item(
+                            additional_property_schema,
+                            current_schema.schema_uri.clone(),
+                            current_schema.definitions.clone(),
+                            self,
+                        )
+                        .await;
+                    }
+                    return Ok(None);
+                }
+                (ValueSchema::Array(array_schema), Accessor::Index(index)) => {
+                    let item_schema = array_schema
+                        .prefix_items
+                        .as_ref()
+                        .and_then(|prefix_items| prefix_items.get(*index))
+                        .or(array_schema.items.as_ref());
+                    let Some(item_schema) = item_schema else {
+                        return Ok(None);
+                    };
+                    return resolve_schema_item(
+                        item_schema,
+                        current_schema.schema_uri.clone(),
+                        current_schema.definitions.clone(),
+                        self,
+                    )
+                    .await;
+                }
+                (ValueSchema::OneOf(one_of_schema), _) => &one_of_schema.schemas,
+                (ValueSchema::AnyOf(any_of_schema), _) => &any_of_schema.schemas,
+                (ValueSchema::AllOf(all_of_schema), _) => &all_of_schema.schemas,
+                _ => return Ok(None),
+            };
+
+            let Some(resolved_schemas) = resolve_and_collect_schemas(
+                schemas,
+                current_schema.schema_uri.clone(),
+                current_schema.definitions.clone(),
+                self,
+                schema_visits,
+                std::slice::from_ref(accessor),
+            )
+            .await
+            else {
+                return Ok(None);
+            };
+
+            for resolved_schema in resolved_schemas {
+                if let Some(sub_schema) = self
+                    .resolve_accessor_schema(resolved_schema, accessor, schema_visits)
+                    .await?
+                {
+                    return Ok(Some(sub_schema));
+                }
+            }
+
+            Ok(None)
+        }
+        .boxed()
+    }
+}
+
+fn find_value_node(
+    value_node: tombi_json::ValueNode,
+    range: tombi_text::Range,
+) -> Option<tombi_json::ValueNode> {
+    if value_node.range() == range {
+        return Some(value_node);
+    }
+    if !value_node.range().contains(range.start) {
+        return None;
+    }
+
+    match value_node {
+        tombi_json::ValueNode::Object(object) => object
+            .properties
+            .into_iter()
+            .find_map(|(_, value_node)| find_value_node(value_node, range)),
+        tombi_json::ValueNode::Array(array) => array
+            .items
+            .into_iter()
+            .find_map(|value_node| find_value_node(value_node, range)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn resolve_sub_schema_follows_references() {
+        let schema_path = std::env::temp_dir().join(format!(
+            "tombi-sub-schema-{}-{}.json",
+            std::process::id(),
+            "resolve_sub_schema_follows_references"
+        ));
+        std::fs::write(
+            &schema_path,
+            r##"{
+                "type": "object",
+                "properties": {
+                    "package": { "$ref": "#/definitions/Package" }
+                },
+                "definitions": {
+                    "Package": {
+                        "type": "object",
+                        "properties": {
+                            "edition": { "type": "string", "enum": ["2021", "2024"] }
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+
+        let schema_store = SchemaStore::new();
+        let schema_uri = SchemaUri::from_file_path(&schema_path).unwrap();
+        let accessors = [
+            Accessor::Key("package".to_string()),
+            Accessor::Key("edition".to_string()),
+        ];
+        let current_schema = schema_store
+            .resolve_sub_schema(&schema_uri, &accessors)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            current_schema.value_schema.as_ref(),
+            ValueSchema::String(_)
+        ));
+
+        let schema_value = schema_store
+            .fetch_current_schema_value(&current_schema)
+            .await
+            .unwrap()
+            .unwrap();
+        pretty_assertions::assert_eq!(
+            tombi_json::from_value_node::<serde_json::Value>(schema_value).unwrap(),
+            serde_json::json!({ "type": "string", "enum": ["2021", "2024"] })
+        );
+
+        let missing = schema_store
+            .resolve_sub_schema(&schema_uri, &[Accessor::Key("workspace".to_string())])
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+
+        let _ = std::fs::remove_file(&schema_path);
+    }
+}
@@ -229,3 +229,9 @@ pub fn issue_1895_rustfmt_like_schema_path() -> PathBuf {
         .join("schemas")
         .join("issue-1895-rustfmt-like.schema.json")
 }
+
+pub fn toml_schema_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("toml-schema-test.schema.toml")
+}
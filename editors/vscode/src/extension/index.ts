@@ -32,6 +32,8 @@ export const SUPPORT_TOMBI_CONFIG_FILENAMES = [
 export const SUPPORT_JSON_LANGUAGES = ["json"];
 export const TOMBI_DEV_VERSION = "0.0.0-dev";
 const OPEN_TOOLTIP_LINK_COMMAND = `${EXTENSION_ID}.openTooltipLink`;
+// Sent by the language server in the schema code lens.
+const OPEN_SCHEMA_COMMAND = `${EXTENSION_ID}.openSchema`;
 const MIN_VERSION_FOR_TOMBI_TOOLTIP_LINK = "0.11.2";
 
 export class Extension {
@@ -115,6 +117,13 @@ export class Extension {
         async (target: string) => command.openTooltipLink(target, this.client),
       ),
     );
+    this.context.subscriptions.push(
+      vscode.commands.registerCommand(
+        OPEN_SCHEMA_COMMAND,
+        async (schemaUri: string) =>
+          command.openTooltipLink(schemaUri, this.client),
+      ),
+    );
     this.context.subscriptions.push(
       vscode.commands.registerCommand(
         `${EXTENSION_ID}.openServerLogs`,
@@ -47,6 +47,17 @@ pub(crate) fn is_any_dependency_path_accessor(accessors: &[Accessor]) -> bool {
         || is_dependency_path_accessor(accessors)
 }
 
+#[inline]
+pub(crate) fn is_patch_accessor(accessors: &[Accessor]) -> bool {
+    matches_accessors!(accessors, ["patch", _, _]) || matches_accessors!(accessors, ["replace", _])
+}
+
+#[inline]
+pub(crate) fn is_patch_path_accessor(accessors: &[Accessor]) -> bool {
+    matches_accessors!(accessors, ["patch", _, _, "path"])
+        || matches_accessors!(accessors, ["replace", _, "path"])
+}
+
 #[inline]
 pub(crate) fn is_workspace_key_accessor(accessors: &[Accessor]) -> bool {
     matches!(accessors.last(), Some(Accessor::Key(key)) if key == "workspace")
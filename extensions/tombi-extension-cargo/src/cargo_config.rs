@@ -0,0 +1,1022 @@
+use std::path::Path;
+
+use itertools::Itertools;
+use tombi_document_tree::{Table, Value, dig_accessors, dig_keys};
+use tombi_extension::{
+    CompletionContent, CompletionContentPriority, CompletionEdit, CompletionHint, CompletionKind,
+    HoverMetadata, HoverTextChange,
+};
+use tombi_hashmap::HashSet;
+use tombi_schema_store::{Accessor, matches_accessors};
+
+use crate::{built_in_target_triples, canonicalize_or_original, target_triple_description};
+
+/// A known key of a Cargo configuration file.
+pub(crate) struct ConfigKey {
+    name: &'static str,
+    description: &'static str,
+    table: ConfigTable,
+}
+
+/// What a key of a Cargo configuration file holds.
+pub(crate) enum ConfigTable {
+    /// A value, not a table.
+    Value(ConfigValue),
+    /// A table with known keys.
+    Keys(&'static [ConfigKey]),
+    /// A table whose keys are chosen by the user, each holding the same table.
+    Named(ConfigName, &'static ConfigTable),
+}
+
+/// The values suggested for a key.
+pub(crate) enum ConfigValue {
+    Any,
+    OneOf(&'static [&'static str]),
+    Target,
+}
+
+/// The keys suggested for a table whose keys are chosen by the user.
+pub(crate) enum ConfigName {
+    /// Any name, shown as the placeholder in titles.
+    Any(&'static str),
+    /// A target triple or a `cfg(...)` expression.
+    Target,
+    /// `crates-io` or a registry of `[registries]`.
+    Registry,
+}
+
+impl ConfigName {
+    fn placeholder(&self) -> &'static str {
+        match self {
+            Self::Any(placeholder) => placeholder,
+            Self::Target => "<triple>",
+            Self::Registry => "<registry>",
+        }
+    }
+}
+
+impl ConfigKey {
+    pub(crate) const fn new(
+        name: &'static str,
+        description: &'static str,
+        table: ConfigTable,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            table,
+        }
+    }
+}
+
+const fn value(name: &'static str, description: &'static str) -> ConfigKey {
+    ConfigKey::new(name, description, ConfigTable::Value(ConfigValue::Any))
+}
+
+const fn one_of(
+    name: &'static str,
+    description: &'static str,
+    values: &'static [&'static str],
+) -> ConfigKey {
+    ConfigKey::new(
+        name,
+        description,
+        ConfigTable::Value(ConfigValue::OneOf(values)),
+    )
+}
+
+/// The keys of a dependency in `[patch.<registry>]`.
+const DEPENDENCY_KEYS: &[ConfigKey] = &[
+    value("version", "The version requirement of the patched crate."),
+    value("path", "The local directory of the patched crate."),
+    value("git", "The git repository of the patched crate."),
+    value("branch", "The git branch to use."),
+    value("tag", "The git tag to use."),
+    value("rev", "The git revision to use."),
+    value(
+        "package",
+        "The name of the crate, if it differs from the key.",
+    ),
+    value("registry", "The registry of the patched crate."),
+    value("features", "The features to enable."),
+    value(
+        "default-features",
+        "Whether the default features are enabled.",
+    ),
+];
+
+/// The keys of `.cargo/config.toml`.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/config.html
+static CARGO_CONFIG: ConfigTable = ConfigTable::Keys(&[
+    ConfigKey::new(
+        "alias",
+        "Command aliases, run with `cargo <name>`.",
+        ConfigTable::Named(
+            ConfigName::Any("<name>"),
+            &ConfigTable::Value(ConfigValue::Any),
+        ),
+    ),
+    ConfigKey::new(
+        "build",
+        "Settings of the compilation.",
+        ConfigTable::Keys(&[
+            value(
+                "jobs",
+                "The number of parallel jobs, or a negative number to leave CPUs free.",
+            ),
+            value("rustc", "The `rustc` executable."),
+            value("rustc-wrapper", "A wrapper to execute instead of `rustc`."),
+            value(
+                "rustc-workspace-wrapper",
+                "A wrapper to execute instead of `rustc`, for workspace members only.",
+            ),
+            value("rustdoc", "The `rustdoc` executable."),
+            ConfigKey::new(
+                "target",
+                "The default target triple, or triples, to build for.",
+                ConfigTable::Value(ConfigValue::Target),
+            ),
+            value("target-dir", "The directory of the compiler outputs."),
+            value(
+                "rustflags",
+                "Extra flags passed to all compiler invocations, as a string or an array.",
+            ),
+            value(
+                "rustdocflags",
+                "Extra flags passed to `rustdoc`, as a string or an array.",
+            ),
+            value("incremental", "Whether incremental compilation is enabled."),
+            value(
+                "dep-info-basedir",
+                "The path prefix stripped from the paths of the dep-info files.",
+            ),
+        ]),
+    ),
+    ConfigKey::new(
+        "cargo-new",
+        "Settings of `cargo new`.",
+        ConfigTable::Keys(&[one_of(
+            "vcs",
+            "The version control system of new packages.",
+            &["git", "hg", "pijul", "fossil", "none"],
+        )]),
+    ),
+    ConfigKey::new(
+        "credential-alias",
+        "Aliases of credential providers.",
+        ConfigTable::Named(
+            ConfigName::Any("<name>"),
+            &ConfigTable::Value(ConfigValue::Any),
+        ),
+    ),
+    ConfigKey::new(
+        "doc",
+        "Settings of `cargo doc`.",
+        ConfigTable::Keys(&[value(
+            "browser",
+            "The browser opened by `cargo doc --open`.",
+        )]),
+    ),
+    ConfigKey::new(
+        "env",
+        "Environment variables set for build scripts, rustc invocations, `cargo run` and `cargo build`.",
+        ConfigTable::Named(
+            ConfigName::Any("<name>"),
+            &ConfigTable::Keys(&[
+                value("value", "The value of the environment variable."),
+                value(
+                    "force",
+                    "Whether the value overrides the variable set in the environment.",
+                ),
+                value(
+                    "relative",
+                    "Whether the value is a path relative to the directory containing `.cargo`.",
+                ),
+            ]),
+        ),
+    ),
+    ConfigKey::new(
+        "future-incompat-report",
+        "Settings of the future incompatibility reports.",
+        ConfigTable::Keys(&[one_of(
+            "frequency",
+            "How often the report is shown.",
+            &["always", "never"],
+        )]),
+    ),
+    ConfigKey::new(
+        "http",
+        "Settings of the HTTP requests.",
+        ConfigTable::Keys(&[
+            value("debug", "Whether the HTTP requests are logged."),
+            value("proxy", "The HTTP proxy, as `host:port`."),
+            value("timeout", "The timeout of each request, in seconds."),
+            value("cainfo", "The CA certificate bundle."),
+            value(
+                "check-revoke",
+                "Whether certificate revocation is checked on Windows.",
+            ),
+            value("ssl-version", "The minimum TLS version."),
+            value(
+                "low-speed-limit",
+                "The speed in bytes per second below which a request times out.",
+            ),
+            value("multiplexing", "Whether HTTP/2 multiplexing is used."),
+            value("user-agent", "The user agent of the requests."),
+        ]),
+    ),
+    ConfigKey::new(
+        "install",
+        "Settings of `cargo install`.",
+        ConfigTable::Keys(&[value("root", "The directory binaries are installed into.")]),
+    ),
+    ConfigKey::new(
+        "net",
+        "Settings of the network.",
+        ConfigTable::Keys(&[
+            value("retry", "The number of retries of network errors."),
+            value(
+                "git-fetch-with-cli",
+                "Whether the `git` executable fetches repositories.",
+            ),
+            value("offline", "Whether Cargo stays offline."),
+            ConfigKey::new(
+                "ssh",
+                "Settings of the SSH connections.",
+                ConfigTable::Keys(&[value("known-hosts", "Additional SSH known hosts entries.")]),
+            ),
+        ]),
+    ),
+    ConfigKey::new(
+        "patch",
+        "Dependencies replaced for every workspace using this configuration.",
+        ConfigTable::Named(
+            ConfigName::Registry,
+            &ConfigTable::Named(
+                ConfigName::Any("<name>"),
+                &ConfigTable::Keys(DEPENDENCY_KEYS),
+            ),
+        ),
+    ),
+    ConfigKey::new(
+        "profile",
+        "Profile settings overriding the profiles of `Cargo.toml`.",
+        ConfigTable::Named(
+            ConfigName::Any("<name>"),
+            &ConfigTable::Keys(&[
+                value("inherits", "The profile this custom profile inherits from."),
+                value("opt-level", "The optimization level."),
+                value("debug", "The amount of debug information."),
+                value("split-debuginfo", "Where debug information is placed."),
+                value(
+                    "strip",
+                    "Whether symbols or debug information are stripped.",
+                ),
+                value("debug-assertions", "Whether debug assertions are enabled."),
+                value(
+                    "overflow-checks",
+                    "Whether integer overflow checks are enabled.",
+                ),
+                value("lto", "The link-time optimization."),
+                one_of("panic", "The panic strategy.", &["unwind", "abort"]),
+                value("incremental", "Whether incremental compilation is enabled."),
+                value("codegen-units", "The number of code generation units."),
+                value("rpath", "Whether rpath is enabled."),
+            ]),
+        ),
+    ),
+    ConfigKey::new(
+        "registries",
+        "Registries other than crates.io.",
+        ConfigTable::Named(
+            ConfigName::Any("<name>"),
+            &ConfigTable::Keys(&[
+                value("index", "The URL of the registry index."),
+                value("token", "The authentication token of the registry."),
+                value(
+                    "credential-provider",
+                    "The credential provider of the registry.",
+                ),
+                one_of(
+                    "protocol",
+                    "The protocol of crates.io, for `registries.crates-io` only.",
+                    &["git", "sparse"],
+                ),
+            ]),
+        ),
+    ),
+    ConfigKey::new(
+        "registry",
+        "Settings of the default registry.",
+        ConfigTable::Keys(&[
+            value(
+                "default",
+                "The registry used by commands without `--registry`.",
+            ),
+            value(
+                "credential-provider",
+                "The credential provider of crates.io.",
+            ),
+            value("token", "The authentication token of crates.io."),
+            value(
+                "global-credential-providers",
+                "The credential providers of registries without their own.",
+            ),
+        ]),
+    ),
+    ConfigKey::new(
+        "resolver",
+        "Settings of the dependency resolver.",
+        ConfigTable::Keys(&[one_of(
+            "incompatible-rust-versions",
+            "Whether dependencies requiring a newer Rust are avoided.",
+            &["allow", "fallback"],
+        )]),
+    ),
+    ConfigKey::new(
+        "source",
+        "Sources replacing other sources, such as vendored directories and mirrors.",
+        ConfigTable::Named(
+            ConfigName::Any("<name>"),
+            &ConfigTable::Keys(&[
+                value("replace-with", "The source replacing this one."),
+                value("directory", "A directory of vendored crates."),
+                value("registry", "The URL of a registry index."),
+                value("local-registry", "A directory of a local registry."),
+                value("git", "The URL of a git repository."),
+                value("branch", "The git branch to use."),
+                value("tag", "The git tag to use."),
+                value("rev", "The git revision to use."),
+            ]),
+        ),
+    ),
+    ConfigKey::new(
+        "target",
+        "Settings for a target triple or a `cfg(...)` expression.",
+        ConfigTable::Named(
+            ConfigName::Target,
+            &ConfigTable::Keys(&[
+                value("linker", "The linker, for target triples only."),
+                value(
+                    "runner",
+                    "The runner executing binaries, tests and benchmarks.",
+                ),
+                value(
+                    "rustflags",
+                    "Extra flags passed to compiler invocations for the target.",
+                ),
+                value(
+                    "rustdocflags",
+                    "Extra flags passed to `rustdoc` for the target.",
+                ),
+            ]),
+        ),
+    ),
+    ConfigKey::new(
+        "term",
+        "Settings of the terminal output.",
+        ConfigTable::Keys(&[
+            value("quiet", "Whether Cargo prints no log messages."),
+            value("verbose", "Whether Cargo prints more log messages."),
+            one_of(
+                "color",
+                "Whether the output is colored.",
+                &["auto", "always", "never"],
+            ),
+            value("hyperlinks", "Whether the output contains hyperlinks."),
+            value("unicode", "Whether the output contains Unicode characters."),
+            ConfigKey::new(
+                "progress",
+                "Settings of the progress bar.",
+                ConfigTable::Keys(&[
+                    one_of(
+                        "when",
+                        "Whether the progress bar is shown.",
+                        &["auto", "always", "never"],
+                    ),
+                    value("width", "The width of the progress bar."),
+                ]),
+            ),
+        ]),
+    ),
+    ConfigKey::new(
+        "unstable",
+        "Unstable `-Z` flags, on the nightly channel only.",
+        ConfigTable::Named(
+            ConfigName::Any("<flag>"),
+            &ConfigTable::Value(ConfigValue::Any),
+        ),
+    ),
+]);
+
+/// The kinds of the library search paths of `-L [KIND=]PATH`.
+const SEARCH_PATH_KINDS: &[&str] = &["dependency", "crate", "native", "framework", "all"];
+
+/// A position in the tree of configuration keys.
+struct ConfigPath {
+    table: &'static ConfigTable,
+    key: Option<&'static ConfigKey>,
+    title: Vec<&'static str>,
+}
+
+fn resolve_config_path(root: &'static ConfigTable, accessors: &[Accessor]) -> Option<ConfigPath> {
+    let mut path = ConfigPath {
+        table: root,
+        key: None,
+        title: Vec::new(),
+    };
+    for accessor in accessors {
+        let name = accessor.as_key()?;
+        match path.table {
+            ConfigTable::Keys(keys) => {
+                let key = keys.iter().find(|key| key.name == name)?;
+                path.table = &key.table;
+                path.key = Some(key);
+                path.title.push(key.name);
+            }
+            ConfigTable::Named(config_name, table) => {
+                path.table = table;
+                path.key = None;
+                path.title.push(config_name.placeholder());
+            }
+            ConfigTable::Value(_) => return None,
+        }
+    }
+
+    Some(path)
+}
+
+/// The accessors of the key holding the value, without the index of an array element.
+fn key_accessors(accessors: &[Accessor]) -> &[Accessor] {
+    match accessors.split_last() {
+        Some((Accessor::Index(_), key_accessors)) => key_accessors,
+        _ => accessors,
+    }
+}
+
+fn dig_table<'a>(
+    document_tree: &'a tombi_document_tree::DocumentTree,
+    accessors: &'a [Accessor],
+) -> Option<&'a Table> {
+    if accessors.is_empty() {
+        return Some(document_tree);
+    }
+    match dig_accessors(document_tree, accessors) {
+        Some((_, Value::Table(table))) => Some(table),
+        _ => None,
+    }
+}
+
+/// The table the completed key belongs to, the typed prefix of the key and the range it replaces.
+fn key_completion_context<'a>(
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &'a [Accessor],
+    completion_hint: Option<CompletionHint>,
+) -> Option<(&'a [Accessor], &'a str, tombi_text::Range)> {
+    let editing_key = |parent_accessors: &'a [Accessor], name: &'a str| {
+        let (key, _) = dig_table(document_tree, parent_accessors)?.get_key_value(name)?;
+        Some((parent_accessors, name, key.range()))
+    };
+
+    match completion_hint {
+        Some(CompletionHint::InTableHeader) => match accessors.split_last() {
+            Some((Accessor::Key(name), parent_accessors)) => {
+                let context = editing_key(parent_accessors, name)?;
+                if position > context.2.end {
+                    // The cursor is after a dot, as in `[target.]`.
+                    Some((accessors, "", tombi_text::Range::at(position)))
+                } else {
+                    Some(context)
+                }
+            }
+            Some(_) => None,
+            None => Some((accessors, "", tombi_text::Range::at(position))),
+        },
+        Some(CompletionHint::DotTrigger { .. }) => {
+            Some((accessors, "", tombi_text::Range::at(position)))
+        }
+        Some(CompletionHint::Comma { .. }) | None => match accessors.split_last() {
+            Some((Accessor::Key(name), parent_accessors)) => {
+                match dig_accessors(document_tree, accessors)? {
+                    (_, Value::Table(_)) => Some((accessors, "", tombi_text::Range::at(position))),
+                    (_, Value::Incomplete { .. }) => editing_key(parent_accessors, name),
+                    _ => None,
+                }
+            }
+            Some(_) => None,
+            None => Some((accessors, "", tombi_text::Range::at(position))),
+        },
+        Some(CompletionHint::EqualTrigger { .. } | CompletionHint::InArray { .. }) => None,
+    }
+}
+
+/// Complete the keys and values of a configuration file described by `root`.
+pub(crate) fn complete_config(
+    root: &'static ConfigTable,
+    priority_name: &str,
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+    completion_hint: Option<CompletionHint>,
+) -> Option<Vec<CompletionContent>> {
+    if let Some((parent_accessors, prefix, range)) =
+        key_completion_context(document_tree, position, accessors, completion_hint)
+    {
+        return complete_config_keys(
+            root,
+            priority_name,
+            document_tree,
+            parent_accessors,
+            prefix,
+            range,
+            completion_hint,
+        );
+    }
+
+    let path = resolve_config_path(root, key_accessors(accessors))?;
+    let ConfigTable::Value(config_value) = path.table else {
+        return None;
+    };
+    let detail = path.title.join(".");
+
+    let (string_range, existing_values) = match dig_accessors(document_tree, accessors) {
+        Some((_, Value::String(string))) => (Some(string.range()), Vec::new()),
+        Some((_, Value::Array(array))) => (
+            None,
+            array
+                .values()
+                .iter()
+                .filter_map(|value| match value {
+                    Value::String(string) => Some(string.value()),
+                    _ => None,
+                })
+                .collect_vec(),
+        ),
+        Some((_, Value::Incomplete { .. })) | None => (None, Vec::new()),
+        Some(_) => return None,
+    };
+
+    let values: Vec<&str> = match config_value {
+        ConfigValue::Any => return None,
+        ConfigValue::OneOf(values) => values.to_vec(),
+        ConfigValue::Target => built_in_target_triples().collect(),
+    };
+
+    let completions = values
+        .into_iter()
+        .filter(|value| !existing_values.contains(value))
+        .enumerate()
+        .map(|(index, value)| {
+            let label = format!("\"{value}\"");
+            let edit = match string_range {
+                Some(range) => CompletionEdit::new_string_literal_while_editing(&label, range),
+                None => CompletionEdit::new_literal(&label, position, completion_hint),
+            };
+            CompletionContent {
+                label,
+                kind: CompletionKind::String,
+                emoji_icon: Some('🦀'),
+                priority: CompletionContentPriority::Custom(format!(
+                    "10__{priority_name}_{index:>04}__"
+                )),
+                detail: Some(detail.clone()),
+                documentation: None,
+                filter_text: None,
+                schema_uri: None,
+                deprecated: None,
+                edit,
+                preselect: None,
+                in_comment: false,
+            }
+        })
+        .collect_vec();
+
+    (!completions.is_empty()).then_some(completions)
+}
+
+fn complete_config_keys(
+    root: &'static ConfigTable,
+    priority_name: &str,
+    document_tree: &tombi_document_tree::DocumentTree,
+    parent_accessors: &[Accessor],
+    prefix: &str,
+    range: tombi_text::Range,
+    completion_hint: Option<CompletionHint>,
+) -> Option<Vec<CompletionContent>> {
+    let path = resolve_config_path(root, parent_accessors)?;
+    let in_table_header = matches!(completion_hint, Some(CompletionHint::InTableHeader));
+
+    let candidates: Vec<(String, Option<&ConfigKey>)> = match path.table {
+        ConfigTable::Keys(keys) => keys
+            .iter()
+            // A table header cannot hold a value.
+            .filter(|key| !(in_table_header && matches!(key.table, ConfigTable::Value(_))))
+            .map(|key| (key.name.to_string(), Some(key)))
+            .collect(),
+        ConfigTable::Named(ConfigName::Target, _) => built_in_target_triples()
+            .map(|triple| (triple.to_string(), None))
+            .collect(),
+        ConfigTable::Named(ConfigName::Registry, _) => std::iter::once("crates-io".to_string())
+            .chain(match dig_keys(document_tree, &["registries"]) {
+                Some((_, Value::Table(registries))) => {
+                    registries.keys().map(|key| key.value.clone()).collect_vec()
+                }
+                _ => Vec::new(),
+            })
+            .map(|registry| (registry, None))
+            .collect(),
+        ConfigTable::Named(ConfigName::Any(_), _) | ConfigTable::Value(_) => return None,
+    };
+
+    let existing_keys = dig_table(document_tree, parent_accessors)
+        .map(|table| {
+            table
+                .keys()
+                .map(|key| key.value.as_str())
+                .filter(|key| *key != prefix)
+                .collect::<HashSet<_>>()
+        })
+        .unwrap_or_default();
+
+    let completions = candidates
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(prefix) && !existing_keys.contains(name.as_str()))
+        .enumerate()
+        .map(|(index, (name, key))| {
+            let detail = match key {
+                Some(key) => path.title.iter().chain([&key.name]).join("."),
+                None => path.title.join("."),
+            };
+            CompletionContent {
+                edit: CompletionEdit::new_key(&quote_key(&name), range, completion_hint),
+                label: name,
+                kind: CompletionKind::Key,
+                emoji_icon: Some('🦀'),
+                priority: CompletionContentPriority::Custom(format!(
+                    "10__{priority_name}_{index:>04}__"
+                )),
+                detail: Some(detail),
+                documentation: key.map(|key| key.description.to_string()),
+                filter_text: None,
+                schema_uri: None,
+                deprecated: None,
+                preselect: None,
+                in_comment: false,
+            }
+        })
+        .collect_vec();
+
+    (!completions.is_empty()).then_some(completions)
+}
+
+/// Quote a key that cannot be written as a bare key, such as `thumbv8m.main-none-eabi`.
+pub(crate) fn quote_key(name: &str) -> String {
+    if name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        name.to_string()
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+/// Complete the keys of `.cargo/config.toml`, and the target triples of `build.target` and `[target]`.
+pub(crate) fn complete_cargo_config(
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+    completion_hint: Option<CompletionHint>,
+) -> Option<Vec<CompletionContent>> {
+    complete_config(
+        &CARGO_CONFIG,
+        "cargo_config",
+        document_tree,
+        position,
+        accessors,
+        completion_hint,
+    )
+}
+
+/// Describe the key of `.cargo/config.toml` under the cursor.
+pub(crate) fn cargo_config_hover_metadata(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+) -> Option<HoverMetadata> {
+    let path = resolve_config_path(&CARGO_CONFIG, key_accessors(accessors))?;
+    let key = path.key?;
+
+    let mut description = key.description.to_string();
+    if let ConfigTable::Value(ConfigValue::Target) = key.table
+        && let Some((_, Value::String(target))) = dig_accessors(document_tree, accessors)
+    {
+        description.push_str("\n\n");
+        description.push_str(&target_triple_description(target.value()));
+    }
+
+    Some(HoverMetadata {
+        title: Some(HoverTextChange::Replace(path.title.join("."))),
+        description: Some(HoverTextChange::Replace(description)),
+    })
+}
+
+/// Go to the library search paths of `rustflags` and the vendored crates of `[source.<name>] directory`.
+///
+/// Relative paths are resolved from the directory containing `.cargo`,
+/// where Cargo runs the compiler for a workspace.
+pub(crate) fn goto_definition_for_cargo_config(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    config_path: &Path,
+) -> Vec<tombi_extension::Location> {
+    let Some(base_dir) = config_path.parent().and_then(Path::parent) else {
+        return Vec::new();
+    };
+
+    if matches_accessors!(accessors, ["source", _, "directory"]) {
+        let Some((_, Value::String(directory))) = dig_accessors(document_tree, accessors) else {
+            return Vec::new();
+        };
+        return vendored_crate_locations(&base_dir.join(directory.value()));
+    }
+
+    let flags_accessors = key_accessors(accessors);
+    if !(matches_accessors!(flags_accessors, ["build", "rustflags"])
+        || matches_accessors!(flags_accessors, ["build", "rustdocflags"])
+        || matches_accessors!(flags_accessors, ["target", _, "rustflags"])
+        || matches_accessors!(flags_accessors, ["target", _, "rustdocflags"]))
+    {
+        return Vec::new();
+    }
+    let flags = match dig_accessors(document_tree, flags_accessors) {
+        Some((_, Value::String(flags))) => flags.value().split_whitespace().collect_vec(),
+        Some((_, Value::Array(flags))) => flags
+            .values()
+            .iter()
+            .filter_map(|flag| match flag {
+                Value::String(flag) => Some(flag.value()),
+                _ => None,
+            })
+            .collect_vec(),
+        _ => return Vec::new(),
+    };
+
+    let search_paths = match accessors.last() {
+        Some(Accessor::Index(index)) => search_path_at(&flags, *index).into_iter().collect_vec(),
+        _ => (0..flags.len())
+            .filter_map(|index| search_path_at(&flags, index))
+            .unique()
+            .collect_vec(),
+    };
+
+    search_paths
+        .into_iter()
+        .map(|search_path| base_dir.join(search_path))
+        .filter(|path| path.exists())
+        .filter_map(|path| path_location(&path))
+        .collect()
+}
+
+/// The path of the `-L [KIND=]PATH` flag the flag at `index` belongs to.
+fn search_path_at<'a>(flags: &[&'a str], index: usize) -> Option<&'a str> {
+    let flag = *flags.get(index)?;
+    let search_path = if flag == "-L" {
+        *flags.get(index + 1)?
+    } else if index > 0 && flags[index - 1] == "-L" {
+        flag
+    } else {
+        flag.strip_prefix("-L")?
+    };
+
+    Some(match search_path.split_once('=') {
+        Some((kind, path)) if SEARCH_PATH_KINDS.contains(&kind) => path,
+        _ => search_path,
+    })
+}
+
+/// The manifests of the crates vendored in the directory, or the directory itself.
+fn vendored_crate_locations(directory: &Path) -> Vec<tombi_extension::Location> {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+    let manifests = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path().join("Cargo.toml"))
+        .filter(|manifest| manifest.is_file())
+        .sorted()
+        .collect_vec();
+
+    if manifests.is_empty() {
+        path_location(directory).into_iter().collect()
+    } else {
+        manifests
+            .iter()
+            .filter_map(|manifest| path_location(manifest))
+            .collect()
+    }
+}
+
+fn path_location(path: &Path) -> Option<tombi_extension::Location> {
+    let path = canonicalize_or_original(path.to_path_buf());
+    Some(tombi_extension::Location {
+        uri: tombi_uri::Uri::from_file_path(&path).ok()?,
+        range: tombi_text::Range::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::IntoDocumentTreeAndErrors;
+
+    use super::*;
+
+    fn key(value: &str) -> Accessor {
+        Accessor::Key(value.to_string())
+    }
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.into_document_tree_and_errors(Default::default()).tree
+    }
+
+    fn completion_labels(
+        source: &str,
+        position: tombi_text::Position,
+        accessors: &[Accessor],
+        completion_hint: Option<CompletionHint>,
+    ) -> Vec<String> {
+        complete_cargo_config(
+            &parse_document_tree(source),
+            position,
+            accessors,
+            completion_hint,
+        )
+        .unwrap_or_default()
+        .into_iter()
+        .map(|completion| completion.label)
+        .collect()
+    }
+
+    #[test]
+    fn completes_keys_of_tables() {
+        assert_eq!(
+            completion_labels(
+                "[build]\ntarget-dir = \"out\"\n",
+                tombi_text::Position::new(2, 0),
+                &[key("build")],
+                None,
+            )
+            .into_iter()
+            .take(4)
+            .collect_vec(),
+            vec!["jobs", "rustc", "rustc-wrapper", "rustc-workspace-wrapper"]
+        );
+        assert_eq!(
+            completion_labels(
+                "[build]\ntar\n",
+                tombi_text::Position::new(1, 3),
+                &[key("build"), key("tar")],
+                None,
+            ),
+            vec!["target", "target-dir"]
+        );
+        assert_eq!(
+            completion_labels(
+                "[ne]\n",
+                tombi_text::Position::new(0, 3),
+                &[key("ne")],
+                Some(CompletionHint::InTableHeader),
+            ),
+            vec!["net"]
+        );
+    }
+
+    #[test]
+    fn completes_target_triples() {
+        let triples = completion_labels(
+            "[target.x86_64-unknown-linux]\n",
+            tombi_text::Position::new(0, 28),
+            &[key("target"), key("x86_64-unknown-linux")],
+            Some(CompletionHint::InTableHeader),
+        );
+        assert!(triples.contains(&"x86_64-unknown-linux-gnu".to_string()));
+        assert!(
+            triples
+                .iter()
+                .all(|triple| triple.starts_with("x86_64-unknown-linux"))
+        );
+
+        let values = completion_labels(
+            "[build]\ntarget = \"\"\n",
+            tombi_text::Position::new(1, 10),
+            &[key("build"), key("target")],
+            None,
+        );
+        assert!(values.contains(&"\"aarch64-apple-darwin\"".to_string()));
+    }
+
+    #[test]
+    fn completes_patch_registries() {
+        assert_eq!(
+            completion_labels(
+                "[registries.internal]\nindex = \"sparse+https://example.com/\"\n\n[patch.]\n",
+                tombi_text::Position::new(3, 7),
+                &[key("patch")],
+                Some(CompletionHint::InTableHeader),
+            ),
+            vec!["crates-io", "internal"]
+        );
+    }
+
+    #[test]
+    fn describes_keys_on_hover() {
+        let document_tree = parse_document_tree("[build]\ntarget = \"wasm32-unknown-unknown\"\n");
+        let metadata =
+            cargo_config_hover_metadata(&document_tree, &[key("build"), key("target")]).unwrap();
+
+        assert_eq!(
+            metadata.title,
+            Some(HoverTextChange::Replace("build.target".to_string()))
+        );
+        let Some(HoverTextChange::Replace(description)) = metadata.description else {
+            panic!("hover should replace the description");
+        };
+        assert!(description.contains("- `target_arch = \"wasm32\"`"));
+
+        assert_eq!(
+            cargo_config_hover_metadata(
+                &document_tree,
+                &[
+                    key("target"),
+                    key("x86_64-unknown-linux-gnu"),
+                    key("linker")
+                ],
+            )
+            .and_then(|metadata| metadata.title),
+            Some(HoverTextChange::Replace(
+                "target.<triple>.linker".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn finds_search_paths_of_rustflags() {
+        let flags = ["-L", "native=libs", "-Lvendor/lib", "-C", "opt-level=3"];
+
+        assert_eq!(search_path_at(&flags, 0), Some("libs"));
+        assert_eq!(search_path_at(&flags, 1), Some("libs"));
+        assert_eq!(search_path_at(&flags, 2), Some("vendor/lib"));
+        assert_eq!(search_path_at(&flags, 4), None);
+    }
+
+    #[test]
+    fn goes_to_vendored_crates_and_search_paths() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join(".cargo")).unwrap();
+        std::fs::create_dir_all(root.join("vendor/serde")).unwrap();
+        std::fs::write(root.join("vendor/serde/Cargo.toml"), "[package]\n").unwrap();
+        std::fs::create_dir_all(root.join("libs")).unwrap();
+
+        let document_tree = parse_document_tree(
+            r#"
+[build]
+rustflags = ["-L", "native=libs", "-L", "missing"]
+
+[source.vendored-sources]
+directory = "vendor"
+"#,
+        );
+        let config_path = root.join(".cargo/config.toml");
+        let paths = |accessors: &[Accessor]| {
+            goto_definition_for_cargo_config(&document_tree, accessors, &config_path)
+                .into_iter()
+                .map(|location| {
+                    location
+                        .uri
+                        .to_file_path()
+                        .unwrap()
+                        .strip_prefix(root.canonicalize().unwrap())
+                        .unwrap_or_else(|_| Path::new("?"))
+                        .to_path_buf()
+                })
+                .collect_vec()
+        };
+
+        assert_eq!(
+            paths(&[key("build"), key("rustflags"), Accessor::Index(1)]),
+            vec![Path::new("libs")]
+        );
+        assert_eq!(
+            paths(&[key("build"), key("rustflags")]),
+            vec![Path::new("libs")]
+        );
+        assert_eq!(
+            paths(&[key("source"), key("vendored-sources"), key("directory")]),
+            vec![Path::new("vendor/serde/Cargo.toml")]
+        );
+    }
+}
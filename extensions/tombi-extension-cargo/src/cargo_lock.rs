@@ -13,6 +13,7 @@ const LOCKFILE_CACHE_KEY: &str = "cargo_lock";
 #[derive(Debug, Clone)]
 pub(crate) struct CargoLock {
     pub(crate) packages: Vec<CargoLockPackage>,
+    pub(crate) unused_patches: Vec<CargoLockPatch>,
     unique_package_versions: HashMap<String, Option<String>>,
 }
 
@@ -20,6 +21,9 @@ pub(crate) struct CargoLock {
 pub(crate) struct CargoLockPackage {
     pub(crate) name: String,
     pub(crate) version: String,
+    /// Where the package comes from, such as a registry. Workspace members have none.
+    #[serde(default)]
+    pub(crate) source: Option<String>,
     pub(crate) dependencies: Vec<CargoLockDependency>,
 }
 
@@ -29,9 +33,18 @@ pub(crate) struct CargoLockDependency {
     pub(crate) version: Option<String>,
 }
 
+/// A `[[patch.unused]]` entry, recorded when a `[patch]` matches no dependency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CargoLockPatch {
+    pub(crate) name: String,
+    pub(crate) version: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CachedCargoLock {
     packages: Vec<CargoLockPackage>,
+    #[serde(default)]
+    unused_patches: Vec<CargoLockPatch>,
 }
 
 pub(crate) async fn load_cached_cargo_lock(
@@ -50,7 +63,10 @@ pub(crate) async fn load_cached_cargo_lock(
 
     serde_json::from_value::<CachedCargoLock>(cache_value.as_ref().clone())
         .ok()
-        .map(|cache| CargoLock::new(cache.packages))
+        .map(|cache| CargoLock {
+            unused_patches: cache.unused_patches,
+            ..CargoLock::new(cache.packages)
+        })
 }
 
 pub(crate) fn load_cargo_lock_from_path(
@@ -82,11 +98,14 @@ impl CargoLock {
         let unique_package_versions = compute_unique_package_versions(&packages);
         Self {
             packages,
+            unused_patches: Vec::new(),
             unique_package_versions,
         }
     }
 
-    fn from_document_tree(document_tree: &tombi_document_tree::DocumentTree) -> Option<Self> {
+    pub(crate) fn from_document_tree(
+        document_tree: &tombi_document_tree::DocumentTree,
+    ) -> Option<Self> {
         let (_, Value::Array(packages)) = dig_keys(document_tree, &["package"])? else {
             return None;
         };
@@ -95,7 +114,18 @@ impl CargoLock {
             .iter()
             .filter_map(CargoLockPackage::from_value)
             .collect();
-        Some(Self::new(packages))
+        let unused_patches = match dig_keys(document_tree, &["patch", "unused"]) {
+            Some((_, Value::Array(unused_patches))) => unused_patches
+                .iter()
+                .filter_map(CargoLockPatch::from_value)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Some(Self {
+            unused_patches,
+            ..Self::new(packages)
+        })
     }
 
     pub(crate) fn into_parts(self) -> (Vec<CargoLockPackage>, HashMap<String, Option<String>>) {
@@ -128,6 +158,138 @@ impl CargoLock {
             .flatten()
             .map(str::to_string)
     }
+
+    /// The locked versions of `crate_name`, in ascending order.
+    pub(crate) fn package_versions(&self, crate_name: &str) -> Vec<&str> {
+        let mut versions = self
+            .packages
+            .iter()
+            .filter(|package| package.name == crate_name)
+            .map(|package| package.version.as_str())
+            .collect::<Vec<_>>();
+        versions.sort_by(|a, b| compare_versions(a, b));
+        versions.dedup();
+        versions
+    }
+
+    /// Whether `Cargo.lock` lists a patch of `crate_name` as unused.
+    pub(crate) fn is_unused_patch(&self, crate_name: &str) -> bool {
+        self.unused_patches
+            .iter()
+            .any(|patch| patch.name == crate_name)
+    }
+
+    /// The chains of locked packages pulling in `crate_name`, grouped by the version they resolve to.
+    ///
+    /// Each chain starts at a package depending on `crate_name` directly and follows
+    /// the shortest path of dependents up to the first workspace member.
+    pub(crate) fn dependents(&self, crate_name: &str) -> Vec<(&str, Vec<Vec<&CargoLockPackage>>)> {
+        let reverse_dependencies = self.reverse_dependencies();
+
+        self.package_versions(crate_name)
+            .into_iter()
+            .map(|version| {
+                let mut dependents = reverse_dependencies
+                    .get(&(crate_name, version))
+                    .cloned()
+                    .unwrap_or_default();
+                dependents.sort_by(|a, b| {
+                    a.name
+                        .cmp(&b.name)
+                        .then_with(|| compare_versions(&a.version, &b.version))
+                });
+                let chains = dependents
+                    .into_iter()
+                    .map(|package| dependent_chain(package, &reverse_dependencies))
+                    .collect();
+                (version, chains)
+            })
+            .collect()
+    }
+
+    /// The packages depending on each `(name, version)`.
+    fn reverse_dependencies(&self) -> HashMap<(&str, &str), Vec<&CargoLockPackage>> {
+        let mut reverse_dependencies = HashMap::<(&str, &str), Vec<&CargoLockPackage>>::new();
+
+        for package in &self.packages {
+            for dependency in &package.dependencies {
+                let Some(version) = dependency.version.as_deref().or_else(|| {
+                    self.unique_package_versions
+                        .get(&dependency.name)
+                        .and_then(|version| version.as_deref())
+                }) else {
+                    continue;
+                };
+                reverse_dependencies
+                    .entry((dependency.name.as_str(), version))
+                    .or_default()
+                    .push(package);
+            }
+        }
+
+        reverse_dependencies
+    }
+}
+
+/// The shortest chain of dependents from `package` up to a workspace member,
+/// or up to a package nothing depends on when no member is reached.
+fn dependent_chain<'a>(
+    package: &'a CargoLockPackage,
+    reverse_dependencies: &HashMap<(&'a str, &'a str), Vec<&'a CargoLockPackage>>,
+) -> Vec<&'a CargoLockPackage> {
+    let key = |package: &'a CargoLockPackage| (package.name.as_str(), package.version.as_str());
+
+    let chain_to = |last: &'a CargoLockPackage, reached_from: &HashMap<_, &'a CargoLockPackage>| {
+        let mut chain = vec![last];
+        while let Some(previous) = reached_from.get(&key(chain[chain.len() - 1])) {
+            chain.push(previous);
+        }
+        chain.reverse();
+        chain
+    };
+
+    // Breadth-first, remembering the package each dependent was reached from.
+    let mut reached_from = HashMap::<(&str, &str), &CargoLockPackage>::new();
+    let mut visited = HashSet::new();
+    visited.insert(key(package));
+    let mut queue = std::collections::VecDeque::from([package]);
+    let mut root = None;
+
+    while let Some(current) = queue.pop_front() {
+        if current.is_workspace_member() {
+            return chain_to(current, &reached_from);
+        }
+
+        let dependents = reverse_dependencies
+            .get(&key(current))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        if dependents.is_empty() {
+            root.get_or_insert(current);
+            continue;
+        }
+
+        for dependent in dependents {
+            if visited.insert(key(dependent)) {
+                reached_from.insert(key(dependent), current);
+                queue.push_back(dependent);
+            }
+        }
+    }
+
+    match root {
+        Some(root) => chain_to(root, &reached_from),
+        // Every dependent is part of a cycle.
+        None => vec![package],
+    }
+}
+
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
 }
 
 pub(crate) fn exact_crates_io_version(version_requirement: &str) -> Option<String> {
@@ -161,6 +323,7 @@ fn parse_cached_cargo_lock_json(
     let cargo_lock = load_cargo_lock_from_path(cargo_lock_path, toml_version)?;
     serde_json::to_value(CachedCargoLock {
         packages: cargo_lock.packages,
+        unused_patches: cargo_lock.unused_patches,
     })
     .ok()
 }
@@ -209,6 +372,10 @@ impl CargoLockPackage {
             Some(Value::String(version)) => version.value().to_string(),
             _ => return None,
         };
+        let source = match table.get("source") {
+            Some(Value::String(source)) => Some(source.value().to_string()),
+            _ => None,
+        };
         let dependencies = match table.get("dependencies") {
             Some(Value::Array(dependencies)) => dependencies
                 .iter()
@@ -220,11 +387,28 @@ impl CargoLockPackage {
         Some(Self {
             name,
             version,
+            source,
             dependencies,
         })
     }
 }
 
+impl CargoLockPatch {
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::Table(table) = value else {
+            return None;
+        };
+
+        match (table.get("name"), table.get("version")) {
+            (Some(Value::String(name)), Some(Value::String(version))) => Some(Self {
+                name: name.value().to_string(),
+                version: version.value().to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
 impl CargoLockDependency {
     fn from_value(value: &Value) -> Option<Self> {
         let Value::String(dependency) = value else {
@@ -234,7 +418,7 @@ impl CargoLockDependency {
         Some(Self::parse(dependency.value()))
     }
 
-    fn parse(value: &str) -> Self {
+    pub(crate) fn parse(value: &str) -> Self {
         let value = strip_lockfile_dependency_source(value.trim());
 
         if let Some((name, version)) = value.rsplit_once(' ')
@@ -262,6 +446,10 @@ fn strip_lockfile_dependency_source(value: &str) -> &str {
 }
 
 impl CargoLockPackage {
+    pub(crate) fn is_workspace_member(&self) -> bool {
+        self.source.is_none()
+    }
+
     pub(crate) fn lockfile_resolved_dependency_version<'a>(
         &'a self,
         dependency_name: &str,
@@ -324,12 +512,38 @@ mod tests {
         assert_eq!(dependency.version.as_deref(), Some("0.9.12+spec-1.1.0"));
     }
 
+    #[test]
+    fn parses_unused_patches() {
+        let root = tombi_ast::Root::cast(
+            tombi_parser::parse(
+                r#"
+[[package]]
+name = "serde"
+version = "1.0.219"
+
+[[patch.unused]]
+name = "tokio"
+version = "2.0.0"
+"#,
+            )
+            .into_syntax_node(),
+        )
+        .unwrap();
+        let document_tree = root.try_into_document_tree(TomlVersion::default()).unwrap();
+        let cargo_lock = CargoLock::from_document_tree(&document_tree).unwrap();
+
+        assert!(cargo_lock.is_unused_patch("tokio"));
+        assert!(!cargo_lock.is_unused_patch("serde"));
+        assert_eq!(cargo_lock.package_versions("serde"), vec!["1.0.219"]);
+    }
+
     #[test]
     fn resolves_unique_dependency_version_from_lockfile() {
         let cargo_lock = CargoLock::new(vec![
             CargoLockPackage {
                 name: "demo".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "criterion".to_string(),
                     version: Some("0.5.1".to_string()),
@@ -338,6 +552,7 @@ mod tests {
             CargoLockPackage {
                 name: "helper".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "criterion".to_string(),
                     version: None,
@@ -346,6 +561,7 @@ mod tests {
             CargoLockPackage {
                 name: "criterion".to_string(),
                 version: "0.5.1".to_string(),
+                source: None,
                 dependencies: Vec::new(),
             },
         ]);
@@ -362,6 +578,7 @@ mod tests {
             CargoLockPackage {
                 name: "demo".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "faststr".to_string(),
                     version: Some("0.2.1".to_string()),
@@ -370,6 +587,7 @@ mod tests {
             CargoLockPackage {
                 name: "helper".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "faststr".to_string(),
                     version: Some("0.2.2".to_string()),
@@ -386,6 +604,7 @@ mod tests {
             CargoLockPackage {
                 name: "demo".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "toml".to_string(),
                     version: Some("0.9.12+spec-1.1.0".to_string()),
@@ -394,6 +613,7 @@ mod tests {
             CargoLockPackage {
                 name: "toml".to_string(),
                 version: "0.9.12+spec-1.1.0".to_string(),
+                source: None,
                 dependencies: Vec::new(),
             },
         ]);
@@ -403,4 +623,137 @@ mod tests {
             Some("0.9.12+spec-1.1.0".to_string())
         );
     }
+
+    fn package(
+        name: &str,
+        version: &str,
+        dependencies: &[(&str, Option<&str>)],
+    ) -> CargoLockPackage {
+        CargoLockPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            source: Some("registry+https://github.com/rust-lang/crates.io-index".to_string()),
+            dependencies: dependencies
+                .iter()
+                .map(|(name, version)| CargoLockDependency {
+                    name: name.to_string(),
+                    version: version.map(str::to_string),
+                })
+                .collect(),
+        }
+    }
+
+    fn member(name: &str, dependencies: &[(&str, Option<&str>)]) -> CargoLockPackage {
+        CargoLockPackage {
+            source: None,
+            ..package(name, "0.1.0", dependencies)
+        }
+    }
+
+    #[test]
+    fn groups_dependents_by_resolved_version() {
+        let cargo_lock = CargoLock::new(vec![
+            package("demo", "0.1.0", &[("mio", Some("1.0.3")), ("tokio", None)]),
+            package("tokio", "1.45.0", &[("mio", Some("1.0.3"))]),
+            package("notify", "6.1.1", &[("mio", Some("0.8.11"))]),
+            package("mio", "0.8.11", &[]),
+            package("mio", "1.0.3", &[]),
+        ]);
+
+        assert_eq!(cargo_lock.package_versions("mio"), vec!["0.8.11", "1.0.3"]);
+        assert_eq!(
+            cargo_lock
+                .dependents("mio")
+                .into_iter()
+                .map(|(version, chains)| (
+                    version,
+                    chains
+                        .into_iter()
+                        .map(|chain| chain[0].name.as_str())
+                        .collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            vec![("0.8.11", vec!["notify"]), ("1.0.3", vec!["demo", "tokio"])]
+        );
+        assert_eq!(
+            cargo_lock
+                .dependents("tokio")
+                .into_iter()
+                .map(|(version, chains)| (version, chains.len()))
+                .collect::<Vec<_>>(),
+            vec![("1.45.0", 1)]
+        );
+    }
+
+    #[test]
+    fn follows_dependents_up_to_the_first_workspace_member() {
+        let cargo_lock = CargoLock::new(vec![
+            member("app", &[("hyper", None), ("serde", None)]),
+            member("cli", &[("app", None)]),
+            package("hyper", "1.6.0", &[("tokio", None)]),
+            package("tokio", "1.45.0", &[("mio", None)]),
+            package("mio", "1.0.3", &[]),
+            package("serde", "1.0.219", &[]),
+        ]);
+
+        let chains = cargo_lock
+            .dependents("mio")
+            .into_iter()
+            .map(|(version, chains)| {
+                (
+                    version,
+                    chains
+                        .into_iter()
+                        .map(|chain| {
+                            chain
+                                .into_iter()
+                                .map(|package| package.name.as_str())
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(chains, vec![("1.0.3", vec![vec!["tokio", "hyper", "app"]])]);
+    }
+
+    #[test]
+    fn follows_dependents_up_to_a_package_nothing_depends_on_without_members() {
+        let cargo_lock = CargoLock::new(vec![
+            package("hyper", "1.6.0", &[("tokio", None)]),
+            package("tokio", "1.45.0", &[("mio", None)]),
+            package("mio", "1.0.3", &[]),
+        ]);
+
+        let (_, chains) = cargo_lock.dependents("mio").into_iter().next().unwrap();
+        assert_eq!(
+            chains
+                .into_iter()
+                .map(|chain| chain
+                    .into_iter()
+                    .map(|package| package.name.as_str())
+                    .collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![vec!["tokio", "hyper"]]
+        );
+    }
+
+    #[test]
+    fn stops_following_dependents_at_a_cycle() {
+        let cargo_lock = CargoLock::new(vec![
+            package("a", "0.1.0", &[("b", None)]),
+            package("b", "0.1.0", &[("a", None), ("c", None)]),
+            package("c", "0.1.0", &[]),
+        ]);
+
+        let (_, chains) = cargo_lock.dependents("c").into_iter().next().unwrap();
+        assert_eq!(
+            chains
+                .into_iter()
+                .map(|chain| chain.len())
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
 }
@@ -8,8 +8,9 @@ use tower_lsp::lsp_types::{
 };
 
 use crate::{
-    dependency_parent_accessors, fetch_crates_io_crate, find_workspace_cargo_toml,
-    get_workspace_cargo_toml_path, is_any_dependency_accessor,
+    cargo_config::quote_key, dependency_package_name, dependency_parent_accessors,
+    fetch_crates_io_crate, find_workspace_cargo_toml, get_workspace_cargo_toml_path,
+    is_any_dependency_accessor,
 };
 
 pub enum CodeActionRefactorRewriteName {
@@ -118,6 +119,51 @@ pub enum CodeActionRefactorRewriteName {
     /// serde = "1.0.228"
     /// ```
     UpdateDependencyToLatestVersion,
+
+    /// Patch Dependency with Local Path
+    ///
+    /// Overrides the dependency with a local checkout in the workspace root Cargo.toml.
+    ///
+    /// Before
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// serde = "1.0"
+    /// ```
+    ///
+    /// After applying "Patch Dependency with Local Path"
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// serde = "1.0"
+    ///
+    /// [patch.crates-io]
+    /// serde = { path = "../serde" }
+    /// ```
+    PatchDependencyWithLocalPath,
+
+    /// Patch Dependency with Git Branch
+    ///
+    /// Overrides the dependency with a branch of its crates.io repository
+    /// in the workspace root Cargo.toml.
+    ///
+    /// Before
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// serde = "1.0"
+    /// ```
+    ///
+    /// After applying "Patch Dependency with Git Branch"
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// serde = "1.0"
+    ///
+    /// [patch.crates-io]
+    /// serde = { git = "https://github.com/serde-rs/serde", branch = "main" }
+    /// ```
+    PatchDependencyWithGitBranch,
 }
 
 impl std::fmt::Display for CodeActionRefactorRewriteName {
@@ -138,6 +184,12 @@ impl std::fmt::Display for CodeActionRefactorRewriteName {
             CodeActionRefactorRewriteName::UpdateDependencyToLatestVersion => {
                 write!(f, "Update Dependency to Latest Version")
             }
+            CodeActionRefactorRewriteName::PatchDependencyWithLocalPath => {
+                write!(f, "Patch Dependency with Local Path")
+            }
+            CodeActionRefactorRewriteName::PatchDependencyWithGitBranch => {
+                write!(f, "Patch Dependency with Git Branch")
+            }
         }
     }
 }
@@ -178,6 +230,7 @@ pub async fn code_action(
             code_actions_for_workspace_cargo_toml(
                 text_document_uri,
                 line_index,
+                root,
                 document_tree,
                 accessors,
                 offline,
@@ -211,6 +264,7 @@ pub async fn code_action(
 async fn code_actions_for_workspace_cargo_toml(
     text_document_uri: &tombi_uri::Uri,
     line_index: &tombi_text::LineIndex,
+    root: &tombi_ast::Root,
     document_tree: &tombi_document_tree::DocumentTree,
     accessors: &[Accessor],
     offline: bool,
@@ -258,13 +312,39 @@ async fn code_actions_for_workspace_cargo_toml(
         code_actions.push(CodeActionOrCommand::CodeAction(action));
     }
 
+    if code_action_features
+        .as_ref()
+        .and_then(|code_action| code_action.patch_dependency())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+    {
+        code_actions.extend(
+            patch_dependency_code_actions(
+                document_tree,
+                accessors,
+                PatchManifest {
+                    uri: text_document_uri,
+                    line_index,
+                    root,
+                    document_tree,
+                },
+                offline,
+                cache_options,
+            )
+            .await?
+            .into_iter()
+            .map(CodeActionOrCommand::CodeAction),
+        );
+    }
+
     Ok(code_actions)
 }
 
 async fn code_actions_for_crate_cargo_toml(
     text_document_uri: &tombi_uri::Uri,
     line_index: &tombi_text::LineIndex,
-    _root: &tombi_ast::Root,
+    root: &tombi_ast::Root,
     document_tree: &tombi_document_tree::DocumentTree,
     cargo_toml_path: &std::path::Path,
     accessors: &[Accessor],
@@ -279,6 +359,12 @@ async fn code_actions_for_crate_cargo_toml(
     let code_action_features = features
         .and_then(|features| features.lsp())
         .and_then(|lsp| lsp.code_action());
+    let patch_dependency_enabled = code_action_features
+        .as_ref()
+        .and_then(|code_action| code_action.patch_dependency())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value();
 
     if let Some((workspace_cargo_toml_path, workspace_root, workspace_document_tree)) =
         find_workspace_cargo_toml(
@@ -294,6 +380,29 @@ async fn code_actions_for_crate_cargo_toml(
         let workspace_line_index =
             tombi_text::LineIndex::new(&workspace_text, line_index.encoding_kind);
 
+        // Cargo only reads `[patch]` from the workspace root.
+        if patch_dependency_enabled
+            && let Ok(workspace_uri) = tombi_uri::Uri::from_file_path(&workspace_cargo_toml_path)
+        {
+            code_actions.extend(
+                patch_dependency_code_actions(
+                    document_tree,
+                    accessors,
+                    PatchManifest {
+                        uri: &workspace_uri,
+                        line_index: &workspace_line_index,
+                        root: &workspace_root,
+                        document_tree: &workspace_document_tree,
+                    },
+                    offline,
+                    cache_options,
+                )
+                .await?
+                .into_iter()
+                .map(CodeActionOrCommand::CodeAction),
+            );
+        }
+
         // Add workspace-specific code actions here
         if code_action_features
             .as_ref()
@@ -354,6 +463,24 @@ async fn code_actions_for_crate_cargo_toml(
         {
             code_actions.push(CodeActionOrCommand::CodeAction(action));
         }
+    } else if patch_dependency_enabled {
+        code_actions.extend(
+            patch_dependency_code_actions(
+                document_tree,
+                accessors,
+                PatchManifest {
+                    uri: text_document_uri,
+                    line_index,
+                    root,
+                    document_tree,
+                },
+                offline,
+                cache_options,
+            )
+            .await?
+            .into_iter()
+            .map(CodeActionOrCommand::CodeAction),
+        );
     }
 
     // Add crate-specific code actions here
@@ -476,6 +603,153 @@ async fn update_dependency_to_latest_version_code_action(
     }))
 }
 
+/// The `Cargo.toml` that receives `[patch]` entries.
+struct PatchManifest<'a> {
+    uri: &'a tombi_uri::Uri,
+    line_index: &'a tombi_text::LineIndex,
+    root: &'a tombi_ast::Root,
+    document_tree: &'a tombi_document_tree::DocumentTree,
+}
+
+/// Add a `[patch]` entry that overrides the dependency with a local path or a git branch.
+///
+/// The git branch action uses the repository published on crates.io,
+/// so it is only offered for crates.io dependencies.
+async fn patch_dependency_code_actions(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    patch_manifest: PatchManifest<'_>,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+) -> Result<Vec<CodeAction>, tower_lsp::jsonrpc::Error> {
+    if !is_any_dependency_accessor(accessors) {
+        return Ok(Vec::new());
+    }
+
+    let Some((Accessor::Key(dependency_key), dependency_value)) =
+        dig_accessors(document_tree, accessors)
+    else {
+        return Ok(Vec::new());
+    };
+
+    // The workspace defines the source of inherited dependencies.
+    let dependency_value = match dependency_value {
+        Value::Table(table)
+            if matches!(
+                table.get("workspace"),
+                Some(Value::Boolean(workspace)) if workspace.value()
+            ) =>
+        {
+            match dig_keys(
+                patch_manifest.document_tree,
+                &["workspace", "dependencies", dependency_key],
+            ) {
+                Some((_, value)) => value,
+                None => return Ok(Vec::new()),
+            }
+        }
+        _ => dependency_value,
+    };
+
+    let (patch_source, is_crates_io) = match dependency_value {
+        Value::Table(table) if table.get("path").is_some() => return Ok(Vec::new()),
+        Value::Table(table) => match (table.get("git"), table.get("registry")) {
+            (Some(Value::String(git)), _) => (git.value(), false),
+            (_, Some(Value::String(registry))) => (registry.value(), false),
+            _ => ("crates-io", true),
+        },
+        _ => ("crates-io", true),
+    };
+    let package_name = dependency_package_name(dependency_key, dependency_value);
+
+    if dig_keys(
+        patch_manifest.document_tree,
+        &["patch", patch_source, package_name],
+    )
+    .is_some()
+    {
+        return Ok(Vec::new());
+    }
+
+    let mut patches = vec![(
+        CodeActionRefactorRewriteName::PatchDependencyWithLocalPath,
+        format!("{{ path = \"../{package_name}\" }}"),
+    )];
+    if is_crates_io
+        && let Some(repository) = fetch_crates_io_crate(package_name, offline, cache_options)
+            .await?
+            .and_then(|response| response.crate_info.repository)
+    {
+        patches.push((
+            CodeActionRefactorRewriteName::PatchDependencyWithGitBranch,
+            format!("{{ git = \"{repository}\", branch = \"main\" }}"),
+        ));
+    }
+
+    Ok(patches
+        .into_iter()
+        .filter_map(|(name, patch_value_text)| {
+            let edit = generate_patch_edit(
+                patch_manifest.line_index,
+                patch_manifest.root,
+                patch_manifest.document_tree,
+                patch_source,
+                package_name,
+                &patch_value_text,
+            )?;
+
+            Some(CodeAction {
+                title: name.to_string(),
+                kind: Some(CodeActionKind::REFACTOR_REWRITE.clone()),
+                diagnostics: None,
+                edit: Some(WorkspaceEdit {
+                    changes: None,
+                    document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                        text_document: OptionalVersionedTextDocumentIdentifier {
+                            uri: patch_manifest.uri.to_owned().into(),
+                            version: None,
+                        },
+                        edits: vec![OneOf::Left(edit)],
+                    }])),
+                    change_annotations: None,
+                }),
+                ..Default::default()
+            })
+        })
+        .collect())
+}
+
+/// Generate TextEdit for adding an entry to `[patch.<source>]`, creating the table if needed.
+fn generate_patch_edit(
+    line_index: &tombi_text::LineIndex,
+    root: &tombi_ast::Root,
+    document_tree: &tombi_document_tree::DocumentTree,
+    patch_source: &str,
+    crate_name: &str,
+    patch_value_text: &str,
+) -> Option<TextEdit> {
+    if dig_keys(document_tree, &["patch", patch_source]).is_some() {
+        return generate_table_entry_edit(
+            line_index,
+            root,
+            document_tree,
+            &["patch", patch_source],
+            crate_name,
+            patch_value_text,
+        );
+    }
+
+    use tombi_ast::AstNode;
+
+    Some(TextEdit {
+        range: tombi_text::Range::at(root.syntax().range().end).into_lsp(line_index),
+        new_text: format!(
+            "\n[patch.{}]\n{crate_name} = {patch_value_text}\n",
+            quote_key(patch_source)
+        ),
+    })
+}
+
 /// Convert a package field to inherit from workspace configuration.
 ///
 /// Before
@@ -966,12 +1240,13 @@ fn add_to_workspace_and_inherit_dependency_code_action(
     };
 
     // Generate workspace edit for workspace.dependencies
-    let workspace_edit = generate_workspace_dependencies_edit(
+    let workspace_edit = generate_table_entry_edit(
         workspace_line_index,
         workspace_root,
         workspace_document_tree,
+        &["workspace", "dependencies"],
         crate_name,
-        crate_value,
+        &crate_value.to_string(),
     )?;
 
     // Generate member edit to convert to workspace inheritance.
@@ -1013,31 +1288,29 @@ fn add_to_workspace_and_inherit_dependency_code_action(
     })
 }
 
-/// Generate TextEdit for adding dependency to workspace.dependencies
-fn generate_workspace_dependencies_edit(
-    workspace_line_index: &tombi_text::LineIndex,
-    workspace_root: &tombi_ast::Root,
-    workspace_document_tree: &tombi_document_tree::DocumentTree,
+/// Generate TextEdit for adding a crate to a dependency table, such as workspace.dependencies
+fn generate_table_entry_edit(
+    line_index: &tombi_text::LineIndex,
+    root: &tombi_ast::Root,
+    document_tree: &tombi_document_tree::DocumentTree,
+    table_keys: &[&str],
     crate_name: &str,
-    crate_value: &tombi_document_tree::Value,
+    crate_value_text: &str,
 ) -> Option<TextEdit> {
-    // Get or prepare workspace.dependencies section
-    let workspace_deps = dig_keys(workspace_document_tree, &["workspace", "dependencies"]);
-
-    let Some((_, deps_table)) = workspace_deps else {
-        // NOTE: `workspace.dependencies` section doesn't exist, need to create it
+    // Get or prepare the dependency table
+    let Some((_, table_value)) = dig_keys(document_tree, table_keys) else {
+        // NOTE: The table doesn't exist, need to create it
         //       For now, return None - this will be handled in a future enhancement
         return None;
     };
 
-    let tombi_document_tree::Value::Table(table) = deps_table else {
+    let tombi_document_tree::Value::Table(table) = table_value else {
         return None;
     };
 
     // Get existing crate names and calculate insertion index
     let existing_crates: Vec<&str> = table.keys().map(|key| key.value.as_str()).collect();
     let insertion_index = calculate_insertion_index(&existing_crates, crate_name);
-    let crate_value_text = crate_value.to_string();
 
     // Find insertion position in the actual table
     let (insertion_range, new_text) = if table.kind() == TableKind::Table {
@@ -1069,11 +1342,8 @@ fn generate_workspace_dependencies_edit(
         )
     } else if matches!(table.kind(), TableKind::InlineTable { .. }) {
         // Handle InlineTable case using AST for accurate comma handling
-        let ast_inline_table = get_ast_inline_table_from_document_tree(
-            workspace_root,
-            workspace_document_tree,
-            &["workspace", "dependencies"],
-        )?;
+        let ast_inline_table =
+            get_ast_inline_table_from_document_tree(root, document_tree, table_keys)?;
 
         let new_entry_text = format!("{crate_name} = {crate_value_text}");
         let (insertion_pos, new_text) =
@@ -1085,7 +1355,7 @@ fn generate_workspace_dependencies_edit(
     };
 
     Some(TextEdit {
-        range: insertion_range.into_lsp(workspace_line_index),
+        range: insertion_range.into_lsp(line_index),
         new_text,
     })
 }
@@ -1227,6 +1497,7 @@ mod tests {
             line_offsets.push(acc);
             acc += line.len() + 1;
         }
+        line_offsets.push(acc);
 
         let start_line = edit.range.start.line as usize;
         let start_char = edit.range.start.character as usize;
@@ -1302,6 +1573,59 @@ serde.features = ["derive"]"#
         );
     }
 
+    fn generate_patch_edit_for_source(source: &str, patch_source: &str) -> String {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node())
+            .expect("expected root");
+        let document_tree = root
+            .clone()
+            .try_into_document_tree(tombi_config::TomlVersion::default())
+            .expect("expected document tree");
+        let line_index = LineIndex::new(source, EncodingKind::Utf16);
+
+        let edit = generate_patch_edit(
+            &line_index,
+            &root,
+            &document_tree,
+            patch_source,
+            "serde",
+            r#"{ path = "../serde" }"#,
+        )
+        .expect("expected edit");
+
+        apply_text_edit(source, &edit)
+    }
+
+    #[test]
+    fn generate_patch_edit_appends_patch_table() {
+        assert_eq!(
+            generate_patch_edit_for_source(
+                "[workspace]\nmembers = [\"app\"]\n",
+                "https://github.com/serde-rs/serde"
+            ),
+            r#"[workspace]
+members = ["app"]
+
+[patch."https://github.com/serde-rs/serde"]
+serde = { path = "../serde" }
+"#
+        );
+    }
+
+    #[test]
+    fn generate_patch_edit_inserts_into_existing_patch_table() {
+        assert_eq!(
+            generate_patch_edit_for_source(
+                "[patch.crates-io]\nanyhow = { path = \"../anyhow\" }\ntokio = { path = \"../tokio\" }\n",
+                "crates-io"
+            ),
+            r#"[patch.crates-io]
+anyhow = { path = "../anyhow" }
+serde = { path = "../serde" }
+tokio = { path = "../tokio" }
+"#
+        );
+    }
+
     #[test]
     fn generate_member_workspace_true_edit_returns_none_for_non_inline_table_without_version() {
         let (source, value, accessor_context) =
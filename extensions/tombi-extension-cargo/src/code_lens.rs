@@ -0,0 +1,279 @@
+use tombi_config::TomlVersion;
+use tombi_document_tree::{LikeString, Value, dig_keys};
+use tombi_extension::CodeLens;
+use tombi_hashmap::HashMap;
+
+use crate::{goto_workspace_member_crates, load_cargo_toml};
+
+const DEPENDENCY_KINDS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+enum CargoCodeLensFeature {
+    WorkspaceDependencyUsage,
+    DependencyFeatures,
+}
+
+pub async fn code_lens(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    toml_version: TomlVersion,
+    features: Option<&tombi_config::CargoExtensionFeatures>,
+) -> Result<Option<Vec<CodeLens>>, tower_lsp::jsonrpc::Error> {
+    if !text_document_uri.path().ends_with("Cargo.toml") {
+        return Ok(None);
+    }
+    let Ok(cargo_toml_path) = text_document_uri.to_file_path() else {
+        return Ok(None);
+    };
+
+    let mut code_lenses = Vec::new();
+
+    if cargo_code_lens_enabled(features, CargoCodeLensFeature::WorkspaceDependencyUsage) {
+        code_lenses.extend(workspace_dependency_usage_code_lenses(
+            document_tree,
+            &cargo_toml_path,
+            toml_version,
+        )?);
+    }
+
+    if cargo_code_lens_enabled(features, CargoCodeLensFeature::DependencyFeatures) {
+        code_lenses.extend(dependency_features_code_lenses(document_tree));
+    }
+
+    if code_lenses.is_empty() {
+        Ok(None)
+    } else {
+        code_lenses.sort_by_key(|code_lens| code_lens.range);
+        Ok(Some(code_lenses))
+    }
+}
+
+/// Show how many workspace members use each `[workspace.dependencies]` entry.
+fn workspace_dependency_usage_code_lenses(
+    workspace_document_tree: &tombi_document_tree::DocumentTree,
+    workspace_cargo_toml_path: &std::path::Path,
+    toml_version: TomlVersion,
+) -> Result<Vec<CodeLens>, tower_lsp::jsonrpc::Error> {
+    let Some((_, Value::Table(workspace_dependencies))) =
+        dig_keys(workspace_document_tree, &["workspace", "dependencies"])
+    else {
+        return Ok(Vec::new());
+    };
+
+    let mut usage_counts: HashMap<String, usize> = HashMap::new();
+    for crate_location in goto_workspace_member_crates(
+        workspace_document_tree,
+        &[],
+        workspace_cargo_toml_path,
+        toml_version,
+        "members",
+    )? {
+        let Some((_, crate_document_tree)) =
+            load_cargo_toml(&crate_location.cargo_toml_path, toml_version)
+        else {
+            continue;
+        };
+
+        let mut dependency_names = workspace_inherited_dependency_names(&crate_document_tree);
+        dependency_names.sort_unstable();
+        dependency_names.dedup();
+        for dependency_name in dependency_names {
+            *usage_counts.entry(dependency_name.to_string()).or_default() += 1;
+        }
+    }
+
+    Ok(workspace_dependencies
+        .keys()
+        .map(|dependency_key| {
+            let count = usage_counts
+                .get(dependency_key.value())
+                .copied()
+                .unwrap_or_default();
+            CodeLens {
+                range: dependency_key.range(),
+                title: match count {
+                    1 => "used by 1 member".to_string(),
+                    count => format!("used by {count} members"),
+                },
+                command: None,
+                arguments: None,
+            }
+        })
+        .collect())
+}
+
+/// The dependency keys with `workspace = true` in all the dependency tables of a member.
+fn workspace_inherited_dependency_names(
+    crate_document_tree: &tombi_document_tree::DocumentTree,
+) -> Vec<&str> {
+    dependency_tables(crate_document_tree)
+        .into_iter()
+        .flat_map(|dependencies| dependencies.key_values())
+        .filter(|(_, dependency_value)| {
+            matches!(
+                dependency_value,
+                Value::Table(table)
+                    if matches!(table.get("workspace"), Some(Value::Boolean(workspace)) if workspace.value())
+            )
+        })
+        .map(|(dependency_key, _)| dependency_key.value())
+        .collect()
+}
+
+/// Show how many features are enabled in the `features` array of each dependency.
+fn dependency_features_code_lenses(
+    document_tree: &tombi_document_tree::DocumentTree,
+) -> Vec<CodeLens> {
+    let mut tables = dependency_tables(document_tree);
+    if let Some((_, Value::Table(workspace_dependencies))) =
+        dig_keys(document_tree, &["workspace", "dependencies"])
+    {
+        tables.push(workspace_dependencies);
+    }
+
+    tables
+        .into_iter()
+        .flat_map(|dependencies| dependencies.key_values())
+        .filter_map(|(dependency_key, dependency_value)| {
+            let Value::Table(table) = dependency_value else {
+                return None;
+            };
+            let Some(Value::Array(features)) = table.get("features") else {
+                return None;
+            };
+            let count = features.values().len();
+
+            (count > 0).then(|| CodeLens {
+                range: dependency_key.range(),
+                title: match count {
+                    1 => "1 feature enabled".to_string(),
+                    count => format!("{count} features enabled"),
+                },
+                command: None,
+                arguments: None,
+            })
+        })
+        .collect()
+}
+
+/// The `[dependencies]`, `[dev-dependencies]` and `[build-dependencies]` tables,
+/// including the ones under `[target.<cfg>]`.
+fn dependency_tables(
+    document_tree: &tombi_document_tree::DocumentTree,
+) -> Vec<&tombi_document_tree::Table> {
+    let mut tables = Vec::new();
+    for dependency_kind in DEPENDENCY_KINDS {
+        if let Some((_, Value::Table(dependencies))) = dig_keys(document_tree, &[dependency_kind]) {
+            tables.push(dependencies);
+        }
+    }
+
+    if let Some((_, Value::Table(targets))) = dig_keys(document_tree, &["target"]) {
+        for target_value in targets.values() {
+            let Value::Table(target_table) = target_value else {
+                continue;
+            };
+            for dependency_kind in DEPENDENCY_KINDS {
+                if let Some(Value::Table(dependencies)) = target_table.get(dependency_kind) {
+                    tables.push(dependencies);
+                }
+            }
+        }
+    }
+
+    tables
+}
+
+fn cargo_code_lens_enabled(
+    features: Option<&tombi_config::CargoExtensionFeatures>,
+    feature: CargoCodeLensFeature,
+) -> bool {
+    features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.code_lens())
+        .and_then(|code_lens| match feature {
+            CargoCodeLensFeature::WorkspaceDependencyUsage => {
+                code_lens.workspace_dependency_usage()
+            }
+            CargoCodeLensFeature::DependencyFeatures => code_lens.dependency_features(),
+        })
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    fn titles(code_lenses: &[CodeLens]) -> Vec<&str> {
+        code_lenses
+            .iter()
+            .map(|code_lens| code_lens.title.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn shows_workspace_dependency_usage_and_dependency_features() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+
+        let workspace_source = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.dependencies]
+serde = { version = "1", features = ["derive"] }
+tokio = { version = "1", features = ["macros", "rt"] }
+unused = "1"
+"#;
+        std::fs::write(root.join("Cargo.toml"), workspace_source).unwrap();
+        for (name, dependencies) in [
+            (
+                "a",
+                "serde.workspace = true\n[dev-dependencies]\nserde = { workspace = true }\n",
+            ),
+            (
+                "b",
+                "serde = { workspace = true }\n[target.'cfg(unix)'.dependencies]\ntokio.workspace = true\n",
+            ),
+        ] {
+            let crate_dir = root.join("crates").join(name);
+            std::fs::create_dir_all(&crate_dir).unwrap();
+            std::fs::write(
+                crate_dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n\n[dependencies]\n{dependencies}"),
+            )
+            .unwrap();
+        }
+
+        let text_document_uri = tombi_uri::Uri::from_file_path(root.join("Cargo.toml")).unwrap();
+        let code_lenses = code_lens(
+            &text_document_uri,
+            &parse_document_tree(workspace_source),
+            TomlVersion::default(),
+            None,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            titles(&code_lenses),
+            vec![
+                "used by 2 members",
+                "1 feature enabled",
+                "used by 1 member",
+                "2 features enabled",
+                "used by 0 members",
+            ]
+        );
+    }
+}
@@ -18,8 +18,11 @@ use tombi_schema_store::matches_accessors;
 use tombi_version_sort::version_sort;
 use tower_lsp::lsp_types::InsertTextFormat;
 
+use crate::cargo_config::complete_cargo_config;
 use crate::cargo_lock::{exact_crates_io_version, load_cached_cargo_lock};
+use crate::rust_toolchain::complete_rust_toolchain;
 use crate::{
+    complete_lint_names, complete_target_cfg,
     crates_io::{
         CratesIoCrateVersionsResponse, CratesIoVersionDetailResponse, CratesIoVersionsResponse,
     },
@@ -28,9 +31,13 @@ use crate::{
 };
 
 enum CargoCompletionFeature {
+    CargoConfig,
     DependencyVersion,
     DependencyFeature,
+    Lint,
     Path,
+    RustToolchain,
+    TargetCfg,
 }
 
 pub async fn completion(
@@ -45,7 +52,9 @@ pub async fn completion(
     cache_options: Option<&tombi_cache::Options>,
     features: Option<&tombi_config::CargoExtensionFeatures>,
 ) -> Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error> {
-    if !text_document_uri.path().ends_with("Cargo.toml") {
+    let is_cargo_config = text_document_uri.path().ends_with(".cargo/config.toml");
+    let is_rust_toolchain = text_document_uri.path().ends_with("rust-toolchain.toml");
+    if !(text_document_uri.path().ends_with("Cargo.toml") || is_cargo_config || is_rust_toolchain) {
         return Ok(None);
     }
 
@@ -63,6 +72,36 @@ pub async fn completion(
         return Ok(None);
     }
 
+    if is_cargo_config {
+        return Ok(
+            cargo_completion_enabled(features, CargoCompletionFeature::TargetCfg)
+                .then(|| complete_target_cfg(document_tree, position, accessors))
+                .flatten()
+                .or_else(|| {
+                    cargo_completion_enabled(features, CargoCompletionFeature::CargoConfig)
+                        .then(|| {
+                            complete_cargo_config(
+                                document_tree,
+                                position,
+                                accessors,
+                                completion_hint,
+                            )
+                        })
+                        .flatten()
+                }),
+        );
+    }
+
+    if is_rust_toolchain {
+        return Ok(
+            cargo_completion_enabled(features, CargoCompletionFeature::RustToolchain)
+                .then(|| {
+                    complete_rust_toolchain(document_tree, position, accessors, completion_hint)
+                })
+                .flatten(),
+        );
+    }
+
     if let Some(completions) = cargo_completion_enabled(features, CargoCompletionFeature::Path)
         .then(|| completion_cargo_file_path(text_document_uri, document_tree, position, accessors))
         .flatten()
@@ -70,6 +109,20 @@ pub async fn completion(
         return Ok(Some(completions));
     }
 
+    if let Some(completions) = cargo_completion_enabled(features, CargoCompletionFeature::Lint)
+        .then(|| complete_lint_names(document_tree, position, accessors, completion_hint))
+        .flatten()
+    {
+        return Ok(Some(completions));
+    }
+
+    if let Some(completions) = cargo_completion_enabled(features, CargoCompletionFeature::TargetCfg)
+        .then(|| complete_target_cfg(document_tree, position, accessors))
+        .flatten()
+    {
+        return Ok(Some(completions));
+    }
+
     let cargo_toml_path = std::path::Path::new(text_document_uri.path());
 
     if let Some(Accessor::Key(first)) = accessors.first() {
@@ -394,9 +447,13 @@ fn cargo_completion_enabled(
         .and_then(|features| features.lsp())
         .and_then(|lsp| lsp.completion())
         .and_then(|completion| match feature {
+            CargoCompletionFeature::CargoConfig => completion.cargo_config(),
             CargoCompletionFeature::DependencyVersion => completion.dependency_version(),
             CargoCompletionFeature::DependencyFeature => completion.dependency_feature(),
+            CargoCompletionFeature::Lint => completion.lint(),
             CargoCompletionFeature::Path => completion.path(),
+            CargoCompletionFeature::RustToolchain => completion.rust_toolchain(),
+            CargoCompletionFeature::TargetCfg => completion.target_cfg(),
         })
         .and_then(|feature| feature.enabled)
         .unwrap_or_default()
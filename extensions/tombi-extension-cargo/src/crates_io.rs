@@ -13,6 +13,7 @@ pub(crate) struct CratesIoCrate {
     pub(crate) name: Option<String>,
     pub(crate) description: Option<String>,
     pub(crate) max_version: Option<String>,
+    pub(crate) repository: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
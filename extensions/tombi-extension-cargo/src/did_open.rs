@@ -498,9 +498,13 @@ mod tests {
             lsp: Some(CargoLspFeatures::Features(CargoLspFeatureTree {
                 completion: Some(CargoCompletionFeatures::Features(
                     CargoCompletionFeatureTree {
+                        cargo_config: Some(disabled_toggle()),
                         dependency_version: Some(disabled_toggle()),
                         dependency_feature: Some(disabled_toggle()),
+                        lint: Some(disabled_toggle()),
                         path: Some(disabled_toggle()),
+                        rust_toolchain: Some(disabled_toggle()),
+                        target_cfg: Some(disabled_toggle()),
                     },
                 )),
                 inlay_hint: Some(CargoInlayHintFeatures::Features(
@@ -511,9 +515,14 @@ mod tests {
                     },
                 )),
                 hover: Some(CargoHoverFeatures::Features(CargoHoverFeatureTree {
+                    cargo_config: Some(disabled_toggle()),
                     dependency_detail: Some(disabled_toggle()),
+                    dependents: Some(disabled_toggle()),
                     default_features: None,
                     feature_dependencies: Some(disabled_toggle()),
+                    lint: Some(disabled_toggle()),
+                    patch: Some(disabled_toggle()),
+                    target_cfg: Some(disabled_toggle()),
                 })),
                 ..Default::default()
             })),
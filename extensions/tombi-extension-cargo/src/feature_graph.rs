@@ -0,0 +1,388 @@
+use serde::Deserialize;
+use tombi_document_tree::{Value, dig_keys};
+use tombi_hashmap::HashSet;
+use tombi_schema_store::Accessor;
+
+use crate::feature_navigation::{
+    CargoFeatureRef, dependency_entries, has_explicit_dep_feature, parse_cargo_feature_ref,
+};
+
+/// The custom request for the feature graph of a `Cargo.toml`.
+pub const CARGO_FEATURE_GRAPH_METHOD: &str = "tombi/cargoFeatureGraph";
+
+/// The params of the `tombi/cargoFeatureGraph` request, other than `textDocument`.
+#[derive(Debug, Default, Clone, Copy, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CargoFeatureGraphParams {
+    #[serde(default)]
+    pub format: CargoFeatureGraphFormat,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum CargoFeatureGraphResponse {
+    Json(CargoFeatureGraph),
+    Dot { dot: String },
+}
+
+/// The output format of the `tombi/cargoFeatureGraph` request.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CargoFeatureGraphFormat {
+    #[default]
+    Json,
+    Dot,
+}
+
+/// The features of a package and what each of them enables.
+///
+/// Node IDs use the syntax of `[features]` values:
+/// `name` for features, `dep:name` for optional dependencies
+/// and `name/feature` for features of dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CargoFeatureGraph {
+    pub package: Option<String>,
+    pub nodes: Vec<CargoFeatureGraphNode>,
+    pub edges: Vec<CargoFeatureGraphEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CargoFeatureGraphNode {
+    pub id: String,
+    pub kind: CargoFeatureGraphNodeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CargoFeatureGraphNodeKind {
+    Feature,
+    /// The feature that Cargo defines for an optional dependency never named with `dep:`.
+    ImplicitFeature,
+    OptionalDependency,
+    DependencyFeature,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CargoFeatureGraphEdge {
+    pub from: String,
+    pub to: String,
+    /// Whether the edge comes from `name?/feature`, which does not enable the dependency.
+    pub weak: bool,
+}
+
+/// Answer the `tombi/cargoFeatureGraph` request for a `Cargo.toml`.
+pub fn feature_graph_request(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    params: &serde_json::Value,
+) -> Result<Option<CargoFeatureGraphResponse>, tower_lsp::jsonrpc::Error> {
+    if !text_document_uri.path().ends_with("Cargo.toml") {
+        return Ok(None);
+    }
+
+    let CargoFeatureGraphParams { format } = CargoFeatureGraphParams::deserialize(params)
+        .map_err(|error| tower_lsp::jsonrpc::Error::invalid_params(error.to_string()))?;
+    let graph = feature_graph(document_tree);
+
+    Ok(Some(match format {
+        CargoFeatureGraphFormat::Json => CargoFeatureGraphResponse::Json(graph),
+        CargoFeatureGraphFormat::Dot => CargoFeatureGraphResponse::Dot {
+            dot: graph.to_dot(),
+        },
+    }))
+}
+
+/// Build the feature graph of the package in a `Cargo.toml`.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/features.html
+pub fn feature_graph(document_tree: &tombi_document_tree::DocumentTree) -> CargoFeatureGraph {
+    let mut graph = CargoFeatureGraphBuilder::default();
+
+    let optional_dependencies = optional_dependency_keys(document_tree);
+    let features = match dig_keys(document_tree, &["features"]) {
+        Some((_, Value::Table(features))) => Some(features),
+        _ => None,
+    };
+
+    if let Some(features) = features {
+        for feature_key in features.keys() {
+            graph.add_node(&feature_key.value, CargoFeatureGraphNodeKind::Feature);
+        }
+    }
+    for dependency_key in &optional_dependencies {
+        if !has_explicit_dep_feature(document_tree, dependency_key) {
+            graph.add_node(dependency_key, CargoFeatureGraphNodeKind::ImplicitFeature);
+            graph.add_edge(dependency_key, &format!("dep:{dependency_key}"), false);
+        }
+    }
+
+    for (feature_key, feature_values) in features
+        .into_iter()
+        .flat_map(|features| features.key_values())
+    {
+        let Value::Array(feature_values) = feature_values else {
+            continue;
+        };
+        for feature_value in feature_values.values() {
+            let Value::String(feature_string) = feature_value else {
+                continue;
+            };
+
+            match parse_cargo_feature_ref(feature_string.value()) {
+                CargoFeatureRef::LocalFeature(feature) => {
+                    graph.add_node(feature, CargoFeatureGraphNodeKind::Feature);
+                    graph.add_edge(&feature_key.value, feature, false);
+                }
+                CargoFeatureRef::OptionalDependency(dependency) => {
+                    graph.add_edge(&feature_key.value, &format!("dep:{dependency}"), false);
+                }
+                CargoFeatureRef::DependencyFeature {
+                    dep_key,
+                    feature,
+                    weak,
+                } => {
+                    let dependency_feature = format!("{dep_key}/{feature}");
+                    graph.add_node(
+                        &dependency_feature,
+                        CargoFeatureGraphNodeKind::DependencyFeature,
+                    );
+                    graph.add_edge(&feature_key.value, &dependency_feature, weak);
+                    // `name/feature` also enables an optional dependency.
+                    if !weak && optional_dependencies.iter().any(|key| key == dep_key) {
+                        graph.add_edge(&dependency_feature, &format!("dep:{dep_key}"), false);
+                    }
+                }
+            }
+        }
+    }
+
+    for dependency_key in &optional_dependencies {
+        graph.add_node(
+            &format!("dep:{dependency_key}"),
+            CargoFeatureGraphNodeKind::OptionalDependency,
+        );
+    }
+
+    CargoFeatureGraph {
+        package: match dig_keys(document_tree, &["package", "name"]) {
+            Some((_, Value::String(name))) => Some(name.value().to_string()),
+            _ => None,
+        },
+        nodes: graph.nodes,
+        edges: graph.edges,
+    }
+}
+
+impl CargoFeatureGraph {
+    /// Render the graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec![format!(
+            "digraph {} {{",
+            dot_id(self.package.as_deref().unwrap_or("features"))
+        )];
+        for node in &self.nodes {
+            let attributes = match node.kind {
+                CargoFeatureGraphNodeKind::Feature => "shape=box",
+                CargoFeatureGraphNodeKind::ImplicitFeature => "shape=box, style=dashed",
+                CargoFeatureGraphNodeKind::OptionalDependency => "shape=ellipse",
+                CargoFeatureGraphNodeKind::DependencyFeature => "shape=note",
+            };
+            lines.push(format!("    {} [{attributes}];", dot_id(&node.id)));
+        }
+        for edge in &self.edges {
+            lines.push(format!(
+                "    {} -> {}{};",
+                dot_id(&edge.from),
+                dot_id(&edge.to),
+                if edge.weak { " [style=dashed]" } else { "" }
+            ));
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+fn dot_id(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The keys of the optional dependencies, in the order they are declared.
+fn optional_dependency_keys(document_tree: &tombi_document_tree::DocumentTree) -> Vec<String> {
+    let mut keys = Vec::new();
+    for (accessors, dependency_value) in dependency_entries(document_tree) {
+        if matches!(accessors.first(), Some(Accessor::Key(key)) if key == "workspace") {
+            continue;
+        }
+        let Some(Accessor::Key(dependency_key)) = accessors.last() else {
+            continue;
+        };
+        let Value::Table(dependency_table) = dependency_value else {
+            continue;
+        };
+        let optional = matches!(
+            dependency_table.get("optional"),
+            Some(Value::Boolean(optional)) if optional.value()
+        );
+        if optional && !keys.contains(dependency_key) {
+            keys.push(dependency_key.clone());
+        }
+    }
+    keys
+}
+
+#[derive(Default)]
+struct CargoFeatureGraphBuilder {
+    nodes: Vec<CargoFeatureGraphNode>,
+    node_ids: HashSet<String>,
+    edges: Vec<CargoFeatureGraphEdge>,
+}
+
+impl CargoFeatureGraphBuilder {
+    fn add_node(&mut self, id: &str, kind: CargoFeatureGraphNodeKind) {
+        if self.node_ids.insert(id.to_string()) {
+            self.nodes.push(CargoFeatureGraphNode {
+                id: id.to_string(),
+                kind,
+            });
+        }
+    }
+
+    fn add_edge(&mut self, from: &str, to: &str, weak: bool) {
+        let edge = CargoFeatureGraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            weak,
+        };
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_config::TomlVersion;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    #[test]
+    fn builds_feature_graph_with_optional_and_dependency_features() {
+        let graph = feature_graph(&parse_document_tree(
+            r#"
+[package]
+name = "app"
+
+[dependencies]
+serde = { version = "1", optional = true }
+tokio = { version = "1", optional = true }
+log = "0.4"
+
+[features]
+default = ["std"]
+std = ["dep:serde", "serde/std", "log/std"]
+rt = ["tokio?/rt"]
+"#,
+        ));
+
+        assert_eq!(graph.package.as_deref(), Some("app"));
+        assert_eq!(
+            graph
+                .nodes
+                .iter()
+                .map(|node| (node.id.as_str(), node.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                ("default", CargoFeatureGraphNodeKind::Feature),
+                ("std", CargoFeatureGraphNodeKind::Feature),
+                ("rt", CargoFeatureGraphNodeKind::Feature),
+                ("tokio", CargoFeatureGraphNodeKind::ImplicitFeature),
+                ("serde/std", CargoFeatureGraphNodeKind::DependencyFeature),
+                ("log/std", CargoFeatureGraphNodeKind::DependencyFeature),
+                ("tokio/rt", CargoFeatureGraphNodeKind::DependencyFeature),
+                ("dep:serde", CargoFeatureGraphNodeKind::OptionalDependency),
+                ("dep:tokio", CargoFeatureGraphNodeKind::OptionalDependency),
+            ]
+        );
+        assert_eq!(
+            graph
+                .edges
+                .iter()
+                .map(|edge| (edge.from.as_str(), edge.to.as_str(), edge.weak))
+                .collect::<Vec<_>>(),
+            vec![
+                ("tokio", "dep:tokio", false),
+                ("default", "std", false),
+                ("std", "dep:serde", false),
+                ("std", "serde/std", false),
+                ("serde/std", "dep:serde", false),
+                ("std", "log/std", false),
+                ("rt", "tokio/rt", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_feature_graph_as_dot() {
+        let graph = feature_graph(&parse_document_tree(
+            r#"
+[package]
+name = "app"
+
+[features]
+default = ["std"]
+std = []
+"#,
+        ));
+
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph "app" {
+    "default" [shape=box];
+    "std" [shape=box];
+    "default" -> "std";
+}"#
+        );
+    }
+
+    #[test]
+    fn feature_graph_request_answers_only_cargo_toml() {
+        let document_tree = parse_document_tree("[features]\ndefault = []\n");
+        let uri = |path: &str| tombi_uri::Uri::from_file_path(path).unwrap();
+
+        assert!(
+            feature_graph_request(
+                &uri("/project/pyproject.toml"),
+                &document_tree,
+                &serde_json::json!({})
+            )
+            .unwrap()
+            .is_none()
+        );
+        assert!(
+            feature_graph_request(
+                &uri("/project/Cargo.toml"),
+                &document_tree,
+                &serde_json::json!({ "format": "svg" })
+            )
+            .is_err()
+        );
+        assert!(matches!(
+            feature_graph_request(
+                &uri("/project/Cargo.toml"),
+                &document_tree,
+                &serde_json::json!({ "format": "dot" })
+            ),
+            Ok(Some(CargoFeatureGraphResponse::Dot { .. }))
+        ));
+    }
+}
@@ -449,7 +449,7 @@ fn load_resolved_dependency(
     })
 }
 
-fn has_explicit_dep_feature(
+pub(crate) fn has_explicit_dep_feature(
     document_tree: &tombi_document_tree::DocumentTree,
     dep_key: &str,
 ) -> bool {
@@ -673,7 +673,7 @@ fn resolve_dependency_feature_string_target(
     })
 }
 
-fn dependency_entries(
+pub(crate) fn dependency_entries(
     document_tree: &tombi_document_tree::DocumentTree,
 ) -> Vec<(Vec<Accessor>, &tombi_document_tree::Value)> {
     let mut entries = Vec::new();
@@ -1,12 +1,14 @@
 use crate::{
-    CargoNavigationFeature, classify_cargo_navigation_feature, dependency_feature_string_context,
+    CargoNavigationFeature, cargo_config::goto_definition_for_cargo_config,
+    classify_cargo_navigation_feature, dependency_feature_string_context,
     dependency_parent_accessors, feature_table_string_at_accessors, find_workspace_cargo_toml,
     get_workspace_cargo_toml_path, goto_definition_for_workspace_cargo_toml,
     goto_workspace_managed_dependency_locations, is_dependency_accessor, is_feature_key_accessor,
     is_optional_dependency, is_optional_dependency_accessor, is_package_name_accessor,
-    is_workspace_definition_accessor, is_workspace_dependency_accessor, is_workspace_flag_accessor,
-    is_workspace_managed_dependency_accessor, resolve_dependency_feature_string,
-    resolve_feature_table_string,
+    is_patch_accessor, is_patch_path_accessor, is_workspace_definition_accessor,
+    is_workspace_dependency_accessor, is_workspace_flag_accessor,
+    is_workspace_managed_dependency_accessor, lockfile::goto_definition_for_cargo_lock,
+    patch::goto_patch_locations, resolve_dependency_feature_string, resolve_feature_table_string,
 };
 use tombi_config::TomlVersion;
 use tombi_document_tree::{Value, dig_accessors, dig_keys};
@@ -19,6 +21,34 @@ pub async fn goto_definition(
     toml_version: TomlVersion,
     features: Option<&tombi_config::CargoExtensionFeatures>,
 ) -> Result<Option<Vec<tombi_extension::Location>>, tower_lsp::jsonrpc::Error> {
+    if text_document_uri.path().ends_with("Cargo.lock") {
+        let Ok(cargo_lock_path) = text_document_uri.to_file_path() else {
+            return Ok(Default::default());
+        };
+        if !cargo_lock_goto_definition_enabled(features) {
+            return Ok(None);
+        }
+        let locations = goto_definition_for_cargo_lock(
+            text_document_uri,
+            document_tree,
+            accessors,
+            &cargo_lock_path,
+            toml_version,
+        )?;
+        return Ok((!locations.is_empty()).then_some(locations));
+    }
+
+    if text_document_uri.path().ends_with(".cargo/config.toml") {
+        let Ok(config_path) = text_document_uri.to_file_path() else {
+            return Ok(Default::default());
+        };
+        if !cargo_config_goto_definition_enabled(features) {
+            return Ok(None);
+        }
+        let locations = goto_definition_for_cargo_config(document_tree, accessors, &config_path);
+        return Ok((!locations.is_empty()).then_some(locations));
+    }
+
     // Check if current file is Cargo.toml
     if !text_document_uri.path().ends_with("Cargo.toml") {
         return Ok(Default::default());
@@ -75,6 +105,8 @@ pub async fn goto_definition(
             toml_version,
             false,
         )?
+    } else if is_patch_accessor(accessors) || is_patch_path_accessor(accessors) {
+        goto_patch_locations(document_tree, accessors, &cargo_toml_path, toml_version)
     } else if is_workspace_managed_dependency_accessor(document_tree, accessors) {
         goto_workspace_dependency_locations(
             document_tree,
@@ -279,3 +311,29 @@ fn cargo_goto_definition_enabled(
         .unwrap_or_default()
         .value()
 }
+
+/// Navigation in `Cargo.lock` always resolves dependencies.
+fn cargo_lock_goto_definition_enabled(
+    features: Option<&tombi_config::CargoExtensionFeatures>,
+) -> bool {
+    features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.goto_definition())
+        .and_then(|goto_definition| goto_definition.dependency())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+}
+
+/// Navigation in `.cargo/config.toml` always resolves paths.
+fn cargo_config_goto_definition_enabled(
+    features: Option<&tombi_config::CargoExtensionFeatures>,
+) -> bool {
+    features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.goto_definition())
+        .and_then(|goto_definition| goto_definition.path())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+}
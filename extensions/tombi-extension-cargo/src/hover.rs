@@ -9,15 +9,22 @@ use tombi_hashmap::HashMap;
 use tombi_schema_store::{Accessor, matches_accessors};
 
 use crate::{
-    cargo_lock::{exact_crates_io_version, load_cached_cargo_lock},
+    cargo_config::cargo_config_hover_metadata,
+    cargo_lock::{CargoLockPackage, exact_crates_io_version, load_cached_cargo_lock},
     collect_feature_usage_locations,
     crates_io::CratesIoVersionDetailResponse,
     dependency_package_name, feature_key_at_accessors, feature_usage_target_for_feature_key,
     fetch_crates_io_crate, find_cargo_toml, find_workspace_cargo_toml,
-    get_workspace_cargo_toml_path, is_any_dependency_accessor, load_cargo_toml,
-    sanitize_dependency_key,
+    get_workspace_cargo_toml_path, is_any_dependency_accessor, is_patch_accessor,
+    lint_hover_metadata, load_cargo_toml,
+    lockfile::cargo_lock_hover_metadata,
+    patch::patch_hover_description,
+    sanitize_dependency_key, target_cfg_hover_metadata,
 };
 
+/// The maximum number of dependent chains listed per locked version on hover.
+const MAX_HOVER_DEPENDENTS: usize = 20;
+
 pub async fn hover(
     text_document_uri: &tombi_uri::Uri,
     document_tree: &tombi_document_tree::DocumentTree,
@@ -27,17 +34,65 @@ pub async fn hover(
     offline: bool,
     cache_options: Option<&tombi_cache::Options>,
     dependency_detail_hover_enabled: bool,
+    dependents_hover_enabled: bool,
     feature_dependencies_hover_enabled: bool,
     default_features_hover_enabled: bool,
+    lint_hover_enabled: bool,
+    target_cfg_hover_enabled: bool,
+    cargo_config_hover_enabled: bool,
+    patch_hover_enabled: bool,
 ) -> Result<Option<HoverMetadata>, tower_lsp::jsonrpc::Error> {
+    if text_document_uri.path().ends_with("Cargo.lock") {
+        if !dependents_hover_enabled {
+            return Ok(None);
+        }
+        return Ok(cargo_lock_hover_metadata(document_tree, accessors));
+    }
+
+    if text_document_uri.path().ends_with(".cargo/config.toml") {
+        if target_cfg_hover_enabled && let Some(metadata) = target_cfg_hover_metadata(accessors) {
+            return Ok(Some(metadata));
+        }
+        if !cargo_config_hover_enabled {
+            return Ok(None);
+        }
+        return Ok(cargo_config_hover_metadata(document_tree, accessors));
+    }
+
     if !text_document_uri.path().ends_with("Cargo.toml") {
         return Ok(None);
     }
 
+    if lint_hover_enabled && let Some(metadata) = lint_hover_metadata(accessors) {
+        return Ok(Some(metadata));
+    }
+
+    if target_cfg_hover_enabled && let Some(metadata) = target_cfg_hover_metadata(accessors) {
+        return Ok(Some(metadata));
+    }
+
     let Ok(cargo_toml_path) = text_document_uri.to_file_path() else {
         return Ok(None);
     };
 
+    if is_patch_accessor(accessors) {
+        if !patch_hover_enabled {
+            return Ok(None);
+        }
+        let cargo_lock = load_cached_cargo_lock(&cargo_toml_path, toml_version).await;
+        return Ok(patch_hover_description(
+            document_tree,
+            accessors,
+            &cargo_toml_path,
+            toml_version,
+            cargo_lock.as_ref(),
+        )
+        .map(|description| HoverMetadata {
+            title: None,
+            description: Some(HoverTextChange::Append(description)),
+        }));
+    }
+
     if dependency_detail_hover_enabled
         && let Some(metadata) = feature_key_hover_metadata(
             document_tree,
@@ -68,7 +123,7 @@ pub async fn hover(
         return Ok(Some(metadata));
     }
 
-    if !dependency_detail_hover_enabled {
+    if !dependency_detail_hover_enabled && !dependents_hover_enabled {
         return Ok(None);
     }
 
@@ -105,13 +160,57 @@ pub async fn hover(
         return Ok(None);
     };
 
+    let dependents = if dependents_hover_enabled && hover_target == DependencyHoverTarget::Key {
+        dependents_hover_description(
+            document_tree,
+            dependency_key,
+            dependency_value,
+            &cargo_toml_path,
+            toml_version,
+        )
+        .await
+    } else {
+        None
+    };
+
+    let metadata = if dependency_detail_hover_enabled {
+        dependency_detail_hover_metadata(
+            document_tree,
+            dependency_accessors,
+            dependency_key,
+            dependency_value,
+            hover_target,
+            &cargo_toml_path,
+            toml_version,
+            offline,
+            cache_options,
+        )
+        .await?
+    } else {
+        None
+    };
+
+    Ok(append_dependents_description(metadata, dependents))
+}
+
+async fn dependency_detail_hover_metadata(
+    document_tree: &tombi_document_tree::DocumentTree,
+    dependency_accessors: &[Accessor],
+    dependency_key: &str,
+    dependency_value: &Value,
+    hover_target: DependencyHoverTarget,
+    cargo_toml_path: &Path,
+    toml_version: TomlVersion,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+) -> Result<Option<HoverMetadata>, tower_lsp::jsonrpc::Error> {
     if hover_target == DependencyHoverTarget::Key
         && let Some(metadata) = resolve_local_dependency_metadata(
             document_tree,
             dependency_accessors,
             dependency_key,
             dependency_value,
-            &cargo_toml_path,
+            cargo_toml_path,
             toml_version,
         )
     {
@@ -158,6 +257,89 @@ pub async fn hover(
     }
 }
 
+/// Describe the chains of `Cargo.lock` packages that pull in the hovered dependency, per locked version.
+async fn dependents_hover_description(
+    document_tree: &tombi_document_tree::DocumentTree,
+    dependency_key: &str,
+    dependency_value: &Value,
+    cargo_toml_path: &Path,
+    toml_version: TomlVersion,
+) -> Option<String> {
+    let cargo_lock = load_cached_cargo_lock(cargo_toml_path, toml_version).await?;
+    let package_name = dependency_package_name(dependency_key, dependency_value);
+    let current_package_name = match dig_keys(document_tree, &["package", "name"]) {
+        Some((_, Value::String(name))) => Some(name.value()),
+        _ => None,
+    };
+
+    let sections = cargo_lock
+        .dependents(package_name)
+        .into_iter()
+        .filter_map(|(version, chains)| {
+            let chains = chains
+                .into_iter()
+                .filter(|chain| Some(chain[0].name.as_str()) != current_package_name)
+                .collect_vec();
+            if chains.is_empty() {
+                return None;
+            }
+
+            Some(render_dependents(package_name, version, &chains))
+        })
+        .collect_vec();
+
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+/// Render the chains of packages pulling in `crate_name` at `version` as a Markdown list.
+pub(crate) fn render_dependents(
+    crate_name: &str,
+    version: &str,
+    chains: &[Vec<&CargoLockPackage>],
+) -> String {
+    let mut lines = vec![format!("`{crate_name} {version}` is pulled in by:")];
+    lines.extend(chains.iter().take(MAX_HOVER_DEPENDENTS).map(|chain| {
+        format!(
+            "- {}",
+            chain
+                .iter()
+                .map(|package| format!("`{} {}`", package.name, package.version))
+                .join(" ← ")
+        )
+    }));
+    if chains.len() > MAX_HOVER_DEPENDENTS {
+        lines.push(format!(
+            "- and {} more",
+            chains.len() - MAX_HOVER_DEPENDENTS
+        ));
+    }
+    lines.join("\n")
+}
+
+fn append_dependents_description(
+    metadata: Option<HoverMetadata>,
+    dependents: Option<String>,
+) -> Option<HoverMetadata> {
+    let Some(dependents) = dependents else {
+        return metadata;
+    };
+
+    let mut metadata = metadata.unwrap_or(HoverMetadata {
+        title: None,
+        description: None,
+    });
+    metadata.description = Some(match metadata.description {
+        Some(HoverTextChange::Replace(description)) => {
+            HoverTextChange::Replace(format!("{description}\n\n{dependents}"))
+        }
+        Some(HoverTextChange::Append(description)) => {
+            HoverTextChange::Append(format!("{description}\n\n{dependents}"))
+        }
+        None => HoverTextChange::Append(dependents),
+    });
+    Some(metadata)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum DependencyHoverTarget {
     Key,
@@ -2447,6 +2447,7 @@ mod tests {
             CargoLockPackage {
                 name: "demo".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "serde".to_string(),
                     version: Some("1.0.228".to_string()),
@@ -2455,6 +2456,7 @@ mod tests {
             CargoLockPackage {
                 name: "serde".to_string(),
                 version: "1.0.228".to_string(),
+                source: None,
                 dependencies: Vec::new(),
             },
         ]);
@@ -2473,6 +2475,7 @@ mod tests {
             CargoLockPackage {
                 name: "demo".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "tokio".to_string(),
                     version: Some("1.47.1".to_string()),
@@ -2481,6 +2484,7 @@ mod tests {
             CargoLockPackage {
                 name: "tokio".to_string(),
                 version: "1.47.1".to_string(),
+                source: None,
                 dependencies: Vec::new(),
             },
         ]);
@@ -2503,6 +2507,7 @@ mod tests {
             CargoLockPackage {
                 name: "demo".to_string(),
                 version: "0.1.0".to_string(),
+                source: None,
                 dependencies: vec![CargoLockDependency {
                     name: "criterion".to_string(),
                     version: Some("0.5.1".to_string()),
@@ -2511,6 +2516,7 @@ mod tests {
             CargoLockPackage {
                 name: "criterion".to_string(),
                 version: "0.5.1".to_string(),
+                source: None,
                 dependencies: Vec::new(),
             },
         ]);
@@ -1,35 +1,54 @@
 mod accessors;
+mod cargo_config;
 mod cargo_lock;
 mod cargo_toml;
 mod code_action;
+mod code_lens;
 mod completion;
 mod crates_io;
 mod did_open;
 mod document_link;
+mod extension;
+mod feature_graph;
 mod feature_navigation;
 mod goto_declaration;
 mod goto_definition;
 mod hover;
 mod inlay_hint;
+mod lint;
+mod lockfile;
+mod patch;
 mod references;
+mod rust_lints;
+mod rust_toolchain;
+mod target_cfg;
 mod workspace;
 
 pub use code_action::{CodeActionRefactorRewriteName, code_action};
+pub use code_lens::code_lens;
 pub use completion::completion;
 pub use did_open::did_open;
 pub use document_link::{DocumentLinkToolTip, document_link};
+pub use extension::CargoExtension;
+pub use feature_graph::{
+    CARGO_FEATURE_GRAPH_METHOD, CargoFeatureGraph, CargoFeatureGraphEdge, CargoFeatureGraphFormat,
+    CargoFeatureGraphNode, CargoFeatureGraphNodeKind, CargoFeatureGraphParams,
+    CargoFeatureGraphResponse, feature_graph, feature_graph_request,
+};
 pub use goto_declaration::get_current_declaration;
 pub use goto_declaration::goto_declaration;
 pub use goto_definition::goto_definition;
 pub use hover::hover;
 pub use inlay_hint::inlay_hint;
+pub use lint::lint;
 pub use references::references;
 
 pub(crate) use accessors::{
     dependency_parent_accessors, is_any_dependency_accessor, is_any_dependency_path_accessor,
     is_dependency_accessor, is_dependency_path_accessor, is_feature_key_accessor,
-    is_optional_dependency_accessor, is_package_name_accessor, is_workspace_definition_accessor,
-    is_workspace_dependency_accessor, is_workspace_flag_accessor, is_workspace_key_accessor,
+    is_optional_dependency_accessor, is_package_name_accessor, is_patch_accessor,
+    is_patch_path_accessor, is_workspace_definition_accessor, is_workspace_dependency_accessor,
+    is_workspace_flag_accessor, is_workspace_key_accessor,
     is_workspace_managed_dependency_accessor,
 };
 pub(crate) use cargo_toml::{
@@ -43,6 +62,12 @@ pub(crate) use feature_navigation::{
     feature_usage_target_for_feature_key, feature_usage_target_for_optional_dependency,
     is_optional_dependency, resolve_dependency_feature_string, resolve_feature_table_string,
 };
+pub(crate) use rust_lints::{complete_lint_names, find_lint, lint_hover_metadata, tool_lints};
+pub(crate) use target_cfg::{
+    TargetKey, built_in_target_triples, complete_target_cfg, is_known_target_cfg_value,
+    is_unsupported_target_cfg, parse_target_key, target_cfg_hover_metadata, target_key_span_range,
+    target_triple_description,
+};
 pub(crate) use workspace::{
     canonicalize_or_original, find_package_cargo_toml_paths, find_workspace_cargo_toml,
     get_workspace_cargo_toml_path, goto_definition_for_workspace_cargo_toml,
@@ -0,0 +1,1106 @@
+use std::path::{Path, PathBuf};
+
+use tombi_config::TomlVersion;
+use tombi_diagnostic::Diagnostic;
+use tombi_document_tree::{Value, dig_keys};
+use tombi_hashmap::{HashMap, HashSet};
+use tombi_schema_store::Accessor;
+
+use crate::{
+    TargetKey, canonicalize_or_original,
+    cargo_lock::{CargoLock, load_cached_cargo_lock},
+    dependency_package_name,
+    feature_navigation::{
+        CargoFeatureRef, CargoFeatureUsageTarget, collect_feature_usage_locations,
+        collect_feature_usage_locations_in_manifest, dependency_entries, has_explicit_dep_feature,
+        parse_cargo_feature_ref,
+    },
+    find_lint, find_package_cargo_toml_paths, get_workspace_cargo_toml_path,
+    is_known_target_cfg_value, is_unsupported_target_cfg, load_cargo_toml_document_tree,
+    load_workspace_cargo_toml, parse_target_key,
+    patch::patch_entries,
+    target_key_span_range, tool_lints,
+    workspace::{extract_exclude_patterns, extract_member_patterns},
+};
+
+#[derive(thiserror::Error, Debug)]
+enum DiagnosticKind {
+    #[error("`{feature}` is neither a feature nor an optional dependency")]
+    UnknownFeature { feature: String },
+    #[error("`{dependency}` is not a dependency")]
+    UnknownDependency { dependency: String },
+    #[error("`{dependency}` is not an optional dependency")]
+    NonOptionalDependency { dependency: String },
+    #[error("No Cargo.toml found at path dependency `{path}`")]
+    PathDependencyNotFound { path: String },
+    #[error("`{dependency}` is not defined in `[workspace.dependencies]`")]
+    WorkspaceDependencyNotFound { dependency: String },
+    #[error("`workspace = true` is used, but no workspace Cargo.toml is found")]
+    WorkspaceNotFound,
+    #[error("`lints.workspace = true` is used, but `[workspace.lints]` is not defined")]
+    WorkspaceLintsNotFound,
+    #[error("`{lint}` is not a known `{tool}` lint")]
+    UnknownLint { tool: String, lint: String },
+    #[error("Invalid target `{target}`: {reason}")]
+    InvalidTarget { target: String, reason: String },
+    #[error("`{name} = \"{value}\"` does not match any built-in target")]
+    UnknownTargetCfgValue { name: String, value: String },
+    #[error("`{name}` is not supported in `target.'cfg(...)'` and does not select dependencies")]
+    UnsupportedTargetCfg { name: String },
+    #[error("`{dependency}` resolves to multiple versions in Cargo.lock: {versions}")]
+    DuplicateVersion {
+        dependency: String,
+        versions: String,
+    },
+    #[error("`{dependency}` patch does not apply to any dependency in Cargo.lock")]
+    UnusedPatch { dependency: String },
+    #[error("`{feature}` is not enabled by any other feature or workspace member")]
+    UnusedFeature { feature: String },
+    #[error(
+        "This package is not a member of the workspace {workspace_cargo_toml_path:?}. Add it to `workspace.members` or `workspace.exclude`"
+    )]
+    NotWorkspaceMember { workspace_cargo_toml_path: PathBuf },
+}
+
+impl DiagnosticKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::UnknownFeature { .. } => "cargo-unknown-feature",
+            Self::UnknownDependency { .. } => "cargo-unknown-dependency",
+            Self::NonOptionalDependency { .. } => "cargo-non-optional-dependency",
+            Self::PathDependencyNotFound { .. } => "cargo-path-dependency-not-found",
+            Self::WorkspaceDependencyNotFound { .. } => "cargo-workspace-dependency-not-found",
+            Self::WorkspaceNotFound => "cargo-workspace-not-found",
+            Self::WorkspaceLintsNotFound => "cargo-workspace-lints-not-found",
+            Self::UnknownLint { .. } => "cargo-unknown-lint",
+            Self::InvalidTarget { .. } => "cargo-invalid-target",
+            Self::UnknownTargetCfgValue { .. } => "cargo-unknown-target-cfg-value",
+            Self::UnsupportedTargetCfg { .. } => "cargo-unsupported-target-cfg",
+            Self::DuplicateVersion { .. } => "cargo-duplicate-version",
+            Self::UnusedPatch { .. } => "cargo-unused-patch",
+            Self::UnusedFeature { .. } => "cargo-unused-feature",
+            Self::NotWorkspaceMember { .. } => "cargo-not-workspace-member",
+        }
+    }
+
+    fn into_diagnostic(self, range: tombi_text::Range) -> Diagnostic {
+        match self {
+            // The bundled lint and target tables may lag behind the installed toolchain,
+            // and Cargo itself only warns about unsupported target cfg.
+            // Duplicate versions are legal, only costly, Cargo ignores unused patches,
+            // and unused features may still be enabled by dependents outside the workspace.
+            Self::UnknownLint { .. }
+            | Self::UnknownTargetCfgValue { .. }
+            | Self::UnsupportedTargetCfg { .. }
+            | Self::DuplicateVersion { .. }
+            | Self::UnusedPatch { .. }
+            | Self::UnusedFeature { .. } => {
+                Diagnostic::new_warning(self.to_string(), self.code(), range)
+            }
+            _ => Diagnostic::new_error(self.to_string(), self.code(), range),
+        }
+    }
+}
+
+/// Report `Cargo.toml` problems that Cargo rejects but the JSON Schema cannot express.
+pub async fn lint(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    toml_version: TomlVersion,
+    features: Option<&tombi_config::CargoExtensionFeatures>,
+) -> Vec<Diagnostic> {
+    if !text_document_uri.path().ends_with("Cargo.toml") {
+        return Vec::new();
+    }
+
+    let mut diagnostics = lint_features(document_tree);
+    diagnostics.extend(lint_lint_names(document_tree));
+    diagnostics.extend(lint_targets(document_tree));
+
+    let Ok(cargo_toml_path) = text_document_uri.to_file_path() else {
+        return diagnostics;
+    };
+
+    diagnostics.extend(lint_path_dependencies(document_tree, &cargo_toml_path));
+
+    if duplicate_version_enabled(features)
+        && let Some(cargo_lock) = load_cached_cargo_lock(&cargo_toml_path, toml_version).await
+    {
+        diagnostics.extend(lint_duplicate_versions(document_tree, &cargo_lock));
+    }
+
+    if unused_patch_enabled(features)
+        && (document_tree.contains_key("patch") || document_tree.contains_key("replace"))
+        && let Some(cargo_lock) = load_cached_cargo_lock(&cargo_toml_path, toml_version).await
+    {
+        diagnostics.extend(lint_unused_patches(document_tree, &cargo_lock));
+    }
+
+    if unused_feature_enabled(features) {
+        diagnostics
+            .extend(lint_unused_features(document_tree, &cargo_toml_path, toml_version).await);
+    }
+
+    let workspace_cargo_toml = if document_tree.contains_key("workspace") {
+        None
+    } else {
+        load_workspace_cargo_toml(
+            &cargo_toml_path,
+            get_workspace_cargo_toml_path(document_tree),
+            toml_version,
+        )
+        .await
+    };
+    let workspace_document_tree = if document_tree.contains_key("workspace") {
+        Some(document_tree)
+    } else {
+        workspace_cargo_toml
+            .as_ref()
+            .map(|(_, workspace_document_tree)| workspace_document_tree)
+    };
+
+    diagnostics.extend(lint_workspace_dependencies(
+        document_tree,
+        workspace_document_tree,
+    ));
+    diagnostics.extend(lint_workspace_lints(document_tree, workspace_document_tree));
+
+    if let Some((workspace_cargo_toml_path, workspace_document_tree)) = &workspace_cargo_toml
+        && let Some((package_key, _)) = dig_keys(document_tree, &["package"])
+        && !is_workspace_member(
+            &cargo_toml_path,
+            workspace_cargo_toml_path,
+            workspace_document_tree,
+            toml_version,
+        )
+        .await
+    {
+        diagnostics.push(
+            DiagnosticKind::NotWorkspaceMember {
+                workspace_cargo_toml_path: workspace_cargo_toml_path.clone(),
+            }
+            .into_diagnostic(package_key.range()),
+        );
+    }
+
+    diagnostics
+}
+
+/// Check the entries of `[features]`.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/features.html
+fn lint_features(document_tree: &tombi_document_tree::DocumentTree) -> Vec<Diagnostic> {
+    let Some((_, Value::Table(features))) = dig_keys(document_tree, &["features"]) else {
+        return Vec::new();
+    };
+
+    // `dev-dependencies` cannot be optional, but their features can be enabled.
+    let mut dependencies: HashMap<String, bool> = HashMap::new();
+    for (accessors, dependency_value) in dependency_entries(document_tree) {
+        let Some(Accessor::Key(dependency_key)) = accessors.last() else {
+            continue;
+        };
+        if matches!(accessors.first(), Some(Accessor::Key(key)) if key == "workspace") {
+            continue;
+        }
+        let optional = matches!(
+            dependency_value,
+            Value::Table(table) if matches!(table.get("optional"), Some(Value::Boolean(optional)) if optional.value())
+        );
+        *dependencies.entry(dependency_key.clone()).or_default() |= optional;
+    }
+
+    let mut diagnostics = Vec::new();
+    for feature_value in features.values() {
+        let Value::Array(feature_values) = feature_value else {
+            continue;
+        };
+        for feature_value in feature_values.values() {
+            let Value::String(feature_string) = feature_value else {
+                continue;
+            };
+
+            let kind = match parse_cargo_feature_ref(feature_string.value()) {
+                CargoFeatureRef::LocalFeature(feature) => {
+                    if features.contains_key(feature) {
+                        continue;
+                    }
+                    match dependencies.get(feature) {
+                        Some(true) if !has_explicit_dep_feature(document_tree, feature) => continue,
+                        Some(false) => DiagnosticKind::NonOptionalDependency {
+                            dependency: feature.to_string(),
+                        },
+                        _ => DiagnosticKind::UnknownFeature {
+                            feature: feature.to_string(),
+                        },
+                    }
+                }
+                CargoFeatureRef::OptionalDependency(dependency) => {
+                    match dependencies.get(dependency) {
+                        Some(true) => continue,
+                        Some(false) => DiagnosticKind::NonOptionalDependency {
+                            dependency: dependency.to_string(),
+                        },
+                        None => DiagnosticKind::UnknownDependency {
+                            dependency: dependency.to_string(),
+                        },
+                    }
+                }
+                CargoFeatureRef::DependencyFeature { dep_key, weak, .. } => {
+                    match dependencies.get(dep_key) {
+                        Some(true) => continue,
+                        Some(false) if !weak => continue,
+                        Some(false) => DiagnosticKind::NonOptionalDependency {
+                            dependency: dep_key.to_string(),
+                        },
+                        None => DiagnosticKind::UnknownDependency {
+                            dependency: dep_key.to_string(),
+                        },
+                    }
+                }
+            };
+
+            diagnostics.push(kind.into_diagnostic(feature_string.unquoted_range()));
+        }
+    }
+
+    diagnostics
+}
+
+/// Check the lint names of `[lints]` and `[workspace.lints]` against the bundled lint table.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/manifest.html#the-lints-section
+fn lint_lint_names(document_tree: &tombi_document_tree::DocumentTree) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for keys in [&["lints"][..], &["workspace", "lints"]] {
+        let Some((_, Value::Table(lints))) = dig_keys(document_tree, keys) else {
+            continue;
+        };
+        for (tool_key, tool_table) in lints.key_values() {
+            let Value::Table(tool_table) = tool_table else {
+                continue;
+            };
+            if tool_lints(&tool_key.value).is_none() {
+                continue;
+            }
+            for lint_key in tool_table.keys() {
+                if find_lint(&tool_key.value, &lint_key.value).is_none() {
+                    diagnostics.push(
+                        DiagnosticKind::UnknownLint {
+                            tool: tool_key.value.clone(),
+                            lint: lint_key.value.clone(),
+                        }
+                        .into_diagnostic(lint_key.range()),
+                    );
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Check the `[target.<triple>]` and `[target.'cfg(...)']` keys.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html#platform-specific-dependencies
+fn lint_targets(document_tree: &tombi_document_tree::DocumentTree) -> Vec<Diagnostic> {
+    let Some((_, Value::Table(targets))) = dig_keys(document_tree, &["target"]) else {
+        return Vec::new();
+    };
+
+    let mut diagnostics = Vec::new();
+    for target_key in targets.keys() {
+        let expr = match parse_target_key(&target_key.value) {
+            Ok(TargetKey::Cfg(expr)) => expr,
+            Ok(TargetKey::Triple(_)) => continue,
+            Err(error) => {
+                diagnostics.push(
+                    DiagnosticKind::InvalidTarget {
+                        target: target_key.value.clone(),
+                        reason: error.to_string(),
+                    }
+                    .into_diagnostic(target_key_span_range(target_key, error.span())),
+                );
+                continue;
+            }
+        };
+
+        for (name, value, span) in expr.options() {
+            let kind = if is_unsupported_target_cfg(name) {
+                DiagnosticKind::UnsupportedTargetCfg {
+                    name: name.to_string(),
+                }
+            } else if let Some(value) = value
+                && is_known_target_cfg_value(name, value) == Some(false)
+            {
+                DiagnosticKind::UnknownTargetCfgValue {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                }
+            } else {
+                continue;
+            };
+            diagnostics.push(kind.into_diagnostic(target_key_span_range(target_key, span)));
+        }
+    }
+
+    diagnostics
+}
+
+/// Check that `lints.workspace = true` has `[workspace.lints]` to inherit.
+fn lint_workspace_lints(
+    document_tree: &tombi_document_tree::DocumentTree,
+    workspace_document_tree: Option<&tombi_document_tree::DocumentTree>,
+) -> Option<Diagnostic> {
+    let Some((_, Value::Boolean(workspace))) = dig_keys(document_tree, &["lints", "workspace"])
+    else {
+        return None;
+    };
+    if !workspace.value() {
+        return None;
+    }
+
+    let Some(workspace_document_tree) = workspace_document_tree else {
+        return Some(DiagnosticKind::WorkspaceNotFound.into_diagnostic(workspace.range()));
+    };
+
+    dig_keys(workspace_document_tree, &["workspace", "lints"])
+        .is_none()
+        .then(|| DiagnosticKind::WorkspaceLintsNotFound.into_diagnostic(workspace.range()))
+}
+
+/// Check that every `path` dependency points to a directory with a `Cargo.toml`.
+fn lint_path_dependencies(
+    document_tree: &tombi_document_tree::DocumentTree,
+    cargo_toml_path: &Path,
+) -> Vec<Diagnostic> {
+    let Some(cargo_toml_dir_path) = cargo_toml_path.parent() else {
+        return Vec::new();
+    };
+
+    dependency_entries(document_tree)
+        .into_iter()
+        .filter_map(|(_, dependency_value)| {
+            let Value::Table(table) = dependency_value else {
+                return None;
+            };
+            let Some(Value::String(path)) = table.get("path") else {
+                return None;
+            };
+
+            (!cargo_toml_dir_path
+                .join(path.value())
+                .join("Cargo.toml")
+                .is_file())
+            .then(|| {
+                DiagnosticKind::PathDependencyNotFound {
+                    path: path.value().to_string(),
+                }
+                .into_diagnostic(path.unquoted_range())
+            })
+        })
+        .collect()
+}
+
+/// Check that every `workspace = true` dependency is defined in `[workspace.dependencies]`.
+fn lint_workspace_dependencies(
+    document_tree: &tombi_document_tree::DocumentTree,
+    workspace_document_tree: Option<&tombi_document_tree::DocumentTree>,
+) -> Vec<Diagnostic> {
+    dependency_entries(document_tree)
+        .into_iter()
+        .filter_map(|(accessors, dependency_value)| {
+            if matches!(accessors.first(), Some(Accessor::Key(key)) if key == "workspace") {
+                return None;
+            }
+            let Some(Accessor::Key(dependency_key)) = accessors.last() else {
+                return None;
+            };
+            let Value::Table(table) = dependency_value else {
+                return None;
+            };
+            let Some(Value::Boolean(workspace)) = table.get("workspace") else {
+                return None;
+            };
+            if !workspace.value() {
+                return None;
+            }
+
+            let Some(workspace_document_tree) = workspace_document_tree else {
+                return Some(DiagnosticKind::WorkspaceNotFound.into_diagnostic(workspace.range()));
+            };
+
+            dig_keys(
+                workspace_document_tree,
+                &["workspace", "dependencies", dependency_key],
+            )
+            .is_none()
+            .then(|| {
+                DiagnosticKind::WorkspaceDependencyNotFound {
+                    dependency: dependency_key.to_string(),
+                }
+                .into_diagnostic(workspace.range())
+            })
+        })
+        .collect()
+}
+
+/// Check that no dependency resolves to more than one version in `Cargo.lock`.
+///
+/// Other versions are pulled in by transitive dependencies;
+/// they lengthen builds and make the types of the two versions incompatible.
+fn lint_duplicate_versions(
+    document_tree: &tombi_document_tree::DocumentTree,
+    cargo_lock: &CargoLock,
+) -> Vec<Diagnostic> {
+    dependency_entries(document_tree)
+        .into_iter()
+        .filter_map(|(accessors, dependency_value)| {
+            let keys = accessors
+                .iter()
+                .filter_map(Accessor::as_key)
+                .collect::<Vec<_>>();
+            let (dependency_key, _) = dig_keys(document_tree, &keys)?;
+            let package_name = dependency_package_name(&dependency_key.value, dependency_value);
+
+            let versions = cargo_lock.package_versions(package_name);
+            (versions.len() > 1).then(|| {
+                DiagnosticKind::DuplicateVersion {
+                    dependency: package_name.to_string(),
+                    versions: versions.join(", "),
+                }
+                .into_diagnostic(dependency_key.unquoted_range())
+            })
+        })
+        .collect()
+}
+
+/// Check that every `[patch]` and `[replace]` entry still overrides a locked package.
+///
+/// Cargo records patches that match no dependency under `[[patch.unused]]`,
+/// and leaves replaced packages out of `Cargo.lock` when nothing depends on them.
+fn lint_unused_patches(
+    document_tree: &tombi_document_tree::DocumentTree,
+    cargo_lock: &CargoLock,
+) -> Vec<Diagnostic> {
+    patch_entries(document_tree)
+        .into_iter()
+        .filter(|entry| {
+            cargo_lock.is_unused_patch(entry.package_name)
+                || cargo_lock.package_versions(entry.package_name).is_empty()
+        })
+        .map(|entry| {
+            DiagnosticKind::UnusedPatch {
+                dependency: entry.package_name.to_string(),
+            }
+            .into_diagnostic(entry.key.unquoted_range())
+        })
+        .collect()
+}
+
+/// Check that every feature is enabled by another feature, `default` or a workspace member.
+///
+/// Features of a library may be enabled by dependents outside the workspace,
+/// so this check is opt-in.
+async fn lint_unused_features(
+    document_tree: &tombi_document_tree::DocumentTree,
+    cargo_toml_path: &Path,
+    toml_version: TomlVersion,
+) -> Vec<Diagnostic> {
+    let Some((_, Value::Table(features))) = dig_keys(document_tree, &["features"]) else {
+        return Vec::new();
+    };
+    let cargo_toml_path = canonicalize_or_original(cargo_toml_path.to_path_buf());
+
+    let mut diagnostics = Vec::new();
+    for feature_key in features.keys() {
+        if feature_key.value == "default" {
+            continue;
+        }
+        let target = CargoFeatureUsageTarget::LocalFeature {
+            cargo_toml_path: cargo_toml_path.clone(),
+            feature_name: feature_key.value.to_string(),
+        };
+        // Search the other workspace members only when this manifest does not use the feature.
+        if !collect_feature_usage_locations_in_manifest(
+            document_tree,
+            &cargo_toml_path,
+            &target,
+            toml_version,
+        )
+        .is_empty()
+            || !collect_feature_usage_locations(
+                document_tree,
+                &cargo_toml_path,
+                &target,
+                toml_version,
+            )
+            .await
+            .is_empty()
+        {
+            continue;
+        }
+
+        diagnostics.push(
+            DiagnosticKind::UnusedFeature {
+                feature: feature_key.value.to_string(),
+            }
+            .into_diagnostic(feature_key.unquoted_range()),
+        );
+    }
+
+    diagnostics
+}
+
+fn unused_feature_enabled(features: Option<&tombi_config::CargoExtensionFeatures>) -> bool {
+    features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.diagnostic())
+        .and_then(|diagnostic| diagnostic.unused_feature())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+}
+
+fn unused_patch_enabled(features: Option<&tombi_config::CargoExtensionFeatures>) -> bool {
+    features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.diagnostic())
+        .and_then(|diagnostic| diagnostic.unused_patch())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+}
+
+fn duplicate_version_enabled(features: Option<&tombi_config::CargoExtensionFeatures>) -> bool {
+    features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.diagnostic())
+        .and_then(|diagnostic| diagnostic.duplicate_version())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+}
+
+/// Whether the package is a member of the workspace.
+///
+/// Besides `workspace.members`, path dependencies of members inside the workspace
+/// directory are members, and packages under `workspace.exclude` belong to no workspace.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/workspaces.html#the-members-and-exclude-fields
+async fn is_workspace_member(
+    cargo_toml_path: &Path,
+    workspace_cargo_toml_path: &Path,
+    workspace_document_tree: &tombi_document_tree::DocumentTree,
+    toml_version: TomlVersion,
+) -> bool {
+    let cargo_toml_path = canonicalize_or_original(cargo_toml_path.to_path_buf());
+    let Some(workspace_dir_path) = workspace_cargo_toml_path.parent() else {
+        return true;
+    };
+
+    let exclude_patterns = extract_exclude_patterns(workspace_document_tree);
+    if exclude_patterns.iter().any(|exclude_pattern| {
+        cargo_toml_path.starts_with(canonicalize_or_original(
+            workspace_dir_path.join(exclude_pattern.value()),
+        ))
+    }) {
+        return true;
+    }
+
+    let member_accessors = [
+        Accessor::Key("workspace".to_string()),
+        Accessor::Key("members".to_string()),
+    ];
+    let member_patterns =
+        extract_member_patterns(workspace_document_tree, &member_accessors, "members");
+
+    let mut pending_paths = vec![canonicalize_or_original(
+        workspace_cargo_toml_path.to_path_buf(),
+    )];
+    pending_paths.extend(
+        find_package_cargo_toml_paths(&member_patterns, &exclude_patterns, workspace_dir_path)
+            .map(|(_, member_cargo_toml_path)| canonicalize_or_original(member_cargo_toml_path)),
+    );
+
+    let mut visited_paths = HashSet::new();
+    while let Some(member_cargo_toml_path) = pending_paths.pop() {
+        if member_cargo_toml_path == cargo_toml_path {
+            return true;
+        }
+        if !visited_paths.insert(member_cargo_toml_path.clone()) {
+            continue;
+        }
+
+        let Some((member_cargo_toml_path, member_document_tree)) =
+            load_cargo_toml_document_tree(member_cargo_toml_path, toml_version).await
+        else {
+            continue;
+        };
+        let Some(member_dir_path) = member_cargo_toml_path.parent() else {
+            continue;
+        };
+
+        for (_, dependency_value) in dependency_entries(&member_document_tree) {
+            if let Value::Table(table) = dependency_value
+                && let Some(Value::String(path)) = table.get("path")
+            {
+                let dependency_cargo_toml_path =
+                    canonicalize_or_original(member_dir_path.join(path.value()).join("Cargo.toml"));
+                if dependency_cargo_toml_path.starts_with(workspace_dir_path) {
+                    pending_paths.push(dependency_cargo_toml_path);
+                }
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    async fn lint_codes(cargo_toml_path: &Path) -> Vec<String> {
+        let source = std::fs::read_to_string(cargo_toml_path).unwrap();
+        let text_document_uri = tombi_uri::Uri::from_file_path(cargo_toml_path).unwrap();
+        lint(
+            &text_document_uri,
+            &parse_document_tree(&source),
+            TomlVersion::default(),
+            None,
+        )
+        .await
+        .iter()
+        .map(|diagnostic| diagnostic.code().to_string())
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn reports_unknown_features_and_dependencies() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        std::fs::write(
+            &cargo_toml_path,
+            r#"
+[package]
+name = "app"
+
+[dependencies]
+serde = { version = "1", optional = true }
+regex = "1"
+
+[features]
+default = ["std", "serde"]
+std = []
+json = ["dep:serde", "serde/derive", "regex/std"]
+typo = ["stdd", "dep:regex", "rand/std", "regex?/std"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lint_codes(&cargo_toml_path).await,
+            vec![
+                // `dep:serde` removes the implicit `serde` feature.
+                "cargo-unknown-feature",
+                "cargo-unknown-feature",
+                "cargo-non-optional-dependency",
+                "cargo-unknown-dependency",
+                "cargo-non-optional-dependency",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_missing_path_and_workspace_dependencies() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.dependencies]
+serde = "1"
+"#,
+        )
+        .unwrap();
+        for name in ["a", "b"] {
+            std::fs::create_dir_all(root.join("crates").join(name)).unwrap();
+        }
+        std::fs::write(
+            root.join("crates/b/Cargo.toml"),
+            "[package]\nname = \"b\"\n",
+        )
+        .unwrap();
+        let cargo_toml_path = root.join("crates/a/Cargo.toml");
+        std::fs::write(
+            &cargo_toml_path,
+            r#"
+[package]
+name = "a"
+
+[dependencies]
+serde = { workspace = true }
+tokio = { workspace = true }
+b = { path = "../b" }
+c = { path = "../c" }
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lint_codes(&cargo_toml_path).await,
+            vec![
+                "cargo-path-dependency-not-found",
+                "cargo-workspace-dependency-not-found",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_packages_missing_from_workspace_members() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["crates/a"]
+exclude = ["examples"]
+"#,
+        )
+        .unwrap();
+        for (dir, dependencies) in [
+            ("crates/a", "b = { path = \"../b\" }\n"),
+            ("crates/b", ""),
+            ("crates/c", ""),
+            ("examples/d", ""),
+        ] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+            std::fs::write(
+                root.join(dir).join("Cargo.toml"),
+                format!("[package]\nname = \"x\"\n\n[dependencies]\n{dependencies}"),
+            )
+            .unwrap();
+        }
+
+        assert!(
+            lint_codes(&root.join("crates/a/Cargo.toml"))
+                .await
+                .is_empty()
+        );
+        assert!(
+            lint_codes(&root.join("crates/b/Cargo.toml"))
+                .await
+                .is_empty()
+        );
+        assert!(
+            lint_codes(&root.join("examples/d/Cargo.toml"))
+                .await
+                .is_empty()
+        );
+        assert_eq!(
+            lint_codes(&root.join("crates/c/Cargo.toml")).await,
+            vec!["cargo-not-workspace-member"]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_unknown_lints_and_missing_workspace_lints() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.lints.clippy]
+pedantic = { level = "warn", priority = -1 }
+needless-return = "allow"
+needles_return = "allow"
+"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("crates/a")).unwrap();
+        let cargo_toml_path = root.join("crates/a/Cargo.toml");
+        std::fs::write(
+            &cargo_toml_path,
+            r#"
+[package]
+name = "a"
+
+[lints]
+workspace = true
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lint_codes(&root.join("Cargo.toml")).await,
+            vec!["cargo-unknown-lint"]
+        );
+        assert!(lint_codes(&cargo_toml_path).await.is_empty());
+
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            lint_codes(&cargo_toml_path).await,
+            vec!["cargo-workspace-lints-not-found"]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_invalid_targets_and_target_cfg() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        std::fs::write(
+            &cargo_toml_path,
+            r#"
+[package]
+name = "app"
+
+[target.'cfg(all(unix, target_os = "linx"))'.dependencies]
+[target.'cfg(feature = "simd")'.dependencies]
+[target.'cfg(any(unix, windows)'.dependencies]
+[target.x86_64-unknown-linux-gnu.dependencies]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lint_codes(&cargo_toml_path).await,
+            vec![
+                "cargo-unknown-target-cfg-value",
+                "cargo-unsupported-target-cfg",
+                "cargo-invalid-target",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_duplicate_versions_only_when_enabled() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        let source = r#"
+[package]
+name = "app"
+
+[dependencies]
+mio = "1"
+serde = "1"
+"#;
+        std::fs::write(&cargo_toml_path, source).unwrap();
+        std::fs::write(
+            temp_dir.path().join("Cargo.lock"),
+            r#"
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["mio 1.0.3", "notify", "serde"]
+
+[[package]]
+name = "mio"
+version = "0.8.11"
+
+[[package]]
+name = "mio"
+version = "1.0.3"
+
+[[package]]
+name = "notify"
+version = "6.1.1"
+dependencies = ["mio 0.8.11"]
+
+[[package]]
+name = "serde"
+version = "1.0.219"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(lint_codes(&cargo_toml_path).await, Vec::<String>::new());
+
+        let features = tombi_config::CargoExtensionFeatures::Features(
+            tombi_config::CargoExtensionFeatureTree {
+                lsp: Some(tombi_config::CargoLspFeatures::Features(
+                    tombi_config::CargoLspFeatureTree {
+                        diagnostic: Some(tombi_config::CargoDiagnosticFeatures::Features(
+                            tombi_config::CargoDiagnosticFeatureTree {
+                                duplicate_version: Some(tombi_config::ToggleFeatureDefaultFalse {
+                                    enabled: Some(true.into()),
+                                }),
+                                unused_feature: None,
+                                unused_patch: None,
+                            },
+                        )),
+                        ..Default::default()
+                    },
+                )),
+            },
+        );
+        let diagnostics = lint(
+            &tombi_uri::Uri::from_file_path(&cargo_toml_path).unwrap(),
+            &parse_document_tree(source),
+            TomlVersion::default(),
+            Some(&features),
+        )
+        .await;
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message().to_string())
+                .collect::<Vec<_>>(),
+            vec!["`mio` resolves to multiple versions in Cargo.lock: 0.8.11, 1.0.3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_patches_unused_in_cargo_lock() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        let source = r#"
+[package]
+name = "app"
+
+[dependencies]
+serde = "1"
+tokio = "1"
+
+[patch.crates-io]
+serde = { git = "https://github.com/serde-rs/serde", branch = "main" }
+tokio = { git = "https://github.com/tokio-rs/tokio", branch = "main" }
+
+[replace]
+"log:0.4.0" = { git = "https://github.com/rust-lang/log" }
+"#;
+        std::fs::write(&cargo_toml_path, source).unwrap();
+        std::fs::write(
+            temp_dir.path().join("Cargo.lock"),
+            r#"
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["serde", "tokio"]
+
+[[package]]
+name = "serde"
+version = "1.0.219"
+source = "git+https://github.com/serde-rs/serde?branch=main#0123456789abcdef"
+
+[[package]]
+name = "tokio"
+version = "1.45.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[patch.unused]]
+name = "tokio"
+version = "2.0.0"
+source = "git+https://github.com/tokio-rs/tokio?branch=main#0123456789abcdef"
+"#,
+        )
+        .unwrap();
+
+        let diagnostics = lint(
+            &tombi_uri::Uri::from_file_path(&cargo_toml_path).unwrap(),
+            &parse_document_tree(source),
+            TomlVersion::default(),
+            None,
+        )
+        .await;
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message().to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "`tokio` patch does not apply to any dependency in Cargo.lock",
+                "`log` patch does not apply to any dependency in Cargo.lock",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_features_unused_in_workspace() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"app\", \"core\"]\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("app")).unwrap();
+        std::fs::write(
+            temp_dir.path().join("app").join("Cargo.toml"),
+            r#"
+[package]
+name = "app"
+
+[dependencies]
+core = { path = "../core", features = ["serde"] }
+"#,
+        )
+        .unwrap();
+        let cargo_toml_path = temp_dir.path().join("core").join("Cargo.toml");
+        let source = r#"
+[package]
+name = "core"
+
+[features]
+default = ["std"]
+std = []
+serde = []
+unstable = []
+"#;
+        std::fs::create_dir_all(cargo_toml_path.parent().unwrap()).unwrap();
+        std::fs::write(&cargo_toml_path, source).unwrap();
+
+        let features = tombi_config::CargoExtensionFeatures::Features(
+            tombi_config::CargoExtensionFeatureTree {
+                lsp: Some(tombi_config::CargoLspFeatures::Features(
+                    tombi_config::CargoLspFeatureTree {
+                        diagnostic: Some(tombi_config::CargoDiagnosticFeatures::Features(
+                            tombi_config::CargoDiagnosticFeatureTree {
+                                unused_feature: Some(tombi_config::ToggleFeatureDefaultFalse {
+                                    enabled: Some(true.into()),
+                                }),
+                                ..Default::default()
+                            },
+                        )),
+                        ..Default::default()
+                    },
+                )),
+            },
+        );
+        let diagnostics = lint(
+            &tombi_uri::Uri::from_file_path(&cargo_toml_path).unwrap(),
+            &parse_document_tree(source),
+            TomlVersion::default(),
+            Some(&features),
+        )
+        .await;
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message().to_string())
+                .collect::<Vec<_>>(),
+            vec!["`unstable` is not enabled by any other feature or workspace member"]
+        );
+    }
+}
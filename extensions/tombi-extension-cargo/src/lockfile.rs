@@ -0,0 +1,378 @@
+use std::path::Path;
+
+use itertools::Itertools;
+use tombi_config::TomlVersion;
+use tombi_document_tree::{Value, dig_accessors, dig_keys};
+use tombi_extension::{HoverMetadata, HoverTextChange};
+use tombi_schema_store::{Accessor, matches_accessors};
+
+use crate::{
+    cargo_lock::{CargoLock, CargoLockDependency},
+    goto_workspace_member_crates,
+    hover::render_dependents,
+    load_cargo_toml,
+    references::{collect_member_dependency_references, workspace_package_reference_locations},
+};
+
+/// The `name@version` of a `[[package]]` entry of `Cargo.lock`, used as its document symbol name.
+pub(crate) fn cargo_lock_package_symbol_name(
+    accessors: &[Accessor],
+    value: &Value,
+) -> Option<String> {
+    if !matches_accessors!(accessors, ["package", _]) {
+        return None;
+    }
+    let (name, version) = package_name_and_version(value)?;
+
+    Some(format!("{}@{}", name.value(), version.value()))
+}
+
+/// List the chains of `Cargo.lock` packages that pull in the hovered `[[package]]` entry.
+pub(crate) fn cargo_lock_hover_metadata(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+) -> Option<HoverMetadata> {
+    if !(matches_accessors!(accessors, ["package", _])
+        || matches_accessors!(accessors, ["package", _, "name"])
+        || matches_accessors!(accessors, ["package", _, "version"]))
+    {
+        return None;
+    }
+
+    let (_, package) = dig_accessors(document_tree, &accessors[..2])?;
+    let (name, version) = package_name_and_version(package)?;
+    let cargo_lock = CargoLock::from_document_tree(document_tree)?;
+    let (_, dependents) = cargo_lock
+        .dependents(name.value())
+        .into_iter()
+        .find(|(dependent_version, _)| *dependent_version == version.value())?;
+
+    let description = if dependents.is_empty() {
+        format!(
+            "No package depends on `{} {}`.",
+            name.value(),
+            version.value()
+        )
+    } else {
+        render_dependents(name.value(), version.value(), &dependents)
+    };
+
+    Some(HoverMetadata {
+        title: None,
+        description: Some(HoverTextChange::Append(description)),
+    })
+}
+
+pub(crate) fn goto_definition_for_cargo_lock(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    cargo_lock_path: &Path,
+    toml_version: TomlVersion,
+) -> Result<Vec<tombi_extension::Location>, tower_lsp::jsonrpc::Error> {
+    if matches_accessors!(accessors, ["package", _, "dependencies", _]) {
+        let Some((_, Value::String(dependency))) = dig_accessors(document_tree, accessors) else {
+            return Ok(Vec::new());
+        };
+        let dependency = CargoLockDependency::parse(dependency.value());
+
+        return Ok(package_locations(
+            text_document_uri,
+            document_tree,
+            &dependency.name,
+            dependency.version.as_deref(),
+        ));
+    }
+
+    if matches_accessors!(accessors, ["package", _, "name"]) {
+        let Some((_, package)) = dig_accessors(document_tree, &accessors[..2]) else {
+            return Ok(Vec::new());
+        };
+        let Value::Table(package) = package else {
+            return Ok(Vec::new());
+        };
+        let Some(Value::String(name)) = package.get("name") else {
+            return Ok(Vec::new());
+        };
+
+        return manifest_locations(
+            cargo_lock_path,
+            name.value(),
+            package.contains_key("source"),
+            toml_version,
+        );
+    }
+
+    Ok(Vec::new())
+}
+
+/// The `name` of the `[[package]]` entries matching the dependency.
+///
+/// A dependency without version refers to the only locked version of the package.
+fn package_locations(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    name: &str,
+    version: Option<&str>,
+) -> Vec<tombi_extension::Location> {
+    let Some((_, Value::Array(packages))) = dig_keys(document_tree, &["package"]) else {
+        return Vec::new();
+    };
+
+    packages
+        .values()
+        .iter()
+        .filter_map(package_name_and_version)
+        .filter(|(package_name, package_version)| {
+            package_name.value() == name
+                && version.is_none_or(|version| package_version.value() == version)
+        })
+        .map(|(package_name, _)| tombi_extension::Location {
+            uri: text_document_uri.clone(),
+            range: package_name.unquoted_range(),
+        })
+        .collect()
+}
+
+/// The manifests of the workspace next to `Cargo.lock` that declare the package.
+///
+/// Local packages lead to their own `Cargo.toml`,
+/// and packages from a registry or git to the dependency entries pulling them in.
+fn manifest_locations(
+    cargo_lock_path: &Path,
+    package_name: &str,
+    has_source: bool,
+    toml_version: TomlVersion,
+) -> Result<Vec<tombi_extension::Location>, tower_lsp::jsonrpc::Error> {
+    let Some(root_cargo_toml_path) = cargo_lock_path
+        .parent()
+        .map(|dir_path| dir_path.join("Cargo.toml"))
+    else {
+        return Ok(Vec::new());
+    };
+    let Some((_, root_document_tree)) = load_cargo_toml(&root_cargo_toml_path, toml_version) else {
+        return Ok(Vec::new());
+    };
+
+    let mut locations = Vec::new();
+    if has_source {
+        collect_member_dependency_references(
+            &mut locations,
+            &root_document_tree,
+            &root_cargo_toml_path,
+            package_name,
+        );
+        locations.extend(workspace_package_reference_locations(
+            &root_document_tree,
+            &root_cargo_toml_path,
+            package_name,
+            toml_version,
+        )?);
+    } else {
+        if let Some((_, Value::String(name))) = dig_keys(&root_document_tree, &["package", "name"])
+            && name.value() == package_name
+            && let Ok(uri) = tombi_uri::Uri::from_file_path(&root_cargo_toml_path)
+        {
+            locations.push(tombi_extension::Location {
+                uri,
+                range: name.unquoted_range(),
+            });
+        }
+
+        for crate_location in goto_workspace_member_crates(
+            &root_document_tree,
+            &[],
+            &root_cargo_toml_path,
+            toml_version,
+            "members",
+        )? {
+            let Some((_, member_document_tree)) =
+                load_cargo_toml(&crate_location.cargo_toml_path, toml_version)
+            else {
+                continue;
+            };
+            if matches!(
+                dig_keys(&member_document_tree, &["package", "name"]),
+                Some((_, Value::String(name))) if name.value() == package_name
+            ) && let Some(location) = Option::<tombi_extension::Location>::from(crate_location)
+            {
+                locations.push(location);
+            }
+        }
+    }
+
+    Ok(locations.into_iter().unique().collect())
+}
+
+fn package_name_and_version(
+    value: &Value,
+) -> Option<(&tombi_document_tree::String, &tombi_document_tree::String)> {
+    let Value::Table(table) = value else {
+        return None;
+    };
+    let (Some(Value::String(name)), Some(Value::String(version))) =
+        (table.get("name"), table.get("version"))
+    else {
+        return None;
+    };
+
+    Some((name, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    const CARGO_LOCK: &str = r#"
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["mio 1.0.3", "tokio"]
+
+[[package]]
+name = "mio"
+version = "0.8.11"
+
+[[package]]
+name = "mio"
+version = "1.0.3"
+
+[[package]]
+name = "tokio"
+version = "1.45.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = ["mio 1.0.3"]
+"#;
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    fn package_accessors(index: usize, keys: &[&str]) -> Vec<Accessor> {
+        let mut accessors = vec![Accessor::Key("package".to_string()), Accessor::Index(index)];
+        accessors.extend(keys.iter().map(|key| Accessor::Key(key.to_string())));
+        accessors
+    }
+
+    #[test]
+    fn names_packages_by_name_and_version() {
+        let document_tree = parse_document_tree(CARGO_LOCK);
+        let Some((_, Value::Array(packages))) = dig_keys(&document_tree, &["package"]) else {
+            panic!("`package` should be an array");
+        };
+
+        assert_eq!(
+            packages
+                .values()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, package)| cargo_lock_package_symbol_name(
+                    &package_accessors(index, &[]),
+                    package
+                ))
+                .collect::<Vec<_>>(),
+            vec!["app@0.1.0", "mio@0.8.11", "mio@1.0.3", "tokio@1.45.0"]
+        );
+    }
+
+    #[test]
+    fn hover_lists_dependents_of_the_locked_version() {
+        let document_tree = parse_document_tree(CARGO_LOCK);
+        let description = |index| match cargo_lock_hover_metadata(
+            &document_tree,
+            &package_accessors(index, &["name"]),
+        )
+        .and_then(|metadata| metadata.description)
+        {
+            Some(HoverTextChange::Append(description)) => description,
+            _ => panic!("hover should append a description"),
+        };
+
+        assert_eq!(description(1), "No package depends on `mio 0.8.11`.");
+        assert_eq!(
+            description(2),
+            "`mio 1.0.3` is pulled in by:\n- `app 0.1.0`\n- `tokio 1.45.0` ← `app 0.1.0`"
+        );
+    }
+
+    #[test]
+    fn dependency_string_leads_to_package_entry() {
+        let document_tree = parse_document_tree(CARGO_LOCK);
+        let uri = tombi_uri::Uri::from_file_path("/workspace/Cargo.lock").unwrap();
+        let line = |accessors: &[Accessor]| {
+            goto_definition_for_cargo_lock(
+                &uri,
+                &document_tree,
+                accessors,
+                Path::new("/workspace/Cargo.lock"),
+                TomlVersion::default(),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|location| location.range.start.line)
+            .collect::<Vec<_>>()
+        };
+
+        let mut mio = package_accessors(0, &["dependencies"]);
+        mio.push(Accessor::Index(0));
+        let mut tokio = package_accessors(0, &["dependencies"]);
+        tokio.push(Accessor::Index(1));
+
+        assert_eq!(line(&mio), vec![11]);
+        assert_eq!(line(&tokio), vec![15]);
+    }
+
+    #[test]
+    fn package_name_leads_to_declaring_manifests() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"app\"]\n\n[workspace.dependencies]\ntokio = \"1\"\n",
+        )
+        .unwrap();
+        std::fs::create_dir(root.join("app")).unwrap();
+        std::fs::write(
+            root.join("app/Cargo.toml"),
+            "[package]\nname = \"app\"\n\n[dependencies]\ntokio = { workspace = true }\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("Cargo.lock"), CARGO_LOCK).unwrap();
+
+        let document_tree = parse_document_tree(CARGO_LOCK);
+        let cargo_lock_path = root.join("Cargo.lock");
+        let uri = tombi_uri::Uri::from_file_path(&cargo_lock_path).unwrap();
+        let locations = |index| {
+            goto_definition_for_cargo_lock(
+                &uri,
+                &document_tree,
+                &package_accessors(index, &["name"]),
+                &cargo_lock_path,
+                TomlVersion::default(),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|location| {
+                location
+                    .uri
+                    .to_file_path()
+                    .unwrap()
+                    .strip_prefix(root.canonicalize().unwrap())
+                    .unwrap_or_else(|_| Path::new("?"))
+                    .to_path_buf()
+            })
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(locations(0), vec![Path::new("app/Cargo.toml")]);
+        assert_eq!(
+            locations(3).len(),
+            2,
+            "`tokio` is declared by the workspace and the member"
+        );
+    }
+}
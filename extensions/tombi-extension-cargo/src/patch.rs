@@ -0,0 +1,305 @@
+use std::path::Path;
+
+use tombi_config::TomlVersion;
+use tombi_document_tree::{Value, dig_accessors, dig_keys};
+use tombi_schema_store::Accessor;
+
+use crate::{
+    CrateLocation, cargo_lock::CargoLock, dependency_package_name, dependency_parent_accessors,
+    find_cargo_toml, is_patch_accessor, is_patch_path_accessor,
+};
+
+/// An entry of `[patch.<source>]` or `[replace]`.
+pub(crate) struct PatchEntry<'a> {
+    pub(crate) key: &'a tombi_document_tree::Key,
+    pub(crate) package_name: &'a str,
+}
+
+/// Collect the entries of every `[patch.<source>]` table and of `[replace]`.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/overriding-dependencies.html
+pub(crate) fn patch_entries(
+    document_tree: &tombi_document_tree::DocumentTree,
+) -> Vec<PatchEntry<'_>> {
+    let mut entries = Vec::new();
+
+    if let Some((_, Value::Table(sources))) = dig_keys(document_tree, &["patch"]) {
+        for patches in sources.values() {
+            let Value::Table(patches) = patches else {
+                continue;
+            };
+            for (key, value) in patches.key_values() {
+                entries.push(PatchEntry {
+                    key,
+                    package_name: dependency_package_name(&key.value, value),
+                });
+            }
+        }
+    }
+
+    if let Some((_, Value::Table(replacements))) = dig_keys(document_tree, &["replace"]) {
+        for key in replacements.keys() {
+            entries.push(PatchEntry {
+                key,
+                package_name: replace_package_name(&key.value),
+            });
+        }
+    }
+
+    entries
+}
+
+/// The package name of a `[replace]` package ID spec, such as `foo:0.1.0`,
+/// `foo@0.1.0` or `https://github.com/rust-lang/crates.io-index#foo:0.1.0`.
+fn replace_package_name(package_id: &str) -> &str {
+    let package_id = package_id
+        .rsplit_once('#')
+        .map_or(package_id, |(_, fragment)| fragment);
+
+    package_id.split([':', '@']).next().unwrap_or(package_id)
+}
+
+fn patch_entry_accessors(accessors: &[Accessor]) -> Option<&[Accessor]> {
+    if is_patch_path_accessor(accessors) {
+        Some(dependency_parent_accessors(accessors))
+    } else if is_patch_accessor(accessors) {
+        Some(accessors)
+    } else {
+        None
+    }
+}
+
+/// The `Cargo.toml` of the local crate a `[patch]` or `[replace]` entry points at.
+fn find_patch_cargo_toml(
+    patch_value: &Value,
+    cargo_toml_path: &Path,
+    toml_version: TomlVersion,
+) -> Option<(std::path::PathBuf, tombi_document_tree::DocumentTree)> {
+    let Value::Table(table) = patch_value else {
+        return None;
+    };
+    let Some(Value::String(path)) = table.get("path") else {
+        return None;
+    };
+
+    find_cargo_toml(cargo_toml_path, Path::new(path.value()), toml_version)
+        .map(|(patch_cargo_toml_path, _, document_tree)| (patch_cargo_toml_path, document_tree))
+}
+
+/// Go to the package name in the `Cargo.toml` of a patched local crate.
+pub(crate) fn goto_patch_locations(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    cargo_toml_path: &Path,
+    toml_version: TomlVersion,
+) -> Vec<tombi_extension::Location> {
+    let Some(entry_accessors) = patch_entry_accessors(accessors) else {
+        return Vec::new();
+    };
+    let Some((_, patch_value)) = dig_accessors(document_tree, entry_accessors) else {
+        return Vec::new();
+    };
+    let Some((patch_cargo_toml_path, patch_document_tree)) =
+        find_patch_cargo_toml(patch_value, cargo_toml_path, toml_version)
+    else {
+        return Vec::new();
+    };
+    let Some((_, Value::String(package_name))) =
+        dig_keys(&patch_document_tree, &["package", "name"])
+    else {
+        return Vec::new();
+    };
+
+    Option::<tombi_extension::Location>::from(CrateLocation {
+        cargo_toml_path: patch_cargo_toml_path,
+        package_name_key_range: package_name.unquoted_range(),
+    })
+    .into_iter()
+    .collect()
+}
+
+/// Compare the version a `[patch]` or `[replace]` entry provides with the versions in `Cargo.lock`.
+pub(crate) fn patch_hover_description(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    cargo_toml_path: &Path,
+    toml_version: TomlVersion,
+    cargo_lock: Option<&CargoLock>,
+) -> Option<String> {
+    if !is_patch_accessor(accessors) {
+        return None;
+    }
+    let (Accessor::Key(patch_key), patch_value) = dig_accessors(document_tree, accessors)? else {
+        return None;
+    };
+    let package_name = if accessors
+        .first()
+        .is_some_and(|accessor| accessor == &"replace")
+    {
+        replace_package_name(patch_key)
+    } else {
+        dependency_package_name(patch_key, patch_value)
+    };
+
+    let patched_version = find_patch_cargo_toml(patch_value, cargo_toml_path, toml_version)
+        .and_then(|(_, patch_document_tree)| {
+            match dig_keys(&patch_document_tree, &["package", "version"]) {
+                Some((_, Value::String(version))) => Some(version.value().to_string()),
+                _ => None,
+            }
+        });
+
+    let mut lines = Vec::new();
+    if let Some(patched_version) = &patched_version {
+        lines.push(format!("Patched with `{package_name} {patched_version}`."));
+    }
+
+    if let Some(cargo_lock) = cargo_lock {
+        let locked_versions = cargo_lock.package_versions(package_name);
+        if cargo_lock.is_unused_patch(package_name) {
+            lines.push("`Cargo.lock` lists this patch as unused.".to_string());
+        } else if locked_versions.is_empty() {
+            lines.push(format!("`{package_name}` is not in `Cargo.lock`."));
+        } else {
+            lines.push(format!(
+                "`Cargo.lock` resolves `{package_name}` to {}.",
+                locked_versions
+                    .iter()
+                    .map(|version| format!("`{version}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            if let Some(patched_version) = &patched_version
+                && !locked_versions.contains(&patched_version.as_str())
+            {
+                lines.push(
+                    "The patched version is not locked, so the patch may not apply.".to_string(),
+                );
+            }
+        }
+    }
+
+    (!lines.is_empty()).then(|| lines.join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+    use crate::cargo_lock::{CargoLockPackage, CargoLockPatch};
+
+    fn key(value: &str) -> Accessor {
+        Accessor::Key(value.to_string())
+    }
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    fn package(name: &str, version: &str) -> CargoLockPackage {
+        CargoLockPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            source: None,
+            dependencies: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parses_replace_package_names() {
+        assert_eq!(replace_package_name("foo:0.1.0"), "foo");
+        assert_eq!(replace_package_name("foo@0.1.0"), "foo");
+        assert_eq!(
+            replace_package_name("https://github.com/rust-lang/crates.io-index#foo:0.1.0"),
+            "foo"
+        );
+    }
+
+    #[test]
+    fn collects_patch_and_replace_entries() {
+        let document_tree = parse_document_tree(
+            r#"
+[patch.crates-io]
+serde = { path = "../serde" }
+json = { path = "../json", package = "serde_json" }
+
+[patch."https://github.com/tokio-rs/tokio"]
+tokio = { git = "https://github.com/me/tokio", branch = "fix" }
+
+[replace]
+"log:0.4.0" = { path = "../log" }
+"#,
+        );
+
+        assert_eq!(
+            patch_entries(&document_tree)
+                .into_iter()
+                .map(|entry| entry.package_name)
+                .collect::<Vec<_>>(),
+            vec!["serde", "serde_json", "tokio", "log"]
+        );
+    }
+
+    #[test]
+    fn goes_to_patched_crate_and_compares_locked_version() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("app").join("Cargo.toml");
+        let serde_dir = temp_dir.path().join("serde");
+        std::fs::create_dir_all(cargo_toml_path.parent().unwrap()).unwrap();
+        std::fs::create_dir_all(&serde_dir).unwrap();
+        std::fs::write(
+            serde_dir.join("Cargo.toml"),
+            "[package]\nname = \"serde\"\nversion = \"1.0.300\"\n",
+        )
+        .unwrap();
+        let document_tree =
+            parse_document_tree("[patch.crates-io]\nserde = { path = \"../serde\" }\n");
+        let accessors = [key("patch"), key("crates-io"), key("serde")];
+
+        let locations = goto_patch_locations(
+            &document_tree,
+            &[key("patch"), key("crates-io"), key("serde"), key("path")],
+            &cargo_toml_path,
+            TomlVersion::default(),
+        );
+        assert_eq!(locations.len(), 1);
+        assert!(locations[0].uri.path().ends_with("serde/Cargo.toml"));
+
+        let cargo_lock = CargoLock::new(vec![package("serde", "1.0.228")]);
+        assert_eq!(
+            patch_hover_description(
+                &document_tree,
+                &accessors,
+                &cargo_toml_path,
+                TomlVersion::default(),
+                Some(&cargo_lock),
+            )
+            .as_deref(),
+            Some(
+                "Patched with `serde 1.0.300`.\n\n\
+                 `Cargo.lock` resolves `serde` to `1.0.228`.\n\n\
+                 The patched version is not locked, so the patch may not apply."
+            )
+        );
+
+        let mut cargo_lock = CargoLock::new(vec![package("serde", "1.0.228")]);
+        cargo_lock.unused_patches = vec![CargoLockPatch {
+            name: "serde".to_string(),
+            version: "1.0.300".to_string(),
+        }];
+        assert_eq!(
+            patch_hover_description(
+                &document_tree,
+                &accessors,
+                &cargo_toml_path,
+                TomlVersion::default(),
+                Some(&cargo_lock),
+            )
+            .as_deref(),
+            Some("Patched with `serde 1.0.300`.\n\n`Cargo.lock` lists this patch as unused.")
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use crate::feature_navigation::collect_feature_usage_locations_in_manifest;
 use crate::{
-    collect_feature_usage_locations, dependency_package_name, feature_usage_target_for_feature_key,
-    feature_usage_target_for_optional_dependency, get_workspace_cargo_toml_path,
-    goto_workspace_member_crates, is_optional_dependency, is_workspace_dependency_accessor,
-    load_cargo_toml, load_workspace_cargo_toml, workspace_dependency_usage_locations,
+    canonicalize_or_original, collect_feature_usage_locations, dependency_package_name,
+    feature_usage_target_for_feature_key, feature_usage_target_for_optional_dependency,
+    get_workspace_cargo_toml_path, goto_workspace_member_crates, is_dependency_accessor,
+    is_optional_dependency, is_workspace_dependency_accessor, load_cargo_toml,
+    load_workspace_cargo_toml, workspace_dependency_usage_locations,
 };
 use itertools::Itertools;
 use tombi_config::TomlVersion;
@@ -57,6 +58,9 @@ pub async fn references(
             &cargo_toml_path,
             toml_version,
         )?
+    } else if is_dependency_accessor(accessors) {
+        dependency_reference_locations(document_tree, accessors, &cargo_toml_path, toml_version)
+            .await?
     } else {
         Vec::new()
     };
@@ -86,18 +90,89 @@ pub(crate) async fn package_name_reference_locations(
         return Ok(Vec::new());
     };
 
-    let mut locations = Vec::new();
-    collect_workspace_dependency_references(
-        &mut locations,
+    workspace_package_reference_locations(
         &workspace_document_tree,
         &workspace_cargo_toml_path,
         package_name.value(),
+        toml_version,
+    )
+}
+
+/// List the other dependency keys in the workspace that depend on the same package.
+async fn dependency_reference_locations(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    cargo_toml_path: &std::path::Path,
+    toml_version: TomlVersion,
+) -> Result<Vec<tombi_extension::Location>, tower_lsp::jsonrpc::Error> {
+    debug_assert!(is_dependency_accessor(accessors));
+
+    let keys = accessors.iter().filter_map(Accessor::as_key).collect_vec();
+    let Some((dependency_key, dependency_value)) = dig_keys(document_tree, &keys) else {
+        return Ok(Vec::new());
+    };
+    let package_name = dependency_package_name(&dependency_key.value, dependency_value);
+
+    let locations = if document_tree.contains_key("workspace") {
+        workspace_package_reference_locations(
+            document_tree,
+            cargo_toml_path,
+            package_name,
+            toml_version,
+        )?
+    } else if let Some((workspace_cargo_toml_path, workspace_document_tree)) =
+        load_workspace_cargo_toml(
+            cargo_toml_path,
+            get_workspace_cargo_toml_path(document_tree),
+            toml_version,
+        )
+        .await
+    {
+        workspace_package_reference_locations(
+            &workspace_document_tree,
+            &workspace_cargo_toml_path,
+            package_name,
+            toml_version,
+        )?
+    } else {
+        return Ok(Vec::new());
+    };
+
+    let current_cargo_toml_path = canonicalize_or_original(cargo_toml_path.to_path_buf());
+    let current_range = dependency_key.unquoted_range();
+    Ok(locations
+        .into_iter()
+        .filter(|location| {
+            location.range != current_range
+                || location
+                    .uri
+                    .to_file_path()
+                    .ok()
+                    .map(canonicalize_or_original)
+                    != Some(current_cargo_toml_path.clone())
+        })
+        .collect())
+}
+
+/// List the dependency keys of the workspace and its members that refer to `package_name`.
+pub(crate) fn workspace_package_reference_locations(
+    workspace_document_tree: &tombi_document_tree::DocumentTree,
+    workspace_cargo_toml_path: &std::path::Path,
+    package_name: &str,
+    toml_version: TomlVersion,
+) -> Result<Vec<tombi_extension::Location>, tower_lsp::jsonrpc::Error> {
+    let mut locations = Vec::new();
+    collect_workspace_dependency_references(
+        &mut locations,
+        workspace_document_tree,
+        workspace_cargo_toml_path,
+        package_name,
     );
 
     for crate_location in goto_workspace_member_crates(
-        &workspace_document_tree,
+        workspace_document_tree,
         &[],
-        &workspace_cargo_toml_path,
+        workspace_cargo_toml_path,
         toml_version,
         "members",
     )? {
@@ -111,7 +186,7 @@ pub(crate) async fn package_name_reference_locations(
             &mut locations,
             &crate_document_tree,
             &crate_location.cargo_toml_path,
-            package_name.value(),
+            package_name,
         );
     }
 
@@ -144,7 +219,7 @@ fn collect_workspace_dependency_references(
     }
 }
 
-fn collect_member_dependency_references(
+pub(crate) fn collect_member_dependency_references(
     locations: &mut Vec<tombi_extension::Location>,
     crate_document_tree: &tombi_document_tree::DocumentTree,
     crate_cargo_toml_path: &std::path::Path,
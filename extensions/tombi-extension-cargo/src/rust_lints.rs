@@ -0,0 +1,328 @@
+use std::sync::LazyLock;
+
+use itertools::Itertools;
+use tombi_document_tree::{Value, dig_accessors};
+use tombi_extension::{
+    CompletionContent, CompletionContentPriority, CompletionEdit, CompletionHint, CompletionKind,
+    CompletionTextEdit, HoverMetadata, HoverTextChange, TextEdit,
+};
+use tombi_hashmap::HashSet;
+use tombi_schema_store::Accessor;
+use tower_lsp::lsp_types::InsertTextFormat;
+
+/// The lints of rustc, Clippy and rustdoc.
+///
+/// Regenerate `lints.json` with `cargo xtask codegen lints`.
+static RUST_LINTS: LazyLock<RustLints> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("../lints.json")).expect("lints.json should be valid")
+});
+
+/// Groups with at most this many lints list them on hover.
+const MAX_HOVER_GROUP_LINTS: usize = 20;
+
+#[derive(Debug, serde::Deserialize)]
+struct RustLints {
+    rust: Vec<RustLint>,
+    clippy: Vec<RustLint>,
+    rustdoc: Vec<RustLint>,
+}
+
+/// A lint or lint group, with the snake case name used in `Cargo.toml`.
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct RustLint {
+    pub name: String,
+    /// The default level of a lint, such as `"warn"`.
+    #[serde(default)]
+    pub level: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The lints of a lint group.
+    #[serde(default)]
+    pub lints: Option<Vec<String>>,
+}
+
+impl RustLint {
+    fn detail(&self) -> String {
+        match (&self.lints, &self.level) {
+            (Some(lints), _) => format!("Lint group of {} lints", lints.len()),
+            (None, Some(level)) => format!("{level} by default"),
+            (None, None) => "Lint".to_string(),
+        }
+    }
+
+    fn documentation_url(&self, tool: &str) -> String {
+        let name = &self.name;
+        match (tool, &self.lints) {
+            ("clippy", Some(_)) => "https://doc.rust-lang.org/clippy/lints.html".to_string(),
+            ("clippy", None) => {
+                format!("https://rust-lang.github.io/rust-clippy/master/index.html#{name}")
+            }
+            ("rustdoc", Some(_)) => "https://doc.rust-lang.org/rustdoc/lints.html".to_string(),
+            ("rustdoc", None) => format!("https://doc.rust-lang.org/rustdoc/lints.html#{name}"),
+            (_, Some(_)) => "https://doc.rust-lang.org/rustc/lints/groups.html".to_string(),
+            (_, None) => {
+                let page = match self.level.as_deref() {
+                    Some("allow") => "allowed-by-default",
+                    Some("deny") => "deny-by-default",
+                    _ => "warn-by-default",
+                };
+                format!(
+                    "https://doc.rust-lang.org/rustc/lints/listing/{page}.html#{}",
+                    name.replace('_', "-")
+                )
+            }
+        }
+    }
+
+    fn hover_description(&self, tool: &str) -> String {
+        let mut description = match &self.lints {
+            Some(lints) if lints.len() <= MAX_HOVER_GROUP_LINTS => format!(
+                "Lint group of {} lints: {}",
+                lints.len(),
+                lints.iter().map(|lint| format!("`{lint}`")).join(", ")
+            ),
+            Some(lints) => format!("Lint group of {} lints.", lints.len()),
+            None => self.description.clone().unwrap_or_default(),
+        };
+        if let Some(level) = &self.level {
+            description.push_str(&format!("\n\nDefault level: `{level}`"));
+        }
+        description.push_str(&format!(
+            "\n\n[Documentation]({})",
+            self.documentation_url(tool)
+        ));
+        description
+    }
+}
+
+/// The lints of a tool table in `[lints]`, or `None` for unknown tools.
+pub(crate) fn tool_lints(tool: &str) -> Option<&'static [RustLint]> {
+    match tool {
+        "rust" => Some(&RUST_LINTS.rust),
+        "clippy" => Some(&RUST_LINTS.clippy),
+        "rustdoc" => Some(&RUST_LINTS.rustdoc),
+        _ => None,
+    }
+}
+
+/// Find a lint or lint group, accepting kebab case names as rustc does.
+pub(crate) fn find_lint(tool: &str, name: &str) -> Option<&'static RustLint> {
+    let lints = tool_lints(tool)?;
+    let name = name.replace('-', "_");
+    lints
+        .binary_search_by(|lint| lint.name.as_str().cmp(&name))
+        .ok()
+        .map(|index| &lints[index])
+}
+
+/// Split accessors in `[lints.<tool>]` or `[workspace.lints.<tool>]`
+/// into the accessors of the tool table, the tool and the accessors in the tool table.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/manifest.html#the-lints-section
+pub(crate) fn split_lint_accessors(
+    accessors: &[Accessor],
+) -> Option<(&[Accessor], &str, &[Accessor])> {
+    let tool_index = match accessors {
+        [Accessor::Key(first), Accessor::Key(_), ..] if first == "lints" => 1,
+        [
+            Accessor::Key(first),
+            Accessor::Key(second),
+            Accessor::Key(_),
+            ..,
+        ] if first == "workspace" && second == "lints" => 2,
+        _ => return None,
+    };
+    let Accessor::Key(tool) = &accessors[tool_index] else {
+        return None;
+    };
+
+    Some((
+        &accessors[..=tool_index],
+        tool.as_str(),
+        &accessors[tool_index + 1..],
+    ))
+}
+
+/// Complete the lint names of a tool table in `[lints]`.
+pub(crate) fn complete_lint_names(
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+    completion_hint: Option<CompletionHint>,
+) -> Option<Vec<CompletionContent>> {
+    if completion_hint.is_some() {
+        return None;
+    }
+
+    let (tool_accessors, tool, lint_accessors) = split_lint_accessors(accessors)?;
+    let lints = tool_lints(tool)?;
+
+    let Some((_, Value::Table(tool_table))) = dig_accessors(document_tree, tool_accessors) else {
+        return None;
+    };
+
+    let (lint_prefix, completion_range) = match lint_accessors {
+        [] => (String::new(), tombi_text::Range::at(position)),
+        [Accessor::Key(lint_name)] => {
+            let Some((_, Value::Incomplete { .. })) = dig_accessors(document_tree, accessors)
+            else {
+                return None;
+            };
+            let (lint_key, _) = tool_table.get_key_value(lint_name.as_str())?;
+
+            (lint_name.replace('-', "_"), lint_key.range())
+        }
+        _ => return None,
+    };
+
+    let existing_lint_names = tool_table
+        .keys()
+        .map(|key| key.value.replace('-', "_"))
+        .filter(|lint_name| *lint_name != lint_prefix)
+        .collect::<HashSet<_>>();
+
+    let completions = lints
+        .iter()
+        .filter(|lint| lint.name.starts_with(&lint_prefix))
+        .filter(|lint| !existing_lint_names.contains(&lint.name))
+        .enumerate()
+        .map(|(index, lint)| CompletionContent {
+            label: lint.name.clone(),
+            kind: CompletionKind::Key,
+            emoji_icon: Some('🦀'),
+            priority: CompletionContentPriority::Custom(format!("10__cargo_lint_{index:>04}__")),
+            detail: Some(lint.detail()),
+            documentation: lint.description.clone(),
+            filter_text: None,
+            schema_uri: None,
+            deprecated: None,
+            edit: Some(CompletionEdit {
+                text_edit: CompletionTextEdit::Edit(TextEdit {
+                    range: completion_range,
+                    new_text: format!("{} = \"${{1|allow,warn,deny,forbid|}}\"", lint.name),
+                }),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                additional_text_edits: None,
+            }),
+            preselect: None,
+            in_comment: false,
+        })
+        .collect::<Vec<_>>();
+
+    if completions.is_empty() {
+        None
+    } else {
+        Some(completions)
+    }
+}
+
+/// Describe the lint under the cursor in `[lints]`.
+pub(crate) fn lint_hover_metadata(accessors: &[Accessor]) -> Option<HoverMetadata> {
+    let (_, tool, [Accessor::Key(lint_name), ..]) = split_lint_accessors(accessors)? else {
+        return None;
+    };
+    let lint = find_lint(tool, lint_name)?;
+
+    let title = match tool {
+        "rust" => lint.name.clone(),
+        _ => format!("{tool}::{}", lint.name),
+    };
+
+    Some(HoverMetadata {
+        title: Some(HoverTextChange::Replace(title)),
+        description: Some(HoverTextChange::Replace(lint.hover_description(tool))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::IntoDocumentTreeAndErrors;
+
+    use super::*;
+
+    fn key(value: &str) -> Accessor {
+        Accessor::Key(value.to_string())
+    }
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.into_document_tree_and_errors(Default::default()).tree
+    }
+
+    #[test]
+    fn finds_lints_and_groups_of_each_tool() {
+        assert_eq!(
+            find_lint("rust", "unused_imports").and_then(|lint| lint.level.as_deref()),
+            Some("warn")
+        );
+        assert!(find_lint("rust", "unused").is_some_and(|lint| lint.lints.is_some()));
+        assert!(find_lint("clippy", "needless-return").is_some());
+        assert!(find_lint("clippy", "pedantic").is_some_and(|lint| lint.lints.is_some()));
+        assert!(find_lint("rustdoc", "broken_intra_doc_links").is_some());
+        assert!(find_lint("clippy", "unused_imports").is_none());
+        assert!(find_lint("cargo", "unused_imports").is_none());
+    }
+
+    #[test]
+    fn splits_member_and_workspace_lint_accessors() {
+        let accessors = [key("lints"), key("clippy"), key("needless_return")];
+        assert_eq!(
+            split_lint_accessors(&accessors),
+            Some((&accessors[..2], "clippy", &accessors[2..]))
+        );
+
+        let accessors = [key("workspace"), key("lints"), key("rust")];
+        assert_eq!(
+            split_lint_accessors(&accessors),
+            Some((&accessors[..], "rust", &[][..]))
+        );
+
+        assert_eq!(split_lint_accessors(&[key("lints")]), None);
+    }
+
+    #[test]
+    fn completes_lint_names_by_prefix() {
+        let document_tree =
+            parse_document_tree("[lints.clippy]\nneedless_return = \"allow\"\nneedless_ret\n");
+        let completions = complete_lint_names(
+            &document_tree,
+            tombi_text::Position::new(2, 12),
+            &[key("lints"), key("clippy"), key("needless_ret")],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            completions
+                .iter()
+                .map(|completion| completion.label.as_str())
+                .collect::<Vec<_>>(),
+            vec!["needless_return_with_question_mark"]
+        );
+    }
+
+    #[test]
+    fn describes_lints_on_hover() {
+        let metadata = lint_hover_metadata(&[
+            key("workspace"),
+            key("lints"),
+            key("rust"),
+            key("unused_imports"),
+            key("level"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            metadata.title,
+            Some(HoverTextChange::Replace("unused_imports".to_string()))
+        );
+        let Some(HoverTextChange::Replace(description)) = metadata.description else {
+            panic!("expected a description");
+        };
+        assert!(description.contains("Default level: `warn`"));
+        assert!(description.contains("warn-by-default.html#unused-imports"));
+
+        assert!(lint_hover_metadata(&[key("lints"), key("clippy"), key("typo")]).is_none());
+    }
+}
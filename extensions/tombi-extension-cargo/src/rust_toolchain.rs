@@ -0,0 +1,128 @@
+use tombi_extension::{CompletionContent, CompletionHint};
+use tombi_schema_store::Accessor;
+
+use crate::cargo_config::{ConfigKey, ConfigTable, ConfigValue, complete_config};
+
+/// The keys of `rust-toolchain.toml`.
+///
+/// See: https://rust-lang.github.io/rustup/overrides.html#the-toolchain-file
+static RUST_TOOLCHAIN: ConfigTable = ConfigTable::Keys(&[ConfigKey::new(
+    "toolchain",
+    "The toolchain used in this directory.",
+    ConfigTable::Keys(&[
+        ConfigKey::new(
+            "channel",
+            "The release channel, optionally with a date or a version.",
+            ConfigTable::Value(ConfigValue::OneOf(&["stable", "beta", "nightly"])),
+        ),
+        ConfigKey::new(
+            "components",
+            "The components installed in addition to the profile.",
+            ConfigTable::Value(ConfigValue::OneOf(&[
+                "cargo",
+                "clippy",
+                "llvm-tools",
+                "miri",
+                "rust-analyzer",
+                "rust-docs",
+                "rust-src",
+                "rust-std",
+                "rustc",
+                "rustc-codegen-cranelift",
+                "rustc-dev",
+                "rustfmt",
+            ])),
+        ),
+        ConfigKey::new(
+            "targets",
+            "The targets whose standard library is installed.",
+            ConfigTable::Value(ConfigValue::Target),
+        ),
+        ConfigKey::new(
+            "profile",
+            "The set of components installed with the toolchain.",
+            ConfigTable::Value(ConfigValue::OneOf(&["minimal", "default", "complete"])),
+        ),
+        ConfigKey::new(
+            "path",
+            "The path of a custom toolchain, instead of a channel.",
+            ConfigTable::Value(ConfigValue::Any),
+        ),
+    ]),
+)]);
+
+/// Complete the keys of `rust-toolchain.toml`, and its channels, components and targets.
+pub(crate) fn complete_rust_toolchain(
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+    completion_hint: Option<CompletionHint>,
+) -> Option<Vec<CompletionContent>> {
+    complete_config(
+        &RUST_TOOLCHAIN,
+        "rust_toolchain",
+        document_tree,
+        position,
+        accessors,
+        completion_hint,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::IntoDocumentTreeAndErrors;
+
+    use super::*;
+
+    fn key(value: &str) -> Accessor {
+        Accessor::Key(value.to_string())
+    }
+
+    fn completion_labels(
+        source: &str,
+        position: tombi_text::Position,
+        accessors: &[Accessor],
+    ) -> Vec<String> {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        let document_tree = root.into_document_tree_and_errors(Default::default()).tree;
+
+        complete_rust_toolchain(&document_tree, position, accessors, None)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|completion| completion.label)
+            .collect()
+    }
+
+    #[test]
+    fn completes_toolchain_keys_and_values() {
+        assert_eq!(
+            completion_labels(
+                "[toolchain]\nchannel = \"stable\"\n",
+                tombi_text::Position::new(2, 0),
+                &[key("toolchain")],
+            ),
+            vec!["components", "targets", "profile", "path"]
+        );
+        assert_eq!(
+            completion_labels(
+                "[toolchain]\nchannel = \"n\"\n",
+                tombi_text::Position::new(1, 12),
+                &[key("toolchain"), key("channel")],
+            ),
+            vec!["\"stable\"", "\"beta\"", "\"nightly\""]
+        );
+    }
+
+    #[test]
+    fn skips_listed_components() {
+        let components = completion_labels(
+            "[toolchain]\ncomponents = [\"rustfmt\", ]\n",
+            tombi_text::Position::new(1, 25),
+            &[key("toolchain"), key("components")],
+        );
+
+        assert!(components.contains(&"\"clippy\"".to_string()));
+        assert!(!components.contains(&"\"rustfmt\"".to_string()));
+    }
+}
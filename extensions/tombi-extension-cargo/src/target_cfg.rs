@@ -0,0 +1,908 @@
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use itertools::Itertools;
+use tombi_document_tree::{Key, KeyKind, Value, dig_keys};
+use tombi_extension::{
+    CompletionContent, CompletionContentPriority, CompletionEdit, CompletionKind,
+    CompletionTextEdit, HoverMetadata, HoverTextChange, TextEdit,
+};
+use tombi_schema_store::{Accessor, matches_accessors};
+use tower_lsp::lsp_types::InsertTextFormat;
+
+/// The built-in targets of rustc.
+///
+/// Regenerate `targets.json` with `cargo xtask codegen targets`.
+static BUILT_IN_TARGETS: LazyLock<Vec<BuiltInTarget>> = LazyLock::new(|| {
+    let target_table: TargetTable = serde_json::from_str(include_str!("../targets.json"))
+        .expect("targets.json should be valid");
+
+    target_table
+        .targets
+        .into_iter()
+        .map(|target| BuiltInTarget {
+            cfg: target
+                .cfg
+                .iter()
+                .map(|cfg| match cfg.split_once('=') {
+                    Some((name, value)) => {
+                        (name.to_string(), Some(value.trim_matches('"').to_string()))
+                    }
+                    None => (cfg.to_string(), None),
+                })
+                .collect(),
+            triple: target.triple,
+        })
+        .collect()
+});
+
+/// The cfg names and keys recorded for each built-in target.
+const TARGET_CFG_NAMES: &[&str] = &[
+    "target_abi",
+    "target_arch",
+    "target_endian",
+    "target_env",
+    "target_family",
+    "target_os",
+    "target_pointer_width",
+    "target_vendor",
+    "unix",
+    "windows",
+];
+
+/// The cfg that Cargo does not set when it selects platform-specific dependencies.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html#platform-specific-dependencies
+const UNSUPPORTED_CFG_NAMES: &[&str] = &["debug_assertions", "feature", "proc_macro", "test"];
+
+/// The cfg offered by completion, with their documentation.
+const CFG_COMPLETIONS: &[(&str, CfgCompletionKind, &str)] = &[
+    (
+        "all",
+        CfgCompletionKind::Predicate,
+        "True if all of the predicates are true.",
+    ),
+    (
+        "any",
+        CfgCompletionKind::Predicate,
+        "True if any of the predicates is true.",
+    ),
+    (
+        "not",
+        CfgCompletionKind::Predicate,
+        "True if the predicate is false.",
+    ),
+    ("unix", CfgCompletionKind::Name, "Set on Unix-like targets."),
+    (
+        "windows",
+        CfgCompletionKind::Name,
+        "Set on Windows targets.",
+    ),
+    (
+        "target_os",
+        CfgCompletionKind::Key,
+        "The operating system of the target.",
+    ),
+    (
+        "target_arch",
+        CfgCompletionKind::Key,
+        "The CPU architecture of the target.",
+    ),
+    (
+        "target_family",
+        CfgCompletionKind::Key,
+        "The family of operating systems of the target.",
+    ),
+    (
+        "target_env",
+        CfgCompletionKind::Key,
+        "The ABI or libc of the target.",
+    ),
+    (
+        "target_vendor",
+        CfgCompletionKind::Key,
+        "The vendor of the target.",
+    ),
+    (
+        "target_pointer_width",
+        CfgCompletionKind::Key,
+        "The pointer width of the target in bits.",
+    ),
+    (
+        "target_endian",
+        CfgCompletionKind::Key,
+        "The endianness of the target CPU.",
+    ),
+    (
+        "target_abi",
+        CfgCompletionKind::Key,
+        "The ABI of the target, refining `target_env`.",
+    ),
+    (
+        "feature",
+        CfgCompletionKind::Key,
+        "Not set when Cargo selects dependencies. Use optional dependencies in `[features]` instead.",
+    ),
+];
+
+/// Built-in targets listed on hover, at most.
+const MAX_HOVER_TARGETS: usize = 20;
+
+#[derive(Debug, serde::Deserialize)]
+struct TargetTable {
+    targets: Vec<TargetTableEntry>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TargetTableEntry {
+    triple: String,
+    cfg: Vec<String>,
+}
+
+#[derive(Debug)]
+struct BuiltInTarget {
+    triple: String,
+    cfg: Vec<(String, Option<String>)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CfgCompletionKind {
+    Predicate,
+    Name,
+    Key,
+}
+
+/// The key of a `[target.<key>]` table.
+///
+/// See: https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html#platform-specific-dependencies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TargetKey {
+    Triple(String),
+    Cfg(CfgExpr),
+}
+
+/// A cfg expression, with spans in chars of the target key.
+///
+/// See: https://doc.rust-lang.org/reference/conditional-compilation.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CfgExpr {
+    /// A name such as `unix`, or a key-value pair such as `target_os = "linux"`.
+    Option {
+        name: String,
+        value: Option<String>,
+        span: Range<usize>,
+    },
+    All(Vec<CfgExpr>),
+    Any(Vec<CfgExpr>),
+    Not(Box<CfgExpr>),
+}
+
+impl CfgExpr {
+    /// Visit the names and key-value pairs of the expression.
+    pub(crate) fn options(&self) -> Vec<(&str, Option<&str>, Range<usize>)> {
+        match self {
+            Self::Option { name, value, span } => {
+                vec![(name.as_str(), value.as_deref(), span.clone())]
+            }
+            Self::All(exprs) | Self::Any(exprs) => exprs.iter().flat_map(Self::options).collect(),
+            Self::Not(expr) => expr.options(),
+        }
+    }
+
+    /// Whether the target matches, or `None` if it depends on cfg not recorded for built-in targets.
+    fn matches(&self, target: &BuiltInTarget) -> Option<bool> {
+        match self {
+            Self::Option { name, value, .. } => {
+                TARGET_CFG_NAMES.contains(&name.as_str()).then(|| {
+                    target.cfg.iter().any(|(target_name, target_value)| {
+                        target_name == name && target_value == value
+                    })
+                })
+            }
+            Self::All(exprs) => {
+                let mut matches = Some(true);
+                for expr in exprs {
+                    match expr.matches(target) {
+                        Some(false) => return Some(false),
+                        None => matches = None,
+                        Some(true) => {}
+                    }
+                }
+                matches
+            }
+            Self::Any(exprs) => {
+                let mut matches = Some(false);
+                for expr in exprs {
+                    match expr.matches(target) {
+                        Some(true) => return Some(true),
+                        None => matches = None,
+                        Some(false) => {}
+                    }
+                }
+                matches
+            }
+            Self::Not(expr) => expr.matches(target).map(|matches| !matches),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub(crate) enum TargetKeyError {
+    #[error("expected {expected}, found {found}")]
+    Expected {
+        expected: &'static str,
+        found: String,
+        span: Range<usize>,
+    },
+    #[error("unterminated string")]
+    UnterminatedString { span: Range<usize> },
+    #[error("unexpected character `{character}` in target name")]
+    InvalidTargetName { character: char, span: Range<usize> },
+}
+
+impl TargetKeyError {
+    pub(crate) fn span(&self) -> Range<usize> {
+        match self {
+            Self::Expected { span, .. }
+            | Self::UnterminatedString { span }
+            | Self::InvalidTargetName { span, .. } => span.clone(),
+        }
+    }
+}
+
+/// Parse the key of a `[target.<key>]` table as Cargo does.
+pub(crate) fn parse_target_key(key: &str) -> Result<TargetKey, TargetKeyError> {
+    if !key.starts_with("cfg(") {
+        if let Some((index, character)) = key
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+        {
+            return Err(TargetKeyError::InvalidTargetName {
+                character,
+                span: index..index + 1,
+            });
+        }
+        return Ok(TargetKey::Triple(key.to_string()));
+    }
+
+    let mut parser = CfgParser {
+        tokens: tokenize(key)?,
+        index: 0,
+        end: key.chars().count(),
+    };
+    parser.expect(Token::Ident("cfg".to_string()), "`cfg`")?;
+    parser.expect(Token::OpenParen, "`(`")?;
+    let expr = parser.parse_expr()?;
+    parser.expect(Token::CloseParen, "`)`")?;
+    if let Some((token, span)) = parser.peek() {
+        return Err(TargetKeyError::Expected {
+            expected: "end of the target",
+            found: token.to_string(),
+            span: span.clone(),
+        });
+    }
+
+    Ok(TargetKey::Cfg(expr))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    String(String),
+    OpenParen,
+    CloseParen,
+    Comma,
+    Equals,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ident(ident) => write!(f, "`{ident}`"),
+            Self::String(string) => write!(f, "`\"{string}\"`"),
+            Self::OpenParen => write!(f, "`(`"),
+            Self::CloseParen => write!(f, "`)`"),
+            Self::Comma => write!(f, "`,`"),
+            Self::Equals => write!(f, "`=`"),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<(Token, Range<usize>)>, TargetKeyError> {
+    let chars = text.chars().collect_vec();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let start = index;
+        let token = match chars[index] {
+            c if c.is_whitespace() => {
+                index += 1;
+                continue;
+            }
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            ',' => Token::Comma,
+            '=' => Token::Equals,
+            '"' => {
+                let Some(length) = chars[index + 1..].iter().position(|c| *c == '"') else {
+                    return Err(TargetKeyError::UnterminatedString {
+                        span: start..chars.len(),
+                    });
+                };
+                index += length + 1;
+                Token::String(chars[start + 1..index].iter().collect())
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while chars
+                    .get(index + 1)
+                    .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+                {
+                    index += 1;
+                }
+                Token::Ident(chars[start..=index].iter().collect())
+            }
+            c => {
+                return Err(TargetKeyError::Expected {
+                    expected: "a cfg expression",
+                    found: format!("`{c}`"),
+                    span: start..start + 1,
+                });
+            }
+        };
+        index += 1;
+        tokens.push((token, start..index));
+    }
+
+    Ok(tokens)
+}
+
+struct CfgParser {
+    tokens: Vec<(Token, Range<usize>)>,
+    index: usize,
+    end: usize,
+}
+
+impl CfgParser {
+    fn peek(&self) -> Option<&(Token, Range<usize>)> {
+        self.tokens.get(self.index)
+    }
+
+    fn next(&mut self) -> Option<(Token, Range<usize>)> {
+        let token = self.tokens.get(self.index).cloned();
+        self.index += 1;
+        token
+    }
+
+    fn error(&self, expected: &'static str) -> TargetKeyError {
+        match self.peek() {
+            Some((token, span)) => TargetKeyError::Expected {
+                expected,
+                found: token.to_string(),
+                span: span.clone(),
+            },
+            None => TargetKeyError::Expected {
+                expected,
+                found: "end of the target".to_string(),
+                span: self.end..self.end,
+            },
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: &'static str) -> Result<(), TargetKeyError> {
+        if self.peek().map(|(next, _)| next) == Some(&token) {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<CfgExpr, TargetKeyError> {
+        let Some((Token::Ident(name), name_span)) = self.peek().cloned() else {
+            return Err(self.error("a cfg name"));
+        };
+        self.index += 1;
+
+        match (name.as_str(), self.peek()) {
+            ("all" | "any" | "not", Some((Token::OpenParen, _))) => {
+                self.index += 1;
+                let mut exprs = Vec::new();
+                while !matches!(self.peek(), Some((Token::CloseParen, _))) {
+                    exprs.push(self.parse_expr()?);
+                    if matches!(self.peek(), Some((Token::Comma, _))) {
+                        self.index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                if name == "not" && exprs.len() != 1 {
+                    return Err(TargetKeyError::Expected {
+                        expected: "exactly one predicate in `not(...)`",
+                        found: format!("{} predicates", exprs.len()),
+                        span: name_span,
+                    });
+                }
+                self.expect(Token::CloseParen, "`,` or `)`")?;
+
+                Ok(match name.as_str() {
+                    "all" => CfgExpr::All(exprs),
+                    "any" => CfgExpr::Any(exprs),
+                    _ => CfgExpr::Not(Box::new(exprs.remove(0))),
+                })
+            }
+            (_, Some((Token::Equals, _))) => {
+                self.index += 1;
+                match self.next() {
+                    Some((Token::String(value), value_span)) => Ok(CfgExpr::Option {
+                        name,
+                        value: Some(value),
+                        span: name_span.start..value_span.end,
+                    }),
+                    _ => {
+                        self.index -= 1;
+                        Err(self.error("a string"))
+                    }
+                }
+            }
+            _ => Ok(CfgExpr::Option {
+                name,
+                value: None,
+                span: name_span,
+            }),
+        }
+    }
+}
+
+/// Whether `name = "value"` is set for some built-in target.
+///
+/// Returns `None` for cfg that are not recorded for built-in targets.
+pub(crate) fn is_known_target_cfg_value(name: &str, value: &str) -> Option<bool> {
+    TARGET_CFG_NAMES.contains(&name).then(|| {
+        BUILT_IN_TARGETS.iter().any(|target| {
+            target.cfg.iter().any(|(target_name, target_value)| {
+                target_name == name && target_value.as_deref() == Some(value)
+            })
+        })
+    })
+}
+
+/// Whether Cargo ignores the cfg when it selects platform-specific dependencies.
+pub(crate) fn is_unsupported_target_cfg(name: &str) -> bool {
+    UNSUPPORTED_CFG_NAMES.contains(&name)
+}
+
+/// The range of a span in a target key, or the range of the whole key
+/// when escapes make the key text differ from its value.
+pub(crate) fn target_key_span_range(key: &Key, span: Range<usize>) -> tombi_text::Range {
+    let Some(start) = target_key_start(key) else {
+        return key.range();
+    };
+
+    tombi_text::Range::new(
+        tombi_text::Position::new(start.line, start.column + span.start as tombi_text::Column),
+        tombi_text::Position::new(start.line, start.column + span.end as tombi_text::Column),
+    )
+}
+
+/// The start of the key value in the source, if each char of the value is a char of the source.
+fn target_key_start(key: &Key) -> Option<tombi_text::Position> {
+    let range = key.unquoted_range();
+    let value_length = key.value.chars().count() as tombi_text::Column;
+
+    (range.start.line == range.end.line && range.end.column - range.start.column == value_length)
+        .then_some(range.start)
+}
+
+/// Complete cfg names and values in the key of a `[target.'cfg(...)']` table.
+pub(crate) fn complete_target_cfg(
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+) -> Option<Vec<CompletionContent>> {
+    if !matches_accessors!(accessors, ["target", _]) {
+        return None;
+    }
+    let Some((_, Value::Table(targets))) = dig_keys(document_tree, &["target"]) else {
+        return None;
+    };
+    let (key, _) = targets.get_key_value(accessors[1].as_key()?)?;
+    if !key.value.starts_with("cfg(") {
+        return None;
+    }
+    let start = target_key_start(key)?;
+    if position.line != start.line || position.column < start.column {
+        return None;
+    }
+
+    let chars = key.value.chars().collect_vec();
+    let offset = (position.column - start.column) as usize;
+    if offset < "cfg(".len() || offset > chars.len() {
+        return None;
+    }
+    let prefix = &chars[..offset];
+    let span_range = |span: Range<usize>| target_key_span_range(key, span);
+
+    let quote_indices = prefix.iter().positions(|c| *c == '"').collect_vec();
+    if quote_indices.len() % 2 == 1 {
+        // The cursor is in a string, complete the value of the key before `=`.
+        let value_start = quote_indices.last().copied()? + 1;
+        let before_quote = prefix[..value_start - 1].iter().collect::<String>();
+        let name = before_quote
+            .trim_end()
+            .strip_suffix('=')?
+            .trim_end()
+            .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .next()?
+            .to_string();
+        let value_end = offset + chars[offset..].iter().take_while(|c| **c != '"').count();
+        let value_prefix = prefix[value_start..].iter().collect::<String>();
+
+        let values = if name == "feature" {
+            match dig_keys(document_tree, &["features"]) {
+                Some((_, Value::Table(features))) => {
+                    features.keys().map(|key| key.value.clone()).collect_vec()
+                }
+                _ => Vec::new(),
+            }
+        } else {
+            BUILT_IN_TARGETS
+                .iter()
+                .flat_map(|target| &target.cfg)
+                .filter(|(target_name, _)| *target_name == name)
+                .filter_map(|(_, value)| value.clone())
+                .filter(|value| !value.is_empty())
+                .unique()
+                .sorted()
+                .collect_vec()
+        };
+
+        let completions = values
+            .into_iter()
+            .filter(|value| value.starts_with(&value_prefix))
+            .enumerate()
+            .map(|(index, value)| CompletionContent {
+                label: value.clone(),
+                kind: CompletionKind::String,
+                emoji_icon: Some('🦀'),
+                priority: CompletionContentPriority::Custom(format!(
+                    "10__cargo_target_cfg_{index:>04}__"
+                )),
+                detail: Some(format!("{name} = \"{value}\"")),
+                documentation: None,
+                filter_text: None,
+                schema_uri: None,
+                deprecated: None,
+                edit: Some(CompletionEdit {
+                    text_edit: CompletionTextEdit::Edit(TextEdit {
+                        range: span_range(value_start..value_end),
+                        new_text: value,
+                    }),
+                    insert_text_format: None,
+                    additional_text_edits: None,
+                }),
+                preselect: None,
+                in_comment: false,
+            })
+            .collect_vec();
+
+        return (!completions.is_empty()).then_some(completions);
+    }
+
+    // The cursor is at a cfg name, after `(` or `,`.
+    let is_name_char = |c: &char| c.is_alphanumeric() || *c == '_';
+    let name_start = offset - prefix.iter().rev().take_while(|c| is_name_char(c)).count();
+    let name_end = offset
+        + chars[offset..]
+            .iter()
+            .take_while(|c| is_name_char(c))
+            .count();
+    if !matches!(
+        prefix[..name_start]
+            .iter()
+            .rev()
+            .find(|c| !c.is_whitespace()),
+        Some('(' | ',')
+    ) {
+        return None;
+    }
+    let name_prefix = prefix[name_start..].iter().collect::<String>();
+    let quote = if key.kind() == KeyKind::BasicString {
+        "\\\""
+    } else {
+        "\""
+    };
+
+    let completions = CFG_COMPLETIONS
+        .iter()
+        .filter(|(name, _, _)| name.starts_with(&name_prefix))
+        .enumerate()
+        .map(|(index, (name, kind, documentation))| CompletionContent {
+            label: name.to_string(),
+            kind: CompletionKind::Key,
+            emoji_icon: Some('🦀'),
+            priority: CompletionContentPriority::Custom(format!(
+                "10__cargo_target_cfg_{index:>04}__"
+            )),
+            detail: Some(
+                match kind {
+                    CfgCompletionKind::Predicate => "cfg predicate",
+                    CfgCompletionKind::Name => "cfg name",
+                    CfgCompletionKind::Key => "cfg key",
+                }
+                .to_string(),
+            ),
+            documentation: Some(documentation.to_string()),
+            filter_text: None,
+            schema_uri: None,
+            deprecated: None,
+            edit: Some(CompletionEdit {
+                text_edit: CompletionTextEdit::Edit(TextEdit {
+                    range: span_range(name_start..name_end),
+                    new_text: match kind {
+                        CfgCompletionKind::Predicate => format!("{name}($1)"),
+                        CfgCompletionKind::Name => name.to_string(),
+                        CfgCompletionKind::Key => format!("{name} = {quote}$1{quote}"),
+                    },
+                }),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                additional_text_edits: None,
+            }),
+            preselect: None,
+            in_comment: false,
+        })
+        .collect_vec();
+
+    (!completions.is_empty()).then_some(completions)
+}
+
+/// The triples of the built-in targets of rustc.
+pub(crate) fn built_in_target_triples() -> impl Iterator<Item = &'static str> {
+    BUILT_IN_TARGETS.iter().map(|target| target.triple.as_str())
+}
+
+/// Describe the cfg of a built-in target.
+pub(crate) fn target_triple_description(triple: &str) -> String {
+    match BUILT_IN_TARGETS
+        .iter()
+        .find(|target| target.triple == triple)
+    {
+        Some(target) => format!(
+            "Built-in target with:\n\n{}",
+            target
+                .cfg
+                .iter()
+                .map(|(name, value)| match value {
+                    Some(value) => format!("- `{name} = \"{value}\"`"),
+                    None => format!("- `{name}`"),
+                })
+                .join("\n")
+        ),
+        None => format!("`{triple}` is not a built-in target."),
+    }
+}
+
+/// Describe the built-in targets matched by the key of a `[target.<key>]` table.
+pub(crate) fn target_cfg_hover_metadata(accessors: &[Accessor]) -> Option<HoverMetadata> {
+    if !matches_accessors!(accessors, ["target", _]) {
+        return None;
+    }
+
+    let description = match parse_target_key(accessors[1].as_key()?).ok()? {
+        TargetKey::Triple(triple) => target_triple_description(&triple),
+        TargetKey::Cfg(expr) => {
+            let mut matched = Vec::new();
+            let mut undetermined = Vec::new();
+            for target in BUILT_IN_TARGETS.iter() {
+                match expr.matches(target) {
+                    Some(true) => matched.push(target.triple.as_str()),
+                    None => undetermined.push(target.triple.as_str()),
+                    Some(false) => {}
+                }
+            }
+
+            let mut description = format!(
+                "Matches {} of {} built-in targets{}",
+                matched.len(),
+                BUILT_IN_TARGETS.len(),
+                format_targets(&matched)
+            );
+            if !undetermined.is_empty() {
+                let names = expr
+                    .options()
+                    .into_iter()
+                    .map(|(name, _, _)| name)
+                    .filter(|name| !TARGET_CFG_NAMES.contains(name))
+                    .unique()
+                    .map(|name| format!("`{name}`"))
+                    .join(", ");
+                description.push_str(&format!(
+                    "\n\nMay also match {} built-in targets, depending on {names}{}",
+                    undetermined.len(),
+                    format_targets(&undetermined)
+                ));
+            }
+            description
+        }
+    };
+
+    Some(HoverMetadata {
+        title: None,
+        description: Some(HoverTextChange::Replace(description)),
+    })
+}
+
+fn format_targets(triples: &[&str]) -> String {
+    match triples.len() {
+        0 => ".".to_string(),
+        count if count <= MAX_HOVER_TARGETS => format!(
+            ":\n\n{}",
+            triples
+                .iter()
+                .map(|triple| format!("- `{triple}`"))
+                .join("\n")
+        ),
+        count => format!(
+            ", including:\n\n{}\n- and {} more",
+            triples[..MAX_HOVER_TARGETS]
+                .iter()
+                .map(|triple| format!("- `{triple}`"))
+                .join("\n"),
+            count - MAX_HOVER_TARGETS
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::IntoDocumentTreeAndErrors;
+
+    use super::*;
+
+    fn key(value: &str) -> Accessor {
+        Accessor::Key(value.to_string())
+    }
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.into_document_tree_and_errors(Default::default()).tree
+    }
+
+    fn option(name: &str, value: Option<&str>, span: Range<usize>) -> CfgExpr {
+        CfgExpr::Option {
+            name: name.to_string(),
+            value: value.map(ToString::to_string),
+            span,
+        }
+    }
+
+    fn completion_labels(source: &str, position: tombi_text::Position) -> Vec<String> {
+        let document_tree = parse_document_tree(source);
+        let Some((_, Value::Table(targets))) = dig_keys(&document_tree, &["target"]) else {
+            panic!("expected a target table");
+        };
+        let target = targets.keys().next().unwrap().value.clone();
+
+        complete_target_cfg(&document_tree, position, &[key("target"), key(&target)])
+            .unwrap_or_default()
+            .into_iter()
+            .map(|completion| completion.label)
+            .collect()
+    }
+
+    #[test]
+    fn parses_target_keys() {
+        assert_eq!(
+            parse_target_key("x86_64-unknown-linux-gnu"),
+            Ok(TargetKey::Triple("x86_64-unknown-linux-gnu".to_string()))
+        );
+        assert_eq!(
+            parse_target_key(r#"cfg(all(unix, not(target_os = "macos")))"#),
+            Ok(TargetKey::Cfg(CfgExpr::All(vec![
+                option("unix", None, 8..12),
+                CfgExpr::Not(Box::new(option("target_os", Some("macos"), 18..37))),
+            ])))
+        );
+        assert_eq!(
+            parse_target_key("cfg(any(windows,))"),
+            Ok(TargetKey::Cfg(CfgExpr::Any(vec![option(
+                "windows",
+                None,
+                8..15
+            )])))
+        );
+    }
+
+    #[test]
+    fn reports_target_key_syntax_errors() {
+        for (key, message, span) in [
+            ("cfg(unix", "expected `)`, found end of the target", 8..8),
+            ("cfg()", "expected a cfg name, found `)`", 4..5),
+            (r#"cfg(target_os = "linux)"#, "unterminated string", 16..23),
+            (
+                "cfg(target_os = linux)",
+                "expected a string, found `linux`",
+                16..21,
+            ),
+            (
+                "cfg(not(unix, windows))",
+                "expected exactly one predicate in `not(...)`, found 2 predicates",
+                4..7,
+            ),
+            (
+                "cfg(unix) x",
+                "expected end of the target, found `x`",
+                10..11,
+            ),
+            (
+                "x86_64-unknown-linux-gnu)",
+                "unexpected character `)` in target name",
+                24..25,
+            ),
+        ] {
+            let error = parse_target_key(key).unwrap_err();
+            assert_eq!((error.to_string().as_str(), error.span()), (message, span));
+        }
+    }
+
+    #[test]
+    fn knows_target_cfg_values() {
+        assert_eq!(is_known_target_cfg_value("target_os", "linux"), Some(true));
+        assert_eq!(is_known_target_cfg_value("target_os", "linx"), Some(false));
+        assert_eq!(is_known_target_cfg_value("feature", "serde"), None);
+    }
+
+    #[test]
+    fn completes_cfg_names_and_values() {
+        let labels = completion_labels(
+            "[target.'cfg(target_)'.dependencies]\n",
+            tombi_text::Position::new(0, 20),
+        );
+        assert!(labels.contains(&"target_os".to_string()));
+        assert!(!labels.contains(&"unix".to_string()));
+
+        let labels = completion_labels(
+            "[target.'cfg(target_os = \"li\")'.dependencies]\n",
+            tombi_text::Position::new(0, 28),
+        );
+        assert!(labels.contains(&"linux".to_string()));
+        assert!(!labels.contains(&"windows".to_string()));
+
+        let labels = completion_labels(
+            "[features]\nsimd = []\n\n[target.'cfg(feature = \"\")'.dependencies]\n",
+            tombi_text::Position::new(3, 24),
+        );
+        assert_eq!(labels, vec!["simd"]);
+    }
+
+    #[test]
+    fn describes_matched_targets_on_hover() {
+        let Some(HoverMetadata {
+            description: Some(HoverTextChange::Replace(description)),
+            ..
+        }) = target_cfg_hover_metadata(&[
+            key("target"),
+            key(r#"cfg(all(target_os = "linux", target_arch = "x86_64", target_env = "gnu"))"#),
+        ])
+        else {
+            panic!("expected a description");
+        };
+        assert!(description.starts_with("Matches "));
+        assert!(description.contains("- `x86_64-unknown-linux-gnu`"));
+        assert!(!description.contains("May also match"));
+
+        let Some(HoverMetadata {
+            description: Some(HoverTextChange::Replace(description)),
+            ..
+        }) =
+            target_cfg_hover_metadata(&[key("target"), key(r#"cfg(any(windows, feature = "x"))"#)])
+        else {
+            panic!("expected a description");
+        };
+        assert!(description.contains("depending on `feature`"));
+
+        assert!(target_cfg_hover_metadata(&[key("target"), key("cfg(unix")]).is_none());
+    }
+}
@@ -0,0 +1,107 @@
+//! A stub external extension for tests.
+//!
+//! It reads one JSON-RPC request per line from stdin and answers from the request only:
+//!
+//! - completion: `<accessors joined by ".">@<line>:<column>`
+//! - inlay hint: `<type>` after every top-level value
+//! - document link: every top-level `url` string
+//! - definition: the first top-level key
+//! - declaration: not implemented
+//! - code action: insert `# stub` at the start of the document
+
+use std::io::{BufRead, Write};
+
+use serde_json::{Value, json};
+
+fn main() {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let Ok(request) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+
+        let response = match handle(&request["method"], &request["params"]) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+            Err((code, message)) => json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": { "code": code, "message": message },
+            }),
+        };
+
+        if writeln!(stdout, "{response}").is_err() || stdout.flush().is_err() {
+            break;
+        }
+    }
+}
+
+fn handle(method: &Value, params: &Value) -> Result<Value, (i64, &'static str)> {
+    let entries = params["documentTree"]["entries"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+
+    match method.as_str().unwrap_or_default() {
+        "textDocument/completion" => {
+            let accessors = params["accessors"]
+                .as_array()
+                .map(|accessors| {
+                    accessors
+                        .iter()
+                        .map(|accessor| match accessor {
+                            Value::String(key) => key.clone(),
+                            accessor => accessor.to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(".")
+                })
+                .unwrap_or_default();
+            let position = &params["position"];
+
+            Ok(json!([{
+                "label": format!("{accessors}@{}:{}", position["line"], position["column"]),
+                "detail": "stub",
+            }]))
+        }
+        "textDocument/inlayHint" => Ok(entries
+            .iter()
+            .map(|entry| {
+                json!({
+                    "position": entry["value"]["range"]["end"],
+                    "label": format!(": {}", entry["value"]["type"].as_str().unwrap_or_default()),
+                    "kind": "type",
+                })
+            })
+            .collect()),
+        "textDocument/documentLink" => Ok(entries
+            .iter()
+            .filter(|entry| entry["key"]["value"] == "url" && entry["value"]["type"] == "string")
+            .map(|entry| {
+                json!({
+                    "target": entry["value"]["value"],
+                    "range": entry["value"]["range"],
+                })
+            })
+            .collect()),
+        "textDocument/definition" => Ok(entries
+            .first()
+            .map(|entry| json!([{ "uri": params["uri"], "range": entry["key"]["range"] }]))
+            .unwrap_or(Value::Null)),
+        "textDocument/codeAction" => Ok(json!([{
+            "title": "Insert stub comment",
+            "edits": [{
+                "range": {
+                    "start": { "line": 0, "column": 0 },
+                    "end": { "line": 0, "column": 0 },
+                },
+                "newText": "# stub\n",
+            }],
+        }])),
+        _ => Err((-32601, "Method not found")),
+    }
+}
@@ -0,0 +1,274 @@
+use std::{path::Path, sync::Arc};
+
+use serde::de::DeserializeOwned;
+use tombi_config::{Config, ExternalExtension};
+use tombi_extension::{
+    CodeActionOrCommand, CommentContext, CompletionContent, CompletionHint, DocumentLink,
+    Extension, ExtensionContext, InlayHint, Location,
+};
+use tombi_future::{BoxFuture, Boxable};
+use tombi_schema_store::{Accessor, AccessorContext};
+
+use crate::{
+    process::ExtensionProcess,
+    protocol::{
+        self, CODE_ACTION_METHOD, COMPLETION_METHOD, DECLARATION_METHOD, DEFINITION_METHOD,
+        DOCUMENT_LINK_METHOD, DocumentParams, INLAY_HINT_METHOD,
+    },
+};
+
+/// The extensions registered in `[[extensions.external]]`, each running as its own process.
+///
+/// The extensions run arbitrary commands, so the caller passes only the ones
+/// from a config the user trusts, never from a project config of a checkout.
+/// The processes are started on the first request for a matching file.
+#[derive(Debug, Default)]
+pub struct ExternalExtensions {
+    extensions: Vec<RegisteredExtension>,
+}
+
+#[derive(Debug)]
+struct RegisteredExtension {
+    include: Vec<glob::Pattern>,
+    process: Arc<ExtensionProcess>,
+}
+
+impl ExternalExtensions {
+    /// Register the enabled extensions of the config in `config_dir`.
+    ///
+    /// A relative `command` with a path separator is resolved against `config_dir`.
+    pub fn new<'a>(
+        extensions: impl IntoIterator<Item = &'a ExternalExtension>,
+        config_dir: &Path,
+    ) -> Self {
+        Self {
+            extensions: extensions
+                .into_iter()
+                .filter(|extension| extension.enabled().value())
+                .map(|extension| {
+                    let command = if extension.command.contains(std::path::is_separator) {
+                        config_dir.join(&extension.command)
+                    } else {
+                        extension.command.clone().into()
+                    };
+
+                    RegisteredExtension {
+                        include: extension
+                            .include
+                            .iter()
+                            .filter_map(|pattern| {
+                                glob::Pattern::new(&glob_pattern_for_include(pattern)).ok()
+                            })
+                            .collect(),
+                        process: Arc::new(ExtensionProcess::new(
+                            extension.name.clone(),
+                            command,
+                            extension.args.clone().unwrap_or_default(),
+                        )),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// The processes of the extensions handling the document.
+    fn processes_for(&self, context: &ExtensionContext<'_>) -> Vec<Arc<ExtensionProcess>> {
+        let path = context.text_document_uri.path();
+
+        self.extensions
+            .iter()
+            .filter(|extension| {
+                extension
+                    .include
+                    .iter()
+                    .any(|pattern| pattern.matches(path))
+            })
+            .map(|extension| extension.process.clone())
+            .collect()
+    }
+
+    /// Send the request to every matching extension, collecting the results.
+    async fn request_all<R: DeserializeOwned>(
+        &self,
+        context: &ExtensionContext<'_>,
+        method: &str,
+        params: DocumentParams,
+    ) -> Vec<(Arc<ExtensionProcess>, R)> {
+        let processes = self.processes_for(context);
+        let responses = futures::future::join_all(
+            processes
+                .iter()
+                .map(|process| process.request::<_, R>(method, &params)),
+        )
+        .await;
+
+        processes
+            .into_iter()
+            .zip(responses)
+            .filter_map(|(process, response)| match response {
+                Ok(Some(result)) => Some((process, result)),
+                Ok(None) => None,
+                Err(error) => {
+                    log::warn!("{} extension failed on {method}: {error}", process.name());
+                    None
+                }
+            })
+            .collect()
+    }
+
+    async fn locations(
+        &self,
+        context: &ExtensionContext<'_>,
+        method: &str,
+        accessors: &[Accessor],
+    ) -> Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error> {
+        let params = DocumentParams::new(context).with_accessors(accessors);
+
+        Ok(self
+            .request_all::<Vec<protocol::Location>>(context, method, params)
+            .await
+            .into_iter()
+            .map(|(_, locations)| {
+                locations
+                    .into_iter()
+                    .filter_map(protocol::Location::into_location)
+                    .collect::<Vec<_>>()
+            })
+            .find(|locations| !locations.is_empty()))
+    }
+}
+
+impl Extension for ExternalExtensions {
+    fn name(&self) -> &'static str {
+        "external"
+    }
+
+    fn file_patterns(&self) -> &[&str] {
+        // The files are matched against `include` of each external extension.
+        &["**/*"]
+    }
+
+    fn enabled(&self, _config: &Config) -> bool {
+        !self.extensions.is_empty()
+    }
+
+    fn completion<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+        position: tombi_text::Position,
+        _completion_hint: Option<CompletionHint>,
+        _comment_context: Option<&'a CommentContext>,
+    ) -> BoxFuture<'a, Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            let params = DocumentParams::new(context)
+                .with_accessors(accessors)
+                .with_position(position);
+
+            let completions = self
+                .request_all::<Vec<protocol::Completion>>(context, COMPLETION_METHOD, params)
+                .await
+                .into_iter()
+                .flat_map(|(process, completions)| {
+                    completions
+                        .into_iter()
+                        .map(move |completion| completion.into_completion_content(process.name()))
+                })
+                .collect::<Vec<_>>();
+
+            Ok((!completions.is_empty()).then_some(completions))
+        }
+        .boxed()
+    }
+
+    fn code_action<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        _root: &'a tombi_ast::Root,
+        line_index: &'a tombi_text::LineIndex,
+        accessors: &'a [Accessor],
+        _accessor_contexts: &'a [AccessorContext],
+    ) -> BoxFuture<'a, Result<Option<Vec<CodeActionOrCommand>>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            let params = DocumentParams::new(context).with_accessors(accessors);
+
+            let code_actions = self
+                .request_all::<Vec<protocol::Action>>(context, CODE_ACTION_METHOD, params)
+                .await
+                .into_iter()
+                .flat_map(|(_, actions)| actions)
+                .map(|action| action.into_code_action(context.text_document_uri, line_index))
+                .collect::<Vec<_>>();
+
+            Ok((!code_actions.is_empty()).then_some(code_actions))
+        }
+        .boxed()
+    }
+
+    fn inlay_hint<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        visible_range: tombi_text::Range,
+    ) -> BoxFuture<'a, Result<Option<Vec<InlayHint>>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            let params = DocumentParams::new(context).with_visible_range(visible_range);
+
+            let inlay_hints = self
+                .request_all::<Vec<protocol::InlayHint>>(context, INLAY_HINT_METHOD, params)
+                .await
+                .into_iter()
+                .flat_map(|(_, inlay_hints)| inlay_hints)
+                .map(InlayHint::from)
+                .collect::<Vec<_>>();
+
+            Ok((!inlay_hints.is_empty()).then_some(inlay_hints))
+        }
+        .boxed()
+    }
+
+    fn goto_definition<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        self.locations(context, DEFINITION_METHOD, accessors)
+            .boxed()
+    }
+
+    fn goto_declaration<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        self.locations(context, DECLARATION_METHOD, accessors)
+            .boxed()
+    }
+
+    fn document_link<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<Vec<DocumentLink>>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            let params = DocumentParams::new(context);
+
+            let document_links = self
+                .request_all::<Vec<protocol::DocumentLink>>(context, DOCUMENT_LINK_METHOD, params)
+                .await
+                .into_iter()
+                .flat_map(|(_, document_links)| document_links)
+                .filter_map(protocol::DocumentLink::into_document_link)
+                .collect::<Vec<_>>();
+
+            Ok((!document_links.is_empty()).then_some(document_links))
+        }
+        .boxed()
+    }
+}
+
+fn glob_pattern_for_include(pattern: &str) -> String {
+    if pattern.starts_with('/') || pattern.starts_with("**/") {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    }
+}
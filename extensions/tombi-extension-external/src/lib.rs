@@ -0,0 +1,6 @@
+mod extension;
+mod process;
+pub mod protocol;
+
+pub use extension::ExternalExtensions;
+pub use process::Error;
@@ -0,0 +1,306 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    sync::oneshot,
+};
+use tombi_hashmap::HashMap;
+
+use crate::protocol::METHOD_NOT_FOUND;
+
+/// The requests come from interactive editor features,
+/// so a slow extension must not hold them up.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Failed to start `{}`: {source}", command.display())]
+    Spawn {
+        command: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("The extension exited")]
+    Closed,
+
+    #[error("The extension did not respond within {} ms", REQUEST_TIMEOUT.as_millis())]
+    Timeout,
+
+    #[error("{message} ({code})")]
+    Response { code: i64, message: String },
+}
+
+/// A running external extension, started on the first request.
+///
+/// Requests are sent without waiting for earlier responses. If the process exits,
+/// it is started again on the next request; if it cannot be started at all,
+/// later requests are skipped.
+#[derive(Debug)]
+pub struct ExtensionProcess {
+    name: String,
+    command: PathBuf,
+    args: Vec<String>,
+    state: tokio::sync::Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+enum State {
+    #[default]
+    NotStarted,
+    Running(Arc<Connection>),
+    SpawnFailed,
+}
+
+type PendingRequests = Mutex<HashMap<u64, oneshot::Sender<Response>>>;
+
+#[derive(Debug)]
+struct Connection {
+    // Kept to kill the process on drop.
+    _child: tokio::process::Child,
+    stdin: tokio::sync::Mutex<tokio::process::ChildStdin>,
+    pending: Arc<PendingRequests>,
+    closed: Arc<AtomicBool>,
+    next_id: AtomicU64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Response {
+    id: Option<serde_json::Value>,
+    #[serde(default)]
+    result: Option<serde_json::Value>,
+    #[serde(default)]
+    error: Option<ResponseError>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+impl ExtensionProcess {
+    pub fn new(name: String, command: PathBuf, args: Vec<String>) -> Self {
+        Self {
+            name,
+            command,
+            args,
+            state: Default::default(),
+        }
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Send a request, returning `None` if the extension does not implement the method.
+    pub async fn request<P, R>(&self, method: &str, params: &P) -> Result<Option<R>, Error>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let Some(connection) = self.connection().await? else {
+            return Ok(None);
+        };
+
+        match tokio::time::timeout(REQUEST_TIMEOUT, connection.request(method, params)).await {
+            Ok(Ok(result)) => match result {
+                Some(result) => Ok(serde_json::from_value(result)?),
+                None => Ok(None),
+            },
+            Ok(Err(Error::Response { code, .. })) if code == METHOD_NOT_FOUND => Ok(None),
+            Ok(Err(error)) => Err(error),
+            Err(_) => Err(Error::Timeout),
+        }
+    }
+
+    /// The connection to the process, starting it if it is not running.
+    async fn connection(&self) -> Result<Option<Arc<Connection>>, Error> {
+        let mut state = self.state.lock().await;
+
+        match &*state {
+            State::Running(connection) if !connection.closed.load(Ordering::Acquire) => {
+                return Ok(Some(connection.clone()));
+            }
+            State::SpawnFailed => return Ok(None),
+            State::NotStarted | State::Running(_) => {}
+        }
+
+        match self.spawn() {
+            Ok(connection) => {
+                let connection = Arc::new(connection);
+                *state = State::Running(connection.clone());
+                Ok(Some(connection))
+            }
+            Err(error) => {
+                *state = State::SpawnFailed;
+                Err(error)
+            }
+        }
+    }
+
+    fn spawn(&self) -> Result<Connection, Error> {
+        log::debug!(
+            "Starting {} extension: {}",
+            self.name,
+            self.command.display()
+        );
+
+        let mut child = tokio::process::Command::new(&self.command)
+            .args(&self.args)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::inherit())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|source| Error::Spawn {
+                command: self.command.clone(),
+                source,
+            })?;
+
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(Error::Closed);
+        };
+
+        let pending = Arc::new(PendingRequests::default());
+        let closed = Arc::new(AtomicBool::new(false));
+        tokio::spawn(read_responses(
+            BufReader::new(stdout),
+            pending.clone(),
+            closed.clone(),
+        ));
+
+        Ok(Connection {
+            _child: child,
+            stdin: tokio::sync::Mutex::new(stdin),
+            pending,
+            closed,
+            next_id: AtomicU64::new(1),
+        })
+    }
+}
+
+impl Connection {
+    async fn request<P: Serialize>(
+        &self,
+        method: &str,
+        params: &P,
+    ) -> Result<Option<serde_json::Value>, Error> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+
+        let mut message = serde_json::to_string(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        }))?;
+        message.push('\n');
+
+        let (sender, receiver) = oneshot::channel();
+        lock(&self.pending).insert(id, sender);
+        // Forget the request if it is cancelled, such as by the timeout.
+        let _pending = PendingGuard {
+            pending: &self.pending,
+            id,
+        };
+        if self.closed.load(Ordering::Acquire) {
+            return Err(Error::Closed);
+        }
+
+        {
+            let mut stdin = self.stdin.lock().await;
+            if let Err(error) = write_message(&mut stdin, &message).await {
+                self.closed.store(true, Ordering::Release);
+                return Err(error.into());
+            }
+        }
+
+        let Ok(response) = receiver.await else {
+            return Err(Error::Closed);
+        };
+        if let Some(ResponseError { code, message }) = response.error {
+            return Err(Error::Response { code, message });
+        }
+        Ok(response.result.filter(|result| !result.is_null()))
+    }
+}
+
+struct PendingGuard<'a> {
+    pending: &'a PendingRequests,
+    id: u64,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        lock(self.pending).remove(&self.id);
+    }
+}
+
+async fn write_message(
+    stdin: &mut tokio::process::ChildStdin,
+    message: &str,
+) -> Result<(), std::io::Error> {
+    stdin.write_all(message.as_bytes()).await?;
+    stdin.flush().await
+}
+
+/// Hand each response to the request waiting for it, until the process exits.
+async fn read_responses(
+    mut stdout: BufReader<tokio::process::ChildStdout>,
+    pending: Arc<PendingRequests>,
+    closed: Arc<AtomicBool>,
+) {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match stdout.read_line(&mut line).await {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(error) => {
+                log::warn!("Failed to read from the extension: {error}");
+                break;
+            }
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // Skip notifications and responses to requests that timed out.
+        let Ok(response) = serde_json::from_str::<Response>(&line) else {
+            log::warn!("Invalid message from the extension: {}", line.trim_end());
+            continue;
+        };
+        let Some(id) = response.id.as_ref().and_then(serde_json::Value::as_u64) else {
+            continue;
+        };
+        if let Some(sender) = lock(&pending).remove(&id) {
+            let _ = sender.send(response);
+        }
+    }
+
+    closed.store(true, Ordering::Release);
+    // Dropping the senders wakes the waiting requests with `Error::Closed`.
+    lock(&pending).clear();
+}
+
+fn lock(
+    pending: &PendingRequests,
+) -> std::sync::MutexGuard<'_, HashMap<u64, oneshot::Sender<Response>>> {
+    pending
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,363 @@
+//! The messages exchanged with external extensions.
+//!
+//! Positions are 0-based lines and columns counted in characters,
+//! the same as `tombi_text::Position`.
+
+use std::{borrow::Cow, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use tombi_document_tree::Value;
+use tombi_extension::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CompletionContent, CompletionContentPriority,
+    CompletionEdit, CompletionKind, CompletionTextEdit, DocumentChanges, OneOf, WorkspaceEdit,
+};
+use tombi_schema_store::Accessor;
+use tombi_text::IntoLsp;
+
+pub const COMPLETION_METHOD: &str = "textDocument/completion";
+pub const INLAY_HINT_METHOD: &str = "textDocument/inlayHint";
+pub const DOCUMENT_LINK_METHOD: &str = "textDocument/documentLink";
+pub const DEFINITION_METHOD: &str = "textDocument/definition";
+pub const DECLARATION_METHOD: &str = "textDocument/declaration";
+pub const CODE_ACTION_METHOD: &str = "textDocument/codeAction";
+
+/// The JSON-RPC error code of a method the extension does not implement.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl From<tombi_text::Position> for Position {
+    fn from(position: tombi_text::Position) -> Self {
+        Self {
+            line: position.line,
+            column: position.column,
+        }
+    }
+}
+
+impl From<Position> for tombi_text::Position {
+    fn from(position: Position) -> Self {
+        tombi_text::Position::new(position.line, position.column)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl From<tombi_text::Range> for Range {
+    fn from(range: tombi_text::Range) -> Self {
+        Self {
+            start: range.start.into(),
+            end: range.end.into(),
+        }
+    }
+}
+
+impl From<Range> for tombi_text::Range {
+    fn from(range: Range) -> Self {
+        tombi_text::Range::new(range.start.into(), range.end.into())
+    }
+}
+
+/// The parameters sent with every request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentParams {
+    pub uri: String,
+    pub toml_version: String,
+    /// The document tree, see [`document_tree_to_json`].
+    pub document_tree: serde_json::Value,
+    /// The keys and indices from the root to the cursor, such as `["deploy", "services", 0]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accessors: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visible_range: Option<Range>,
+}
+
+impl DocumentParams {
+    pub fn new(context: &tombi_extension::ExtensionContext<'_>) -> Self {
+        Self {
+            uri: context.text_document_uri.to_string(),
+            toml_version: context.toml_version.to_string(),
+            document_tree: document_tree_to_json(context.document_tree),
+            accessors: Vec::new(),
+            position: None,
+            visible_range: None,
+        }
+    }
+
+    pub fn with_accessors(mut self, accessors: &[Accessor]) -> Self {
+        self.accessors = accessors
+            .iter()
+            .map(|accessor| match accessor {
+                Accessor::Key(key) => serde_json::Value::from(key.as_str()),
+                Accessor::Index(index) => serde_json::Value::from(*index),
+            })
+            .collect();
+        self
+    }
+
+    pub fn with_position(mut self, position: tombi_text::Position) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    pub fn with_visible_range(mut self, visible_range: tombi_text::Range) -> Self {
+        self.visible_range = Some(visible_range.into());
+        self
+    }
+}
+
+/// Convert the document tree to JSON, keeping the ranges of keys and values.
+///
+/// Every value is an object with `type` and `range`.
+/// Tables have `entries` of `{ key: { value, range }, value }`, arrays have `values`,
+/// and the other values have `value`, with date-times as strings.
+pub fn document_tree_to_json(
+    document_tree: &tombi_document_tree::DocumentTree,
+) -> serde_json::Value {
+    value_to_json(document_tree.into())
+}
+
+fn value_to_json(value: &Value) -> serde_json::Value {
+    let (value_type, value_json): (_, serde_json::Value) = match value {
+        Value::Boolean(boolean) => ("boolean", boolean.value().into()),
+        Value::Integer(integer) => ("integer", integer.value().into()),
+        Value::Float(float) => ("float", float.value().into()),
+        Value::String(string) => ("string", string.value().into()),
+        Value::OffsetDateTime(date_time) => ("offset-date-time", date_time.to_string().into()),
+        Value::LocalDateTime(date_time) => ("local-date-time", date_time.to_string().into()),
+        Value::LocalDate(date) => ("local-date", date.to_string().into()),
+        Value::LocalTime(time) => ("local-time", time.to_string().into()),
+        Value::Array(array) => {
+            return serde_json::json!({
+                "type": "array",
+                "range": Range::from(array.range()),
+                "values": array.values().iter().map(value_to_json).collect::<Vec<_>>(),
+            });
+        }
+        Value::Table(table) => {
+            return serde_json::json!({
+                "type": "table",
+                "range": Range::from(table.range()),
+                "entries": table
+                    .key_values()
+                    .iter()
+                    .map(|(key, value)| {
+                        serde_json::json!({
+                            "key": {
+                                "value": key.value,
+                                "range": Range::from(key.range()),
+                            },
+                            "value": value_to_json(value),
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            });
+        }
+        Value::Incomplete { range } => {
+            return serde_json::json!({
+                "type": "incomplete",
+                "range": Range::from(*range),
+            });
+        }
+    };
+
+    serde_json::json!({
+        "type": value_type,
+        "range": Range::from(value.range()),
+        "value": value_json,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Completion {
+    pub label: String,
+    #[serde(default)]
+    pub detail: Option<String>,
+    #[serde(default)]
+    pub documentation: Option<String>,
+    #[serde(default)]
+    pub filter_text: Option<String>,
+    /// The edit applied on selection. The label is inserted at the cursor if omitted.
+    #[serde(default)]
+    pub text_edit: Option<TextEdit>,
+    #[serde(default)]
+    pub deprecated: Option<bool>,
+    #[serde(default)]
+    pub preselect: Option<bool>,
+}
+
+impl Completion {
+    pub fn into_completion_content(self, extension_name: &str) -> CompletionContent {
+        CompletionContent {
+            kind: CompletionKind::Enum,
+            emoji_icon: None,
+            priority: CompletionContentPriority::Custom(format!("10__{extension_name}__")),
+            detail: self.detail,
+            documentation: self.documentation,
+            filter_text: self.filter_text,
+            schema_uri: None,
+            deprecated: self.deprecated,
+            edit: self.text_edit.map(|text_edit| CompletionEdit {
+                text_edit: CompletionTextEdit::Edit(tombi_extension::TextEdit {
+                    range: text_edit.range.into(),
+                    new_text: text_edit.new_text,
+                }),
+                insert_text_format: None,
+                additional_text_edits: None,
+            }),
+            preselect: self.preselect,
+            in_comment: false,
+            label: self.label,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InlayHintKind {
+    Type,
+    Parameter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHint {
+    pub position: Position,
+    pub label: String,
+    #[serde(default)]
+    pub kind: Option<InlayHintKind>,
+    #[serde(default)]
+    pub tooltip: Option<String>,
+    #[serde(default)]
+    pub padding_left: Option<bool>,
+    #[serde(default)]
+    pub padding_right: Option<bool>,
+}
+
+impl From<InlayHint> for tombi_extension::InlayHint {
+    fn from(inlay_hint: InlayHint) -> Self {
+        Self {
+            position: inlay_hint.position.into(),
+            label: inlay_hint.label,
+            kind: inlay_hint.kind.map(|kind| match kind {
+                InlayHintKind::Type => tower_lsp::lsp_types::InlayHintKind::TYPE,
+                InlayHintKind::Parameter => tower_lsp::lsp_types::InlayHintKind::PARAMETER,
+            }),
+            tooltip: inlay_hint.tooltip,
+            padding_left: inlay_hint.padding_left,
+            padding_right: inlay_hint.padding_right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentLink {
+    pub target: String,
+    pub range: Range,
+    #[serde(default)]
+    pub tooltip: Option<String>,
+}
+
+impl DocumentLink {
+    pub fn into_document_link(self) -> Option<tombi_extension::DocumentLink> {
+        Some(tombi_extension::DocumentLink {
+            target: tombi_uri::Uri::from_str(&self.target).ok()?,
+            range: self.range.into(),
+            tooltip: Cow::Owned(self.tooltip.unwrap_or_default()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub uri: String,
+    pub range: Range,
+}
+
+impl Location {
+    pub fn into_location(self) -> Option<tombi_extension::Location> {
+        Some(tombi_extension::Location {
+            uri: tombi_uri::Uri::from_str(&self.uri).ok()?,
+            range: self.range.into(),
+        })
+    }
+}
+
+/// A code action editing the requested document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+    pub title: String,
+    /// The LSP code action kind, such as `"refactor.rewrite"`.
+    #[serde(default)]
+    pub kind: Option<String>,
+    pub edits: Vec<TextEdit>,
+    #[serde(default)]
+    pub is_preferred: Option<bool>,
+}
+
+impl Action {
+    pub fn into_code_action(
+        self,
+        text_document_uri: &tombi_uri::Uri,
+        line_index: &tombi_text::LineIndex,
+    ) -> CodeActionOrCommand {
+        CodeActionOrCommand::CodeAction(CodeAction {
+            title: self.title,
+            kind: Some(
+                self.kind
+                    .map(CodeActionKind::from)
+                    .unwrap_or(CodeActionKind::REFACTOR_REWRITE),
+            ),
+            diagnostics: None,
+            edit: Some(WorkspaceEdit {
+                changes: None,
+                document_changes: Some(DocumentChanges::Edits(vec![
+                    tower_lsp::lsp_types::TextDocumentEdit {
+                        text_document:
+                            tower_lsp::lsp_types::OptionalVersionedTextDocumentIdentifier {
+                                uri: text_document_uri.to_owned().into(),
+                                version: None,
+                            },
+                        edits: self
+                            .edits
+                            .into_iter()
+                            .map(|edit| {
+                                OneOf::Left(tower_lsp::lsp_types::TextEdit {
+                                    range: tombi_text::Range::from(edit.range).into_lsp(line_index),
+                                    new_text: edit.new_text,
+                                })
+                            })
+                            .collect(),
+                    },
+                ])),
+                change_annotations: None,
+            }),
+            command: None,
+            is_preferred: self.is_preferred,
+            disabled: None,
+            data: None,
+        })
+    }
+}
@@ -0,0 +1,211 @@
+use tombi_ast::AstNode;
+use tombi_config::{Config, Extensions, ExternalExtension, TomlVersion};
+use tombi_document_tree::TryIntoDocumentTree;
+use tombi_extension::{CodeActionOrCommand, Extension, ExtensionContext};
+use tombi_extension_external::ExternalExtensions;
+use tombi_schema_store::Accessor;
+
+const SOURCE: &str = r#"name = "web"
+url = "https://example.com/web"
+replicas = 3
+"#;
+
+fn config(command: &str) -> Config {
+    let mut config = Config::default();
+    config.extensions = Some(Extensions {
+        cargo: None,
+        pyproject: None,
+        tombi: None,
+        external: Some(vec![ExternalExtension {
+            name: "stub".to_string(),
+            enabled: None,
+            command: command.to_string(),
+            args: None,
+            include: vec!["deploy/*.toml".into()],
+        }]),
+    });
+    config
+}
+
+fn document_tree() -> tombi_document_tree::DocumentTree {
+    tombi_ast::Root::cast(tombi_parser::parse(SOURCE).into_syntax_node())
+        .unwrap()
+        .try_into_document_tree(TomlVersion::default())
+        .unwrap()
+}
+
+fn uri(path: &str) -> tombi_uri::Uri {
+    tombi_uri::Uri::from_file_path(std::env::temp_dir().join(path)).unwrap()
+}
+
+fn context<'a>(
+    text_document_uri: &'a tombi_uri::Uri,
+    document_tree: &'a tombi_document_tree::DocumentTree,
+    config: &'a Config,
+) -> ExtensionContext<'a> {
+    ExtensionContext {
+        text_document_uri,
+        document_tree,
+        toml_version: TomlVersion::default(),
+        config,
+        offline: true,
+        cache_options: None,
+    }
+}
+
+#[tokio::test]
+async fn external_extension_provides_language_features() {
+    let config = config(env!("CARGO_BIN_EXE_tombi-extension-external-stub"));
+    let document_tree = document_tree();
+    let text_document_uri = uri("deploy/web.toml");
+    let context = context(&text_document_uri, &document_tree, &config);
+    let extension = ExternalExtensions::new(config.external_extensions(), &std::env::temp_dir());
+    assert!(extension.enabled(&config));
+
+    let completions = extension
+        .completion(
+            &context,
+            &[Accessor::Key("name".to_string())],
+            tombi_text::Position::new(0, 8),
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        completions
+            .iter()
+            .map(|completion| completion.label.as_str())
+            .collect::<Vec<_>>(),
+        vec!["name@0:8"]
+    );
+
+    let inlay_hints = extension
+        .inlay_hint(&context, tombi_text::Range::MAX)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        inlay_hints
+            .iter()
+            .map(|inlay_hint| (inlay_hint.position, inlay_hint.label.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (tombi_text::Position::new(0, 12), ": string"),
+            (tombi_text::Position::new(1, 31), ": string"),
+            (tombi_text::Position::new(2, 12), ": integer"),
+        ]
+    );
+
+    let document_links = extension.document_link(&context).await.unwrap().unwrap();
+    assert_eq!(document_links.len(), 1);
+    assert_eq!(document_links[0].target.as_str(), "https://example.com/web");
+
+    let definitions = extension
+        .goto_definition(&context, &[])
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        definitions,
+        vec![tombi_extension::Location {
+            uri: text_document_uri.clone(),
+            range: tombi_text::Range::new(
+                tombi_text::Position::new(0, 0),
+                tombi_text::Position::new(0, 4),
+            ),
+        }]
+    );
+
+    // The stub does not implement declarations.
+    assert!(
+        extension
+            .goto_declaration(&context, &[])
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let root = tombi_ast::Root::cast(tombi_parser::parse(SOURCE).into_syntax_node()).unwrap();
+    let line_index = tombi_text::LineIndex::new(SOURCE, tombi_text::EncodingKind::Utf16);
+    let code_actions = extension
+        .code_action(&context, &root, &line_index, &[], &[])
+        .await
+        .unwrap()
+        .unwrap();
+    let [CodeActionOrCommand::CodeAction(code_action)] = code_actions.as_slice() else {
+        panic!("expected one code action: {code_actions:?}");
+    };
+    assert_eq!(code_action.title, "Insert stub comment");
+}
+
+#[tokio::test]
+async fn external_extension_skips_unmatched_files() {
+    let config = config(env!("CARGO_BIN_EXE_tombi-extension-external-stub"));
+    let document_tree = document_tree();
+    let text_document_uri = uri("other/web.toml");
+    let context = context(&text_document_uri, &document_tree, &config);
+
+    assert!(
+        ExternalExtensions::new(config.external_extensions(), &std::env::temp_dir())
+            .document_link(&context)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn external_extension_tolerates_missing_command() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config = config(&temp_dir.path().join("missing").to_string_lossy());
+    let document_tree = document_tree();
+    let text_document_uri = uri("deploy/web.toml");
+    let context = context(&text_document_uri, &document_tree, &config);
+    let extension = ExternalExtensions::new(config.external_extensions(), temp_dir.path());
+
+    for _ in 0..2 {
+        assert!(extension.document_link(&context).await.unwrap().is_none());
+    }
+}
+
+#[tokio::test]
+async fn external_extension_resolves_relative_command_against_config_dir() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(temp_dir.path().join("bin")).unwrap();
+    let command = temp_dir.path().join("bin").join(
+        std::path::Path::new(env!("CARGO_BIN_EXE_tombi-extension-external-stub"))
+            .file_name()
+            .unwrap(),
+    );
+    std::fs::copy(
+        env!("CARGO_BIN_EXE_tombi-extension-external-stub"),
+        &command,
+    )
+    .unwrap();
+
+    let relative_command = command.strip_prefix(temp_dir.path()).unwrap();
+    let config = config(&relative_command.to_string_lossy());
+    let document_tree = document_tree();
+    let text_document_uri = uri("deploy/web.toml");
+    let context = context(&text_document_uri, &document_tree, &config);
+    let extension = ExternalExtensions::new(config.external_extensions(), temp_dir.path());
+
+    let document_links = extension.document_link(&context).await.unwrap().unwrap();
+    assert_eq!(document_links.len(), 1);
+}
+
+#[tokio::test]
+async fn external_extension_ignores_document_config() {
+    // Only the extensions passed to `ExternalExtensions::new` run,
+    // not the ones in the config of the document.
+    let config = config(env!("CARGO_BIN_EXE_tombi-extension-external-stub"));
+    let document_tree = document_tree();
+    let text_document_uri = uri("deploy/web.toml");
+    let context = context(&text_document_uri, &document_tree, &config);
+    let extension = ExternalExtensions::default();
+
+    assert!(!extension.enabled(&config));
+    assert!(extension.document_link(&context).await.unwrap().is_none());
+}
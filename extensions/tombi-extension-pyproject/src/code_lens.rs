@@ -0,0 +1,111 @@
+use tombi_config::TomlVersion;
+use tombi_extension::CodeLens;
+
+use crate::inlay_hint::{collect_resolved_dependencies, load_uv_lock_cache};
+
+pub async fn code_lens(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    toml_version: TomlVersion,
+    features: Option<&tombi_config::PyprojectExtensionFeatures>,
+) -> Result<Option<Vec<CodeLens>>, tower_lsp::jsonrpc::Error> {
+    if !text_document_uri.path().ends_with("pyproject.toml") {
+        return Ok(None);
+    }
+
+    if !features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.code_lens())
+        .and_then(|code_lens| code_lens.dependency_version())
+        .map(|dependency_version| dependency_version.enabled())
+        .unwrap_or_default()
+        .value()
+    {
+        return Ok(None);
+    }
+
+    let Ok(pyproject_toml_path) = text_document_uri.to_file_path() else {
+        return Ok(None);
+    };
+
+    let Some(uv_lock_cache) = load_uv_lock_cache(&pyproject_toml_path, toml_version).await else {
+        return Ok(None);
+    };
+
+    let code_lenses = collect_resolved_dependencies(document_tree, &uv_lock_cache)
+        .into_iter()
+        .map(|resolved| CodeLens {
+            range: resolved.dependency.range(),
+            title: format!("resolved {} in uv.lock", resolved.version),
+            command: None,
+            arguments: None,
+        })
+        .collect::<Vec<_>>();
+
+    if code_lenses.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(code_lenses))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn shows_resolved_dependency_versions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+
+        let pyproject_source = r#"
+[project]
+name = "demo"
+version = "0.1.0"
+dependencies = ["pytest>=8", "missing"]
+"#;
+        std::fs::write(root.join("pyproject.toml"), pyproject_source).unwrap();
+        std::fs::write(
+            root.join("uv.lock"),
+            r#"
+version = 1
+
+[[package]]
+name = "demo"
+version = "0.1.0"
+dependencies = [{ name = "pytest" }]
+
+[[package]]
+name = "pytest"
+version = "8.3.3"
+"#,
+        )
+        .unwrap();
+
+        let document_tree =
+            tombi_ast::Root::cast(tombi_parser::parse(pyproject_source).into_syntax_node())
+                .unwrap()
+                .try_into_document_tree(TomlVersion::default())
+                .unwrap();
+        let text_document_uri =
+            tombi_uri::Uri::from_file_path(root.join("pyproject.toml")).unwrap();
+
+        let titles = code_lens(
+            &text_document_uri,
+            &document_tree,
+            TomlVersion::default(),
+            None,
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .into_iter()
+        .map(|code_lens| code_lens.title)
+        .collect::<Vec<_>>();
+
+        assert_eq!(titles, vec!["resolved 8.3.3 in uv.lock"]);
+    }
+}
@@ -0,0 +1,259 @@
+use tombi_config::Config;
+use tombi_extension::{
+    CodeActionOrCommand, CodeLens, CommentContext, CompletionContent, CompletionHint, DocumentLink,
+    Extension, ExtensionContext, HoverMetadata, InlayHint, Location,
+};
+use tombi_future::{BoxFuture, Boxable};
+use tombi_schema_store::{Accessor, AccessorContext};
+
+/// The `pyproject.toml`, `uv.lock` and `poetry.lock` extension.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PyprojectExtension;
+
+impl Extension for PyprojectExtension {
+    fn name(&self) -> &'static str {
+        "pyproject"
+    }
+
+    fn file_patterns(&self) -> &[&str] {
+        &["pyproject.toml", "uv.lock", "poetry.lock"]
+    }
+
+    fn enabled(&self, config: &Config) -> bool {
+        config.pyproject_extension_enabled()
+    }
+
+    fn did_open<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<tokio::task::JoinHandle<bool>>, tower_lsp::jsonrpc::Error>>
+    {
+        crate::did_open(
+            context.text_document_uri,
+            context.document_tree,
+            context.toml_version,
+            context.offline,
+            context.cache_options,
+            context.config.pyproject_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn hover<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+        position: tombi_text::Position,
+    ) -> BoxFuture<'a, Result<Option<HoverMetadata>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            let hover = context
+                .config
+                .pyproject_extension_features()
+                .and_then(|features| features.lsp())
+                .and_then(|lsp| lsp.hover());
+            let dependency_detail_hover_enabled = hover
+                .as_ref()
+                .and_then(|hover| hover.dependency_detail())
+                .map(|dependency_detail| dependency_detail.enabled())
+                .unwrap_or_default()
+                .value();
+            let dependents_hover_enabled = hover
+                .as_ref()
+                .and_then(|hover| hover.dependents())
+                .map(|dependents| dependents.enabled())
+                .unwrap_or_default()
+                .value();
+
+            if !(dependency_detail_hover_enabled || dependents_hover_enabled) {
+                return Ok(None);
+            }
+
+            crate::hover(
+                context.text_document_uri,
+                context.document_tree,
+                accessors,
+                position,
+                context.toml_version,
+                context.offline,
+                context.cache_options,
+                dependency_detail_hover_enabled,
+                dependents_hover_enabled,
+            )
+            .await
+        }
+        .boxed()
+    }
+
+    fn completion<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+        position: tombi_text::Position,
+        completion_hint: Option<CompletionHint>,
+        comment_context: Option<&'a CommentContext>,
+    ) -> BoxFuture<'a, Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error>> {
+        crate::completion(
+            context.text_document_uri,
+            context.document_tree,
+            position,
+            accessors,
+            context.toml_version,
+            completion_hint,
+            comment_context,
+            context.config.pyproject_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn code_action<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        root: &'a tombi_ast::Root,
+        line_index: &'a tombi_text::LineIndex,
+        accessors: &'a [Accessor],
+        _accessor_contexts: &'a [AccessorContext],
+    ) -> BoxFuture<'a, Result<Option<Vec<CodeActionOrCommand>>, tower_lsp::jsonrpc::Error>> {
+        crate::code_action(
+            context.text_document_uri,
+            root,
+            context.document_tree,
+            accessors,
+            context.toml_version,
+            line_index,
+            context.config.pyproject_extension_features(),
+            context.offline,
+            context.cache_options,
+        )
+        .boxed()
+    }
+
+    fn code_lens<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<Vec<CodeLens>>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            if !context.config.pyproject_code_lens_enabled() {
+                return Ok(None);
+            }
+
+            crate::code_lens(
+                context.text_document_uri,
+                context.document_tree,
+                context.toml_version,
+                context.config.pyproject_extension_features(),
+            )
+            .await
+        }
+        .boxed()
+    }
+
+    fn inlay_hint<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        visible_range: tombi_text::Range,
+    ) -> BoxFuture<'a, Result<Option<Vec<InlayHint>>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            if !context.config.pyproject_inlay_hint_enabled() {
+                return Ok(None);
+            }
+
+            crate::inlay_hint(
+                context.text_document_uri,
+                context.document_tree,
+                visible_range,
+                context.toml_version,
+                context.config.pyproject_extension_features(),
+            )
+            .await
+        }
+        .boxed()
+    }
+
+    fn goto_definition<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        crate::goto_definition(
+            context.text_document_uri,
+            context.document_tree,
+            accessors,
+            context.toml_version,
+            context.config.pyproject_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn goto_declaration<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        crate::goto_declaration(
+            context.text_document_uri,
+            context.document_tree,
+            accessors,
+            context.toml_version,
+            context.config.pyproject_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn references<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        crate::references(
+            context.text_document_uri,
+            context.document_tree,
+            accessors,
+            context.toml_version,
+            context.config.pyproject_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn current_declaration(
+        &self,
+        context: &ExtensionContext<'_>,
+        accessors: &[Accessor],
+    ) -> Option<Location> {
+        crate::get_current_declaration(context.document_tree, accessors, context.text_document_uri)
+    }
+
+    fn document_symbol_name(
+        &self,
+        context: &ExtensionContext<'_>,
+        accessors: &[Accessor],
+        value: &tombi_document_tree::Value,
+    ) -> Option<String> {
+        crate::lockfile::LockFileKind::from_uri(context.text_document_uri)?;
+        crate::lockfile::lock_package_symbol_name(accessors, value)
+    }
+
+    fn document_link<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<Vec<DocumentLink>>, tower_lsp::jsonrpc::Error>> {
+        crate::document_link(
+            context.text_document_uri,
+            context.document_tree,
+            context.toml_version,
+            context.config.pyproject_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn lint<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Vec<tombi_diagnostic::Diagnostic>> {
+        crate::lint(
+            context.text_document_uri,
+            context.document_tree,
+            context.toml_version,
+        )
+        .boxed()
+    }
+}
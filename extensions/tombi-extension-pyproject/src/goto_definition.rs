@@ -16,8 +16,9 @@ use crate::{
     goto_definition_for_workspace_pyproject_toml, has_uv_sources_accessors,
     is_dependency_name_accessors, is_project_name_accessors, is_pyproject_path_accessors,
     is_uv_source_path_accessors, is_uv_source_workspace_accessors, is_uv_workspace_accessors,
-    load_pyproject_toml_document_tree, parse_requirement, resolve_member_pyproject_toml_path,
-    resolve_relative_path_uri,
+    load_pyproject_toml_document_tree,
+    lockfile::{LockFileKind, goto_definition_for_lock},
+    parse_requirement, resolve_member_pyproject_toml_path, resolve_relative_path_uri,
 };
 
 pub async fn goto_definition(
@@ -27,6 +28,24 @@ pub async fn goto_definition(
     toml_version: TomlVersion,
     features: Option<&tombi_config::PyprojectExtensionFeatures>,
 ) -> Result<Option<Vec<tombi_extension::Location>>, tower_lsp::jsonrpc::Error> {
+    if let Some(lock_file_kind) = LockFileKind::from_uri(text_document_uri) {
+        let Ok(lock_path) = text_document_uri.to_file_path() else {
+            return Ok(Default::default());
+        };
+        if !lock_goto_definition_enabled(features) {
+            return Ok(None);
+        }
+        let locations = goto_definition_for_lock(
+            lock_file_kind,
+            text_document_uri,
+            document_tree,
+            accessors,
+            &lock_path,
+            toml_version,
+        )?;
+        return Ok((!locations.is_empty()).then_some(locations));
+    }
+
     // Check if current file is pyproject.toml
     if !text_document_uri.path().ends_with("pyproject.toml") {
         return Ok(Default::default());
@@ -510,3 +529,16 @@ pub fn get_path_dependency_definition(
         range: package_name.unquoted_range(),
     })
 }
+
+/// Navigation in lockfiles always resolves dependencies.
+fn lock_goto_definition_enabled(
+    features: Option<&tombi_config::PyprojectExtensionFeatures>,
+) -> bool {
+    features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.goto_definition())
+        .and_then(|goto_definition| goto_definition.dependency())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+}
@@ -9,6 +9,7 @@ use tombi_schema_store::{Accessor, matches_accessors};
 use crate::{
     fetch_pypi_project, find_member_project_toml, find_workspace_pyproject_toml,
     get_dependency_accessors, get_project_name, load_pyproject_toml_document_tree,
+    lockfile::{LockFileKind, lock_hover_metadata},
     parse_requirement, resolve_member_pyproject_toml_path,
 };
 
@@ -20,8 +21,21 @@ pub async fn hover(
     toml_version: TomlVersion,
     offline: bool,
     cache_options: Option<&tombi_cache::Options>,
+    dependency_detail_hover_enabled: bool,
+    dependents_hover_enabled: bool,
 ) -> Result<Option<HoverMetadata>, tower_lsp::jsonrpc::Error> {
-    if !text_document_uri.path().ends_with("pyproject.toml") {
+    if let Some(lock_file_kind) = LockFileKind::from_uri(text_document_uri) {
+        if !dependents_hover_enabled {
+            return Ok(None);
+        }
+        return Ok(lock_hover_metadata(
+            lock_file_kind,
+            document_tree,
+            accessors,
+        ));
+    }
+
+    if !text_document_uri.path().ends_with("pyproject.toml") || !dependency_detail_hover_enabled {
         return Ok(None);
     }
 
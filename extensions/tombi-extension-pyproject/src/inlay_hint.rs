@@ -22,21 +22,21 @@ const PYPROJECT_EXTENSION_ID: &str = "tombi-toml/pyproject";
 const INLAY_HINT_LOCKFILE_KEY: &str = "inlay_hint.lockfile";
 
 #[derive(Debug)]
-struct UvLock {
+pub(crate) struct UvLock {
     packages: Vec<UvLockPackage>,
 }
 
 #[derive(Debug)]
-struct UvLockPackage {
-    name: String,
-    version: String,
+pub(crate) struct UvLockPackage {
+    pub(crate) name: String,
+    pub(crate) version: String,
     direct_dependencies: Vec<UvLockDependency>,
 }
 
 #[derive(Debug)]
-struct UvLockDependency {
-    name: String,
-    version: Option<String>,
+pub(crate) struct UvLockDependency {
+    pub(crate) name: String,
+    pub(crate) version: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -62,7 +62,7 @@ struct ProjectResolvedDependencies {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct UvLockInlayCacheData {
+pub(crate) struct UvLockInlayCacheData {
     projects: tombi_hashmap::HashMap<
         ProjectName,
         tombi_hashmap::HashMap<ProjectVersion, ProjectResolvedDependencies>,
@@ -142,19 +142,8 @@ fn inlay_hint_impl(
     let hints = visible_dependency_hints
         .into_iter()
         .filter_map(|hint| {
-            let resolved_version = match hint.resolution {
-                DependencyHintResolution::CurrentPackage => {
-                    let pkg = current_package.as_ref()?;
-                    uv_lock_cache.resolved_dependency_version(
-                        &pkg.name,
-                        &pkg.version,
-                        hint.requirement.name.as_ref(),
-                    )
-                }
-                DependencyHintResolution::UniquePackageVersion => {
-                    uv_lock_cache.unique_dependency_version(hint.requirement.name.as_ref())
-                }
-            }?;
+            let resolved_version =
+                resolve_dependency_version(&hint, current_package.as_ref(), &uv_lock_cache)?;
 
             let current_version = exact_pinned_version(&hint.requirement);
             let label = version_hint_label(current_version.as_deref(), &resolved_version)?;
@@ -177,6 +166,53 @@ fn inlay_hint_impl(
     }
 }
 
+/// A dependency string of `pyproject.toml` with its version resolved in `uv.lock`.
+pub(crate) struct ResolvedDependency<'a> {
+    pub dependency: &'a tombi_document_tree::String,
+    pub version: String,
+}
+
+pub(crate) fn collect_resolved_dependencies<'a>(
+    document_tree: &'a tombi_document_tree::DocumentTree,
+    uv_lock_cache: &UvLockInlayCacheData,
+) -> Vec<ResolvedDependency<'a>> {
+    let current_package = current_package(document_tree);
+
+    collect_dependency_hints(document_tree)
+        .into_iter()
+        .filter_map(|hint| {
+            Some(ResolvedDependency {
+                version: resolve_dependency_version(
+                    &hint,
+                    current_package.as_ref(),
+                    uv_lock_cache,
+                )?,
+                dependency: hint.dependency,
+            })
+        })
+        .collect()
+}
+
+fn resolve_dependency_version(
+    hint: &PyprojectDependencyHint,
+    current_package: Option<&CurrentPackage>,
+    uv_lock_cache: &UvLockInlayCacheData,
+) -> Option<String> {
+    match hint.resolution {
+        DependencyHintResolution::CurrentPackage => {
+            let pkg = current_package?;
+            uv_lock_cache.resolved_dependency_version(
+                &pkg.name,
+                &pkg.version,
+                hint.requirement.name.as_ref(),
+            )
+        }
+        DependencyHintResolution::UniquePackageVersion => {
+            uv_lock_cache.unique_dependency_version(hint.requirement.name.as_ref())
+        }
+    }
+}
+
 struct CurrentPackage {
     name: String,
     version: String,
@@ -315,7 +351,7 @@ fn exact_pinned_version(requirement: &pep508_rs::Requirement<VerbatimUrl>) -> Op
     }
 }
 
-async fn load_uv_lock_cache(
+pub(crate) async fn load_uv_lock_cache(
     pyproject_toml_path: &Path,
     toml_version: TomlVersion,
 ) -> Option<UvLockInlayCacheData> {
@@ -381,7 +417,9 @@ fn uv_lock_cache_key(uv_lock_path: &Path) -> String {
 }
 
 impl UvLock {
-    fn from_document_tree(document_tree: &tombi_document_tree::DocumentTree) -> Option<Self> {
+    pub(crate) fn from_document_tree(
+        document_tree: &tombi_document_tree::DocumentTree,
+    ) -> Option<Self> {
         let (_, Value::Array(packages)) = dig_keys(document_tree, &["package"])? else {
             return None;
         };
@@ -394,6 +432,24 @@ impl UvLock {
         })
     }
 
+    /// The packages depending on the given version of a package.
+    ///
+    /// Dependencies without version refer to the only locked version of the package.
+    pub(crate) fn dependents(&self, name: &str, version: &str) -> Vec<&UvLockPackage> {
+        self.packages
+            .iter()
+            .filter(|package| {
+                package.direct_dependencies.iter().any(|dependency| {
+                    dependency.name == name
+                        && dependency
+                            .version
+                            .as_deref()
+                            .is_none_or(|dependency_version| dependency_version == version)
+                })
+            })
+            .collect()
+    }
+
     fn into_inlay_cache_data(self) -> UvLockInlayCacheData {
         let unique_package_versions = self.unique_package_versions();
         let mut projects = HashMap::new();
@@ -555,7 +611,7 @@ fn uv_dependency_groups(value: Option<&Value>) -> Vec<UvLockDependency> {
 }
 
 impl UvLockDependency {
-    fn from_value(value: &Value) -> Option<Self> {
+    pub(crate) fn from_value(value: &Value) -> Option<Self> {
         let Value::Table(table) = value else {
             return None;
         };
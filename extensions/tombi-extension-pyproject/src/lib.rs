@@ -1,27 +1,34 @@
 mod accessors;
 mod code_action;
+mod code_lens;
 mod completion;
 mod dependency;
 mod did_open;
 mod document_link;
+mod extension;
 mod goto_declaration;
 mod goto_definition;
 mod hover;
 mod inlay_hint;
+mod lint;
+mod lockfile;
 mod manifest;
 mod pypi_org;
 mod references;
 mod workspace;
 
 pub use code_action::{CodeActionRefactorRewriteName, code_action};
+pub use code_lens::code_lens;
 pub use completion::completion;
 pub use did_open::did_open;
 pub use document_link::document_link;
+pub use extension::PyprojectExtension;
 pub use goto_declaration::get_current_declaration;
 pub use goto_declaration::goto_declaration;
 pub use goto_definition::goto_definition;
 pub use hover::hover;
 pub use inlay_hint::inlay_hint;
+pub use lint::lint;
 pub use references::references;
 
 pub(crate) use accessors::{
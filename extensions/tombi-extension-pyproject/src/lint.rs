@@ -0,0 +1,203 @@
+use std::path::Path;
+
+use tombi_config::TomlVersion;
+use tombi_diagnostic::Diagnostic;
+use tombi_document_tree::{Value, dig_keys};
+
+use crate::{find_member_project_toml, find_workspace_pyproject_toml, get_project_name};
+
+#[derive(thiserror::Error, Debug)]
+enum DiagnosticKind {
+    #[error("No such file or directory: `{path}`")]
+    SourcePathNotFound { path: String },
+    #[error("`{package_name}` is not a member of the uv workspace")]
+    UnknownWorkspaceMember { package_name: String },
+    #[error("`workspace = true` is used, but no uv workspace is found")]
+    WorkspaceNotFound,
+}
+
+impl DiagnosticKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::SourcePathNotFound { .. } => "uv-source-path-not-found",
+            Self::UnknownWorkspaceMember { .. } => "uv-unknown-workspace-member",
+            Self::WorkspaceNotFound => "uv-workspace-not-found",
+        }
+    }
+
+    fn into_diagnostic(self, range: tombi_text::Range) -> Diagnostic {
+        Diagnostic::new_error(self.to_string(), self.code(), range)
+    }
+}
+
+/// Report `tool.uv.sources` entries that point nowhere.
+///
+/// See: https://docs.astral.sh/uv/concepts/projects/dependencies/#dependency-sources
+pub async fn lint(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    toml_version: TomlVersion,
+) -> Vec<Diagnostic> {
+    if !text_document_uri.path().ends_with("pyproject.toml") {
+        return Vec::new();
+    }
+
+    let Some((_, Value::Table(sources))) = dig_keys(document_tree, &["tool", "uv", "sources"])
+    else {
+        return Vec::new();
+    };
+
+    let Ok(pyproject_toml_path) = text_document_uri.to_file_path() else {
+        return Vec::new();
+    };
+    let Some(pyproject_toml_dir_path) = pyproject_toml_path.parent() else {
+        return Vec::new();
+    };
+
+    let mut workspace_pyproject_toml = None;
+    let mut diagnostics = Vec::new();
+    for (package_name, source_value) in sources.key_values() {
+        let source_tables = match source_value {
+            Value::Table(table) => vec![table],
+            Value::Array(array) => array
+                .values()
+                .iter()
+                .filter_map(|value| match value {
+                    Value::Table(table) => Some(table),
+                    _ => None,
+                })
+                .collect(),
+            _ => continue,
+        };
+
+        for source_table in source_tables {
+            if let Some(Value::String(path)) = source_table.get("path")
+                && !pyproject_toml_dir_path.join(path.value()).exists()
+            {
+                diagnostics.push(
+                    DiagnosticKind::SourcePathNotFound {
+                        path: path.value().to_string(),
+                    }
+                    .into_diagnostic(path.unquoted_range()),
+                );
+            }
+
+            if let Some(Value::Boolean(workspace)) = source_table.get("workspace")
+                && workspace.value()
+            {
+                let workspace_pyproject_toml = workspace_pyproject_toml.get_or_insert_with(|| {
+                    find_workspace_pyproject_toml(&pyproject_toml_path, toml_version)
+                        .map(|(path, _, document_tree)| (path, document_tree))
+                });
+
+                let kind = match workspace_pyproject_toml {
+                    Some((workspace_pyproject_toml_path, workspace_document_tree)) => {
+                        if is_workspace_member(
+                            &package_name.value,
+                            workspace_pyproject_toml_path,
+                            workspace_document_tree,
+                            toml_version,
+                        ) {
+                            continue;
+                        }
+                        DiagnosticKind::UnknownWorkspaceMember {
+                            package_name: package_name.value.to_string(),
+                        }
+                    }
+                    None => DiagnosticKind::WorkspaceNotFound,
+                };
+                diagnostics.push(kind.into_diagnostic(workspace.range()));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+fn is_workspace_member(
+    package_name: &str,
+    workspace_pyproject_toml_path: &Path,
+    workspace_document_tree: &tombi_document_tree::DocumentTree,
+    toml_version: TomlVersion,
+) -> bool {
+    get_project_name(workspace_document_tree).is_some_and(|name| name.value() == package_name)
+        || find_member_project_toml(
+            package_name,
+            workspace_document_tree,
+            workspace_pyproject_toml_path,
+            toml_version,
+        )
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    async fn lint_codes(pyproject_toml_path: &Path) -> Vec<String> {
+        let source = std::fs::read_to_string(pyproject_toml_path).unwrap();
+        let document_tree = tombi_ast::Root::cast(tombi_parser::parse(&source).into_syntax_node())
+            .unwrap()
+            .try_into_document_tree(TomlVersion::default())
+            .unwrap();
+        let text_document_uri = tombi_uri::Uri::from_file_path(pyproject_toml_path).unwrap();
+
+        lint(&text_document_uri, &document_tree, TomlVersion::default())
+            .await
+            .iter()
+            .map(|diagnostic| diagnostic.code().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn reports_uv_sources_pointing_nowhere() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+
+        std::fs::write(
+            root.join("pyproject.toml"),
+            r#"
+[project]
+name = "root"
+
+[tool.uv.workspace]
+members = ["packages/*"]
+"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("packages/member")).unwrap();
+        std::fs::write(
+            root.join("packages/member/pyproject.toml"),
+            "[project]\nname = \"member\"\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("packages/app")).unwrap();
+        let pyproject_toml_path = root.join("packages/app/pyproject.toml");
+        std::fs::write(
+            &pyproject_toml_path,
+            r#"
+[project]
+name = "app"
+
+[tool.uv.sources]
+root = { workspace = true }
+member = { workspace = true }
+missing = { workspace = true }
+local = { path = "../member" }
+gone = [
+  { path = "../gone", marker = "sys_platform == 'linux'" },
+  { git = "https://github.com/astral-sh/uv" },
+]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lint_codes(&pyproject_toml_path).await,
+            vec!["uv-unknown-workspace-member", "uv-source-path-not-found"]
+        );
+    }
+}
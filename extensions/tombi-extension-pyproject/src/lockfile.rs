@@ -0,0 +1,582 @@
+use std::{path::Path, str::FromStr};
+
+use itertools::Itertools;
+use pep508_rs::PackageName;
+use tombi_config::TomlVersion;
+use tombi_document_tree::{Value, dig_accessors, dig_keys};
+use tombi_extension::{HoverMetadata, HoverTextChange};
+use tombi_schema_store::{Accessor, matches_accessors};
+
+use crate::{
+    collect_all_dependency_requirements_from_document_tree, extract_exclude_patterns,
+    extract_member_patterns, find_pyproject_toml_paths, get_project_name,
+    inlay_hint::{UvLock, UvLockDependency},
+    load_pyproject_toml_document_tree,
+};
+
+/// The maximum number of dependents listed on hover.
+const MAX_HOVER_DEPENDENTS: usize = 20;
+
+/// The lockfiles written by Python package managers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LockFileKind {
+    Uv,
+    Poetry,
+}
+
+impl LockFileKind {
+    pub(crate) fn from_uri(text_document_uri: &tombi_uri::Uri) -> Option<Self> {
+        let path = text_document_uri.path();
+        if path.ends_with("uv.lock") {
+            Some(Self::Uv)
+        } else if path.ends_with("poetry.lock") {
+            Some(Self::Poetry)
+        } else {
+            None
+        }
+    }
+}
+
+/// The `name@version` of a `[[package]]` entry of a lockfile, used as its document symbol name.
+pub(crate) fn lock_package_symbol_name(accessors: &[Accessor], value: &Value) -> Option<String> {
+    if !matches_accessors!(accessors, ["package", _]) {
+        return None;
+    }
+    let (name, version) = package_name_and_version(value)?;
+
+    Some(format!("{}@{}", name.value(), version.value()))
+}
+
+/// List the packages of the lockfile that depend on the hovered `[[package]]` entry.
+pub(crate) fn lock_hover_metadata(
+    lock_file_kind: LockFileKind,
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+) -> Option<HoverMetadata> {
+    if !(matches_accessors!(accessors, ["package", _])
+        || matches_accessors!(accessors, ["package", _, "name"])
+        || matches_accessors!(accessors, ["package", _, "version"]))
+    {
+        return None;
+    }
+
+    let (_, package) = dig_accessors(document_tree, &accessors[..2])?;
+    let (name, version) = package_name_and_version(package)?;
+
+    let dependents = match lock_file_kind {
+        LockFileKind::Uv => UvLock::from_document_tree(document_tree)?
+            .dependents(name.value(), version.value())
+            .into_iter()
+            .map(|package| (package.name.clone(), package.version.clone()))
+            .collect_vec(),
+        LockFileKind::Poetry => poetry_lock_dependents(document_tree, name.value()),
+    };
+
+    let description = if dependents.is_empty() {
+        format!(
+            "No package depends on `{} {}`.",
+            name.value(),
+            version.value()
+        )
+    } else {
+        render_dependents(name.value(), version.value(), &dependents)
+    };
+
+    Some(HoverMetadata {
+        title: None,
+        description: Some(HoverTextChange::Append(description)),
+    })
+}
+
+pub(crate) fn goto_definition_for_lock(
+    lock_file_kind: LockFileKind,
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    lock_path: &Path,
+    toml_version: TomlVersion,
+) -> Result<Vec<tombi_extension::Location>, tower_lsp::jsonrpc::Error> {
+    if matches_accessors!(accessors, ["package", _, "name"]) {
+        let Some((_, Value::Table(package))) = dig_accessors(document_tree, &accessors[..2]) else {
+            return Ok(Vec::new());
+        };
+        let Some(Value::String(name)) = package.get("name") else {
+            return Ok(Vec::new());
+        };
+
+        return Ok(manifest_locations(
+            lock_file_kind,
+            lock_path,
+            name.value(),
+            local_source_path(lock_file_kind, package),
+            toml_version,
+        ));
+    }
+
+    let Some((name, version)) = (match lock_file_kind {
+        LockFileKind::Uv => uv_lock_dependency_at_accessors(document_tree, accessors)
+            .map(|dependency| (dependency.name, dependency.version)),
+        LockFileKind::Poetry => poetry_lock_dependency_at_accessors(accessors),
+    }) else {
+        return Ok(Vec::new());
+    };
+
+    Ok(package_locations(
+        text_document_uri,
+        document_tree,
+        &name,
+        version.as_deref(),
+    ))
+}
+
+/// The dependency of a `uv.lock` package under the cursor.
+///
+/// Dependencies are inline tables like `{ name = "idna", version = "3.10" }`
+/// listed in `dependencies`, or grouped in `optional-dependencies` and `dev-dependencies`.
+fn uv_lock_dependency_at_accessors(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+) -> Option<UvLockDependency> {
+    let dependency_accessors = if let Some(dependency_accessors) = accessors.get(..4)
+        && matches_accessors!(dependency_accessors, ["package", _, "dependencies", _])
+    {
+        dependency_accessors
+    } else if let Some(dependency_accessors) = accessors.get(..5)
+        && (matches_accessors!(
+            dependency_accessors,
+            ["package", _, "optional-dependencies", _, _]
+        ) || matches_accessors!(
+            dependency_accessors,
+            ["package", _, "dev-dependencies", _, _]
+        ))
+    {
+        dependency_accessors
+    } else {
+        return None;
+    };
+    let (_, dependency) = dig_accessors(document_tree, dependency_accessors)?;
+
+    UvLockDependency::from_value(dependency)
+}
+
+/// The dependency of a `poetry.lock` package under the cursor.
+///
+/// Dependencies are keys of `[package.dependencies]` and only name the package.
+fn poetry_lock_dependency_at_accessors(accessors: &[Accessor]) -> Option<(String, Option<String>)> {
+    let dependency_accessors = accessors.get(..4)?;
+    if !matches_accessors!(dependency_accessors, ["package", _, "dependencies", _]) {
+        return None;
+    }
+
+    Some((dependency_accessors[3].as_key()?.to_string(), None))
+}
+
+/// The `name` of the `[[package]]` entries matching the dependency.
+fn package_locations(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    name: &str,
+    version: Option<&str>,
+) -> Vec<tombi_extension::Location> {
+    let Some((_, Value::Array(packages))) = dig_keys(document_tree, &["package"]) else {
+        return Vec::new();
+    };
+    let name = normalize_package_name(name);
+
+    packages
+        .values()
+        .iter()
+        .filter_map(package_name_and_version)
+        .filter(|(package_name, package_version)| {
+            normalize_package_name(package_name.value()) == name
+                && version.is_none_or(|version| package_version.value() == version)
+        })
+        .map(|(package_name, _)| tombi_extension::Location {
+            uri: text_document_uri.clone(),
+            range: package_name.unquoted_range(),
+        })
+        .collect()
+}
+
+/// The directory of a package locked from the local workspace, relative to the lockfile.
+fn local_source_path(
+    lock_file_kind: LockFileKind,
+    package: &tombi_document_tree::Table,
+) -> Option<&str> {
+    let Some(Value::Table(source)) = package.get("source") else {
+        return None;
+    };
+
+    match lock_file_kind {
+        LockFileKind::Uv => ["editable", "virtual", "directory"]
+            .into_iter()
+            .find_map(|key| match source.get(key) {
+                Some(Value::String(path)) => Some(path.value()),
+                _ => None,
+            }),
+        LockFileKind::Poetry => match (source.get("type"), source.get("url")) {
+            (Some(Value::String(source_type)), Some(Value::String(url)))
+                if source_type.value() == "directory" =>
+            {
+                Some(url.value())
+            }
+            _ => None,
+        },
+    }
+}
+
+/// The `pyproject.toml` files next to the lockfile that declare the package.
+///
+/// Local packages lead to their own `project.name`,
+/// and packages from an index to the dependency entries pulling them in.
+fn manifest_locations(
+    lock_file_kind: LockFileKind,
+    lock_path: &Path,
+    package_name: &str,
+    local_source_path: Option<&str>,
+    toml_version: TomlVersion,
+) -> Vec<tombi_extension::Location> {
+    let Some(lock_dir_path) = lock_path.parent() else {
+        return Vec::new();
+    };
+
+    if let Some(local_source_path) = local_source_path {
+        let pyproject_toml_path = lock_dir_path.join(local_source_path).join("pyproject.toml");
+        return load_pyproject_toml_document_tree(&pyproject_toml_path, toml_version)
+            .and_then(|document_tree| {
+                let name = get_project_name(&document_tree)?;
+                Some(tombi_extension::Location {
+                    uri: tombi_uri::Uri::from_file_path(&pyproject_toml_path).ok()?,
+                    range: name.unquoted_range(),
+                })
+            })
+            .into_iter()
+            .collect();
+    }
+
+    let root_pyproject_toml_path = lock_dir_path.join("pyproject.toml");
+    let Some(root_document_tree) =
+        load_pyproject_toml_document_tree(&root_pyproject_toml_path, toml_version)
+    else {
+        return Vec::new();
+    };
+
+    let mut pyproject_toml_paths = vec![root_pyproject_toml_path.clone()];
+    if lock_file_kind == LockFileKind::Uv {
+        let member_patterns = extract_member_patterns(&root_document_tree, &[]);
+        let exclude_patterns = extract_exclude_patterns(&root_document_tree);
+        pyproject_toml_paths.extend(
+            find_pyproject_toml_paths(&member_patterns, &exclude_patterns, lock_dir_path)
+                .map(|(_, pyproject_toml_path)| pyproject_toml_path),
+        );
+    }
+
+    let package_name = normalize_package_name(package_name);
+    let mut locations = Vec::new();
+    for pyproject_toml_path in pyproject_toml_paths.into_iter().unique() {
+        let Some(document_tree) =
+            load_pyproject_toml_document_tree(&pyproject_toml_path, toml_version)
+        else {
+            continue;
+        };
+        let Ok(uri) = tombi_uri::Uri::from_file_path(&pyproject_toml_path) else {
+            continue;
+        };
+
+        let mut ranges = collect_all_dependency_requirements_from_document_tree(&document_tree)
+            .into_iter()
+            .filter(|requirement| requirement.requirement.name.to_string() == package_name)
+            .map(|requirement| requirement.dependency.unquoted_range())
+            .collect_vec();
+        if lock_file_kind == LockFileKind::Poetry {
+            ranges.extend(poetry_dependency_key_ranges(&document_tree, &package_name));
+        }
+
+        locations.extend(ranges.into_iter().map(|range| tombi_extension::Location {
+            uri: uri.clone(),
+            range,
+        }));
+    }
+
+    locations
+}
+
+/// The keys of `[tool.poetry.dependencies]`, `[tool.poetry.dev-dependencies]`
+/// and `[tool.poetry.group.<name>.dependencies]` naming the package.
+fn poetry_dependency_key_ranges(
+    document_tree: &tombi_document_tree::DocumentTree,
+    package_name: &str,
+) -> Vec<tombi_text::Range> {
+    let mut dependency_tables = Vec::new();
+    for keys in [
+        ["tool", "poetry", "dependencies"].as_slice(),
+        ["tool", "poetry", "dev-dependencies"].as_slice(),
+    ] {
+        if let Some((_, Value::Table(dependencies))) = dig_keys(document_tree, keys) {
+            dependency_tables.push(dependencies);
+        }
+    }
+    if let Some((_, Value::Table(groups))) = dig_keys(document_tree, &["tool", "poetry", "group"]) {
+        dependency_tables.extend(groups.values().filter_map(|group| match group {
+            Value::Table(group) => match group.get("dependencies") {
+                Some(Value::Table(dependencies)) => Some(dependencies),
+                _ => None,
+            },
+            _ => None,
+        }));
+    }
+
+    dependency_tables
+        .into_iter()
+        .flat_map(|dependencies| dependencies.keys())
+        .filter(|key| normalize_package_name(&key.value) == package_name)
+        .map(|key| key.range())
+        .collect()
+}
+
+/// The `name` and `version` of `poetry.lock` packages listing the package in `[package.dependencies]`.
+fn poetry_lock_dependents(
+    document_tree: &tombi_document_tree::DocumentTree,
+    name: &str,
+) -> Vec<(String, String)> {
+    let Some((_, Value::Array(packages))) = dig_keys(document_tree, &["package"]) else {
+        return Vec::new();
+    };
+    let name = normalize_package_name(name);
+
+    packages
+        .values()
+        .iter()
+        .filter(|package| {
+            let Value::Table(package) = package else {
+                return false;
+            };
+            let Some(Value::Table(dependencies)) = package.get("dependencies") else {
+                return false;
+            };
+            dependencies
+                .keys()
+                .any(|key| normalize_package_name(&key.value) == name)
+        })
+        .filter_map(package_name_and_version)
+        .map(|(name, version)| (name.value().to_string(), version.value().to_string()))
+        .collect()
+}
+
+fn render_dependents(name: &str, version: &str, dependents: &[(String, String)]) -> String {
+    let mut description = format!("`{name} {version}` is pulled in by:");
+    for (dependent_name, dependent_version) in dependents.iter().take(MAX_HOVER_DEPENDENTS) {
+        description.push_str(&format!("\n- `{dependent_name} {dependent_version}`"));
+    }
+    if dependents.len() > MAX_HOVER_DEPENDENTS {
+        description.push_str(&format!(
+            "\n- and {} more",
+            dependents.len() - MAX_HOVER_DEPENDENTS
+        ));
+    }
+
+    description
+}
+
+/// Normalize a package name as in PEP 503, falling back to the name as written.
+fn normalize_package_name(name: &str) -> String {
+    PackageName::from_str(name)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|_| name.to_string())
+}
+
+fn package_name_and_version(
+    value: &Value,
+) -> Option<(&tombi_document_tree::String, &tombi_document_tree::String)> {
+    let Value::Table(table) = value else {
+        return None;
+    };
+    let (Some(Value::String(name)), Some(Value::String(version))) =
+        (table.get("name"), table.get("version"))
+    else {
+        return None;
+    };
+
+    Some((name, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    const UV_LOCK: &str = r#"
+[[package]]
+name = "demo"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [{ name = "idna" }]
+
+[package.optional-dependencies]
+http = [{ name = "sniffio" }]
+
+[[package]]
+name = "idna"
+version = "3.10"
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+"#;
+
+    const POETRY_LOCK: &str = r#"
+[[package]]
+name = "demo-client"
+version = "0.1.0"
+
+[package.dependencies]
+Typing_Extensions = ">=4.0"
+
+[[package]]
+name = "typing-extensions"
+version = "4.12.2"
+"#;
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    fn package_accessors(index: usize, keys: &[Accessor]) -> Vec<Accessor> {
+        let mut accessors = vec![Accessor::Key("package".to_string()), Accessor::Index(index)];
+        accessors.extend_from_slice(keys);
+        accessors
+    }
+
+    fn key(key: &str) -> Accessor {
+        Accessor::Key(key.to_string())
+    }
+
+    fn hover_description(
+        lock_file_kind: LockFileKind,
+        document_tree: &tombi_document_tree::DocumentTree,
+        index: usize,
+    ) -> String {
+        match lock_hover_metadata(
+            lock_file_kind,
+            document_tree,
+            &package_accessors(index, &[key("name")]),
+        )
+        .and_then(|metadata| metadata.description)
+        {
+            Some(HoverTextChange::Append(description)) => description,
+            _ => panic!("hover should append a description"),
+        }
+    }
+
+    fn goto_lines(
+        lock_file_kind: LockFileKind,
+        document_tree: &tombi_document_tree::DocumentTree,
+        accessors: &[Accessor],
+    ) -> Vec<u32> {
+        let lock_path = Path::new("/workspace/uv.lock");
+        goto_definition_for_lock(
+            lock_file_kind,
+            &tombi_uri::Uri::from_file_path(lock_path).unwrap(),
+            document_tree,
+            accessors,
+            lock_path,
+            TomlVersion::default(),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|location| location.range.start.line)
+        .collect()
+    }
+
+    #[test]
+    fn names_packages_by_name_and_version() {
+        let document_tree = parse_document_tree(UV_LOCK);
+        let Some((_, Value::Array(packages))) = dig_keys(&document_tree, &["package"]) else {
+            panic!("`package` should be an array");
+        };
+
+        assert_eq!(
+            packages
+                .values()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, package)| lock_package_symbol_name(
+                    &package_accessors(index, &[]),
+                    package
+                ))
+                .collect_vec(),
+            vec!["demo@0.1.0", "idna@3.10", "sniffio@1.3.1"]
+        );
+    }
+
+    #[test]
+    fn uv_lock_hover_lists_dependents() {
+        let document_tree = parse_document_tree(UV_LOCK);
+
+        assert_eq!(
+            hover_description(LockFileKind::Uv, &document_tree, 0),
+            "No package depends on `demo 0.1.0`."
+        );
+        assert_eq!(
+            hover_description(LockFileKind::Uv, &document_tree, 2),
+            "`sniffio 1.3.1` is pulled in by:\n- `demo 0.1.0`"
+        );
+    }
+
+    #[test]
+    fn poetry_lock_hover_matches_normalized_names() {
+        let document_tree = parse_document_tree(POETRY_LOCK);
+
+        assert_eq!(
+            hover_description(LockFileKind::Poetry, &document_tree, 1),
+            "`typing-extensions 4.12.2` is pulled in by:\n- `demo-client 0.1.0`"
+        );
+    }
+
+    #[test]
+    fn uv_lock_dependency_leads_to_package_entry() {
+        let document_tree = parse_document_tree(UV_LOCK);
+
+        assert_eq!(
+            goto_lines(
+                LockFileKind::Uv,
+                &document_tree,
+                &package_accessors(0, &[key("dependencies"), Accessor::Index(0), key("name")]),
+            ),
+            vec![11]
+        );
+        assert_eq!(
+            goto_lines(
+                LockFileKind::Uv,
+                &document_tree,
+                &package_accessors(
+                    0,
+                    &[
+                        key("optional-dependencies"),
+                        key("http"),
+                        Accessor::Index(0),
+                        key("name")
+                    ]
+                ),
+            ),
+            vec![15]
+        );
+    }
+
+    #[test]
+    fn poetry_lock_dependency_leads_to_package_entry() {
+        let document_tree = parse_document_tree(POETRY_LOCK);
+
+        assert_eq!(
+            goto_lines(
+                LockFileKind::Poetry,
+                &document_tree,
+                &package_accessors(0, &[key("dependencies"), key("Typing_Extensions")]),
+            ),
+            vec![9]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use tombi_config::{Config, DOT_TOMBI_TOML_FILENAME, TOMBI_TOML_FILENAME};
+use tombi_extension::{
+    CommentContext, CompletionContent, CompletionHint, DocumentLink, Extension, ExtensionContext,
+    HoverMetadata, Location,
+};
+use tombi_future::{BoxFuture, Boxable};
+use tombi_schema_store::Accessor;
+
+/// The extension for Tombi's own `tombi.toml`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TombiExtension;
+
+impl Extension for TombiExtension {
+    fn name(&self) -> &'static str {
+        "tombi"
+    }
+
+    fn file_patterns(&self) -> &[&str] {
+        &[TOMBI_TOML_FILENAME, DOT_TOMBI_TOML_FILENAME]
+    }
+
+    fn enabled(&self, config: &Config) -> bool {
+        config.tombi_extension_enabled()
+    }
+
+    fn hover<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+        position: tombi_text::Position,
+    ) -> BoxFuture<'a, Result<Option<HoverMetadata>, tower_lsp::jsonrpc::Error>> {
+        async move {
+            if !context
+                .config
+                .tombi_extension_features()
+                .and_then(|features| features.lsp())
+                .and_then(|lsp| lsp.hover())
+                .map(|hover| hover.enabled())
+                .unwrap_or_default()
+                .value()
+            {
+                return Ok(None);
+            }
+
+            crate::hover(
+                context.text_document_uri,
+                context.document_tree,
+                accessors,
+                position,
+                context.toml_version,
+                context.offline,
+            )
+            .await
+        }
+        .boxed()
+    }
+
+    fn completion<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+        position: tombi_text::Position,
+        completion_hint: Option<CompletionHint>,
+        comment_context: Option<&'a CommentContext>,
+    ) -> BoxFuture<'a, Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error>> {
+        crate::completion(
+            context.text_document_uri,
+            context.document_tree,
+            position,
+            accessors,
+            context.toml_version,
+            completion_hint,
+            comment_context,
+            context.config.tombi_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn goto_definition<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+        accessors: &'a [Accessor],
+    ) -> BoxFuture<'a, Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error>> {
+        crate::goto_definition(
+            context.text_document_uri,
+            context.document_tree,
+            accessors,
+            context.toml_version,
+            context.config.tombi_extension_features(),
+        )
+        .boxed()
+    }
+
+    fn document_link<'a>(
+        &'a self,
+        context: &'a ExtensionContext<'a>,
+    ) -> BoxFuture<'a, Result<Option<Vec<DocumentLink>>, tower_lsp::jsonrpc::Error>> {
+        crate::document_link(
+            context.text_document_uri,
+            context.document_tree,
+            context.toml_version,
+            context.config.tombi_extension_features(),
+        )
+        .boxed()
+    }
+}
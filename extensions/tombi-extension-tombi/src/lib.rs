@@ -1,9 +1,11 @@
 mod completion;
 mod document_link;
+mod extension;
 mod goto_definition;
 mod hover;
 
 pub use completion::completion;
 pub use document_link::{DocumentLinkToolTip, document_link};
+pub use extension::TombiExtension;
 pub use goto_definition::goto_definition;
 pub use hover::hover;
@@ -190,6 +190,18 @@ pub fn load(search_dir: Option<std::path::PathBuf>) -> Result<Config, tombi_conf
     Ok(config)
 }
 
+/// Load the user or system config, ignoring any project config.
+pub fn load_user_or_system_with_path()
+-> Result<Option<(Config, std::path::PathBuf)>, tombi_config::Error> {
+    let Some((config_path, _)) = get_user_or_system_tombi_config_path_and_level() else {
+        return Ok(None);
+    };
+    let Some(config) = try_from_path(&config_path)? else {
+        unreachable!("{CONFIG_TOML_FILENAME} should always be parsed successfully.");
+    };
+    Ok(Some((config, config_path)))
+}
+
 fn get_user_or_system_tombi_config_path_and_level() -> Option<(std::path::PathBuf, ConfigLevel)> {
     // 1. $XDG_CONFIG_HOME/tombi/config.toml
     if let Ok(xdg_config_home) = std::env::var("XDG_CONFIG_HOME") {
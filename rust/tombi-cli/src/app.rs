@@ -1,5 +1,6 @@
 mod command;
 mod tombi_log;
+mod watch;
 
 use clap::{
     Parser,
@@ -48,6 +49,82 @@ struct CommonArgs {
     no_cache: bool,
 }
 
+/// Select the TOML files touched in git instead of all the files.
+#[derive(clap::Args, Debug)]
+struct GitArgs {
+    /// Only target the TOML files changed in the git working tree
+    ///
+    /// Staged, unstaged and untracked files are targeted.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["staged", "since"])]
+    changed: bool,
+
+    /// Only target the TOML files staged in git
+    #[arg(long, default_value_t = false, conflicts_with = "since")]
+    staged: bool,
+
+    /// Only target the TOML files changed since the merge base with the git revision
+    ///
+    /// Uncommitted and untracked files are also targeted.
+    #[arg(long, value_name = "REV")]
+    since: Option<String>,
+}
+
+impl GitArgs {
+    fn git_filter(&self) -> Option<tombi_glob::GitFilter> {
+        if self.changed {
+            Some(tombi_glob::GitFilter::Changed)
+        } else if self.staged {
+            Some(tombi_glob::GitFilter::Staged)
+        } else {
+            self.since.clone().map(tombi_glob::GitFilter::Since)
+        }
+    }
+
+    async fn file_search(
+        &self,
+        files: &[String],
+        config: &tombi_config::Config,
+        config_path: Option<&std::path::Path>,
+        config_level: tombi_config::ConfigLevel,
+    ) -> tombi_glob::FileSearch {
+        match self.git_filter() {
+            Some(git_filter) => {
+                tombi_glob::FileSearch::new_with_git_filter(
+                    files,
+                    config,
+                    config_path,
+                    config_level,
+                    &git_filter,
+                )
+                .await
+            }
+            None => tombi_glob::FileSearch::new(files, config, config_path, config_level).await,
+        }
+    }
+}
+
+fn new_schema_store(
+    common: &CommonArgs,
+    config: Option<&tombi_config::Config>,
+) -> tombi_schema_store::SchemaStore {
+    tombi_schema_store::SchemaStore::new_with_options(tombi_schema_store::Options {
+        offline: common.offline.then_some(true),
+        strict: config
+            .and_then(|config| config.schema.as_ref())
+            .and_then(|schema_options| schema_options.strict()),
+        cache: Some(tombi_cache::Options {
+            no_cache: common.no_cache.then_some(true),
+            ..Default::default()
+        }),
+    })
+}
+
+fn new_runtime() -> Result<tokio::runtime::Runtime, std::io::Error> {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+}
+
 pub fn run(args: impl Into<Args>) -> Result<(), crate::Error> {
     let args: Args = args.into();
     let log_level = args.verbosity.log_level();
@@ -67,6 +144,7 @@ pub fn run(args: impl Into<Args>) -> Result<(), crate::Error> {
         command::TomlCommand::Format(args) => command::format::run(args),
         command::TomlCommand::Lint(args) => command::lint::run(args),
         command::TomlCommand::Lsp(args) => command::lsp::run(args),
+        command::TomlCommand::Schema(args) => command::schema::run(args),
         command::TomlCommand::Completion(args) => command::completion::run(args),
     }
 }
@@ -2,6 +2,7 @@ pub mod completion;
 pub mod format;
 pub mod lint;
 pub mod lsp;
+pub mod schema;
 
 #[derive(clap::Subcommand)]
 pub enum TomlCommand {
@@ -14,5 +15,7 @@ pub enum TomlCommand {
     #[command(alias = "serve")]
     Lsp(lsp::Args),
 
+    Schema(schema::Args),
+
     Completion(completion::Args),
 }
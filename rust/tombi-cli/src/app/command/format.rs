@@ -6,7 +6,10 @@ use tombi_config::{FormatOptions, TomlVersion};
 use tombi_diagnostic::{Diagnostic, Print};
 use tombi_glob::{FileInputType, FileSearch, FileSearchEntry};
 
-use crate::app::CommonArgs;
+use crate::app::{
+    CommonArgs, GitArgs, new_runtime, new_schema_store,
+    watch::{FileWatcher, WatchEvent, filter_changed_files},
+};
 
 /// Format TOML files.
 #[derive(clap::Args, Debug)]
@@ -38,6 +41,16 @@ pub struct Args {
     #[arg(long, default_value_t = false)]
     quiet: bool,
 
+    /// Watch files and format them again on changes
+    ///
+    /// Only the changed files are formatted again.
+    /// Changes of the config file or of local schema files format all files again.
+    #[arg(long, default_value_t = false, conflicts_with = "stdin_filename")]
+    watch: bool,
+
+    #[command(flatten)]
+    git: GitArgs,
+
     #[command(flatten)]
     common: CommonArgs,
 }
@@ -51,13 +64,16 @@ struct FormatRunSummary {
 }
 
 pub fn run(args: Args) -> Result<(), crate::Error> {
+    if args.watch {
+        if let Err(error) = watch_run(args, crate::app::printer()) {
+            log::error!("{}", error);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     let quiet = args.quiet;
-    let FormatRunSummary {
-        success_num,
-        not_needed_num,
-        skipped_num,
-        error_num,
-    } = match inner_run(args, crate::app::printer()) {
+    let summary = match inner_run(args, crate::app::printer()) {
         Ok(summary) => summary,
         Err(error) => {
             log::error!("{}", error);
@@ -66,45 +82,56 @@ pub fn run(args: Args) -> Result<(), crate::Error> {
     };
 
     if !quiet {
-        match (success_num, not_needed_num) {
-            (0, 0) => {
-                if error_num == 0 && skipped_num == 0 {
-                    eprintln!("No files formatted")
-                }
-            }
-            (success_num, not_needed_num) => {
-                match success_num {
-                    0 => {}
-                    1 => eprintln!("1 file formatted"),
-                    _ => eprintln!("{success_num} files formatted"),
-                };
-                match not_needed_num {
-                    0 => {}
-                    1 => eprintln!("1 file did not need formatting"),
-                    _ => eprintln!("{not_needed_num} files did not need formatting"),
-                }
-            }
-        };
-        match skipped_num {
-            0 => {}
-            1 => eprintln!("1 file skipped"),
-            _ => eprintln!("{skipped_num} files skipped"),
-        };
-        match error_num {
-            0 => {}
-            1 => eprintln!("1 file failed to be formatted"),
-            _ => eprintln!("{error_num} files failed to be formatted"),
-        };
+        print_summary(&summary);
     }
 
-    if error_num > 0 {
+    if summary.error_num > 0 {
         std::process::exit(1);
     }
 
     Ok(())
 }
 
-fn inner_run<P>(args: Args, mut printer: P) -> Result<FormatRunSummary, Box<dyn std::error::Error>>
+fn print_summary(
+    FormatRunSummary {
+        success_num,
+        not_needed_num,
+        skipped_num,
+        error_num,
+    }: &FormatRunSummary,
+) {
+    match (success_num, not_needed_num) {
+        (0, 0) => {
+            if *error_num == 0 && *skipped_num == 0 {
+                eprintln!("No files formatted")
+            }
+        }
+        (success_num, not_needed_num) => {
+            match success_num {
+                0 => {}
+                1 => eprintln!("1 file formatted"),
+                _ => eprintln!("{success_num} files formatted"),
+            };
+            match not_needed_num {
+                0 => {}
+                1 => eprintln!("1 file did not need formatting"),
+                _ => eprintln!("{not_needed_num} files did not need formatting"),
+            }
+        }
+    };
+    match skipped_num {
+        0 => {}
+        1 => eprintln!("1 file skipped"),
+        _ => eprintln!("{skipped_num} files skipped"),
+    };
+    match error_num {
+        0 => {}
+        1 => eprintln!("1 file failed to be formatted"),
+        _ => eprintln!("{error_num} files failed to be formatted"),
+    };
+}
+
+fn inner_run<P>(args: Args, printer: P) -> Result<FormatRunSummary, Box<dyn std::error::Error>>
 where
     Diagnostic: Print<P>,
     crate::Error: Print<P>,
@@ -121,168 +148,270 @@ where
         }
     })?;
 
-    let toml_version = config.toml_version.unwrap_or_default();
-    let schema_options = config.schema.as_ref();
-    let schema_store =
-        tombi_schema_store::SchemaStore::new_with_options(tombi_schema_store::Options {
-            offline: args.common.offline.then_some(true),
-            strict: schema_options.and_then(|schema_options| schema_options.strict()),
-            cache: Some(tombi_cache::Options {
-                no_cache: args.common.no_cache.then_some(true),
-                ..Default::default()
-            }),
-        });
-
-    let Ok(runtime) = tokio::runtime::Builder::new_multi_thread()
-        .enable_all()
-        .build()
-    else {
-        log::error!("Failed to create tokio runtime");
-        std::process::exit(1);
-    };
+    let schema_store = new_schema_store(&args.common, Some(&config));
 
-    runtime.block_on(async {
+    new_runtime()?.block_on(async {
         // Run schema loading and file discovery concurrently
         let (schema_result, input) = tokio::join!(
             schema_store.load_config(&config, config_path.as_deref()),
-            FileSearch::new(&args.files, &config, config_path.as_deref(), config_level,)
+            args.git
+                .file_search(&args.files, &config, config_path.as_deref(), config_level,)
         );
 
         schema_result?;
-        let total_num = input.len();
-        let mut summary = FormatRunSummary::default();
-
-        match input {
-            FileSearch::Stdin => {
-                log::debug!("Formatting... stdin input");
-                let stdin_path = args.stdin_filename.as_ref().map(std::path::PathBuf::from);
-
-                // Get format options with override support
-                let Some(format_options) = tombi_glob::get_format_options(
-                    &config,
-                    stdin_path.as_deref(),
-                    config_path.as_deref(),
-                ) else {
-                    log::debug!("Formatting disabled for stdin by override");
-                    summary.not_needed_num += 1;
-                    return Ok(summary);
-                };
 
-                match format_stdin(
-                    FormatFile::from_stdin(stdin_path),
-                    printer,
-                    toml_version,
-                    args.check,
-                    args.diff,
-                    &format_options,
-                    &schema_store,
-                )
-                .await
-                {
-                    Ok(true) => summary.success_num += 1,
-                    Ok(false) => summary.not_needed_num += 1,
-                    Err(_) => summary.error_num += 1,
+        Ok(format_input(
+            &args,
+            input,
+            printer,
+            &config,
+            config_path.as_deref(),
+            &schema_store,
+        )
+        .await)
+    })
+}
+
+/// Format all files, then format again the files that change.
+///
+/// Changes of the config file or of the local schema files reload them and format all files again.
+fn watch_run<P>(args: Args, printer: P) -> Result<(), Box<dyn std::error::Error>>
+where
+    Diagnostic: Print<P>,
+    crate::Error: Print<P>,
+    P: Clone + Send + 'static,
+{
+    if FileInputType::from(args.files.as_ref()) == FileInputType::Stdin {
+        return Err("--watch cannot be used with the standard input".into());
+    }
+
+    let mut loaded = serde_tombi::config::load_with_path_and_level(std::env::current_dir().ok())?;
+
+    new_runtime()?.block_on(async {
+        loop {
+            let (config, config_path, config_level) = &loaded;
+            let config_path = config_path.as_deref();
+            let schema_store = new_schema_store(&args.common, Some(config));
+            let mut watcher = FileWatcher::new(&args.files, config, config_path, *config_level)?;
+
+            let (schema_result, input) = tokio::join!(
+                schema_store.load_config(config, config_path),
+                args.git
+                    .file_search(&args.files, config, config_path, *config_level)
+            );
+
+            match schema_result {
+                Ok(()) => {
+                    let summary = format_input(
+                        &args,
+                        input,
+                        printer.clone(),
+                        config,
+                        config_path,
+                        &schema_store,
+                    )
+                    .await;
+                    if !args.quiet {
+                        print_summary(&summary);
+                    }
                 }
+                Err(error) => log::error!("{}", error),
             }
-            FileSearch::Files(files) => {
-                let mut tasks = tokio::task::JoinSet::new();
-                let mut errors = Vec::new();
-                for file in files {
-                    match file {
-                        FileSearchEntry::Found(source_path) => {
-                            log::debug!("Formatting... {:?}", &source_path);
-
-                            // Get format options with override support
-                            let Some(format_options) = tombi_glob::get_format_options(
-                                &config,
-                                Some(source_path.as_ref()),
-                                config_path.as_deref(),
-                            ) else {
-                                log::debug!(
-                                    "Formatting disabled for {:?} by override",
-                                    source_path
-                                );
-                                summary.not_needed_num += 1;
-                                continue;
-                            };
-
-                            match FormatFile::from_file(&source_path, args.check).await {
-                                Ok(file) => {
-                                    let printer = printer.clone();
-                                    let schema_store = schema_store.clone();
-
-                                    tasks.spawn(async move {
-                                        format_file(
-                                            file,
-                                            printer,
-                                            &source_path,
-                                            toml_version,
-                                            args.check,
-                                            args.diff,
-                                            &format_options,
-                                            &schema_store,
-                                        )
-                                        .await
-                                    });
-                                }
-                                Err(err) => {
-                                    if err.kind() == std::io::ErrorKind::NotFound {
-                                        crate::Error::TombiGlob(tombi_glob::Error::FileNotFound(
-                                            source_path,
-                                        ))
-                                        .print(&mut printer);
-                                    } else {
-                                        crate::Error::Io(err).print(&mut printer);
-                                    }
-                                    summary.error_num += 1;
-                                }
-                            }
-                        }
-                        FileSearchEntry::Skipped(_) => {
-                            summary.skipped_num += 1;
+
+            loop {
+                if !args.check {
+                    watcher.skip_pending().await;
+                }
+                if !args.quiet {
+                    eprintln!("Watching for changes...");
+                }
+
+                match watcher.next().await {
+                    Some(WatchEvent::Changed(changed_paths)) => {
+                        let input = filter_changed_files(
+                            changed_paths,
+                            &args.files,
+                            config,
+                            config_path,
+                            *config_level,
+                            args.git.git_filter().as_ref(),
+                        )
+                        .await;
+                        if input.is_empty() {
+                            continue;
                         }
-                        FileSearchEntry::Error(err) => {
-                            crate::Error::TombiGlob(err).print(&mut printer);
-                            summary.error_num += 1;
+
+                        let summary = format_input(
+                            &args,
+                            input,
+                            printer.clone(),
+                            config,
+                            config_path,
+                            &schema_store,
+                        )
+                        .await;
+                        if !args.quiet {
+                            print_summary(&summary);
                         }
                     }
+                    Some(WatchEvent::Reload) => break,
+                    None => return Ok(()),
                 }
+            }
+
+            log::info!("Reloading config...");
+            match serde_tombi::config::load_with_path_and_level(std::env::current_dir().ok()) {
+                Ok(reloaded) => loaded = reloaded,
+                Err(error) => log::error!("{}", error),
+            }
+        }
+    })
+}
 
-                while let Some(result) = tasks.join_next().await {
-                    match result {
-                        Ok(Ok(formatted)) => {
-                            if formatted {
-                                summary.success_num += 1;
-                            } else {
-                                summary.not_needed_num += 1;
+async fn format_input<P>(
+    args: &Args,
+    input: FileSearch,
+    mut printer: P,
+    config: &tombi_config::Config,
+    config_path: Option<&std::path::Path>,
+    schema_store: &tombi_schema_store::SchemaStore,
+) -> FormatRunSummary
+where
+    Diagnostic: Print<P>,
+    crate::Error: Print<P>,
+    P: Clone + Send + 'static,
+{
+    let toml_version = config.toml_version.unwrap_or_default();
+    let check = args.check;
+    let diff = args.diff;
+    let total_num = input.len();
+    let mut summary = FormatRunSummary::default();
+
+    match input {
+        FileSearch::Stdin => {
+            log::debug!("Formatting... stdin input");
+            let stdin_path = args.stdin_filename.as_ref().map(std::path::PathBuf::from);
+
+            // Get format options with override support
+            let Some(format_options) =
+                tombi_glob::get_format_options(config, stdin_path.as_deref(), config_path)
+            else {
+                log::debug!("Formatting disabled for stdin by override");
+                summary.not_needed_num += 1;
+                return summary;
+            };
+
+            match format_stdin(
+                FormatFile::from_stdin(stdin_path),
+                printer,
+                toml_version,
+                check,
+                diff,
+                &format_options,
+                schema_store,
+            )
+            .await
+            {
+                Ok(true) => summary.success_num += 1,
+                Ok(false) => summary.not_needed_num += 1,
+                Err(_) => summary.error_num += 1,
+            }
+        }
+        FileSearch::Files(files) => {
+            let mut tasks = tokio::task::JoinSet::new();
+            let mut errors = Vec::new();
+            for file in files {
+                match file {
+                    FileSearchEntry::Found(source_path) => {
+                        log::debug!("Formatting... {:?}", &source_path);
+
+                        // Get format options with override support
+                        let Some(format_options) = tombi_glob::get_format_options(
+                            config,
+                            Some(source_path.as_ref()),
+                            config_path,
+                        ) else {
+                            log::debug!("Formatting disabled for {:?} by override", source_path);
+                            summary.not_needed_num += 1;
+                            continue;
+                        };
+
+                        match FormatFile::from_file(&source_path, check).await {
+                            Ok(file) => {
+                                let printer = printer.clone();
+                                let schema_store = schema_store.clone();
+
+                                tasks.spawn(async move {
+                                    format_file(
+                                        file,
+                                        printer,
+                                        &source_path,
+                                        toml_version,
+                                        check,
+                                        diff,
+                                        &format_options,
+                                        &schema_store,
+                                    )
+                                    .await
+                                });
+                            }
+                            Err(err) => {
+                                if err.kind() == std::io::ErrorKind::NotFound {
+                                    crate::Error::TombiGlob(tombi_glob::Error::FileNotFound(
+                                        source_path,
+                                    ))
+                                    .print(&mut printer);
+                                } else {
+                                    crate::Error::Io(err).print(&mut printer);
+                                }
+                                summary.error_num += 1;
                             }
                         }
-                        Ok(Err(error)) => {
-                            errors.push(error);
-                            summary.error_num += 1;
-                        }
-                        Err(e) => {
-                            log::error!("Task failed {}", e);
-                            summary.error_num += 1;
-                        }
+                    }
+                    FileSearchEntry::Skipped(_) => {
+                        summary.skipped_num += 1;
+                    }
+                    FileSearchEntry::Error(err) => {
+                        crate::Error::TombiGlob(err).print(&mut printer);
+                        summary.error_num += 1;
                     }
                 }
+            }
 
-                if !errors.is_empty() {
-                    for error in errors {
-                        error.print(&mut printer);
+            while let Some(result) = tasks.join_next().await {
+                match result {
+                    Ok(Ok(formatted)) => {
+                        if formatted {
+                            summary.success_num += 1;
+                        } else {
+                            summary.not_needed_num += 1;
+                        }
+                    }
+                    Ok(Err(error)) => {
+                        errors.push(error);
+                        summary.error_num += 1;
+                    }
+                    Err(e) => {
+                        log::error!("Task failed {}", e);
+                        summary.error_num += 1;
                     }
                 }
             }
-        };
 
-        debug_assert_eq!(
-            summary.success_num + summary.not_needed_num + summary.skipped_num + summary.error_num,
-            total_num
-        );
+            if !errors.is_empty() {
+                for error in errors {
+                    error.print(&mut printer);
+                }
+            }
+        }
+    };
 
-        Ok(summary)
-    })
+    debug_assert_eq!(
+        summary.success_num + summary.not_needed_num + summary.skipped_num + summary.error_num,
+        total_num
+    );
+
+    summary
 }
 
 // For standard input: --check outputs formatted TOML and returns error if different
@@ -1,9 +1,21 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
 use tokio::io::AsyncReadExt;
+use tombi_ast::AstNode;
 use tombi_config::{LintOptions, TomlVersion};
 use tombi_diagnostic::{Diagnostic, Print};
-use tombi_glob::{FileSearch, FileSearchEntry};
+use tombi_document_tree::IntoDocumentTreeAndErrors;
+use tombi_extension::{ExtensionContext, ExtensionRegistry};
+use tombi_glob::{FileInputType, FileSearch, FileSearchEntry};
+use tombi_linter::Baseline;
 
-use crate::app::CommonArgs;
+use crate::app::{
+    CommonArgs, GitArgs, new_runtime, new_schema_store,
+    watch::{FileWatcher, WatchEvent, filter_changed_files},
+};
 
 /// Lint TOML files.
 #[derive(clap::Args, Debug)]
@@ -33,24 +45,73 @@ pub struct Args {
     #[arg(long, default_value_t = false)]
     quiet: bool,
 
+    /// Report unused lint suppressions
+    ///
+    /// If `true`, the program will warn about `lint.rules.<rule>.disabled = true` and
+    /// `lint.disabled = true` comment directives that suppress nothing, and about
+    /// `[[overrides]]` items with `lint.enabled = false` whose matched files have nothing to suppress.
+    #[arg(long, default_value_t = false)]
+    report_unused_directives: bool,
+
+    /// Record the current diagnostics to a baseline file
+    ///
+    /// Diagnostics are recorded by file, accessor path and code, so the baseline survives line changes.
+    /// The recorded diagnostics are not reported.
+    #[arg(long, value_name = "PATH", conflicts_with = "baseline")]
+    write_baseline: Option<PathBuf>,
+
+    /// Report only the diagnostics not recorded in the baseline file
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Watch files and lint them again on changes
+    ///
+    /// Only the changed files are linted again.
+    /// Changes of the config file or of local schema files lint all files again.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["stdin_filename", "write_baseline"])]
+    watch: bool,
+
+    #[command(flatten)]
+    git: GitArgs,
+
     #[command(flatten)]
     common: CommonArgs,
 }
 
+/// How the diagnostics are checked against a baseline file.
+#[derive(Debug, Clone)]
+enum BaselineMode {
+    None,
+    Write {
+        path: PathBuf,
+        baseline: Arc<Mutex<Baseline>>,
+    },
+    Filter {
+        path: PathBuf,
+        baseline: Arc<Baseline>,
+    },
+}
+
 #[derive(Debug, Default)]
 struct LintRunSummary {
     success_num: usize,
     skipped_num: usize,
     error_num: usize,
+    unused_override_num: usize,
 }
 
 pub fn run(args: Args) -> Result<(), crate::Error> {
+    if args.watch {
+        if let Err(error) = watch_run(args, crate::app::printer()) {
+            log::error!("{}", error);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     let quiet = args.quiet;
-    let LintRunSummary {
-        success_num,
-        skipped_num,
-        error_num,
-    } = match inner_run(args, crate::app::printer()) {
+    let error_on_warnings = args.error_on_warnings;
+    let summary = match inner_run(args, crate::app::printer()) {
         Ok(summary) => summary,
         Err(error) => {
             log::error!("{}", error);
@@ -59,37 +120,48 @@ pub fn run(args: Args) -> Result<(), crate::Error> {
     };
 
     if !quiet {
-        match success_num {
-            0 => {
-                if error_num == 0 && skipped_num == 0 {
-                    eprintln!("No files linted")
-                }
-            }
-            1 => eprintln!("1 file linted successfully"),
-            _ => eprintln!("{success_num} files linted successfully"),
-        }
+        print_summary(&summary);
+    }
 
-        match skipped_num {
-            0 => {}
-            1 => eprintln!("1 file skipped"),
-            _ => eprintln!("{skipped_num} files skipped"),
-        }
+    if summary.error_num > 0 || (error_on_warnings && summary.unused_override_num > 0) {
+        std::process::exit(1);
+    }
 
-        match error_num {
-            0 => {}
-            1 => eprintln!("1 file failed to be linted"),
-            _ => eprintln!("{error_num} files failed to be linted"),
+    Ok(())
+}
+
+fn print_summary(
+    LintRunSummary {
+        success_num,
+        skipped_num,
+        error_num,
+        ..
+    }: &LintRunSummary,
+) {
+    match success_num {
+        0 => {
+            if *error_num == 0 && *skipped_num == 0 {
+                eprintln!("No files linted")
+            }
         }
+        1 => eprintln!("1 file linted successfully"),
+        _ => eprintln!("{success_num} files linted successfully"),
     }
 
-    if error_num > 0 {
-        std::process::exit(1);
+    match skipped_num {
+        0 => {}
+        1 => eprintln!("1 file skipped"),
+        _ => eprintln!("{skipped_num} files skipped"),
     }
 
-    Ok(())
+    match error_num {
+        0 => {}
+        1 => eprintln!("1 file failed to be linted"),
+        _ => eprintln!("{error_num} files failed to be linted"),
+    }
 }
 
-fn inner_run<P>(args: Args, mut printer: P) -> Result<LintRunSummary, Box<dyn std::error::Error>>
+fn inner_run<P>(args: Args, printer: P) -> Result<LintRunSummary, Box<dyn std::error::Error>>
 where
     Diagnostic: Print<P>,
     crate::Error: Print<P>,
@@ -98,152 +170,360 @@ where
     let (config, config_path, config_level) =
         serde_tombi::config::load_with_path_and_level(std::env::current_dir().ok())?;
 
-    let toml_version = config.toml_version.unwrap_or_default();
-    let schema_options = config.schema.as_ref();
-    let schema_store =
-        tombi_schema_store::SchemaStore::new_with_options(tombi_schema_store::Options {
-            offline: args.common.offline.then_some(true),
-            strict: schema_options.and_then(|schema_options| schema_options.strict()),
-            cache: Some(tombi_cache::Options {
-                no_cache: args.common.no_cache.then_some(true),
-                ..Default::default()
-            }),
-        });
-
-    let Ok(runtime) = tokio::runtime::Builder::new_multi_thread()
-        .enable_all()
-        .build()
-    else {
-        log::error!("Failed to create tokio runtime");
-        std::process::exit(1);
+    let baseline_mode = if let Some(path) = &args.write_baseline {
+        BaselineMode::Write {
+            path: path.clone(),
+            baseline: Default::default(),
+        }
+    } else if let Some(path) = &args.baseline {
+        let baseline = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        BaselineMode::Filter {
+            path: path.clone(),
+            baseline: Arc::new(baseline),
+        }
+    } else {
+        BaselineMode::None
     };
+    let schema_store = new_schema_store(&args.common, Some(&config));
 
-    runtime.block_on(async {
+    new_runtime()?.block_on(async {
         // Run schema loading and file discovery concurrently
         let (schema_result, input) = tokio::join!(
             schema_store.load_config(&config, config_path.as_deref()),
-            tombi_glob::FileSearch::new(&args.files, &config, config_path.as_deref(), config_level)
+            args.git
+                .file_search(&args.files, &config, config_path.as_deref(), config_level)
         );
 
         schema_result?;
-        let total_num = input.len();
-        let mut summary = LintRunSummary::default();
-
-        match input {
-            FileSearch::Stdin => {
-                log::debug!("linting... stdin input");
-                let stdin_path = args.stdin_filename.as_deref().map(std::path::Path::new);
-
-                // Get lint options with override support
-                let Some(lint_options) =
-                    tombi_glob::get_lint_options(&config, stdin_path, config_path.as_deref())
-                else {
-                    log::debug!("Linting disabled for stdin by override");
-                    summary.success_num += 1;
-                    return Ok(summary);
-                };
-
-                if lint_file(
-                    tokio::io::stdin(),
-                    printer,
-                    stdin_path,
-                    toml_version,
-                    &lint_options,
-                    &schema_store,
-                    args.error_on_warnings,
-                )
-                .await
-                {
-                    summary.success_num += 1;
-                } else {
-                    summary.error_num += 1;
+
+        let summary = lint_input(
+            &args,
+            input,
+            printer,
+            &config,
+            config_path.as_deref(),
+            &schema_store,
+            &baseline_mode,
+            true,
+        )
+        .await;
+
+        if let BaselineMode::Write { path, baseline } = &baseline_mode {
+            let baseline = baseline.lock().map_err(|err| err.to_string())?;
+            std::fs::write(path, serde_json::to_string_pretty(&*baseline)? + "\n")?;
+            if !args.quiet {
+                match baseline.len() {
+                    1 => eprintln!("1 diagnostic written to {}", path.display()),
+                    n => eprintln!("{n} diagnostics written to {}", path.display()),
                 }
             }
-            FileSearch::Files(files) => {
-                let mut tasks = tokio::task::JoinSet::new();
-
-                for file in files {
-                    match file {
-                        FileSearchEntry::Found(source_path) => {
-                            log::debug!("linting... {:?}", source_path);
-
-                            // Get lint options with override support
-                            let Some(lint_options) = tombi_glob::get_lint_options(
-                                &config,
-                                Some(source_path.as_ref()),
-                                config_path.as_deref(),
-                            ) else {
-                                log::debug!("Linting disabled for {:?} by override", source_path);
-                                summary.success_num += 1;
-                                continue;
-                            };
-
-                            match tokio::fs::File::open(&source_path).await {
-                                Ok(file) => {
-                                    let printer = printer.clone();
-                                    let schema_store = schema_store.clone();
-
-                                    tasks.spawn(async move {
-                                        lint_file(
-                                            file,
-                                            printer,
-                                            Some(source_path.as_ref()),
-                                            toml_version,
-                                            &lint_options,
-                                            &schema_store,
-                                            args.error_on_warnings,
-                                        )
-                                        .await
-                                    });
-                                }
-                                Err(err) => {
-                                    if err.kind() == std::io::ErrorKind::NotFound {
-                                        crate::Error::TombiGlob(tombi_glob::Error::FileNotFound(
-                                            source_path,
-                                        ))
-                                        .print(&mut printer);
-                                    } else {
-                                        crate::Error::Io(err).print(&mut printer);
-                                    }
-                                    summary.error_num += 1;
-                                }
-                            }
-                        }
-                        FileSearchEntry::Skipped(_) => {
-                            summary.skipped_num += 1;
+        }
+
+        Ok(summary)
+    })
+}
+
+/// Lint all files, then lint again the files that change.
+///
+/// Changes of the config file or of the local schema files reload them and lint all files again.
+fn watch_run<P>(args: Args, printer: P) -> Result<(), Box<dyn std::error::Error>>
+where
+    Diagnostic: Print<P>,
+    crate::Error: Print<P>,
+    P: Clone + Send + 'static,
+{
+    if FileInputType::from(args.files.as_ref()) == FileInputType::Stdin {
+        return Err("--watch cannot be used with the standard input".into());
+    }
+
+    let baseline_mode = match &args.baseline {
+        Some(path) => BaselineMode::Filter {
+            path: path.clone(),
+            baseline: Arc::new(serde_json::from_str(&std::fs::read_to_string(path)?)?),
+        },
+        None => BaselineMode::None,
+    };
+
+    let mut loaded = serde_tombi::config::load_with_path_and_level(std::env::current_dir().ok())?;
+
+    new_runtime()?.block_on(async {
+        loop {
+            let (config, config_path, config_level) = &loaded;
+            let config_path = config_path.as_deref();
+            let schema_store = new_schema_store(&args.common, Some(config));
+            let mut watcher = FileWatcher::new(&args.files, config, config_path, *config_level)?;
+
+            let (schema_result, input) = tokio::join!(
+                schema_store.load_config(config, config_path),
+                args.git
+                    .file_search(&args.files, config, config_path, *config_level)
+            );
+
+            match schema_result {
+                Ok(()) => {
+                    let summary = lint_input(
+                        &args,
+                        input,
+                        printer.clone(),
+                        config,
+                        config_path,
+                        &schema_store,
+                        &baseline_mode,
+                        true,
+                    )
+                    .await;
+                    if !args.quiet {
+                        print_summary(&summary);
+                    }
+                }
+                Err(error) => log::error!("{}", error),
+            }
+
+            loop {
+                if !args.quiet {
+                    eprintln!("Watching for changes...");
+                }
+
+                match watcher.next().await {
+                    Some(WatchEvent::Changed(changed_paths)) => {
+                        let input = filter_changed_files(
+                            changed_paths,
+                            &args.files,
+                            config,
+                            config_path,
+                            *config_level,
+                            args.git.git_filter().as_ref(),
+                        )
+                        .await;
+                        if input.is_empty() {
+                            continue;
                         }
-                        FileSearchEntry::Error(err) => {
-                            crate::Error::TombiGlob(err).print(&mut printer);
-                            summary.error_num += 1;
+
+                        let summary = lint_input(
+                            &args,
+                            input,
+                            printer.clone(),
+                            config,
+                            config_path,
+                            &schema_store,
+                            &baseline_mode,
+                            // Only some files are linted again, so an override can't be judged unused.
+                            false,
+                        )
+                        .await;
+                        if !args.quiet {
+                            print_summary(&summary);
                         }
                     }
+                    Some(WatchEvent::Reload) => break,
+                    None => return Ok(()),
                 }
+            }
 
-                while let Some(result) = tasks.join_next().await {
-                    match result {
-                        Ok(success) => {
-                            if success {
-                                summary.success_num += 1;
-                            } else {
+            log::info!("Reloading config...");
+            match serde_tombi::config::load_with_path_and_level(std::env::current_dir().ok()) {
+                Ok(reloaded) => loaded = reloaded,
+                Err(error) => log::error!("{}", error),
+            }
+        }
+    })
+}
+
+async fn lint_input<P>(
+    args: &Args,
+    input: FileSearch,
+    mut printer: P,
+    config: &tombi_config::Config,
+    config_path: Option<&std::path::Path>,
+    schema_store: &tombi_schema_store::SchemaStore,
+    baseline_mode: &BaselineMode,
+    report_unused_overrides: bool,
+) -> LintRunSummary
+where
+    Diagnostic: Print<P>,
+    crate::Error: Print<P>,
+    P: Clone + Send + 'static,
+{
+    let toml_version = config.toml_version.unwrap_or_default();
+    let error_on_warnings = args.error_on_warnings;
+    let report_unused_directives = args.report_unused_directives;
+    let report_unused_overrides = report_unused_directives && report_unused_overrides;
+    let total_num = input.len();
+    let mut summary = LintRunSummary::default();
+    let extension_registry = Arc::new(tombi_lsp::default_extension_registry());
+
+    match input {
+        FileSearch::Stdin => {
+            log::debug!("linting... stdin input");
+            let stdin_path = args.stdin_filename.as_deref().map(std::path::Path::new);
+
+            // Get lint options with override support
+            let Some(lint_options) = tombi_glob::get_lint_options(config, stdin_path, config_path)
+            else {
+                log::debug!("Linting disabled for stdin by override");
+                summary.success_num += 1;
+                return summary;
+            };
+
+            if lint_file(
+                tokio::io::stdin(),
+                printer,
+                stdin_path,
+                toml_version,
+                &lint_options,
+                config,
+                schema_store,
+                &extension_registry,
+                error_on_warnings,
+                report_unused_directives,
+                baseline_mode,
+            )
+            .await
+            {
+                summary.success_num += 1;
+            } else {
+                summary.error_num += 1;
+            }
+        }
+        FileSearch::Files(files) => {
+            let mut tasks = tokio::task::JoinSet::new();
+            let shared_config = Arc::new(config.clone());
+            let mut override_tasks = tokio::task::JoinSet::new();
+            let mut matched_override_indices = std::collections::BTreeSet::new();
+
+            for file in files {
+                match file {
+                    FileSearchEntry::Found(source_path) => {
+                        log::debug!("linting... {:?}", source_path);
+
+                        // Get lint options with override support
+                        let Some(lint_options) = tombi_glob::get_lint_options(
+                            config,
+                            Some(source_path.as_ref()),
+                            config_path,
+                        ) else {
+                            log::debug!("Linting disabled for {:?} by override", source_path);
+                            if report_unused_overrides
+                                && let Some(override_index) = tombi_glob::get_override_index(
+                                    config,
+                                    &source_path,
+                                    config_path,
+                                )
+                            {
+                                matched_override_indices.insert(override_index);
+
+                                let lint_options = config.merge_lint(None);
+                                let config = shared_config.clone();
+                                let schema_store = schema_store.clone();
+                                let extension_registry = extension_registry.clone();
+                                override_tasks.spawn(async move {
+                                    let suppressed = has_diagnostics(
+                                        &source_path,
+                                        toml_version,
+                                        &lint_options,
+                                        &config,
+                                        &schema_store,
+                                        &extension_registry,
+                                    )
+                                    .await;
+                                    (override_index, suppressed)
+                                });
+                            }
+                            summary.success_num += 1;
+                            continue;
+                        };
+
+                        match tokio::fs::File::open(&source_path).await {
+                            Ok(file) => {
+                                let printer = printer.clone();
+                                let config = shared_config.clone();
+                                let schema_store = schema_store.clone();
+                                let extension_registry = extension_registry.clone();
+                                let baseline_mode = baseline_mode.clone();
+
+                                tasks.spawn(async move {
+                                    lint_file(
+                                        file,
+                                        printer,
+                                        Some(source_path.as_ref()),
+                                        toml_version,
+                                        &lint_options,
+                                        &config,
+                                        &schema_store,
+                                        &extension_registry,
+                                        error_on_warnings,
+                                        report_unused_directives,
+                                        &baseline_mode,
+                                    )
+                                    .await
+                                });
+                            }
+                            Err(err) => {
+                                if err.kind() == std::io::ErrorKind::NotFound {
+                                    crate::Error::TombiGlob(tombi_glob::Error::FileNotFound(
+                                        source_path,
+                                    ))
+                                    .print(&mut printer);
+                                } else {
+                                    crate::Error::Io(err).print(&mut printer);
+                                }
                                 summary.error_num += 1;
                             }
                         }
-                        Err(e) => {
-                            log::error!("Task failed {}", e);
+                    }
+                    FileSearchEntry::Skipped(_) => {
+                        summary.skipped_num += 1;
+                    }
+                    FileSearchEntry::Error(err) => {
+                        crate::Error::TombiGlob(err).print(&mut printer);
+                        summary.error_num += 1;
+                    }
+                }
+            }
+
+            while let Some(result) = tasks.join_next().await {
+                match result {
+                    Ok(success) => {
+                        if success {
+                            summary.success_num += 1;
+                        } else {
                             summary.error_num += 1;
                         }
                     }
+                    Err(e) => {
+                        log::error!("Task failed {}", e);
+                        summary.error_num += 1;
+                    }
                 }
             }
+
+            while let Some(result) = override_tasks.join_next().await {
+                match result {
+                    Ok((override_index, true)) => {
+                        matched_override_indices.remove(&override_index);
+                    }
+                    Ok((_, false)) => {}
+                    Err(e) => {
+                        log::error!("Task failed {}", e);
+                    }
+                }
+            }
+
+            if report_unused_overrides && let Some(config_path) = config_path {
+                let diagnostics =
+                    unused_override_diagnostics(config_path, &matched_override_indices);
+                summary.unused_override_num = diagnostics.len();
+                diagnostics.print(&mut printer);
+            }
         }
+    }
 
-        debug_assert_eq!(
-            summary.success_num + summary.skipped_num + summary.error_num,
-            total_num
-        );
+    debug_assert_eq!(
+        summary.success_num + summary.skipped_num + summary.error_num,
+        total_num
+    );
 
-        Ok(summary)
-    })
+    summary
 }
 
 async fn lint_file<R, P>(
@@ -252,8 +532,12 @@ async fn lint_file<R, P>(
     source_path: Option<&std::path::Path>,
     toml_version: TomlVersion,
     lint_options: &LintOptions,
+    config: &tombi_config::Config,
     schema_store: &tombi_schema_store::SchemaStore,
+    extension_registry: &ExtensionRegistry,
     error_on_warnings: bool,
+    report_unused_directives: bool,
+    baseline_mode: &BaselineMode,
 ) -> bool
 where
     Diagnostic: Print<P>,
@@ -265,16 +549,62 @@ where
     if reader.read_to_string(&mut source).await.is_err() {
         return false;
     }
-    let Err(diagnostics) = tombi_linter::Linter::new(
+    let mut diagnostics = match tombi_linter::Linter::new(
         toml_version,
         lint_options,
         source_path.map(itertools::Either::Right),
         schema_store,
     )
+    .report_unused_directives(report_unused_directives)
     .lint(&source)
     .await
-    else {
+    {
+        Ok(()) => Vec::new(),
+        Err(diagnostics) => diagnostics,
+    };
+    if let Some(source_path) = source_path {
+        diagnostics.extend(
+            extension_diagnostics(
+                &source,
+                source_path,
+                toml_version,
+                config,
+                schema_store,
+                extension_registry,
+            )
+            .await,
+        );
+    }
+    if diagnostics.is_empty() {
         return true;
+    }
+
+    let diagnostics = match baseline_mode {
+        BaselineMode::None => diagnostics,
+        BaselineMode::Write { path, baseline } => {
+            if let Ok(mut baseline) = baseline.lock() {
+                baseline.insert(
+                    baseline_file_key(path, source_path),
+                    &source,
+                    toml_version,
+                    &diagnostics,
+                );
+            }
+            return true;
+        }
+        BaselineMode::Filter { path, baseline } => {
+            let (new_diagnostics, baselined_diagnostics) = baseline.partition(
+                &baseline_file_key(path, source_path),
+                &source,
+                toml_version,
+                diagnostics,
+            );
+            log::debug!(
+                "{} diagnostics are recorded in the baseline",
+                baselined_diagnostics.len()
+            );
+            new_diagnostics
+        }
     };
 
     let diagnostics = if let Some(source_path) = source_path {
@@ -294,3 +624,162 @@ where
         diagnostics.iter().all(Diagnostic::is_warning)
     }
 }
+
+/// Collect the diagnostics of the extensions handling the file, such as the Cargo extension.
+async fn extension_diagnostics(
+    source: &str,
+    source_path: &std::path::Path,
+    toml_version: TomlVersion,
+    config: &tombi_config::Config,
+    schema_store: &tombi_schema_store::SchemaStore,
+    extension_registry: &ExtensionRegistry,
+) -> Vec<Diagnostic> {
+    let Ok(text_document_uri) = std::path::absolute(source_path)
+        .map_err(|_| ())
+        .and_then(tombi_uri::Uri::from_file_path)
+    else {
+        return Vec::new();
+    };
+
+    let mut extensions = extension_registry
+        .extensions_for(&text_document_uri, config)
+        .peekable();
+    if extensions.peek().is_none() {
+        return Vec::new();
+    }
+
+    let Some(root) = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()) else {
+        return Vec::new();
+    };
+    let document_tree = root.into_document_tree_and_errors(toml_version).tree;
+
+    let extension_context = ExtensionContext {
+        text_document_uri: &text_document_uri,
+        document_tree: &document_tree,
+        toml_version,
+        config,
+        offline: schema_store.offline(),
+        cache_options: schema_store.cache_options(),
+    };
+
+    let mut diagnostics = Vec::new();
+    for extension in extensions {
+        diagnostics.extend(extension.lint(&extension_context).await);
+    }
+    diagnostics
+}
+
+fn baseline_file_key(
+    baseline_path: &std::path::Path,
+    source_path: Option<&std::path::Path>,
+) -> String {
+    match source_path {
+        Some(source_path) => Baseline::file_key(baseline_path, source_path),
+        None => "-".to_string(),
+    }
+}
+
+/// The range of `lint.enabled = false` in an `[[overrides]]` item, or of the whole item.
+fn override_lint_enabled_range(override_item: &tombi_document_tree::Value) -> tombi_text::Range {
+    if let tombi_document_tree::Value::Table(override_table) = override_item
+        && let Some(tombi_document_tree::Value::Table(lint)) = override_table.get("lint")
+        && let Some((enabled_key, enabled)) = lint.get_key_value("enabled")
+    {
+        return enabled_key.range() + enabled.range();
+    }
+    override_item.range()
+}
+
+/// Check if linting the file without its `[[overrides]]` item reports any diagnostics,
+/// including those of the extensions.
+async fn has_diagnostics(
+    source_path: &std::path::Path,
+    toml_version: TomlVersion,
+    lint_options: &LintOptions,
+    config: &tombi_config::Config,
+    schema_store: &tombi_schema_store::SchemaStore,
+    extension_registry: &ExtensionRegistry,
+) -> bool {
+    let Ok(source) = tokio::fs::read_to_string(source_path).await else {
+        return false;
+    };
+
+    if tombi_linter::Linter::new(
+        toml_version,
+        lint_options,
+        Some(itertools::Either::Right(source_path)),
+        schema_store,
+    )
+    .lint(&source)
+    .await
+    .is_err()
+    {
+        return true;
+    }
+
+    !extension_diagnostics(
+        &source,
+        source_path,
+        toml_version,
+        config,
+        schema_store,
+        extension_registry,
+    )
+    .await
+    .is_empty()
+}
+
+/// Warn about the `[[overrides]]` items with `lint.enabled = false` that suppressed nothing.
+fn unused_override_diagnostics(
+    config_path: &std::path::Path,
+    override_indices: &std::collections::BTreeSet<usize>,
+) -> Vec<Diagnostic> {
+    if override_indices.is_empty() {
+        return Vec::new();
+    }
+
+    let Ok(config_text) = std::fs::read_to_string(config_path) else {
+        return Vec::new();
+    };
+    let Some(root) = tombi_ast::Root::cast(tombi_parser::parse(&config_text).into_syntax_node())
+    else {
+        return Vec::new();
+    };
+    let document_tree = root
+        .into_document_tree_and_errors(TomlVersion::default())
+        .tree;
+
+    // `overrides` in tombi.toml, `tool.tombi.overrides` in pyproject.toml.
+    let overrides = [&["overrides"][..], &["tool", "tombi", "overrides"][..]]
+        .into_iter()
+        .find_map(
+            |keys| match tombi_document_tree::dig_keys(&document_tree, keys) {
+                Some((_, tombi_document_tree::Value::Array(overrides))) => Some(overrides),
+                _ => None,
+            },
+        );
+    let override_ranges = overrides
+        .map(|overrides| {
+            overrides
+                .values()
+                .iter()
+                .map(override_lint_enabled_range)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    override_indices
+        .iter()
+        .map(|override_index| {
+            Diagnostic::new_warning(
+                "`lint.enabled = false` suppresses nothing. Please remove it.",
+                "unused-directive",
+                override_ranges
+                    .get(*override_index)
+                    .copied()
+                    .unwrap_or_default(),
+            )
+            .with_source_file(config_path)
+        })
+        .collect()
+}
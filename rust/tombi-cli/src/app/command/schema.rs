@@ -0,0 +1,52 @@
+pub mod list;
+pub mod pull;
+pub mod show;
+pub mod which;
+
+/// Inspect and download JSON Schemas.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    #[command(subcommand)]
+    subcommand: SchemaCommand,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum SchemaCommand {
+    Which(which::Args),
+    Show(show::Args),
+    List(list::Args),
+    Pull(pull::Args),
+}
+
+pub fn run(args: Args) -> Result<(), crate::Error> {
+    let result = match args.subcommand {
+        SchemaCommand::Which(args) => which::run(args),
+        SchemaCommand::Show(args) => show::run(args),
+        SchemaCommand::List(args) => list::run(args),
+        SchemaCommand::Pull(args) => pull::run(args),
+    };
+
+    if let Err(error) = result {
+        log::error!("{}", error);
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Describe where a schema association comes from.
+fn schema_source(
+    schema: &tombi_schema_store::Schema,
+    config_path: Option<&std::path::Path>,
+) -> String {
+    match (schema.source, &schema.catalog_uri, config_path) {
+        (tombi_schema_store::SchemaSource::Catalog, Some(catalog_uri), _) => {
+            format!("catalog ({catalog_uri})")
+        }
+        (tombi_schema_store::SchemaSource::Config, _, Some(config_path)) => {
+            format!("[[schemas]] ({})", config_path.display())
+        }
+        (tombi_schema_store::SchemaSource::Config, _, None) => "[[schemas]]".to_string(),
+        (source, _, _) => source.to_string(),
+    }
+}
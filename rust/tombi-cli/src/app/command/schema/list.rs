@@ -0,0 +1,39 @@
+use itertools::Itertools;
+
+use crate::app::CommonArgs;
+
+/// List the schemas loaded from the config and the catalogs.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+pub fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let (config, config_path, _) =
+        serde_tombi::config::load_with_path_and_level(std::env::current_dir().ok())?;
+    let schema_store = crate::app::new_schema_store(&args.common, Some(&config));
+
+    crate::app::new_runtime()?.block_on(async {
+        schema_store
+            .load_config(&config, config_path.as_deref())
+            .await?;
+
+        for schema in schema_store.list_schemas().await {
+            match &schema.title {
+                Some(title) => println!("{title}: {}", schema.schema_uri),
+                None => println!("{}", schema.schema_uri),
+            }
+            println!(
+                "    source: {}",
+                super::schema_source(&schema, config_path.as_deref())
+            );
+            println!("    include: {}", schema.include.iter().join(", "));
+            if let Some(toml_version) = schema.toml_version {
+                println!("    toml-version: {toml_version}");
+            }
+        }
+
+        Ok(())
+    })
+}
@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use crate::app::CommonArgs;
+
+/// Download a schema catalog and its schemas into a local directory.
+///
+/// The TOML schemas of the catalog and their transitive remote `$ref` targets are downloaded,
+/// and the remote references are rewritten to relative paths.
+/// Point `schema.catalog.paths` at the written `catalog.json` to resolve every schema locally.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Schema catalog path or URL to download
+    #[arg(long, value_name = "URL", default_value = tombi_schema_store::json::DEFAULT_CATALOG_URL)]
+    catalog: String,
+
+    /// Directory to write the catalog and schemas to
+    #[arg(long, value_name = "DIR", default_value = "schema-bundle")]
+    out: PathBuf,
+
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+pub fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let catalog_uri = tombi_config::SchemaCatalogPath::from(args.catalog.as_str())
+        .try_to_catalog_url(std::env::current_dir().ok().as_deref())
+        .map(tombi_schema_store::CatalogUri::from)
+        .map_err(|_| format!("invalid catalog path or URL: {}", args.catalog))?;
+
+    let schema_store = crate::app::new_schema_store(&args.common, None);
+    let bundle = crate::app::new_runtime()?
+        .block_on(schema_store.pull_schema_bundle(&catalog_uri, &args.out))?;
+
+    for error in &bundle.errors {
+        log::error!("{}", error);
+    }
+
+    match bundle.schema_count {
+        1 => eprintln!("1 schema pulled"),
+        n => eprintln!("{n} schemas pulled"),
+    }
+    eprintln!("Catalog written to {}", bundle.catalog_path.display());
+
+    match bundle.errors.len() {
+        0 => Ok(()),
+        1 => Err("1 schema failed to be pulled".into()),
+        n => Err(format!("{n} schemas failed to be pulled").into()),
+    }
+}
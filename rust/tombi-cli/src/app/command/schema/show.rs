@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use tombi_schema_store::{Accessor, PatternAccessor, SchemaUri};
+
+use crate::app::CommonArgs;
+
+/// Print a schema, or its subschema at a key path.
+///
+/// `$ref`s are resolved, and the JSON of the resolved subschema is printed.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Schema URI or file path
+    schema: String,
+
+    /// Key path of the subschema, such as `package.edition` or `bin[0].name`
+    #[arg(long, value_name = "KEYS")]
+    at: Option<String>,
+
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+pub fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let schema_uri = match SchemaUri::from_str(&args.schema) {
+        Ok(schema_uri) => schema_uri,
+        Err(_) => std::path::absolute(&args.schema)
+            .ok()
+            .and_then(|schema_path| SchemaUri::from_file_path(schema_path).ok())
+            .ok_or_else(|| format!("invalid schema URI or file path: {}", args.schema))?,
+    };
+    let accessors = match &args.at {
+        Some(keys) => parse_accessors(keys)?,
+        None => Vec::new(),
+    };
+
+    let schema_store = crate::app::new_schema_store(&args.common, None);
+
+    crate::app::new_runtime()?.block_on(async {
+        let Some(current_schema) = schema_store
+            .resolve_sub_schema(&schema_uri, &accessors)
+            .await?
+        else {
+            return Err(match &args.at {
+                Some(keys) => format!("schema not found at `{keys}` in {schema_uri}"),
+                None => format!("schema not found: {schema_uri}"),
+            }
+            .into());
+        };
+
+        let Some(schema_value) = schema_store
+            .fetch_current_schema_value(&current_schema)
+            .await?
+        else {
+            return Err(format!("schema not found: {}", current_schema.schema_uri).into());
+        };
+
+        let schema_value = tombi_json::from_value_node::<serde_json::Value>(schema_value)?;
+        println!("{}", serde_json::to_string_pretty(&schema_value)?);
+
+        Ok(())
+    })
+}
+
+fn parse_accessors(keys: &str) -> Result<Vec<Accessor>, String> {
+    PatternAccessor::parse(keys)
+        .and_then(|pattern_accessors| {
+            pattern_accessors
+                .into_iter()
+                .map(|pattern_accessor| match pattern_accessor {
+                    PatternAccessor::Key(key) => Some(Accessor::Key(key)),
+                    PatternAccessor::Index(index) => Some(Accessor::Index(index)),
+                    PatternAccessor::AnyKey | PatternAccessor::AnyIndex => None,
+                })
+                .collect()
+        })
+        .ok_or_else(|| format!("invalid key path: {keys}"))
+}
@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use itertools::Either;
+use tombi_schema_store::{PatternAccessors, SchemaUri};
+
+use crate::app::CommonArgs;
+
+/// Print the schema applied to a TOML file.
+///
+/// The schema URI, where the association comes from, and the effective TOML version are printed.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// TOML file to inspect
+    file: PathBuf,
+
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+pub fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let (config, config_path, _) =
+        serde_tombi::config::load_with_path_and_level(std::env::current_dir().ok())?;
+    let source = std::fs::read_to_string(&args.file)?;
+    let source_path = std::fs::canonicalize(&args.file).unwrap_or_else(|_| args.file.clone());
+    let schema_store = crate::app::new_schema_store(&args.common, Some(&config));
+
+    crate::app::new_runtime()?.block_on(async {
+        schema_store
+            .load_config(&config, config_path.as_deref())
+            .await?;
+
+        let (root, _) = tombi_parser::parse(&source).into_root_and_errors();
+        let source_schema = schema_store
+            .resolve_source_schema_from_ast(&root, Some(Either::Right(&source_path)))
+            .await
+            .map_err(|(error, _)| error)?;
+        let directive_schema_uri = root
+            .schema_document_comment_directive(Some(&source_path))
+            .and_then(|directive| directive.uri.ok());
+        let matching_schemas = schema_store.list_matching_schemas(&source_path).await;
+
+        let association_source = |schema_uri: &SchemaUri| {
+            if directive_schema_uri.as_ref() == Some(schema_uri) {
+                return "#:schema directive".to_string();
+            }
+            matching_schemas
+                .iter()
+                .find(|schema| &schema.schema_uri == schema_uri)
+                .map(|schema| super::schema_source(schema, config_path.as_deref()))
+                .unwrap_or_else(|| "unknown".to_string())
+        };
+
+        match source_schema
+            .as_ref()
+            .and_then(|source_schema| source_schema.root_schema.as_ref())
+        {
+            Some(root_schema) => {
+                println!("schema: {}", root_schema.schema_uri);
+                println!("source: {}", association_source(&root_schema.schema_uri));
+            }
+            None => println!("schema: none"),
+        }
+
+        if let Some(source_schema) = &source_schema {
+            for (accessors, schema_uri) in &source_schema.sub_schema_uri_map {
+                println!(
+                    "sub-schema [{}]: {} ({})",
+                    PatternAccessors::from(accessors.clone()),
+                    schema_uri,
+                    association_source(schema_uri)
+                );
+            }
+        }
+
+        let toml_version =
+            tombi_validator::comment_directive::get_tombi_document_comment_directive(&root)
+                .await
+                .and_then(|directive| directive.toml_version)
+                .or_else(|| {
+                    source_schema
+                        .as_ref()
+                        .and_then(|source_schema| source_schema.toml_version())
+                })
+                .or(config.toml_version)
+                .unwrap_or_default();
+        println!("toml-version: {toml_version}");
+
+        Ok(())
+    })
+}
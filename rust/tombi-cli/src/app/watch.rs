@@ -0,0 +1,354 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use itertools::Itertools;
+use notify::Watcher;
+use tombi_config::{Config, ConfigLevel, config_base_dir};
+
+/// Wait for the burst of events of a single save to settle.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+pub enum WatchEvent {
+    /// TOML files changed. Re-run only on them.
+    Changed(Vec<PathBuf>),
+
+    /// The config file or a local schema file changed. Reload them and re-run on all files.
+    Reload,
+}
+
+/// Watch the file search roots, the config file and the local schema files.
+pub struct FileWatcher {
+    _watcher: notify::RecommendedWatcher,
+    receiver: tokio::sync::mpsc::UnboundedReceiver<notify::Event>,
+    reload_paths: Vec<PathBuf>,
+}
+
+impl FileWatcher {
+    pub fn new(
+        files: &[String],
+        config: &Config,
+        config_path: Option<&Path>,
+        config_level: ConfigLevel,
+    ) -> Result<Self, notify::Error> {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+                Ok(event) => {
+                    let _ = sender.send(event);
+                }
+                Err(err) => log::warn!("Failed to watch files: {err}"),
+            })?;
+
+        let root = match config_level {
+            ConfigLevel::Project => config_path
+                .and_then(config_base_dir)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(".")),
+            _ => PathBuf::from("."),
+        };
+
+        if files.is_empty() || files.iter().any(|file| is_glob_pattern(file)) {
+            watcher.watch(&root, notify::RecursiveMode::Recursive)?;
+        }
+        for file in files.iter().filter(|file| !is_glob_pattern(file)) {
+            let path = Path::new(file);
+            if path.exists() {
+                watcher.watch(path, notify::RecursiveMode::Recursive)?;
+            }
+        }
+
+        let reload_paths = reload_paths(config, config_path);
+        for reload_path in &reload_paths {
+            // Editors often save by renaming, so watch the directory instead of the file.
+            if let Some(dir) = reload_path.parent()
+                && let Err(err) = watcher.watch(dir, notify::RecursiveMode::NonRecursive)
+            {
+                log::warn!("Failed to watch {dir:?}: {err}");
+            }
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            receiver,
+            reload_paths,
+        })
+    }
+
+    /// Wait for the next change.
+    pub async fn next(&mut self) -> Option<WatchEvent> {
+        loop {
+            let paths = self.next_changed_paths().await?;
+
+            if paths
+                .iter()
+                .any(|path| self.reload_paths.iter().any(|reload| reload == path))
+            {
+                return Some(WatchEvent::Reload);
+            }
+
+            let paths = paths
+                .into_iter()
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|extension| extension == "toml")
+                })
+                .collect_vec();
+
+            if !paths.is_empty() {
+                return Some(WatchEvent::Changed(paths));
+            }
+        }
+    }
+
+    /// Drop the pending changes, e.g. the ones caused by writing formatted files.
+    pub async fn skip_pending(&mut self) {
+        tokio::time::sleep(DEBOUNCE_DURATION).await;
+        while self.receiver.try_recv().is_ok() {}
+    }
+
+    async fn next_changed_paths(&mut self) -> Option<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        loop {
+            let event = if paths.is_empty() {
+                self.receiver.recv().await?
+            } else {
+                match tokio::time::timeout(DEBOUNCE_DURATION, self.receiver.recv()).await {
+                    Ok(Some(event)) => event,
+                    Ok(None) | Err(_) => break,
+                }
+            };
+
+            if matches!(
+                event.kind,
+                notify::EventKind::Create(_)
+                    | notify::EventKind::Modify(_)
+                    | notify::EventKind::Remove(_)
+            ) {
+                paths.extend(event.paths);
+            }
+        }
+
+        Some(
+            paths
+                .into_iter()
+                .map(|path| path.canonicalize().unwrap_or(path))
+                .unique()
+                .collect_vec(),
+        )
+    }
+}
+
+/// Select the changed files to run on again, without walking the project.
+///
+/// The changed files are limited like the file search: to the file arguments,
+/// to `files.include`/`files.exclude` and to the files selected by the git filter.
+pub async fn filter_changed_files(
+    changed_paths: Vec<PathBuf>,
+    files: &[String],
+    config: &Config,
+    config_path: Option<&Path>,
+    config_level: ConfigLevel,
+    git_filter: Option<&tombi_glob::GitFilter>,
+) -> tombi_glob::FileSearch {
+    let changed_paths = match git_filter {
+        Some(git_filter) => match git_filter.changed_paths(Path::new(".")).await {
+            Ok(git_paths) => {
+                let git_paths = git_paths
+                    .into_iter()
+                    .map(|path| path.canonicalize().unwrap_or(path))
+                    .collect_vec();
+                changed_paths
+                    .into_iter()
+                    .filter(|path| git_paths.contains(path))
+                    .collect_vec()
+            }
+            Err(err) => {
+                return tombi_glob::FileSearch::Files(vec![tombi_glob::FileSearchEntry::Error(
+                    err,
+                )]);
+            }
+        },
+        None => changed_paths,
+    };
+
+    tombi_glob::FileSearch::new_with_paths(files, config, config_path, config_level, changed_paths)
+}
+
+/// The config file and the local schema and catalog files.
+fn reload_paths(config: &Config, config_path: Option<&Path>) -> Vec<PathBuf> {
+    let Some(config_path) = config_path else {
+        return Vec::new();
+    };
+    let base_dir = config_base_dir(config_path).unwrap_or(Path::new("."));
+
+    let schema_paths = config
+        .schemas
+        .iter()
+        .flatten()
+        .map(|schema| schema.path().to_string());
+    let catalog_paths = config
+        .schema
+        .as_ref()
+        .and_then(|schema| schema.catalog_paths())
+        .into_iter()
+        .flatten()
+        .map(|catalog_path| catalog_path.value().to_string());
+
+    std::iter::once(config_path.to_path_buf())
+        .chain(
+            schema_paths
+                .chain(catalog_paths)
+                .filter(|path| !path.contains("://"))
+                .map(|path| base_dir.join(path)),
+        )
+        .map(|path| path.canonicalize().unwrap_or(path))
+        .collect()
+}
+
+fn is_glob_pattern(path: &str) -> bool {
+    path.chars().any(|c| matches!(c, '*' | '?' | '[' | ']'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_files(dir: &Path, files: &[(&str, &str)]) {
+        for (path, text) in files {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        }
+    }
+
+    fn load_config(config_path: &Path) -> Config {
+        serde_tombi::config::try_from_path(config_path)
+            .unwrap()
+            .unwrap()
+    }
+
+    async fn changed_files(dir: &Path, files: &[&str], changed_paths: &[&str]) -> Vec<PathBuf> {
+        let config_path = dir.join("tombi.toml");
+        let config = load_config(&config_path);
+        let files = files
+            .iter()
+            .map(|file| dir.join(file).to_string_lossy().to_string())
+            .collect_vec();
+        let changed_paths = changed_paths
+            .iter()
+            .map(|path| dir.join(path))
+            .collect_vec();
+
+        let tombi_glob::FileSearch::Files(entries) = filter_changed_files(
+            changed_paths,
+            &files,
+            &config,
+            Some(&config_path),
+            ConfigLevel::Project,
+            None,
+        )
+        .await
+        else {
+            panic!("expected files");
+        };
+
+        entries
+            .into_iter()
+            .map(|entry| match entry {
+                tombi_glob::FileSearchEntry::Found(path) => {
+                    path.strip_prefix(dir).unwrap().to_path_buf()
+                }
+                entry => panic!("unexpected entry: {entry:?}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn filter_changed_files_by_include_and_exclude() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().canonicalize().unwrap();
+        write_files(
+            &dir,
+            &[
+                (
+                    "tombi.toml",
+                    "[files]\ninclude = [\"**/*.toml\"]\nexclude = [\"vendor/**\"]\n",
+                ),
+                ("a.toml", ""),
+                ("sub/b.toml", ""),
+                ("vendor/c.toml", ""),
+            ],
+        );
+
+        pretty_assertions::assert_eq!(
+            changed_files(
+                &dir,
+                &[],
+                &["a.toml", "sub/b.toml", "vendor/c.toml", "deleted.toml"],
+            )
+            .await,
+            vec![PathBuf::from("a.toml"), PathBuf::from("sub/b.toml")]
+        );
+    }
+
+    #[tokio::test]
+    async fn filter_changed_files_by_file_arguments() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().canonicalize().unwrap();
+        write_files(
+            &dir,
+            &[("tombi.toml", ""), ("a.toml", ""), ("sub/b.toml", "")],
+        );
+
+        pretty_assertions::assert_eq!(
+            changed_files(&dir, &["sub"], &["a.toml", "sub/b.toml"]).await,
+            vec![PathBuf::from("sub/b.toml")]
+        );
+    }
+
+    #[test]
+    fn reload_paths_include_local_schemas_and_catalogs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().canonicalize().unwrap();
+        write_files(
+            &dir,
+            &[
+                (
+                    "tombi.toml",
+                    r#"
+[schema]
+catalog.paths = ["catalog.json", "https://www.schemastore.org/api/json/catalog.json"]
+
+[[schemas]]
+path = "schemas/local.schema.json"
+include = ["local.toml"]
+
+[[schemas]]
+path = "https://example.com/remote.schema.json"
+include = ["remote.toml"]
+"#,
+                ),
+                ("catalog.json", "{}"),
+                ("schemas/local.schema.json", "{}"),
+            ],
+        );
+        let config_path = dir.join("tombi.toml");
+
+        pretty_assertions::assert_eq!(
+            reload_paths(&load_config(&config_path), Some(&config_path)),
+            vec![
+                config_path.clone(),
+                dir.join("schemas/local.schema.json"),
+                dir.join("catalog.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reload_paths_without_config_file() {
+        assert!(reload_paths(&Config::default(), None).is_empty());
+    }
+}
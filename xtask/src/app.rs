@@ -35,6 +35,8 @@ pub fn run(args: impl Into<Args>) -> Result<(), anyhow::Error> {
             }
             command::CodeGenCommand::Grammar => command::codegen_grammar::run()?,
             command::CodeGenCommand::Jsonschema => command::codegen_jsonschema::run()?,
+            command::CodeGenCommand::Lints => command::codegen_lints::run()?,
+            command::CodeGenCommand::Targets => command::codegen_targets::run()?,
         },
         command::XTaskCommand::SetVersion => {
             command::set_version::run(&xshell::Shell::new().unwrap())?
@@ -1,6 +1,8 @@
 pub mod codegen;
 pub mod codegen_grammar;
 pub mod codegen_jsonschema;
+pub mod codegen_lints;
+pub mod codegen_targets;
 pub mod dist;
 pub mod set_version;
 pub mod toml_test;
@@ -4,4 +4,7 @@ pub enum CodeGenCommand {
     All,
     Grammar,
     Jsonschema,
+    /// Requires the Clippy component of the installed toolchain.
+    Lints,
+    Targets,
 }
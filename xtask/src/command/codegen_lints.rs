@@ -0,0 +1,125 @@
+use crate::utils::project_root_path;
+
+/// A lint or lint group listed by `-W help`.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Lint {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// The lints of a lint group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lints: Option<Vec<String>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct LintTable {
+    rustc_version: String,
+    rust: Vec<Lint>,
+    clippy: Vec<Lint>,
+    rustdoc: Vec<Lint>,
+}
+
+/// Generate the lint table bundled with the Cargo extension from the installed toolchain.
+///
+/// Requires `rustc`, `rustdoc` and `clippy-driver` on `PATH`.
+pub fn run() -> Result<(), anyhow::Error> {
+    let sh = xshell::Shell::new()?;
+
+    let rustc_version = xshell::cmd!(sh, "rustc --version").read()?;
+    let lint_table = LintTable {
+        rustc_version,
+        rust: parse_lints(&xshell::cmd!(sh, "rustc -W help").read()?, None),
+        clippy: parse_lints(
+            &xshell::cmd!(sh, "clippy-driver -W help").read()?,
+            Some("clippy"),
+        ),
+        rustdoc: parse_lints(
+            &xshell::cmd!(sh, "rustdoc -W help").read()?,
+            Some("rustdoc"),
+        ),
+    };
+
+    std::fs::write(
+        project_root_path()
+            .join("extensions")
+            .join("tombi-extension-cargo")
+            .join("lints.json"),
+        serde_json::to_string_pretty(&lint_table)? + "\n",
+    )?;
+
+    Ok(())
+}
+
+/// Parse the output of `-W help`, keeping the lints of the tool.
+///
+/// Lint names are converted to the snake case used in `Cargo.toml`.
+fn parse_lints(output: &str, tool: Option<&str>) -> Vec<Lint> {
+    let mut lints = Vec::new();
+    let mut in_groups = false;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if line.starts_with("Lint checks ") {
+            in_groups = false;
+            continue;
+        }
+        if line.starts_with("Lint groups ") {
+            in_groups = true;
+            continue;
+        }
+
+        let Some((name, rest)) = line.split_once("  ") else {
+            continue;
+        };
+        let name = match (tool, name.split_once("::")) {
+            (None, None) => name,
+            (Some(tool), Some((prefix, name))) if prefix == tool => name,
+            _ => continue,
+        };
+        let rest = rest.trim_start();
+        if name == "name" || name.starts_with('-') {
+            continue;
+        }
+        let name = name.replace('-', "_");
+
+        if in_groups {
+            let members = rest
+                .split(", ")
+                .map(|member| {
+                    member
+                        .split_once("::")
+                        .map_or(member, |(_, member)| member)
+                        .replace('-', "_")
+                })
+                .collect::<Vec<_>>();
+            // `warnings` describes its lints instead of listing them, and is also listed as a lint.
+            if members.iter().any(|member| member.contains(' ')) {
+                continue;
+            }
+            lints.push(Lint {
+                name,
+                level: None,
+                description: None,
+                lints: Some(members),
+            });
+        } else {
+            let Some((level, description)) = rest.split_once(char::is_whitespace) else {
+                continue;
+            };
+            lints.push(Lint {
+                name,
+                level: Some(level.to_string()),
+                description: Some(description.trim().to_string()),
+                lints: None,
+            });
+        }
+    }
+
+    lints.sort_by(|a, b| a.name.cmp(&b.name));
+    lints.dedup_by(|a, b| a.name == b.name);
+    lints
+}
@@ -0,0 +1,82 @@
+use crate::utils::project_root_path;
+
+/// The cfg names and keys of `rustc --print cfg` kept in the target table.
+///
+/// Target features and atomics are left out, as they depend on `-C target-feature`.
+const TARGET_CFG_NAMES: &[&str] = &[
+    "target_abi",
+    "target_arch",
+    "target_endian",
+    "target_env",
+    "target_family",
+    "target_os",
+    "target_pointer_width",
+    "target_vendor",
+    "unix",
+    "windows",
+];
+
+/// A built-in target with its cfg in the `rustc --print cfg` format.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Target {
+    triple: String,
+    cfg: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct TargetTable {
+    rustc_version: String,
+    targets: Vec<Target>,
+}
+
+/// Generate the target table bundled with the Cargo extension from the installed toolchain.
+///
+/// Requires `rustc` on `PATH`.
+pub fn run() -> Result<(), anyhow::Error> {
+    let sh = xshell::Shell::new()?;
+
+    let rustc_version = xshell::cmd!(sh, "rustc --version").read()?;
+    let mut targets = Vec::new();
+    for triple in xshell::cmd!(sh, "rustc --print target-list")
+        .read()?
+        .lines()
+    {
+        let output = xshell::cmd!(sh, "rustc --print cfg --target {triple}").read()?;
+        targets.push(Target {
+            triple: triple.to_string(),
+            cfg: parse_cfg(&output),
+        });
+    }
+    targets.sort_by(|a, b| a.triple.cmp(&b.triple));
+
+    std::fs::write(
+        project_root_path()
+            .join("extensions")
+            .join("tombi-extension-cargo")
+            .join("targets.json"),
+        serde_json::to_string_pretty(&TargetTable {
+            rustc_version,
+            targets,
+        })? + "\n",
+    )?;
+
+    Ok(())
+}
+
+/// Parse the output of `rustc --print cfg`, keeping the cfg of [`TARGET_CFG_NAMES`].
+fn parse_cfg(output: &str) -> Vec<String> {
+    let mut cfg = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| {
+            let name = line.split_once('=').map_or(*line, |(name, _)| name);
+            TARGET_CFG_NAMES.contains(&name)
+        })
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    cfg.sort();
+    cfg.dedup();
+    cfg
+}